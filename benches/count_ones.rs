@@ -0,0 +1,27 @@
+use apint::{
+    ApInt,
+    BitWidth,
+};
+use criterion::{
+    black_box,
+    criterion_group,
+    criterion_main,
+    BenchmarkId,
+    Criterion,
+};
+
+// Run with `--features popcount_table_lookup` to compare the lookup table
+// strategy for large widths against the default popcount loop.
+fn count_ones(c: &mut Criterion) {
+    let mut group = c.benchmark_group("count_ones");
+    for &width in &[64, 128, 256, 1024, 4096, 16384] {
+        let apint = ApInt::random_with_width(BitWidth::new(width).unwrap());
+        group.bench_with_input(BenchmarkId::from_parameter(width), &apint, |b, apint| {
+            b.iter(|| black_box(apint).count_ones())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, count_ones);
+criterion_main!(benches);
@@ -0,0 +1,46 @@
+use apint::{
+    ApInt,
+    BitWidth,
+};
+use criterion::{
+    black_box,
+    criterion_group,
+    criterion_main,
+    BenchmarkId,
+    Criterion,
+};
+
+/// Parses `input` one decimal character at a time by multiplying with `10`
+/// and adding the digit.
+fn from_decimal_by_chars(input: &str, width: BitWidth) -> ApInt {
+    let mut result = ApInt::zero(width);
+    for c in input.bytes() {
+        result.wrapping_mul_u64_assign(10);
+        result.wrapping_add_u64_assign(u64::from(c - b'0'));
+    }
+    result
+}
+
+// Compares decimal parsing of `from_str_radix` against parsing one character
+// at a time.
+fn from_decimal(c: &mut Criterion) {
+    let mut group = c.benchmark_group("from_decimal");
+    group.sample_size(10);
+    for &len in &[10_000, 100_000] {
+        // `log2(10) < 3.33` bits are required per decimal digit
+        let width = BitWidth::new(len * 333 / 100 + 1).unwrap();
+        let input = ApInt::random_with_width(width).to_string_radix(10);
+        group.bench_with_input(
+            BenchmarkId::new("from_str_radix", len),
+            &input,
+            |b, input| b.iter(|| ApInt::from_str_radix(black_box(input), 10, width)),
+        );
+        group.bench_with_input(BenchmarkId::new("chars", len), &input, |b, input| {
+            b.iter(|| from_decimal_by_chars(black_box(input), width))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, from_decimal);
+criterion_main!(benches);
@@ -0,0 +1,47 @@
+use apint::{
+    ApInt,
+    BitWidth,
+};
+use criterion::{
+    black_box,
+    criterion_group,
+    criterion_main,
+    BenchmarkId,
+    Criterion,
+};
+
+// Compares the fused `mul_add_assign` against a multiplication into a
+// temporary followed by an addition.
+fn mul_add(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mul_add");
+    for &width in &[256, 2048] {
+        let width = BitWidth::new(width).unwrap();
+        let acc = ApInt::random_with_width(width);
+        let a = ApInt::random_with_width(width);
+        let b = ApInt::random_with_width(width);
+        group.bench_with_input(
+            BenchmarkId::new("fused", width.to_usize()),
+            &width,
+            |bench, _| {
+                let mut acc = acc.clone();
+                bench.iter(|| acc.wrapping_mul_add_assign(black_box(&a), black_box(&b)))
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("two_step", width.to_usize()),
+            &width,
+            |bench, _| {
+                let mut acc = acc.clone();
+                bench.iter(|| {
+                    let product =
+                        black_box(&a).clone().into_wrapping_mul(black_box(&b))?;
+                    acc.wrapping_add_assign(&product)
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, mul_add);
+criterion_main!(benches);
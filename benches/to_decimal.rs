@@ -0,0 +1,45 @@
+use apint::{
+    ApInt,
+    BitWidth,
+};
+use criterion::{
+    black_box,
+    criterion_group,
+    criterion_main,
+    BenchmarkId,
+    Criterion,
+};
+
+/// Formats `apint` by repeated division through `10^19` which is the
+/// quadratic algorithm that `to_string_radix` uses for small values.
+fn to_decimal_by_chunks(apint: &ApInt) -> String {
+    let chunks = apint.decimal_chunks().collect::<Vec<_>>();
+    let mut chunks = chunks.iter().rev();
+    let mut result = chunks.next().unwrap().to_string();
+    for chunk in chunks {
+        result.push_str(&format!("{:019}", chunk));
+    }
+    result
+}
+
+// Compares the divide and conquer decimal conversion of `to_string_radix`
+// against repeated division by `10^19`.
+fn to_decimal(c: &mut Criterion) {
+    let mut group = c.benchmark_group("to_decimal");
+    group.sample_size(10);
+    for &width in &[10_000, 100_000] {
+        let apint = ApInt::random_with_width(BitWidth::new(width).unwrap());
+        group.bench_with_input(
+            BenchmarkId::new("divide_and_conquer", width),
+            &apint,
+            |b, apint| b.iter(|| black_box(apint).to_string_radix(10)),
+        );
+        group.bench_with_input(BenchmarkId::new("chunks", width), &apint, |b, apint| {
+            b.iter(|| to_decimal_by_chunks(black_box(apint)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, to_decimal);
+criterion_main!(benches);
@@ -0,0 +1,19 @@
+#![no_main]
+
+use apint::{
+    ApInt,
+    Width,
+};
+use libfuzzer_sys::fuzz_target;
+
+// Compares `wrapping_add` of `ApInt`s with up to 128 bits against `u128`.
+fuzz_target!(|operands: (ApInt, ApInt)| {
+    let (lhs, rhs) = operands;
+    let width = lhs.width().to_usize().min(128);
+    let lhs = lhs.into_zero_resize(width);
+    let rhs = rhs.into_zero_resize(width);
+    let mask = u128::max_value() >> (128 - width);
+    let expected = lhs.resize_to_u128().wrapping_add(rhs.resize_to_u128()) & mask;
+    let result = lhs.into_wrapping_add(&rhs).unwrap();
+    assert_eq!(result.resize_to_u128(), expected);
+});
@@ -0,0 +1,122 @@
+use crate::{
+    ApInt,
+    BitWidth,
+    Int,
+    UInt,
+};
+use arbitrary::{
+    Arbitrary,
+    Result,
+    Unstructured,
+};
+
+/// The maximum bit width of `ApInt`s created from unstructured data.
+///
+/// This keeps fuzz iterations fast while still covering many `Digit`s.
+const MAX_ARBITRARY_WIDTH: u16 = 4096;
+
+/// The number of bytes consumed to choose the bit width.
+const WIDTH_BYTES: usize = core::mem::size_of::<u16>();
+
+/// Creates `ApInt`s from unstructured data, e.g. for fuzzing.
+///
+/// The first two bytes choose a bit width of at most 4096 bits. The bits are
+/// then filled from the remaining bytes in little-endian order, missing
+/// bytes are treated as zero once the data is exhausted.
+impl<'a> Arbitrary<'a> for ApInt {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<ApInt> {
+        let width = usize::from(u.int_in_range(1..=MAX_ARBITRARY_WIDTH)?);
+        let len_bytes = width.div_ceil(8);
+        let bytes = u.bytes(len_bytes.min(u.len()))?;
+        let result = BitWidth::new(len_bytes * 8)
+            .and_then(|byte_width| ApInt::from_bytes_le(byte_width, bytes))
+            .and_then(|value| value.into_truncate(width))
+            .expect(
+                "The bit width is at least `1` and there are at most as many bytes as \
+                 required for it so we can expect the construction to be successful.",
+            );
+        Ok(result)
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (
+            WIDTH_BYTES,
+            Some(WIDTH_BYTES + usize::from(MAX_ARBITRARY_WIDTH) / 8),
+        )
+    }
+}
+
+/// For more details look into the `Arbitrary` implementation of `ApInt`.
+impl<'a> Arbitrary<'a> for UInt {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<UInt> {
+        ApInt::arbitrary(u).map(UInt::from)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        ApInt::size_hint(depth)
+    }
+}
+
+/// For more details look into the `Arbitrary` implementation of `ApInt`.
+impl<'a> Arbitrary<'a> for Int {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Int> {
+        ApInt::arbitrary(u).map(Int::from)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        ApInt::size_hint(depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Width;
+
+    #[test]
+    fn empty() {
+        let mut u = Unstructured::new(&[]);
+        assert_eq!(ApInt::arbitrary(&mut u).unwrap(), ApInt::zero(BitWidth::w1()));
+    }
+
+    #[test]
+    fn width_and_bits() {
+        let mut data = vec![0_u8; WIDTH_BYTES];
+        data.extend_from_slice(&[0xFF; 512]);
+        for width_byte in 0..=255 {
+            data[0] = width_byte;
+            data[1] = width_byte;
+            let value = ApInt::arbitrary(&mut Unstructured::new(&data)).unwrap();
+            let width = value.width().to_usize();
+            assert!((1..=usize::from(MAX_ARBITRARY_WIDTH)).contains(&width));
+            assert_eq!(value, ApInt::all_set(value.width()));
+        }
+    }
+
+    #[test]
+    fn zero_padding() {
+        let data = [0x00, 0x7F, 0x12, 0x34];
+        let value = ApInt::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert_eq!(value.width().to_usize(), 128);
+        assert_eq!(value, ApInt::from_u128(0x3412));
+    }
+
+    #[test]
+    fn consumes_at_most_size_hint() {
+        let data = [0xFF_u8; 1024];
+        let (min, max) = ApInt::size_hint(0);
+        let mut u = Unstructured::new(&data);
+        let value = ApInt::arbitrary(&mut u).unwrap();
+        let consumed = data.len() - u.len();
+        assert!(min <= consumed && consumed <= max.unwrap());
+        assert_eq!(consumed, WIDTH_BYTES + value.width().to_usize().div_ceil(8));
+    }
+
+    #[test]
+    fn wrappers() {
+        let data = [0x00, 0x3F, 0x80];
+        let uint = UInt::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        let int = Int::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert_eq!(uint.into_apint(), int.into_apint());
+    }
+}
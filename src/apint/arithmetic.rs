@@ -17,13 +17,21 @@ use crate::{
         try_forward_bin_mut_impl,
     },
     ApInt,
+    BitPos,
+    BitWidth,
     Digit,
+    DigitRepr,
     DoubleDigit,
     Error,
     Result,
     Width,
 };
 
+use core::{
+    borrow::Borrow,
+    mem,
+};
+
 /// # Basic Arithmetic Operations
 ///
 /// **Note**: unless otherwise noted in the function specific documentation,
@@ -94,11 +102,207 @@ impl ApInt {
         forward_mut_impl(self, ApInt::wrapping_dec)
     }
 
+    /// Increments this `ApInt` by one inplace and returns `true` if it wrapped
+    /// around from `unsigned_max_value` to zero.
+    pub fn overflowing_inc(&mut self) -> bool {
+        self.overflowing_add_u64_assign(1)
+    }
+
+    /// Increments this `ApInt` by one and returns the result as well as a
+    /// boolean indicating if it wrapped around to zero.
+    pub fn into_overflowing_inc(mut self) -> (ApInt, bool) {
+        let overflow = self.overflowing_inc();
+        (self, overflow)
+    }
+
+    /// Decrements this `ApInt` by one inplace and returns `true` if it wrapped
+    /// around from zero to `unsigned_max_value`.
+    pub fn overflowing_dec(&mut self) -> bool {
+        self.overflowing_sub_u64_assign(1)
+    }
+
+    /// Decrements this `ApInt` by one and returns the result as well as a
+    /// boolean indicating if it wrapped around to `unsigned_max_value`.
+    pub fn into_overflowing_dec(mut self) -> (ApInt, bool) {
+        let overflow = self.overflowing_dec();
+        (self, overflow)
+    }
+
+    /// Add-assigns the scalar `rhs` to `self` inplace and returns `true` if
+    /// the result overflowed the bit width of `self`.
+    ///
+    /// The carry is only propagated as far as needed, so this is `O(1)`
+    /// amortized like `wrapping_inc` and does **not** allocate memory.
+    pub fn overflowing_add_u64_assign(&mut self, rhs: u64) -> bool {
+        let mut rhs = Digit::split_u64(rhs);
+        let mut carry = false;
+        for digit in self.as_digit_slice_mut() {
+            let addend = match rhs.next() {
+                Some(addend) => addend,
+                None if carry => Digit::ZERO,
+                None => break,
+            };
+            let (sum, carry0) = digit.overflowing_add(addend);
+            let (sum, carry1) = sum.overflowing_add(Digit(carry as DigitRepr));
+            *digit = sum;
+            carry = carry0 || carry1;
+        }
+        // for unaligned bit widths the carry may also end up in the unused bits
+        let overflow = carry
+            || rhs.any(|addend| !addend.is_zero())
+            || match self.width().excess_bits() {
+                Some(used) => !(self.most_significant_digit() >> used).is_zero(),
+                None => false,
+            };
+        self.clear_unused_bits();
+        overflow
+    }
+
+    /// Add-assigns the scalar `rhs` to `self` inplace.
+    ///
+    /// See `overflowing_add_u64_assign` for more information.
+    pub fn wrapping_add_u64_assign(&mut self, rhs: u64) {
+        self.overflowing_add_u64_assign(rhs);
+    }
+
+    /// Subtract-assigns the scalar `rhs` from `self` inplace and returns `true`
+    /// if the result underflowed, i.e. if `rhs` is greater than `self`.
+    ///
+    /// The borrow is only propagated as far as needed, so this is `O(1)`
+    /// amortized like `wrapping_dec` and does **not** allocate memory.
+    pub fn overflowing_sub_u64_assign(&mut self, rhs: u64) -> bool {
+        // since the unused bits are zero, the borrow out of the most significant
+        // digit is the borrow out of the most significant bit
+        let mut rhs = Digit::split_u64(rhs);
+        let mut borrow = false;
+        for digit in self.as_digit_slice_mut() {
+            let subtrahend = match rhs.next() {
+                Some(subtrahend) => subtrahend,
+                None if borrow => Digit::ZERO,
+                None => break,
+            };
+            let (diff, borrow0) = digit.overflowing_sub(subtrahend);
+            let (diff, borrow1) = diff.overflowing_sub(Digit(borrow as DigitRepr));
+            *digit = diff;
+            borrow = borrow0 || borrow1;
+        }
+        let borrow = borrow || rhs.any(|subtrahend| !subtrahend.is_zero());
+        self.clear_unused_bits();
+        borrow
+    }
+
+    /// Subtract-assigns the scalar `rhs` from `self` inplace.
+    ///
+    /// See `overflowing_sub_u64_assign` for more information.
+    pub fn wrapping_sub_u64_assign(&mut self, rhs: u64) {
+        self.overflowing_sub_u64_assign(rhs);
+    }
+
+    /// Returns the wrapping sum of all elements of `iter` with the given bit
+    /// width, which is zero for an empty iterator.
+    ///
+    /// # Errors
+    ///
+    /// - If any element does not have a bit width of `width`.
+    pub fn try_sum<I>(width: BitWidth, iter: I) -> Result<ApInt>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<ApInt>,
+    {
+        let mut acc = ApInt::zero(width);
+        for x in iter {
+            acc.wrapping_add_assign(x.borrow())?;
+        }
+        Ok(acc)
+    }
+
+    /// Returns the wrapping product of all elements of `iter` with the given
+    /// bit width, which is one for an empty iterator.
+    ///
+    /// # Errors
+    ///
+    /// - If any element does not have a bit width of `width`.
+    pub fn try_product<I>(width: BitWidth, iter: I) -> Result<ApInt>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<ApInt>,
+    {
+        let mut acc = ApInt::one(width);
+        for x in iter {
+            acc.wrapping_mul_assign(x.borrow())?;
+        }
+        Ok(acc)
+    }
+
+    /// Returns the factorial `n!` with the given bit width.
+    ///
+    /// # Errors
+    ///
+    /// - If `n!` does not fit into `width` bits.
+    pub fn factorial(width: BitWidth, n: u64) -> Result<ApInt> {
+        let mut acc = ApInt::one(width);
+        for i in 2..=n {
+            if acc.carrying_mul_u64_assign(i) != 0 {
+                return Err(Error::result_overflow(width))
+            }
+        }
+        Ok(acc)
+    }
+
+    /// Returns the binomial coefficient `n` choose `k` with the given bit
+    /// width, which is zero if `k > n`.
+    ///
+    /// This uses the multiplicative formula which divides after every step
+    /// and never computes full factorials.
+    ///
+    /// # Errors
+    ///
+    /// - If the binomial coefficient does not fit into `width` bits.
+    pub fn binomial(width: BitWidth, n: u64, k: u64) -> Result<ApInt> {
+        if k > n {
+            return Ok(ApInt::zero(width))
+        }
+        let k = k.min(n - k);
+        // The partial results `C(n - k + i, i)` grow monotonically towards the
+        // final result, so if that fits into `width` bits then every product
+        // with a `u64` factor before the division fits into `64` extra bits.
+        let mut acc = ApInt::one(BitWidth::new(width.to_usize() + 64).unwrap());
+        for i in 1..=k {
+            if acc.carrying_mul_u64_assign(n - k + i) != 0 {
+                return Err(Error::result_overflow(width))
+            }
+            // the division is always exact
+            acc.div_rem_u64_assign(i).unwrap();
+            if acc.width().to_usize() - acc.leading_zeros() > width.to_usize() {
+                return Err(Error::result_overflow(width))
+            }
+        }
+        Ok(acc.into_truncate(width).unwrap())
+    }
+
     /// Negates this `ApInt` inplace.
+    ///
+    /// **Note:** The negation of `signed_min_value` wraps around to itself.
     pub fn wrapping_neg(&mut self) {
-        self.bitnot();
-        self.wrapping_inc();
-        //`wrapping_inc` handles clearing the unused bits
+        // bitnot and increment in a single pass
+        match self.access_data_mut() {
+            DataAccessMut::Inl(x) => {
+                *x = (!*x).wrapping_add(Digit::ONE);
+            }
+            DataAccessMut::Ext(x) => {
+                let mut carry = true;
+                for digit in x.iter_mut() {
+                    if carry {
+                        let (v, c) = (!*digit).overflowing_add(Digit::ONE);
+                        *digit = v;
+                        carry = c;
+                    } else {
+                        *digit = !*digit;
+                    }
+                }
+            }
+        }
+        self.clear_unused_bits();
     }
 
     /// Negates this `ApInt` and returns the result.
@@ -106,6 +310,93 @@ impl ApInt {
         forward_mut_impl(self, ApInt::wrapping_neg)
     }
 
+    /// Negates this `ApInt` inplace and returns `true` if the negation was not
+    /// representable according to the **signed** interpretation, which is only
+    /// the case for `signed_min_value`.
+    pub fn overflowing_neg(&mut self) -> bool {
+        let negative = self.msb();
+        self.wrapping_neg();
+        // only `signed_min_value` keeps its sign on negation
+        negative && self.msb()
+    }
+
+    /// Negates this `ApInt` and returns the result as well as a boolean
+    /// indicating if overflow occured according to the **signed**
+    /// interpretation.
+    ///
+    /// See `overflowing_neg` for more information.
+    pub fn into_overflowing_neg(mut self) -> (ApInt, bool) {
+        let overflow = self.overflowing_neg();
+        (self, overflow)
+    }
+
+    /// Negates this `ApInt` and returns the result or `None` if `self` is
+    /// `signed_min_value`.
+    pub fn into_checked_neg(self) -> Option<ApInt> {
+        match self.into_overflowing_neg() {
+            (neg, false) => Some(neg),
+            (_, true) => None,
+        }
+    }
+
+    /// Converts this `ApInt` inplace into its absolute value according to the
+    /// **signed** interpretation.
+    ///
+    /// # Note
+    ///
+    /// - Does nothing for positive `ApInt` instances.
+    /// - The absolute value of `signed_min_value` wraps around to itself.
+    pub fn wrapping_abs(&mut self) {
+        self.overflowing_abs();
+    }
+
+    /// Returns the absolute value of this `ApInt` according to the **signed**
+    /// interpretation.
+    ///
+    /// See `wrapping_abs` for more information.
+    pub fn into_wrapping_abs(self) -> ApInt {
+        forward_mut_impl(self, ApInt::wrapping_abs)
+    }
+
+    /// Converts this `ApInt` inplace into its absolute value according to the
+    /// **signed** interpretation and returns `true` if the absolute value was
+    /// not representable, which is only the case for `signed_min_value`.
+    pub fn overflowing_abs(&mut self) -> bool {
+        if !self.msb() {
+            return false
+        }
+        self.wrapping_neg();
+        // only `signed_min_value` is still negative after negation
+        self.msb()
+    }
+
+    /// Returns the absolute value of this `ApInt` according to the **signed**
+    /// interpretation as well as a boolean indicating if overflow occured.
+    ///
+    /// See `overflowing_abs` for more information.
+    pub fn into_overflowing_abs(mut self) -> (ApInt, bool) {
+        let overflow = self.overflowing_abs();
+        (self, overflow)
+    }
+
+    /// Returns the absolute value of this `ApInt` according to the **signed**
+    /// interpretation or `None` if `self` is `signed_min_value`.
+    pub fn into_checked_abs(self) -> Option<ApInt> {
+        match self.into_overflowing_abs() {
+            (abs, false) => Some(abs),
+            (_, true) => None,
+        }
+    }
+
+    /// Returns the magnitude of this `ApInt` according to the **signed**
+    /// interpretation as an **unsigned** value of the same bit width.
+    ///
+    /// **Note:** This always succeeds since the magnitude of `signed_min_value`
+    /// is representable by the **unsigned** interpretation.
+    pub fn into_unsigned_abs(self) -> ApInt {
+        self.into_wrapping_abs()
+    }
+
     /// Add-assigns `rhs` to `self` inplace.
     ///
     /// # Errors
@@ -146,15 +437,16 @@ impl ApInt {
     /// overflow occured, according to the **unsigned** interpretation of
     /// overflow.
     ///
+    /// The returned boolean is the carry out of the most significant bit of
+    /// `self`, i.e. bit `width` of the unreduced sum.
+    ///
     /// # Errors
     ///
     /// - If `self` and `rhs` have unmatching bit widths.
-    // TODO: add tests
-    #[allow(dead_code)]
-    pub(crate) fn overflowing_uadd_assign(&mut self, rhs: &ApInt) -> Result<bool> {
+    pub fn overflowing_uadd_assign(&mut self, rhs: &ApInt) -> Result<bool> {
         match self.width().excess_bits() {
             Some(excess) => {
-                let mask = Digit::ONES >> excess;
+                let mask = Digit::ONES >> (Digit::BITS - excess);
                 match self.zip_access_data_mut_self(rhs)? {
                     Inl(lhs, rhs) => {
                         let temp = lhs.wrapping_add(rhs);
@@ -209,6 +501,18 @@ impl ApInt {
         }
     }
 
+    /// Adds `rhs` to `self` and returns the result as well as a boolean
+    /// indicating if overflow occured, according to the **unsigned**
+    /// interpretation of overflow.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn into_overflowing_uadd(mut self, rhs: &ApInt) -> Result<(ApInt, bool)> {
+        let overflow = self.overflowing_uadd_assign(rhs)?;
+        Ok((self, overflow))
+    }
+
     /// Add-assigns `rhs` to `self` inplace, and returns a boolean indicating if
     /// overflow occured, according to the **signed** interpretation of
     /// overflow.
@@ -216,15 +520,25 @@ impl ApInt {
     /// # Errors
     ///
     /// - If `self` and `rhs` have unmatching bit widths.
-    // TODO: add tests
-    #[allow(dead_code)]
-    pub(crate) fn overflowing_sadd_assign(&mut self, rhs: &ApInt) -> Result<bool> {
+    pub fn overflowing_sadd_assign(&mut self, rhs: &ApInt) -> Result<bool> {
         let self_sign = self.msb();
         let rhs_sign = rhs.msb();
         self.wrapping_add_assign(rhs)?;
         Ok((self_sign == rhs_sign) && (self_sign != self.msb()))
     }
 
+    /// Adds `rhs` to `self` and returns the result as well as a boolean
+    /// indicating if overflow occured, according to the **signed**
+    /// interpretation of overflow.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn into_overflowing_sadd(mut self, rhs: &ApInt) -> Result<(ApInt, bool)> {
+        let overflow = self.overflowing_sadd_assign(rhs)?;
+        Ok((self, overflow))
+    }
+
     /// Subtract-assigns `rhs` from `self` inplace.
     ///
     /// # Errors
@@ -265,98 +579,548 @@ impl ApInt {
         try_forward_bin_mut_impl(self, rhs, ApInt::wrapping_sub_assign)
     }
 
-    /// Multiply-assigns `rhs` to `self` inplace. This function **may** allocate
-    /// memory.
+    /// Subtract-assigns `rhs` from `self` inplace, and returns a boolean
+    /// indicating if overflow occured, according to the **unsigned**
+    /// interpretation of overflow.
+    ///
+    /// # Note
+    ///
+    /// The returned boolean is the borrow out of the most significant bit of
+    /// `self`, i.e. whether `rhs` is greater than `self`.
     ///
     /// # Errors
     ///
     /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn overflowing_usub_assign(&mut self, rhs: &ApInt) -> Result<bool> {
+        self.borrowing_sub_assign(rhs, false)
+    }
+
+    /// Add-assigns `rhs` and the incoming `carry` to `self` inplace and
+    /// returns the carry out of the most significant bit of `self`.
     ///
-    /// # Performance
+    /// This allows chaining additions of integers that are split up into
+    /// multiple `ApInt`s of the same bit width, starting at the least
+    /// significant one with a `carry` of `false`.
     ///
-    /// If the function detects a large number of leading zeros in front of the
-    /// most significant 1 bit, it will apply optimizations so that wasted
-    /// multiplications and additions of zero are avoided. This function is
-    /// designed to efficiently handle 5 common kinds of multiplication.
-    /// Small here means both small ApInt `BitWidth` and/or small **unsigned**
-    /// numerical significance. (Signed multiplication works, but two's
-    /// complement negative numbers may have a large number of leading ones,
-    /// leading to potential inefficiency.)
+    /// # Errors
     ///
-    /// - multiplication of zero by any size integer (no allocation)
-    /// - multiplication of small (<= 1 `Digit`) integers (no allocation)
-    /// - wrapping multiplication of medium size (<= 512 bits) integers
-    /// - multiplication of medium size integers that will not overflow
-    /// - multiplication of small integers by large integers (or large integers
-    ///   multiplied by small integers) (no allocation)
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn carrying_add_assign(&mut self, rhs: &ApInt, carry: bool) -> Result<bool> {
+        let carry = match self.zip_access_data_mut_self(rhs)? {
+            Inl(lhs, rhs) => {
+                let (sum, carry0) = lhs.overflowing_add(rhs);
+                let (sum, carry1) = sum.overflowing_add(Digit(carry as DigitRepr));
+                *lhs = sum;
+                carry0 || carry1
+            }
+            Ext(lhs, rhs) => {
+                let mut carry = carry;
+                for (l, r) in lhs.iter_mut().zip(rhs) {
+                    let (sum, carry0) = l.overflowing_add(*r);
+                    let (sum, carry1) = sum.overflowing_add(Digit(carry as DigitRepr));
+                    *l = sum;
+                    carry = carry0 || carry1;
+                }
+                carry
+            }
+        };
+        // for unaligned bit widths the carry ends up in the unused bits
+        let carry = carry
+            || match self.width().excess_bits() {
+                Some(used) => !(self.most_significant_digit() >> used).is_zero(),
+                None => false,
+            };
+        self.clear_unused_bits();
+        Ok(carry)
+    }
+
+    /// Subtract-assigns `rhs` and the incoming `borrow` from `self` inplace
+    /// and returns the borrow out of the most significant bit of `self`.
     ///
-    /// Currently, Karatsuba multiplication is not implemented, so large integer
-    /// multiplication may be very slow compared to other algorithms.
-    /// According to Wikipedia, Karatsuba algorithms outperform 𝒪(n^2)
-    /// algorithms, starting around 320-640 bits.
-    pub fn wrapping_mul_assign(&mut self, rhs: &ApInt) -> Result<()> {
-        match self.zip_access_data_mut_self(rhs)? {
+    /// This allows chaining subtractions of integers that are split up into
+    /// multiple `ApInt`s of the same bit width, starting at the least
+    /// significant one with a `borrow` of `false`.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn borrowing_sub_assign(&mut self, rhs: &ApInt, borrow: bool) -> Result<bool> {
+        // since the unused bits of both operands are zero, the borrow out of the
+        // most significant digit is the borrow out of the most significant bit
+        let borrow = match self.zip_access_data_mut_self(rhs)? {
             Inl(lhs, rhs) => {
-                *lhs = lhs.wrapping_mul(rhs);
+                let (diff, borrow0) = lhs.overflowing_sub(rhs);
+                let (diff, borrow1) = diff.overflowing_sub(Digit(borrow as DigitRepr));
+                *lhs = diff;
+                borrow0 || borrow1
             }
             Ext(lhs, rhs) => {
-                // finds the most significant nonzero digit (for later optimizations) and
-                // handles early return of multiplication by zero.
-                let rhs_sig_nonzero: usize = match rhs.iter().rposition(|x| !x.is_zero())
-                {
-                    Some(x) => x,
-                    None => {
-                        for x in lhs.iter_mut() {
-                            x.unset_all()
-                        }
-                        return Ok(())
-                    }
-                };
-                let lhs_sig_nonzero: usize = match lhs.iter().rposition(|x| !x.is_zero())
-                {
-                    Some(x) => x,
-                    None => {
-                        for x in lhs.iter_mut() {
-                            x.unset_all()
-                        }
-                        return Ok(())
+                let mut borrow = borrow;
+                for (l, r) in lhs.iter_mut().zip(rhs) {
+                    let (diff, borrow0) = l.overflowing_sub(*r);
+                    let (diff, borrow1) =
+                        diff.overflowing_sub(Digit(borrow as DigitRepr));
+                    *l = diff;
+                    borrow = borrow0 || borrow1;
+                }
+                borrow
+            }
+        };
+        self.clear_unused_bits();
+        Ok(borrow)
+    }
+
+    /// Subtracts `rhs` from `self` and returns the result as well as a boolean
+    /// indicating if overflow occured, according to the **unsigned**
+    /// interpretation of overflow.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn into_overflowing_usub(mut self, rhs: &ApInt) -> Result<(ApInt, bool)> {
+        let overflow = self.overflowing_usub_assign(rhs)?;
+        Ok((self, overflow))
+    }
+
+    /// Subtract-assigns `rhs` from `self` inplace, and returns a boolean
+    /// indicating if overflow occured, according to the **signed**
+    /// interpretation of overflow.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn overflowing_ssub_assign(&mut self, rhs: &ApInt) -> Result<bool> {
+        let self_sign = self.msb();
+        let rhs_sign = rhs.msb();
+        self.wrapping_sub_assign(rhs)?;
+        Ok((self_sign != rhs_sign) && (self_sign != self.msb()))
+    }
+
+    /// Subtracts `rhs` from `self` and returns the result as well as a boolean
+    /// indicating if overflow occured, according to the **signed**
+    /// interpretation of overflow.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn into_overflowing_ssub(mut self, rhs: &ApInt) -> Result<(ApInt, bool)> {
+        let overflow = self.overflowing_ssub_assign(rhs)?;
+        Ok((self, overflow))
+    }
+
+    /// Returns the absolute difference `|self - rhs|` using **unsigned**
+    /// interpretation.
+    ///
+    /// **Note:** This subtracts in a single pass and negates the result
+    /// inplace if a borrow occured.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn abs_diff(&self, rhs: &ApInt) -> Result<ApInt> {
+        let mut diff = self.clone();
+        if diff.borrowing_sub_assign(rhs, false)? {
+            diff.wrapping_neg();
+        }
+        Ok(diff)
+    }
+
+    /// Returns the absolute difference `|self - rhs|` using **signed**
+    /// interpretation of `self` and `rhs`.
+    ///
+    /// **Note:** The result is always representable when interpreted as
+    /// **unsigned**, even for `signed_max_value` and `signed_min_value`. This
+    /// subtracts in a single pass and negates the result inplace if `self` is
+    /// less than `rhs`.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn sabs_diff(&self, rhs: &ApInt) -> Result<ApInt> {
+        let mut diff = self.clone();
+        let borrow = diff.borrowing_sub_assign(rhs, false)?;
+        let (lhs_neg, rhs_neg) = (self.msb(), rhs.msb());
+        // with differing signs the negative operand is the lesser one, and
+        // otherwise the unsigned borrow decides
+        let less = if lhs_neg != rhs_neg { lhs_neg } else { borrow };
+        if less {
+            diff.wrapping_neg();
+        }
+        Ok(diff)
+    }
+
+    /// Computes `floor((self + rhs) / 2)` without overflow using either
+    /// **unsigned** or **signed** interpretation.
+    ///
+    /// This uses `(self & rhs) + ((self ^ rhs) >> 1)` in a single pass over the
+    /// digits, where the shift is arithmetic in the signed case.
+    fn midpoint_floor(&self, rhs: &ApInt, signed: bool) -> Result<ApInt> {
+        let mut result = self.clone();
+        let width = result.width();
+        // position of the most significant bit inside of the most significant
+        // digit, which is filled in by the shift
+        let msb_pos = (width.to_usize() - 1) % Digit::BITS;
+        match result.zip_access_data_mut_self(rhs)? {
+            Inl(lhs, rhs) => {
+                let xor = *lhs ^ rhs;
+                let mut shifted = xor >> 1;
+                if signed {
+                    shifted |= xor & (Digit::ONE << msb_pos);
+                }
+                *lhs = (*lhs & rhs).wrapping_add(shifted);
+            }
+            Ext(lhs, rhs) => {
+                let len = lhs.len();
+                let mut carry = false;
+                for i in 0..len {
+                    let xor = lhs[i] ^ rhs[i];
+                    let mut shifted = xor >> 1;
+                    if i + 1 < len {
+                        shifted |= (lhs[i + 1] ^ rhs[i + 1]) << (Digit::BITS - 1);
+                    } else if signed {
+                        shifted |= xor & (Digit::ONE << msb_pos);
                     }
-                };
-                // for several routines below there was a nested loop that had its first
-                // and last iterations unrolled (and the unrolled loops
-                // had their first and last iterations unrolled), and then
-                // some if statements are added for digit overflow checks.
-                // This is done because the compiler probably cannot properly unroll the
-                // carry system, overflow system, and figure out that only
-                // `Digit` multiplications were needed instead of
-                // `DoubleDigit` multiplications in some places.
-                match (lhs_sig_nonzero == 0, rhs_sig_nonzero == 0) {
-                    (false, false) => {
-                        let lhs_sig_bits = (lhs_sig_nonzero * Digit::BITS)
-                            + (Digit::BITS
-                                - (lhs[lhs_sig_nonzero].leading_zeros() as usize));
-                        let rhs_sig_bits = (rhs_sig_nonzero * Digit::BITS)
-                            + (Digit::BITS
-                                - (rhs[rhs_sig_nonzero].leading_zeros() as usize));
-                        let tot_sig_bits = lhs_sig_bits + rhs_sig_bits;
-                        if tot_sig_bits <= (lhs.len() * Digit::BITS) {
-                            // No possibility of `Digit` wise overflow. Note that end bits
-                            // still have to be trimmed for
-                            // `ApInt`s with a width that is not a multiple of
-                            //`Digit`s.
-                            // first digit of first row
-                            let mult = lhs[0];
-                            let temp = mult.carrying_mul(rhs[0]);
-                            // middle digits of first row
-                            // the goal here with `sum` is to allocate and initialize it
-                            // only once here.
-                            let mut sum =
-                                Vec::with_capacity(lhs_sig_nonzero + rhs_sig_nonzero + 2);
-                            sum.push(temp.0);
-                            let mut mul_carry = temp.1;
-                            for rhs_i in 1..rhs_sig_nonzero {
-                                let temp = mult.carrying_mul_add(rhs[rhs_i], mul_carry);
-                                sum.push(temp.0);
+                    let (sum, carry0) = (lhs[i] & rhs[i]).overflowing_add(shifted);
+                    let (sum, carry1) = sum.overflowing_add(Digit(carry as DigitRepr));
+                    lhs[i] = sum;
+                    carry = carry0 || carry1;
+                }
+            }
+        }
+        result.clear_unused_bits();
+        Ok(result)
+    }
+
+    /// Returns the average of `self` and `rhs` rounded down using **unsigned**
+    /// interpretation, computed without overflow.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn midpoint(&self, rhs: &ApInt) -> Result<ApInt> {
+        self.midpoint_floor(rhs, false)
+    }
+
+    /// Returns the average of `self` and `rhs` using **signed** interpretation,
+    /// computed without overflow.
+    ///
+    /// **Note:** Like `i64::midpoint`, the result is rounded towards zero.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn smidpoint(&self, rhs: &ApInt) -> Result<ApInt> {
+        let mut result = self.midpoint_floor(rhs, true)?;
+        // the floor differs from rounding towards zero only if the sum is odd
+        // and negative
+        if self.is_odd() != rhs.is_odd() && result.msb() {
+            result.wrapping_inc();
+        }
+        Ok(result)
+    }
+
+    /// Adds `rhs` to `self` and returns the result, or `None` if overflow
+    /// occured according to the **unsigned** interpretation of overflow.
+    ///
+    /// # Note
+    ///
+    /// Unlike the `checked_*` functions of this crate, which only check the
+    /// bit widths of their operands, this checks the operation itself for
+    /// overflow, like `checked_add` of the primitive integers. This will
+    /// **not** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn into_overflow_checked_uadd(mut self, rhs: &ApInt) -> Result<Option<ApInt>> {
+        let overflow = self.overflowing_uadd_assign(rhs)?;
+        Ok(if overflow { None } else { Some(self) })
+    }
+
+    /// Subtracts `rhs` from `self` and returns the result, or `None` if
+    /// overflow occured according to the **unsigned** interpretation of
+    /// overflow.
+    ///
+    /// **Note:** This will **not** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn into_overflow_checked_usub(mut self, rhs: &ApInt) -> Result<Option<ApInt>> {
+        let overflow = self.overflowing_usub_assign(rhs)?;
+        Ok(if overflow { None } else { Some(self) })
+    }
+
+    /// Adds `rhs` to `self` and returns the result, or `None` if overflow
+    /// occured according to the **signed** interpretation of overflow.
+    ///
+    /// **Note:** This will **not** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn into_overflow_checked_sadd(mut self, rhs: &ApInt) -> Result<Option<ApInt>> {
+        let overflow = self.overflowing_sadd_assign(rhs)?;
+        Ok(if overflow { None } else { Some(self) })
+    }
+
+    /// Subtracts `rhs` from `self` and returns the result, or `None` if
+    /// overflow occured according to the **signed** interpretation of
+    /// overflow.
+    ///
+    /// **Note:** This will **not** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn into_overflow_checked_ssub(mut self, rhs: &ApInt) -> Result<Option<ApInt>> {
+        let overflow = self.overflowing_ssub_assign(rhs)?;
+        Ok(if overflow { None } else { Some(self) })
+    }
+
+    /// Multiplies `rhs` with `self` and returns the result, or `None` if
+    /// overflow occured according to the **unsigned** interpretation of
+    /// overflow.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn into_overflow_checked_umul(mut self, rhs: &ApInt) -> Result<Option<ApInt>> {
+        let overflow = self.overflowing_umul_assign(rhs)?;
+        Ok(if overflow { None } else { Some(self) })
+    }
+
+    /// Multiplies `rhs` with `self` and returns the result, or `None` if
+    /// overflow occured according to the **signed** interpretation of
+    /// overflow.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn into_overflow_checked_smul(mut self, rhs: &ApInt) -> Result<Option<ApInt>> {
+        let overflow = self.overflowing_smul_assign(rhs)?;
+        Ok(if overflow { None } else { Some(self) })
+    }
+
+    /// Add-assigns `rhs` to `self` inplace, clamping the result to the
+    /// **unsigned** maximum value of the bit width on overflow.
+    ///
+    /// **Note:** This will **not** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn saturating_uadd_assign(&mut self, rhs: &ApInt) -> Result<()> {
+        if self.overflowing_uadd_assign(rhs)? {
+            self.set_all();
+        }
+        Ok(())
+    }
+
+    /// Adds `rhs` to `self` and returns the result, clamped to the
+    /// **unsigned** maximum value of the bit width on overflow.
+    ///
+    /// **Note:** This will **not** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn into_saturating_uadd(self, rhs: &ApInt) -> Result<ApInt> {
+        try_forward_bin_mut_impl(self, rhs, ApInt::saturating_uadd_assign)
+    }
+
+    /// Subtract-assigns `rhs` from `self` inplace, clamping the result to
+    /// zero if `rhs` is greater than `self` in the **unsigned**
+    /// interpretation.
+    ///
+    /// **Note:** This will **not** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn saturating_usub_assign(&mut self, rhs: &ApInt) -> Result<()> {
+        if self.overflowing_usub_assign(rhs)? {
+            self.unset_all();
+        }
+        Ok(())
+    }
+
+    /// Subtracts `rhs` from `self` and returns the result, clamped to zero if
+    /// `rhs` is greater than `self` in the **unsigned** interpretation.
+    ///
+    /// **Note:** This will **not** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn into_saturating_usub(self, rhs: &ApInt) -> Result<ApInt> {
+        try_forward_bin_mut_impl(self, rhs, ApInt::saturating_usub_assign)
+    }
+
+    /// Sets `self` to the **signed** maximum value of its bit width if
+    /// `negative` is `false`, or to the **signed** minimum value otherwise.
+    pub(in crate::apint) fn saturate_signed(&mut self, negative: bool) {
+        if negative {
+            self.unset_all();
+            self.set_msb();
+        } else {
+            self.set_all();
+            self.unset_msb();
+        }
+    }
+
+    /// Add-assigns `rhs` to `self` inplace, clamping the result to the
+    /// **signed** minimum or maximum value of the bit width on overflow.
+    ///
+    /// **Note:** This will **not** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn saturating_sadd_assign(&mut self, rhs: &ApInt) -> Result<()> {
+        // signed addition can only overflow towards the common sign of both
+        // operands
+        let self_sign = self.msb();
+        if self.overflowing_sadd_assign(rhs)? {
+            self.saturate_signed(self_sign);
+        }
+        Ok(())
+    }
+
+    /// Adds `rhs` to `self` and returns the result, clamped to the **signed**
+    /// minimum or maximum value of the bit width on overflow.
+    ///
+    /// **Note:** This will **not** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn into_saturating_sadd(self, rhs: &ApInt) -> Result<ApInt> {
+        try_forward_bin_mut_impl(self, rhs, ApInt::saturating_sadd_assign)
+    }
+
+    /// Subtract-assigns `rhs` from `self` inplace, clamping the result to the
+    /// **signed** minimum or maximum value of the bit width on overflow.
+    ///
+    /// **Note:** This will **not** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn saturating_ssub_assign(&mut self, rhs: &ApInt) -> Result<()> {
+        // signed subtraction can only overflow towards the sign of `self`,
+        // this also holds if `rhs` is the signed minimum value
+        let self_sign = self.msb();
+        if self.overflowing_ssub_assign(rhs)? {
+            self.saturate_signed(self_sign);
+        }
+        Ok(())
+    }
+
+    /// Subtracts `rhs` from `self` and returns the result, clamped to the
+    /// **signed** minimum or maximum value of the bit width on overflow.
+    ///
+    /// **Note:** This will **not** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn into_saturating_ssub(self, rhs: &ApInt) -> Result<ApInt> {
+        try_forward_bin_mut_impl(self, rhs, ApInt::saturating_ssub_assign)
+    }
+
+    /// Multiply-assigns `rhs` to `self` inplace. This function **may** allocate
+    /// memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    ///
+    /// # Performance
+    ///
+    /// If the function detects a large number of leading zeros in front of the
+    /// most significant 1 bit, it will apply optimizations so that wasted
+    /// multiplications and additions of zero are avoided. This function is
+    /// designed to efficiently handle 5 common kinds of multiplication.
+    /// Small here means both small ApInt `BitWidth` and/or small **unsigned**
+    /// numerical significance. (Signed multiplication works, but two's
+    /// complement negative numbers may have a large number of leading ones,
+    /// leading to potential inefficiency.)
+    ///
+    /// - multiplication of zero by any size integer (no allocation)
+    /// - multiplication of small (<= 1 `Digit`) integers (no allocation)
+    /// - wrapping multiplication of medium size (<= 512 bits) integers
+    /// - multiplication of medium size integers that will not overflow
+    /// - multiplication of small integers by large integers (or large integers
+    ///   multiplied by small integers) (no allocation)
+    ///
+    /// Currently, Karatsuba multiplication is not implemented, so large integer
+    /// multiplication may be very slow compared to other algorithms.
+    /// According to Wikipedia, Karatsuba algorithms outperform 𝒪(n^2)
+    /// algorithms, starting around 320-640 bits.
+    pub fn wrapping_mul_assign(&mut self, rhs: &ApInt) -> Result<()> {
+        match self.zip_access_data_mut_self(rhs)? {
+            Inl(lhs, rhs) => {
+                *lhs = lhs.wrapping_mul(rhs);
+            }
+            Ext(lhs, rhs) => {
+                // finds the most significant nonzero digit (for later optimizations) and
+                // handles early return of multiplication by zero.
+                let rhs_sig_nonzero: usize = match rhs.iter().rposition(|x| !x.is_zero())
+                {
+                    Some(x) => x,
+                    None => {
+                        for x in lhs.iter_mut() {
+                            x.unset_all()
+                        }
+                        return Ok(())
+                    }
+                };
+                let lhs_sig_nonzero: usize = match lhs.iter().rposition(|x| !x.is_zero())
+                {
+                    Some(x) => x,
+                    None => {
+                        for x in lhs.iter_mut() {
+                            x.unset_all()
+                        }
+                        return Ok(())
+                    }
+                };
+                // for several routines below there was a nested loop that had its first
+                // and last iterations unrolled (and the unrolled loops
+                // had their first and last iterations unrolled), and then
+                // some if statements are added for digit overflow checks.
+                // This is done because the compiler probably cannot properly unroll the
+                // carry system, overflow system, and figure out that only
+                // `Digit` multiplications were needed instead of
+                // `DoubleDigit` multiplications in some places.
+                match (lhs_sig_nonzero == 0, rhs_sig_nonzero == 0) {
+                    (false, false) => {
+                        let lhs_sig_bits = (lhs_sig_nonzero * Digit::BITS)
+                            + (Digit::BITS
+                                - (lhs[lhs_sig_nonzero].leading_zeros() as usize));
+                        let rhs_sig_bits = (rhs_sig_nonzero * Digit::BITS)
+                            + (Digit::BITS
+                                - (rhs[rhs_sig_nonzero].leading_zeros() as usize));
+                        let tot_sig_bits = lhs_sig_bits + rhs_sig_bits;
+                        if tot_sig_bits <= (lhs.len() * Digit::BITS) {
+                            // No possibility of `Digit` wise overflow. Note that end bits
+                            // still have to be trimmed for
+                            // `ApInt`s with a width that is not a multiple of
+                            //`Digit`s.
+                            // first digit of first row
+                            let mult = lhs[0];
+                            let temp = mult.carrying_mul(rhs[0]);
+                            // middle digits of first row
+                            // the goal here with `sum` is to allocate and initialize it
+                            // only once here.
+                            let mut sum =
+                                Vec::with_capacity(lhs_sig_nonzero + rhs_sig_nonzero + 2);
+                            sum.push(temp.0);
+                            let mut mul_carry = temp.1;
+                            for rhs_i in 1..rhs_sig_nonzero {
+                                let temp = mult.carrying_mul_add(rhs[rhs_i], mul_carry);
+                                sum.push(temp.0);
                                 mul_carry = temp.1;
                             }
                             let temp =
@@ -557,37 +1321,629 @@ impl ApInt {
     pub fn into_wrapping_mul(self, rhs: &ApInt) -> Result<ApInt> {
         try_forward_bin_mut_impl(self, rhs, ApInt::wrapping_mul_assign)
     }
-}
 
-/// # Division Operations
-///
-/// **Note**: unless otherwise noted in the function specific documentation,
-///
-/// - The functions do **not** allocate memory.
-/// - The function works for both signed and unsigned interpretations of an
-///   `ApInt`. In other words, in the low-level bit-wise representation there is
-///   no difference between a signed and unsigned operation by the function on
-///   fixed bit-width integers. (Cite: LLVM)
-///
-/// In almost all integer division algorithms where "just" the quotient is
-/// calculated, the remainder is also produced and actually exists in memory (or
-/// at least is only one O(n) operation away) prior to being dropped or
-/// overwritten, and vice versa for remainder only calculations. Note here
-/// that functions with `div` in their names (e.g. `wrapping_div`) should really
-/// be called `quo` (quotient) functions, because the division process produces
-/// both the quotient and remainder. However, to stay with Rust's naming scheme
-/// we have kept `div` naming. The instruction for division on many CPUs sets
-/// registers to both results of the division process, and compilers will detect
-/// if code uses both results and only use one division instruction. There is no
-/// such detection for `ApInt`s, and thus the `divrem` and `remdiv` type
-/// instructions exist to explicitly use just one division function for both
-/// results.
-///
-/// ## Performance
-///
-/// All of the division functions in this `impl` quickly check for various edge
-/// cases and use an efficient algorithm for these cases.
-/// Small here means both small ApInt `BitWidth` and/or small **unsigned**
+    /// Multiply-assigns `rhs` to `self` inplace, and returns a boolean
+    /// indicating if overflow occured, according to the **unsigned**
+    /// interpretation of overflow. This is equal to the `umul_ov` of LLVM.
+    ///
+    /// `self` is set to the wrapped product in both cases.
+    ///
+    /// The double-width product is only computed if the significant bits of
+    /// both operands do not already decide whether the product overflows.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn overflowing_umul_assign(&mut self, rhs: &ApInt) -> Result<bool> {
+        let width = self.width().to_usize();
+        if let Inl(lhs, rhs) = self.zip_access_data_mut_self(rhs)? {
+            let (lo, hi) = lhs.carrying_mul(rhs);
+            *lhs = lo;
+            let unused_bits = Digit::BITS - width;
+            let overflow = !hi.is_zero() || (lo.leading_zeros() as usize) < unused_bits;
+            self.clear_unused_bits();
+            return Ok(overflow)
+        }
+        let lhs_bits = width - self.leading_zeros();
+        let rhs_bits = width - rhs.leading_zeros();
+        // `2^(lhs_bits - 1) * 2^(rhs_bits - 1) <= self * rhs
+        //  < 2^lhs_bits * 2^rhs_bits`
+        if lhs_bits == 0 || rhs_bits == 0 || lhs_bits + rhs_bits <= width {
+            self.wrapping_mul_assign(rhs)?;
+            return Ok(false)
+        }
+        if lhs_bits + rhs_bits > width + 1 {
+            self.wrapping_mul_assign(rhs)?;
+            return Ok(true)
+        }
+        // the product has either `width` or `width + 1` significant bits
+        let extended_width = BitWidth::new(width + 1).unwrap();
+        let mut product = self.clone().into_zero_extend(extended_width)?;
+        product.wrapping_mul_assign(&rhs.clone().into_zero_extend(extended_width)?)?;
+        let overflow = product.msb();
+        *self = product.into_truncate(width)?;
+        Ok(overflow)
+    }
+
+    /// Multiplies `rhs` with `self` and returns the wrapped result as well as
+    /// a boolean indicating if overflow occured, according to the **unsigned**
+    /// interpretation of overflow.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn into_overflowing_umul(mut self, rhs: &ApInt) -> Result<(ApInt, bool)> {
+        let overflow = self.overflowing_umul_assign(rhs)?;
+        Ok((self, overflow))
+    }
+
+    /// Multiply-assigns `rhs` to `self` inplace, and returns a boolean
+    /// indicating if overflow occured, according to the **signed**
+    /// interpretation of overflow. This is equal to the `smul_ov` of LLVM.
+    ///
+    /// `self` is set to the wrapped product in both cases.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn overflowing_smul_assign(&mut self, rhs: &ApInt) -> Result<bool> {
+        if self.width() != rhs.width() {
+            return Error::unmatching_bitwidths(self.width(), rhs.width()).into()
+        }
+        // the wrapped product of the magnitudes only differs from the wrapped
+        // signed product in its sign
+        let negative = self.msb() != rhs.msb();
+        if self.msb() {
+            self.wrapping_neg();
+        }
+        let overflow = if rhs.msb() {
+            self.overflowing_umul_assign(&rhs.clone().into_wrapping_neg())?
+        } else {
+            self.overflowing_umul_assign(rhs)?
+        };
+        // the magnitude of a negative product may be one greater than the
+        // magnitude of the signed maximum value
+        let overflow =
+            overflow || (self.msb() && !(negative && self.count_ones() == 1));
+        if negative {
+            self.wrapping_neg();
+        }
+        Ok(overflow)
+    }
+
+    /// Multiplies `rhs` with `self` and returns the wrapped result as well as
+    /// a boolean indicating if overflow occured, according to the **signed**
+    /// interpretation of overflow.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn into_overflowing_smul(mut self, rhs: &ApInt) -> Result<(ApInt, bool)> {
+        let overflow = self.overflowing_smul_assign(rhs)?;
+        Ok((self, overflow))
+    }
+
+    /// Multiply-assigns `rhs` to `self` inplace, clamping the result to the
+    /// **unsigned** maximum value of the bit width on overflow.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn saturating_umul_assign(&mut self, rhs: &ApInt) -> Result<()> {
+        if self.width() != rhs.width() {
+            return Error::unmatching_bitwidths(self.width(), rhs.width()).into()
+        }
+        // `self * rhs >= 2^(lhs_bits - 1) * 2^(rhs_bits - 1)` saturates without
+        // computing the product
+        let bits = self.required_bits(false) + rhs.required_bits(false);
+        if bits > self.width().to_usize() + 1 || self.overflowing_umul_assign(rhs)? {
+            self.set_all();
+        }
+        Ok(())
+    }
+
+    /// Multiplies `rhs` with `self` and returns the result, clamped to the
+    /// **unsigned** maximum value of the bit width on overflow.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn into_saturating_umul(self, rhs: &ApInt) -> Result<ApInt> {
+        try_forward_bin_mut_impl(self, rhs, ApInt::saturating_umul_assign)
+    }
+
+    /// Multiply-assigns `rhs` to `self` inplace, clamping the result to the
+    /// **signed** minimum or maximum value of the bit width on overflow.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn saturating_smul_assign(&mut self, rhs: &ApInt) -> Result<()> {
+        if self.width() != rhs.width() {
+            return Error::unmatching_bitwidths(self.width(), rhs.width()).into()
+        }
+        let negative = self.msb() != rhs.msb();
+        // the magnitudes need one bit less than the signed values, so the same
+        // bound as in `saturating_umul_assign` applies to them
+        let bits = self.required_bits(true) + rhs.required_bits(true) - 2;
+        if bits > self.width().to_usize() + 1 || self.overflowing_smul_assign(rhs)? {
+            self.saturate_signed(negative);
+        }
+        Ok(())
+    }
+
+    /// Multiplies `rhs` with `self` and returns the result, clamped to the
+    /// **signed** minimum or maximum value of the bit width on overflow.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn into_saturating_smul(self, rhs: &ApInt) -> Result<ApInt> {
+        try_forward_bin_mut_impl(self, rhs, ApInt::saturating_smul_assign)
+    }
+
+    /// Multiply-assigns `self` by the scalar `rhs` inplace and returns the
+    /// part of the product that does not fit into the bit width of `self`.
+    ///
+    /// This is a single pass over the digits of `self` and does **not**
+    /// allocate memory.
+    pub fn carrying_mul_u64_assign(&mut self, rhs: u64) -> u64 {
+        let mut carry = 0_u64;
+        for digit in self.as_digit_slice_mut() {
+            let product = u128::from(digit.repr()) * u128::from(rhs) + u128::from(carry);
+            *digit = Digit(product as DigitRepr);
+            carry = (product >> Digit::BITS) as u64;
+        }
+        // the product is less than `2^(width + 64)`, so the bits beyond the bit
+        // width always fit into a single `u64`
+        let overflow = match self.width().excess_bits() {
+            Some(used) => {
+                (carry << (Digit::BITS - used))
+                    | u64::from((self.most_significant_digit() >> used).repr())
+            }
+            None => carry,
+        };
+        self.clear_unused_bits();
+        overflow
+    }
+
+    /// Multiply-assigns `self` by the scalar `rhs` inplace.
+    ///
+    /// This is a single pass over the digits of `self` and does **not**
+    /// allocate memory.
+    pub fn wrapping_mul_u64_assign(&mut self, rhs: u64) {
+        self.carrying_mul_u64_assign(rhs);
+    }
+
+    /// Accumulates the product of `a` and `b` into `self` inplace and returns
+    /// a boolean indicating if overflow occured, according to the
+    /// **unsigned** interpretation of overflow.
+    ///
+    /// # Note
+    ///
+    /// - The addition is folded into the inner loop of the multiplication, so
+    ///   this is faster than a multiplication followed by an addition and will
+    ///   **not** allocate memory.
+    /// - The returned boolean is set if `self + a * b` is not representable by
+    ///   the bit width of `self`.
+    ///
+    /// # Errors
+    ///
+    /// - If `self`, `a` and `b` do not all have the same bit width.
+    pub fn mul_add_assign(&mut self, a: &ApInt, b: &ApInt) -> Result<bool> {
+        if self.width() != a.width() {
+            return Error::unmatching_bitwidths(self.width(), a.width()).into()
+        }
+        if self.width() != b.width() {
+            return Error::unmatching_bitwidths(self.width(), b.width()).into()
+        }
+        let mut overflow = ApInt::mul_add_digits(
+            self.as_digit_slice_mut(),
+            a.as_digit_slice(),
+            b.as_digit_slice(),
+        );
+        if let Some(excess) = self.width().excess_bits() {
+            overflow |= !(self.most_significant_digit() >> excess).is_zero();
+        }
+        self.clear_unused_bits();
+        Ok(overflow)
+    }
+
+    /// Accumulates the product of `a` and `b` into `self` inplace, wrapping
+    /// around on overflow.
+    ///
+    /// **Note:** This will **not** allocate memory. See `mul_add_assign` for
+    /// more information.
+    ///
+    /// # Errors
+    ///
+    /// - If `self`, `a` and `b` do not all have the same bit width.
+    pub fn wrapping_mul_add_assign(&mut self, a: &ApInt, b: &ApInt) -> Result<()> {
+        self.mul_add_assign(a, b).map(|_| ())
+    }
+
+    /// Adds the product of `a` and `b` to `self` and returns the result as
+    /// well as a boolean indicating if overflow occured, according to the
+    /// **unsigned** interpretation of overflow.
+    ///
+    /// **Note:** This will **not** allocate memory. See `mul_add_assign` for
+    /// more information.
+    ///
+    /// # Errors
+    ///
+    /// - If `self`, `a` and `b` do not all have the same bit width.
+    pub fn into_mul_add(mut self, a: &ApInt, b: &ApInt) -> Result<(ApInt, bool)> {
+        let overflow = self.mul_add_assign(a, b)?;
+        Ok((self, overflow))
+    }
+
+    /// Accumulates the product of `a` and `b` into the digits of `acc`,
+    /// discarding everything at or above the length of `acc`.
+    ///
+    /// Returns `true` if any nonzero digit or carry was discarded.
+    ///
+    /// All three slices must have the same length.
+    fn mul_add_digits(acc: &mut [Digit], a: &[Digit], b: &[Digit]) -> bool {
+        let len = acc.len();
+        let (a_sig, b_sig) = match (
+            a.iter().rposition(|x| !x.is_zero()),
+            b.iter().rposition(|x| !x.is_zero()),
+        ) {
+            (Some(a_sig), Some(b_sig)) => (a_sig, b_sig),
+            // the product is zero
+            _ => return false,
+        };
+        // partial products of digits that land at or above `len`
+        let mut overflow = (a_sig + b_sig) >= len;
+        for a_i in 0..=a_sig {
+            let mult = a[a_i];
+            if mult.is_zero() {
+                continue
+            }
+            let mut carry = Digit::ZERO;
+            let b_upper = (len - a_i).min(b_sig + 1);
+            for b_i in 0..b_upper {
+                let temp = mult
+                    .dd()
+                    .wrapping_mul(b[b_i].dd())
+                    .wrapping_add(acc[a_i + b_i].dd())
+                    .wrapping_add(carry.dd());
+                acc[a_i + b_i] = temp.lo();
+                carry = temp.hi();
+            }
+            // propagate the carry of this row through the accumulator
+            for digit in acc[(a_i + b_upper)..].iter_mut() {
+                if carry.is_zero() {
+                    break
+                }
+                let temp = digit.carrying_add(carry);
+                *digit = temp.0;
+                carry = temp.1;
+            }
+            overflow |= !carry.is_zero();
+        }
+        overflow
+    }
+
+    /// Assigns the product of `a` and `b` to `dst` without allocating and
+    /// returns `true` if the product overflowed according to the **unsigned**
+    /// interpretation.
+    ///
+    /// All three `ApInt`s must have the same bit width.
+    fn overflowing_umul_into(dst: &mut ApInt, a: &ApInt, b: &ApInt) -> bool {
+        dst.unset_all();
+        dst.mul_add_assign(a, b).unwrap()
+    }
+
+    /// Raises `self` to the power of `exp` inplace and returns a boolean
+    /// indicating if overflow occured, according to the **unsigned**
+    /// interpretation of overflow.
+    ///
+    /// # Note
+    ///
+    /// - Zero to the power of zero yields one.
+    /// - The result wraps around on overflow.
+    /// - This uses exponentiation by squaring. This function allocates two
+    ///   buffers of the bit width of `self` up front which are alternated
+    ///   between, so no allocations happen inside of the loop.
+    pub fn overflowing_upow_assign(&mut self, exp: u32) -> bool {
+        let width = self.width();
+        let mut base = mem::replace(self, ApInt::one(width));
+        if exp == 0 {
+            return false
+        }
+        let mut temp = ApInt::zero(width);
+        let mut overflow = false;
+        let mut exp = exp;
+        // `self` is used as the accumulator
+        while exp > 1 {
+            if (exp & 1) == 1 {
+                overflow |= ApInt::overflowing_umul_into(&mut temp, self, &base);
+                mem::swap(self, &mut temp);
+            }
+            overflow |= ApInt::overflowing_umul_into(&mut temp, &base, &base);
+            mem::swap(&mut base, &mut temp);
+            exp /= 2;
+        }
+        overflow |= ApInt::overflowing_umul_into(&mut temp, self, &base);
+        mem::swap(self, &mut temp);
+        overflow
+    }
+
+    /// Raises `self` to the power of `exp` and returns the result as well as a
+    /// boolean indicating if overflow occured, according to the **unsigned**
+    /// interpretation of overflow.
+    ///
+    /// See `overflowing_upow_assign` for more information.
+    pub fn into_overflowing_upow(mut self, exp: u32) -> (ApInt, bool) {
+        let overflow = self.overflowing_upow_assign(exp);
+        (self, overflow)
+    }
+
+    /// Raises `self` to the power of `exp` inplace and returns a boolean
+    /// indicating if overflow occured, according to the **signed**
+    /// interpretation of overflow.
+    ///
+    /// # Note
+    ///
+    /// - Zero to the power of zero yields one.
+    /// - The result wraps around on overflow.
+    /// - See `overflowing_upow_assign` for more information.
+    pub fn overflowing_spow_assign(&mut self, exp: u32) -> bool {
+        let negative = self.msb() && ((exp & 1) == 1);
+        if self.msb() {
+            self.wrapping_neg();
+        }
+        // the magnitude of the result is calculated, which is always
+        // representable by the **unsigned** interpretation
+        let mut overflow = self.overflowing_upow_assign(exp);
+        if self.msb() {
+            // only the magnitude of `signed_min_value` has its most significant
+            // bit set and is still representable
+            overflow |= !(negative && (self.count_ones() == 1));
+        }
+        if negative {
+            self.wrapping_neg();
+        }
+        overflow
+    }
+
+    /// Raises `self` to the power of `exp` and returns the result as well as a
+    /// boolean indicating if overflow occured, according to the **signed**
+    /// interpretation of overflow.
+    ///
+    /// See `overflowing_spow_assign` for more information.
+    pub fn into_overflowing_spow(mut self, exp: u32) -> (ApInt, bool) {
+        let overflow = self.overflowing_spow_assign(exp);
+        (self, overflow)
+    }
+
+    /// Raises `self` to the power of `exp` inplace, wrapping around on
+    /// overflow.
+    ///
+    /// # Note
+    ///
+    /// - Zero to the power of zero yields one.
+    /// - There is no difference between signed and unsigned wrapping
+    ///   exponentiation.
+    /// - See `overflowing_upow_assign` for more information.
+    pub fn wrapping_pow_assign(&mut self, exp: u32) {
+        self.overflowing_upow_assign(exp);
+    }
+
+    /// Raises `self` to the power of `exp` and returns the result, wrapping
+    /// around on overflow.
+    ///
+    /// See `wrapping_pow_assign` for more information.
+    pub fn into_wrapping_pow(mut self, exp: u32) -> ApInt {
+        self.wrapping_pow_assign(exp);
+        self
+    }
+
+    /// Returns `self` to the power of `exp` or `None` if the result is not
+    /// representable by the bit width of `self` according to the **unsigned**
+    /// interpretation.
+    ///
+    /// **Note:** This function allocates memory.
+    pub fn checked_upow(&self, exp: u32) -> Option<ApInt> {
+        match self.clone().into_overflowing_upow(exp) {
+            (pow, false) => Some(pow),
+            (_, true) => None,
+        }
+    }
+
+    /// Returns `self` to the power of `exp` or `None` if the result is not
+    /// representable by the bit width of `self` according to the **signed**
+    /// interpretation.
+    ///
+    /// **Note:** This function allocates memory.
+    pub fn checked_spow(&self, exp: u32) -> Option<ApInt> {
+        match self.clone().into_overflowing_spow(exp) {
+            (pow, false) => Some(pow),
+            (_, true) => None,
+        }
+    }
+
+    /// Computes the carry-save sum of `a`, `b` and `c` and returns the
+    /// `(sum, carry)` pair.
+    ///
+    /// Each bit of `sum` is `a ^ b ^ c` and each bit of `carry` is the
+    /// majority `(a & b) | (a & c) | (b & c)` of the corresponding input bits.
+    /// The carry bits are **not** shifted, so `sum + (carry << 1)` equals
+    /// `a + b + c` modulo the bit width. No carries are propagated which makes
+    /// this the building block for fast multi-operand addition.
+    ///
+    /// **Note:** This function allocates memory for the two results.
+    ///
+    /// # Errors
+    ///
+    /// - If `a`, `b` and `c` do not all have the same bit width.
+    pub fn carry_save_add(a: &ApInt, b: &ApInt, c: &ApInt) -> Result<(ApInt, ApInt)> {
+        if a.width() != b.width() {
+            return Error::unmatching_bitwidths(a.width(), b.width()).into()
+        }
+        if a.width() != c.width() {
+            return Error::unmatching_bitwidths(a.width(), c.width()).into()
+        }
+        let mut sum = a.clone();
+        let mut carry = a.clone();
+        let digits = sum
+            .as_digit_slice_mut()
+            .iter_mut()
+            .zip(carry.as_digit_slice_mut())
+            .zip(b.as_digit_slice().iter().zip(c.as_digit_slice()));
+        for ((s, k), (&b, &c)) in digits {
+            let a = *s;
+            *s = a ^ b ^ c;
+            *k = (a & b) | (a & c) | (b & c);
+        }
+        Ok((sum, carry))
+    }
+
+    /// Returns the bit position of the smallest power of two that is greater
+    /// than or equal to `self` interpreted as unsigned integer or `None` if
+    /// that power of two is not representable by the bit width of `self`.
+    fn next_power_of_two_pos(&self) -> Option<BitPos> {
+        match self.msb_set_pos() {
+            None => Some(BitPos::from(0)),
+            Some(pos) if self.is_power_of_two() => Some(pos),
+            Some(pos) => {
+                let next = pos.to_usize() + 1;
+                if self.width().is_valid_pos(next) {
+                    Some(BitPos::from(next))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Returns the smallest power of two that is greater than or equal to
+    /// `self` interpreted as unsigned integer.
+    ///
+    /// # Note
+    ///
+    /// - Zero (`0`) results in one (`1`).
+    /// - If the next power of two is not representable by the bit width of
+    ///   `self` the result wraps around to zero (`0`).
+    /// - This operation will **not** allocate memory and computes inplace of
+    ///   `self`.
+    pub fn into_next_power_of_two(mut self) -> ApInt {
+        let pos = self.next_power_of_two_pos();
+        self.unset_all();
+        if let Some(pos) = pos {
+            self.set_bit_at(pos).expect(
+                "`next_power_of_two_pos` only returns valid bit positions for the bit \
+                 width of `self`.",
+            )
+        }
+        self
+    }
+
+    /// Returns the smallest power of two that is greater than or equal to
+    /// `self` interpreted as unsigned integer or `None` if it is not
+    /// representable by the bit width of `self`.
+    ///
+    /// # Note
+    ///
+    /// - Zero (`0`) results in one (`1`).
+    /// - This function **may** allocate memory.
+    pub fn checked_next_power_of_two(&self) -> Option<ApInt> {
+        self.next_power_of_two_pos()
+            .map(|_| self.clone().into_next_power_of_two())
+    }
+
+    /// Carry-less multiplies `self` with `rhs` and returns the full product.
+    ///
+    /// Both `ApInt`s are interpreted as polynomials over GF(2), so partial
+    /// products are combined with XOR instead of addition. The returned
+    /// `ApInt` has a bit width of `self.width() + rhs.width()` and thus never
+    /// loses any bits of the product.
+    ///
+    /// **Note:** `self` and `rhs` may have different bit widths. This function
+    /// allocates memory.
+    pub fn clmul(&self, rhs: &ApInt) -> ApInt {
+        let lhs = self.as_digit_slice();
+        let rhs_digits = rhs.as_digit_slice();
+        let mut product = vec![Digit::ZERO; lhs.len() + rhs_digits.len()];
+        for (i, &l) in lhs.iter().enumerate() {
+            if l.is_zero() {
+                continue
+            }
+            for (j, &r) in rhs_digits.iter().enumerate() {
+                let (lo, hi) = l.carryless_mul(r);
+                product[i + j] ^= lo;
+                product[i + j + 1] ^= hi;
+            }
+        }
+        let width = self.width().to_usize() + rhs.width().to_usize();
+        ApInt::from_iter(product)
+            .and_then(|product| product.into_truncate(width))
+            .expect(
+                "The product has at least one digit and enough digits for the sum of \
+                 the bit widths of `self` and `rhs`.",
+            )
+    }
+
+    /// Carry-less multiply-assigns `rhs` to `self` inplace, truncating the
+    /// product to the bit width of `self`.
+    ///
+    /// **Note:** This function allocates memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn wrapping_clmul_assign(&mut self, rhs: &ApInt) -> Result<()> {
+        if self.width() != rhs.width() {
+            return Error::unmatching_bitwidths(self.width(), rhs.width()).into()
+        }
+        let product = self.clmul(rhs).into_truncate(self.width())?;
+        *self = product;
+        Ok(())
+    }
+
+    /// Carry-less multiplies `self` with `rhs` and returns the product
+    /// truncated to the bit width of `self`.
+    ///
+    /// **Note:** This function allocates memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn into_wrapping_clmul(self, rhs: &ApInt) -> Result<ApInt> {
+        try_forward_bin_mut_impl(self, rhs, ApInt::wrapping_clmul_assign)
+    }
+}
+
+/// # Division Operations
+///
+/// **Note**: unless otherwise noted in the function specific documentation,
+///
+/// - The functions do **not** allocate memory.
+/// - The function works for both signed and unsigned interpretations of an
+///   `ApInt`. In other words, in the low-level bit-wise representation there is
+///   no difference between a signed and unsigned operation by the function on
+///   fixed bit-width integers. (Cite: LLVM)
+///
+/// In almost all integer division algorithms where "just" the quotient is
+/// calculated, the remainder is also produced and actually exists in memory (or
+/// at least is only one O(n) operation away) prior to being dropped or
+/// overwritten, and vice versa for remainder only calculations. Note here
+/// that functions with `div` in their names (e.g. `wrapping_div`) should really
+/// be called `quo` (quotient) functions, because the division process produces
+/// both the quotient and remainder. However, to stay with Rust's naming scheme
+/// we have kept `div` naming. The instruction for division on many CPUs sets
+/// registers to both results of the division process, and compilers will detect
+/// if code uses both results and only use one division instruction. There is no
+/// such detection for `ApInt`s, and thus the `divrem` and `remdiv` type
+/// instructions exist to explicitly use just one division function for both
+/// results.
+///
+/// ## Performance
+///
+/// All of the division functions in this `impl` quickly check for various edge
+/// cases and use an efficient algorithm for these cases.
+/// Small here means both small ApInt `BitWidth` and/or small **unsigned**
 /// numerical significance. (Signed division works, but two's complement
 /// negative numbers may have a large number of leading ones, leading to
 /// potential inefficiency.)
@@ -598,11 +1954,9 @@ impl ApInt {
 ///   allocation)
 /// - division of any integer by small (1 `Digit`) very small (0.5 `Digit`)
 ///   integers (no allocation)
-/// - division where the number of leading zeros of both arguments are within
-///   one `Digit` (less allocation than what long division normally requires)
-/// - during long division, the algorithm may encounter a case from above and
-///   will use that instead
-/// - division of medium size (<= 512 bits) integers
+///
+/// All other divisions by multi `Digit` divisors use Knuth's Algorithm D,
+/// which allocates normalized copies of both arguments.
 ///
 /// Currently, algorithms faster than 𝒪(n^2) are not implemented, so large
 /// integer division may be very slow compared to other algorithms.
@@ -619,14 +1973,11 @@ impl ApInt {
     /// the case of division by zero.
     #[inline]
     pub(crate) fn aarons_algorithm_divrem(duo: &mut [Digit], div: &mut [Digit]) -> bool {
-        // Some parts were put into their own functions and macros because indentation
-        // levels were getting too high, even for me.
+        // Some parts were put into their own functions because indentation levels
+        // were getting too high, even for me.
 
-        // The algorithm here is just like the algorithm in
-        // https://github.com/AaronKutch/specialized-div-rem,
-        // except that there are more branches and preconditions. There are comments in
-        // this function such as  `//quotient is 0 or 1 check` which correspond
-        // to comments in that function.
+        // Single `Digit` divisors are handled by short division, everything else
+        // by Knuth's Algorithm D in `large_div_by_large`.
 
         // assumptions:
         //  *ini_duo_sd > 0
@@ -652,703 +2003,158 @@ impl ApInt {
         // assumptions:
         //  *ini_duo_sd > 0
         //  *div_sd == 0
-        //  *div[0].leading_zeros >= 32
+        //  *div[0].leading_zeros >= Digit::BITS / 2
         #[inline(always)]
-        fn large_div_by_u32(duo: &mut [Digit], ini_duo_sd: usize, div: &mut [Digit]) {
-            let div_u32 = div[0].repr() as u32;
-            #[inline(always)]
-            fn dd(x: u32) -> Digit {
-                Digit(u64::from(x))
-            }
-            #[inline(always)]
-            fn lo(x: Digit) -> u32 {
-                x.repr() as u32
-            }
+        fn large_div_by_half_digit(
+            duo: &mut [Digit],
+            ini_duo_sd: usize,
+            div: &mut [Digit],
+        ) {
+            const HALF: usize = Digit::BITS / 2;
+            let div_half = div[0];
             #[inline(always)]
-            fn hi(x: Digit) -> u32 {
-                (x.repr() >> 32) as u32
+            fn lo(x: Digit) -> Digit {
+                x & (Digit::ONES >> HALF)
             }
             #[inline(always)]
-            fn from_lo_hi(lo: u32, hi: u32) -> Digit {
-                Digit(u64::from(lo) | (u64::from(hi) << 32))
+            fn hi(x: Digit) -> Digit {
+                x >> HALF
             }
             #[inline(always)]
-            fn wrapping_divrem(x: u32, y: u32) -> (u32, u32) {
-                (x.wrapping_div(y), x.wrapping_rem(y))
+            fn from_lo_hi(lo: Digit, hi: Digit) -> Digit {
+                lo | (hi << HALF)
             }
-            let (mut quo_hi, mut rem_hi) = wrapping_divrem(hi(duo[ini_duo_sd]), div_u32);
+            let (mut quo_hi, mut rem_hi) = hi(duo[ini_duo_sd]).wrapping_divrem(div_half);
             let duo_double = from_lo_hi(lo(duo[ini_duo_sd]), rem_hi);
-            let temp = duo_double.wrapping_divrem(dd(div_u32));
+            let temp = duo_double.wrapping_divrem(div_half);
             let mut quo_lo = lo(temp.0);
             let mut rem_lo = lo(temp.1);
             duo[ini_duo_sd] = from_lo_hi(quo_lo, quo_hi);
             for duo_sd_sub1 in (0..ini_duo_sd).rev() {
                 let duo_double_hi = from_lo_hi(hi(duo[duo_sd_sub1]), rem_lo);
-                let temp_hi = duo_double_hi.wrapping_divrem(dd(div_u32));
+                let temp_hi = duo_double_hi.wrapping_divrem(div_half);
                 quo_hi = lo(temp_hi.0);
                 rem_hi = lo(temp_hi.1);
                 let duo_double_lo = from_lo_hi(lo(duo[duo_sd_sub1]), rem_hi);
-                let temp_lo = duo_double_lo.wrapping_divrem(dd(div_u32));
+                let temp_lo = duo_double_lo.wrapping_divrem(div_half);
                 quo_lo = lo(temp_lo.0);
                 rem_lo = lo(temp_lo.1);
                 duo[duo_sd_sub1] = from_lo_hi(quo_lo, quo_hi);
             }
-            div[0] = Digit(u64::from(rem_lo));
+            div[0] = rem_lo;
         }
 
-        // modifies the `$array` to be the two's complement of itself, all the way up to
-        // a `$len` number of digits.
-        macro_rules! twos_complement {
-            ($len:expr, $array:ident) => {
-                for i0 in 0..$len {
-                    let bitnot = !$array[i0];
-                    match bitnot.overflowing_add(Digit::ONE) {
-                        (v, false) => {
-                            $array[i0] = v;
-                            for i1 in (i0 + 1)..$len {
-                                $array[i1] = !$array[i1]
-                            }
-                            break
-                        }
-                        (v, true) => {
-                            $array[i0] = v;
-                        }
-                    }
-                }
-            };
+        // Shifts the digits of `src` left by `shift < Digit::BITS` bits, pushes them
+        // onto `dst` and returns the bits shifted out of the most significant digit.
+        #[inline(always)]
+        fn shl_digits_into(src: &[Digit], shift: usize, dst: &mut Vec<Digit>) -> Digit {
+            if shift == 0 {
+                dst.extend_from_slice(src);
+                return Digit::ZERO
+            }
+            let mut carry = Digit::ZERO;
+            for &digit in src {
+                dst.push((digit << shift) | carry);
+                carry = digit >> (Digit::BITS - shift);
+            }
+            carry
         }
 
-        // uge stands for "unsigned greater or equal to"
-        // This checks for `$lhs >= $rhs` (checking only up to $lhs_len and $rhs_len
-        // respectively), and runs `$ge_branch` if true and `$ln_branch`
-        // otherwise
-        macro_rules! uge {
-            (
-                $lhs_len:expr,
-                $lhs:ident,
-                $rhs_len:expr,
-                $rhs:ident,
-                $ge_branch:block,
-                $ln_branch:block
-            ) => {
-                let mut b0 = false;
-                // allows lhs.len() to be smaller than rhs.len()
-                for i in ($lhs_len..$rhs_len).rev() {
-                    if !$rhs[i].is_zero() {
-                        b0 = true;
+        // Knuth's Algorithm D (The Art of Computer Programming, Vol. 2, 4.3.1).
+        //
+        // assumes that:
+        // ini_duo_sd >= div_sd
+        // div_sd > 0
+        #[inline(always)]
+        fn large_div_by_large(
+            duo: &mut [Digit], // the dividend which will become the quotient
+            ini_duo_sd: usize, // the initial most significant digit of `duo`
+            div: &mut [Digit], // the divisor which will become the remainder
+            div_sd: usize,     // the most significant digit of `div`
+        ) {
+            let div_len = div_sd + 1;
+            // D1: normalize both operands so that the most significant bit of the
+            // divisor is set, which keeps every quotient digit estimate at most 2 too
+            // large. The dividend gets an extra digit for the bits shifted out.
+            let shift = div[div_sd].leading_zeros() as usize;
+            let mut norm_div = Vec::with_capacity(div_len);
+            shl_digits_into(&div[..div_len], shift, &mut norm_div);
+            let mut norm_duo = Vec::with_capacity(ini_duo_sd + 2);
+            let duo_carry = shl_digits_into(&duo[..=ini_duo_sd], shift, &mut norm_duo);
+            norm_duo.push(duo_carry);
+            let div_hi = norm_div[div_sd];
+            let div_lo = norm_div[div_sd - 1];
+            // D2: loop over the quotient digits from most to least significant
+            for quo_i in (0..=(ini_duo_sd - div_sd)).rev() {
+                // D3: estimate the quotient digit from the top two digits of the
+                // current partial remainder and refine it with the third digit
+                let duo_top = DoubleDigit::from_lo_hi(
+                    norm_duo[quo_i + div_len - 1],
+                    norm_duo[quo_i + div_len],
+                );
+                let (mut quo_hat, mut rem_hat) = duo_top.wrapping_divrem(div_hi.dd());
+                while !quo_hat.hi().is_zero()
+                    || quo_hat.lo().dd().wrapping_mul(div_lo.dd())
+                        > DoubleDigit::from_lo_hi(
+                            norm_duo[quo_i + div_len - 2],
+                            rem_hat.lo(),
+                        )
+                {
+                    quo_hat = quo_hat - Digit::ONE.dd();
+                    rem_hat = rem_hat + div_hi.dd();
+                    if !rem_hat.hi().is_zero() {
                         break
                     }
                 }
-                if b0
-                    || ({
-                        let mut b1 = false;
-                        for i in (0..$lhs_len).rev() {
-                            if $lhs[i] < $rhs[i] {
-                                b1 = true;
-                                break
-                            } else if $lhs[i] != $rhs[i] {
-                                break
-                            }
-                        }
-                        b1
-                    })
-                {
-                    $ln_branch
-                } else {
-                    $ge_branch
-                }
-            };
-        }
-
-        // ugt stands for "unsigned greater than"
-        // This checks for `$lhs > $rhs` (checking only up to $lhs_len and $rhs_len
-        // respectively), and runs `$gt_branch` if true and `$le_branch`
-        // otherwise
-        macro_rules! ugt {
-            (
-                $lhs_len:expr,
-                $lhs:ident,
-                $rhs_len:expr,
-                $rhs:ident,
-                $gt_branch:block,
-                $le_branch:block
-            ) => {
-                let mut b0 = false;
-                // allows lhs.len() to be smaller than rhs.len()
-                for i in ($lhs_len..$rhs_len).rev() {
-                    if !$rhs[i].is_zero() {
-                        b0 = true;
-                        break
-                    }
-                }
-                if b0
-                    || ({
-                        let mut b1 = true;
-                        for i in (0..$lhs_len).rev() {
-                            if $lhs[i] > $rhs[i] {
-                                b1 = false;
-                                break
-                            } else if $lhs[i] != $rhs[i] {
-                                break
-                            }
-                        }
-                        b1
-                    })
-                {
-                    $le_branch
-                } else {
-                    $gt_branch
-                }
-            };
-        }
-
-        // assigns `$sum + $sub` to `$target`,
-        // and zeros out `$sum` except for it sets `$sum[0]` to `$val`
-        macro_rules! special0 {
-            ($len:expr, $sum:ident, $sub:ident, $target:ident, $val:expr) => {{
-                // subtraction (`sub` is the two's complement of some value)
-                let (sum, mut carry) = $sum[0].carrying_add($sub[0]);
-                $target[0] = sum;
-                $sum[0] = $val;
-                for i in 1..($len - 1) {
-                    let temp = $sum[i]
-                        .dd()
-                        .wrapping_add($sub[i].dd())
-                        .wrapping_add(carry.dd());
-                    $target[i] = temp.lo();
-                    $sum[i].unset_all();
-                    carry = temp.hi();
+                let mut quo_digit = quo_hat.lo();
+                // D4: multiply and subtract `quo_digit * norm_div` from the partial
+                // remainder
+                let mut mul_carry = Digit::ZERO;
+                let mut borrow = false;
+                for div_i in 0..div_len {
+                    let (prod, carry) =
+                        quo_digit.carrying_mul_add(norm_div[div_i], mul_carry);
+                    mul_carry = carry;
+                    let (temp, borrow0) = norm_duo[quo_i + div_i].overflowing_sub(prod);
+                    let (temp, borrow1) =
+                        temp.overflowing_sub(Digit(borrow as DigitRepr));
+                    norm_duo[quo_i + div_i] = temp;
+                    borrow = borrow0 || borrow1;
                 }
-                $target[$len - 1] = $sum[$len - 1]
-                    .wrapping_add($sub[$len - 1])
-                    .wrapping_add(carry);
-                $sum[$len - 1].unset_all();
-            }};
-        }
-
-        // assigns `$sum + $sub` to `$target`,
-        // and assigns `$val + $add` to `$sum`
-        macro_rules! special1 {
-            ($len:expr, $sum:ident, $sub:ident, $targ:ident, $val:expr, $add:ident) => {{
-                // subtraction (`sub` is the two's complement of some value)
-                let (temp, mut carry) = $sum[0].carrying_add($sub[0]);
-                $targ[0] = temp;
-                for i in 1..($len - 1) {
-                    let temp = $sum[i]
-                        .dd()
-                        .wrapping_add($sub[i].dd())
-                        .wrapping_add(carry.dd());
-                    $targ[i] = temp.lo();
-                    carry = temp.hi();
-                }
-                $targ[$len - 1] = $sum[$len - 1]
-                    .wrapping_add($sub[$len - 1])
-                    .wrapping_add(carry);
-                let (temp, mut carry) = $add[0].carrying_add($val);
-                $sum[0] = temp;
-                for i0 in 1..$len {
-                    if carry.is_zero() {
-                        for i1 in i0..$len {
-                            $sum[i1] = $add[i1];
-                            break
-                        }
+                let (temp, borrow0) =
+                    norm_duo[quo_i + div_len].overflowing_sub(mul_carry);
+                let (temp, borrow1) = temp.overflowing_sub(Digit(borrow as DigitRepr));
+                norm_duo[quo_i + div_len] = temp;
+                // D5 and D6: the estimate was still one too large, so add the divisor
+                // back. This happens with a probability of about `2 / 2^64`.
+                if borrow0 || borrow1 {
+                    quo_digit = quo_digit.wrapping_sub(Digit::ONE);
+                    let mut carry = Digit::ZERO;
+                    for div_i in 0..div_len {
+                        let temp = norm_duo[quo_i + div_i]
+                            .dd()
+                            .wrapping_add(norm_div[div_i].dd())
+                            .wrapping_add(carry.dd());
+                        norm_duo[quo_i + div_i] = temp.lo();
+                        carry = temp.hi();
                     }
-                    let temp = $add[i0].carrying_add(carry);
-                    $sum[i0] = temp.0;
-                    carry = temp.1;
-                }
-            }};
-        }
-
-        // assigns `$sum + $add` to `$sum`
-        macro_rules! add {
-            ($len:expr, $sum:ident, $add:ident) => {{
-                let (sum, mut carry) = $sum[0].carrying_add($add[0]);
-                $sum[0] = sum;
-                for i in 1..($len - 1) {
-                    let temp = $sum[i]
-                        .dd()
-                        .wrapping_add($add[i].dd())
-                        .wrapping_add(carry.dd());
-                    $sum[i] = temp.lo();
-                    carry = temp.hi();
+                    // the carry out of the top digit cancels the borrow from above
+                    norm_duo[quo_i + div_len] =
+                        norm_duo[quo_i + div_len].wrapping_add(carry);
                 }
-                $sum[$len - 1] = $sum[$len - 1]
-                    .wrapping_add($add[$len - 1])
-                    .wrapping_add(carry);
-            }};
-        }
-
-        // assumes that:
-        // ini_duo_sd > 1
-        // div_sd > 1
-        #[inline(always)]
-        fn large_div_by_large(
-            len: usize,        // equal to the length of `duo` and `div`, must be > 2
-            duo: &mut [Digit], // the dividend which will become the quotient
-            ini_duo_sd: usize, // the initial most significant digit of `duo`
-            div: &mut [Digit], // the divisor which will become the remainder
-            div_sd: usize,     // the most significant digit of `div`
-        ) {
-            let ini_duo_lz = duo[ini_duo_sd].leading_zeros() as usize;
-            let div_lz = div[div_sd].leading_zeros() as usize;
-            // number of significant bits
-            let ini_duo_sb =
-                (ini_duo_sd * Digit::BITS) + (Digit::BITS - (ini_duo_lz as usize));
-            let div_sb = (div_sd * Digit::BITS) + (Digit::BITS - div_lz);
-            // quotient is 0 precheck
-            if ini_duo_sb < div_sb {
-                // the quotient should be 0 and remainder should be `duo`
-                for i in 0..=ini_duo_sd {
-                    div[i] = duo[i];
-                    duo[i].unset_all();
-                }
-                for i in (ini_duo_sd + 1)..=div_sd {
-                    div[i].unset_all();
-                }
-                return
-            }
-            // quotient is 0 or 1 check
-            if ini_duo_sb == div_sb {
-                let place = ini_duo_sd + 1;
-                uge!(
-                    place,
-                    duo,
-                    place,
-                    div,
-                    {
-                        twos_complement!(place, div);
-                        special0!(place, duo, div, div, Digit::ONE);
-                        return
-                    },
-                    {
-                        for i in 0..=ini_duo_sd {
-                            div[i] = duo[i];
-                            duo[i].unset_all();
-                        }
-                        for i in place..=div_sd {
-                            div[i].unset_all();
-                        }
-                        return
-                    }
-                );
+                duo[quo_i] = quo_digit;
             }
-            let ini_bits = ini_duo_sb - div_sb;
-            // difference between the places of the significant bits
-            if ini_bits < Digit::BITS {
-                // the `mul` or `mul - 1` algorithm
-                let (duo_sig_dd, div_sig_dd) = if ini_duo_lz == 0 {
-                    // avoid shr overflow
-                    (
-                        DoubleDigit::from_lo_hi(duo[ini_duo_sd - 1], duo[ini_duo_sd]),
-                        DoubleDigit::from_lo_hi(div[ini_duo_sd - 1], div[ini_duo_sd]),
-                    )
-                } else {
-                    (
-                        (duo[ini_duo_sd].dd() << (ini_duo_lz + Digit::BITS))
-                            | (duo[ini_duo_sd - 1].dd() << ini_duo_lz)
-                            | (duo[ini_duo_sd - 2].dd() >> (Digit::BITS - ini_duo_lz)),
-                        (div[ini_duo_sd].dd() << (ini_duo_lz + Digit::BITS))
-                            | (div[ini_duo_sd - 1].dd() << ini_duo_lz)
-                            | (div[ini_duo_sd - 2].dd() >> (Digit::BITS - ini_duo_lz)),
-                    )
-                };
-                let mul = duo_sig_dd.wrapping_div(div_sig_dd).lo();
-                // Allocation could be avoided but it would involve more long division to
-                // recover
-                //`div`.
-                // this will become `-(div * mul)`
-                let mut sub: Vec<Digit> = Vec::with_capacity(len);
-                // first digit done and carry
-                let temp = mul.carrying_mul(div[0]);
-                sub.push(temp.0);
-                let mut carry = temp.1;
-                // middle of row
-                for i in 1..div_sd {
-                    let temp = mul.carrying_mul_add(div[i], carry);
-                    sub.push(temp.0);
-                    carry = temp.1;
-                }
-                // final digit, test for `div * mul > duo`, and then form the two's
-                // complement
-                if div_sd == len - 1 {
-                    let temp = mul.carrying_mul_add(div[div_sd], carry);
-                    sub.push(temp.0);
-                    if !temp.1.is_zero() {
-                        // overflow
-                        // the quotient should be `mul - 1` and remainder should be
-                        //`duo + (div - div*mul)`
-                        twos_complement!(len, sub);
-                        add!(len, sub, div);
-                        special0!(len, duo, sub, div, mul.wrapping_sub(Digit::ONE));
-                        return
-                    }
-                    // if `div * mul > duo`
-                    ugt!(
-                        len,
-                        sub,
-                        len,
-                        duo,
-                        {
-                            twos_complement!(len, sub);
-                            add!(len, sub, div);
-                            special0!(len, duo, sub, div, mul.wrapping_sub(Digit::ONE));
-                            return
-                        },
-                        {
-                            // the quotient is `mult` and remainder is `duo - (div *
-                            // mult)`
-                            twos_complement!(len, sub);
-                            special0!(len, duo, sub, div, mul);
-                            return
-                        }
-                    );
-                } else {
-                    let temp = mul.carrying_mul_add(div[div_sd], carry);
-                    sub.push(temp.0);
-                    sub.push(temp.1);
-                    for _ in sub.len()..len {
-                        sub.push(Digit::ZERO);
-                    }
-                    // if `div * mul > duo`
-                    ugt!(
-                        len,
-                        sub,
-                        len,
-                        duo,
-                        {
-                            twos_complement!(len, sub);
-                            add!(len, sub, div);
-                            special0!(len, duo, sub, div, mul.wrapping_sub(Digit::ONE));
-                            return
-                        },
-                        {
-                            // the quotient is `mult` and remainder is `duo - (div *
-                            // mult)`
-                            twos_complement!(len, sub);
-                            special0!(len, duo, sub, div, mul);
-                            return
-                        }
-                    );
-                }
+            for digit in duo[(ini_duo_sd - div_sd + 1)..=ini_duo_sd].iter_mut() {
+                digit.unset_all();
             }
-            let mut duo_sd = ini_duo_sd;
-            let mut duo_lz = ini_duo_lz;
-            // the number of lesser significant digits and bits not a part of `div_sig_d`
-            let div_lesser_bits =
-                Digit::BITS - (div_lz as usize) + (Digit::BITS * (div_sd - 1));
-            // the most significant `Digit` bits of div
-            let div_sig_d = if div_lz == 0 {
-                div[div_sd]
-            } else {
-                (div[div_sd] << div_lz) | (div[div_sd - 1] >> (Digit::BITS - div_lz))
-            };
-            // has to be a `DoubleDigit` in case of overflow
-            let div_sig_d_add1 = div_sig_d.dd().wrapping_add(Digit::ONE.dd());
-            let mut duo_lesser_bits;
-            let mut duo_sig_dd;
-            // TODO: fix sizes here and below
-            let quo_potential = len;
-            // if ini_bits % Digit::BITS == 0 {ini_bits / Digit::BITS}
-            // else {(ini_bits / Digit::BITS) + 1};
-            let mut quo: Vec<Digit> = vec![Digit::ZERO; quo_potential as usize];
-            loop {
-                duo_lesser_bits =
-                    (Digit::BITS - (duo_lz as usize)) + (Digit::BITS * (duo_sd - 2));
-                duo_sig_dd = if duo_lz == 0 {
-                    DoubleDigit::from_lo_hi(duo[duo_sd - 1], duo[duo_sd])
+            // D8: unnormalize the remainder
+            for div_i in 0..div_len {
+                div[div_i] = if shift == 0 {
+                    norm_duo[div_i]
                 } else {
-                    (duo[duo_sd].dd() << (duo_lz + Digit::BITS))
-                        | (duo[duo_sd - 1].dd() << duo_lz)
-                        | (duo[duo_sd - 2].dd() >> (Digit::BITS - duo_lz))
+                    (norm_duo[div_i] >> shift)
+                        | (norm_duo[div_i + 1] << (Digit::BITS - shift))
                 };
-                if duo_lesser_bits >= div_lesser_bits {
-                    let bits = duo_lesser_bits - div_lesser_bits;
-                    // bits_ll is the number of lesser bits in the digit that contains
-                    // lesser and greater bits
-                    let (digits, bits_ll) = (bits / Digit::BITS, bits % Digit::BITS);
-                    // Unfortunately, `mul` here can be up to (2^2n - 1)/(2^(n-1)), where
-                    // `n` is the number of bits in a `Digit`. This
-                    // means that an `n+1` bit integer is needed to
-                    // store mul. Because only one extra higher bit is involved,
-                    // the algebraic simplification `(mul + 2^n)*div` to `mul*div +
-                    // div*2^n` can be used when that highest bit is
-                    // set. This just requires faster and simpler
-                    // addition inlining hell instead of long multiplication inlining
-                    // hell.
-                    let mul = duo_sig_dd.wrapping_div(div_sig_d_add1);
-                    // add `mul << bits` to `quo`
-                    // no inlining hell here because `bits_ll < n` and it takes a shift of
-                    // `n` to overflow
-                    let split_mul = mul << bits_ll;
-                    let (temp, mut carry) = split_mul.lo().carrying_add(quo[digits]);
-                    quo[digits] = temp;
-                    let temp = split_mul
-                        .hi()
-                        .dd()
-                        .wrapping_add(quo[digits + 1].dd())
-                        .wrapping_add(carry.dd());
-                    quo[digits + 1] = temp.lo();
-                    carry = temp.hi();
-                    for i in (digits + 2)..quo.len() {
-                        if carry.is_zero() {
-                            break
-                        }
-                        let temp = quo[i].carrying_add(carry);
-                        quo[i] = temp.0;
-                        carry = temp.1;
-                    }
-                    // special long division algorithm core.
-                    // Note that nearly all branches before this are not just wanted for
-                    // performance reasons but are actually required
-                    // in order to not break this. these blocks
-                    // subtract `(mul * div) << bits` from `duo` check
-                    // for highest bit set
-                    if mul.hi().is_zero() {
-                        let mul = mul.lo();
-                        // carry for bits that wrap across digit boundaries when `<<
-                        // bits_ll` applied
-                        let (temp0, mut wrap_carry) = (div[0].dd() << bits_ll).lo_hi();
-                        // the regular multiplication carry
-                        let (temp1, mut mul_carry) =
-                            mul.dd().wrapping_mul(temp0.dd()).lo_hi();
-                        // this carry includes the two's complement increment carry
-                        let (temp2, mut add_carry) = (!temp1)
-                            .dd()
-                            .wrapping_add(duo[digits].dd())
-                            .wrapping_add(Digit::ONE.dd())
-                            .lo_hi();
-                        duo[digits] = temp2;
-                        for i in (digits + 1)..=duo_sd {
-                            let temp0 = ((div[i - digits].dd() << bits_ll)
-                                | wrap_carry.dd())
-                            .lo_hi();
-                            wrap_carry = temp0.1;
-                            let temp1 = mul
-                                .dd()
-                                .wrapping_mul(temp0.0.dd())
-                                .wrapping_add(mul_carry.dd())
-                                .lo_hi();
-                            mul_carry = temp1.1;
-                            let temp2 = (!temp1.0)
-                                .dd()
-                                .wrapping_add(duo[i].dd())
-                                .wrapping_add(add_carry.dd())
-                                .lo_hi();
-                            add_carry = temp2.1;
-                            duo[i] = temp2.0;
-                        }
-                    } else {
-                        //  2222x <- mul_carry
-                        //   7987 <- div
-                        //      3 <- mul (13) without high bit
-                        // *_____
-                        //  23961 <- temp0
-                        //
-                        // 1111xx <- add0_carry
-                        //  23961 <- temp0
-                        //  7987  <- div shifted up by one digit
-                        //+______
-                        // 103831 <- temp1
-                        //
-                        // subtract duo by temp1 negated (with the carry from the two's
-                        // complement being put into `wrap_carry`)
-                        // and shifted (with `wrap_carry`)
-
-                        let mul = mul.lo();
-                        let (temp0, mut mul_carry) = mul.carrying_mul(div[0]);
-                        let temp1 = temp0;
-                        let mut add0_carry = Digit::ZERO;
-                        // the increment from the two's complement can be stored in
-                        // `wrap_carry`
-                        let (temp2, mut wrap_carry) =
-                            ((!temp1).dd().wrapping_add(Digit::ONE.dd()) << bits_ll)
-                                .lo_hi();
-                        let (temp3, mut add1_carry) = temp2.carrying_add(duo[digits]);
-                        duo[digits] = temp3;
-                        for i in (digits + 1)..=duo_sd {
-                            let temp0 = mul
-                                .dd()
-                                .wrapping_mul(div[i - digits].dd())
-                                .wrapping_add(mul_carry.dd());
-                            mul_carry = temp0.hi();
-                            let temp1 = temp0
-                                .lo()
-                                .dd()
-                                .wrapping_add(div[i - digits - 1].dd())
-                                .wrapping_add(add0_carry.dd());
-                            add0_carry = temp1.hi();
-                            let temp2 = ((!temp1.lo()).dd() << bits_ll)
-                                .wrapping_add(wrap_carry.dd());
-                            wrap_carry = temp2.hi();
-                            let temp3 = temp2
-                                .lo()
-                                .dd()
-                                .wrapping_add(duo[i].dd())
-                                .wrapping_add(add1_carry.dd());
-                            add1_carry = temp3.hi();
-                            duo[i] = temp3.lo();
-                        }
-                    }
-                } else {
-                    // the `mul` or `mul - 1` algorithm with addition from `quo`
-                    let div_sig_dd = if duo_lz == 0 {
-                        // avoid shr overflow
-                        DoubleDigit::from_lo_hi(div[duo_sd - 1], div[duo_sd])
-                    } else {
-                        (div[duo_sd].dd() << (duo_lz + Digit::BITS))
-                            | (div[duo_sd - 1].dd() << duo_lz)
-                            | (div[duo_sd - 2].dd() >> (Digit::BITS - duo_lz))
-                    };
-                    let mul = duo_sig_dd.wrapping_div(div_sig_dd).lo();
-                    // I could avoid allocation but it would involve more long division to
-                    // recover
-                    //`div`, followed by a second long multiplication with `mul - 1`.
-                    // this will become `-(div * mul)`
-                    // note: div_sd != len - 1 because it would be caught by the first
-                    // `mul` or
-                    //`mul-1` algorithm
-                    let mut sub: Vec<Digit> = Vec::with_capacity(len);
-                    // first digit done and carry
-                    let (temp, mut mul_carry) =
-                        mul.dd().wrapping_mul(div[0].dd()).lo_hi();
-                    sub.push(temp);
-                    for i in 1..div_sd {
-                        let temp = mul.carrying_mul_add(div[i], mul_carry);
-                        sub.push(temp.0);
-                        mul_carry = temp.1;
-                    }
-                    let temp = mul.carrying_mul_add(div[div_sd], mul_carry);
-                    sub.push(temp.0);
-                    sub.push(temp.1);
-                    for _ in (div_sd + 2)..len {
-                        sub.push(Digit::ZERO);
-                    }
-                    let sub_len = sub.len();
-                    ugt!(
-                        sub_len,
-                        sub,
-                        len,
-                        duo,
-                        {
-                            // the quotient is `quo + (mult - 1)` and remainder is
-                            //`duo + (div - div*mul)`
-                            twos_complement!(sub_len, sub);
-                            add!(sub_len, sub, div);
-                            special1!(
-                                sub_len,
-                                duo,
-                                sub,
-                                div,
-                                mul.wrapping_sub(Digit::ONE),
-                                quo
-                            );
-                            return
-                        },
-                        {
-                            // the quotient is `quo + mult` and remainder is `duo - (div *
-                            // mult)`
-                            twos_complement!(sub_len, sub);
-                            special1!(sub_len, duo, sub, div, mul, quo);
-                            return
-                        }
-                    );
-                }
-                // find the new `duo_sd`
-                for i in (0..=duo_sd).rev() {
-                    if !duo[i].is_zero() {
-                        duo_sd = i;
-                        break
-                    }
-                    if i == 0 {
-                        // the quotient should be `quo` and remainder should be zero
-                        for i in 0..len {
-                            div[i] = Digit::ZERO;
-                            duo[i] = quo[i];
-                        }
-                        return
-                    }
-                }
-                duo_lz = duo[duo_sd].leading_zeros() as usize;
-                let duo_sb = (duo_sd * Digit::BITS) + (Digit::BITS - duo_lz);
-                //`quo` should have 0 or 1 added to it check
-                if duo_sb == div_sb {
-                    // if `div <= duo`
-                    uge!(
-                        len,
-                        duo,
-                        len,
-                        div,
-                        {
-                            // the quotient should be `quo + 1` and remainder should be
-                            // `duo - div`
-                            twos_complement!(len, div);
-                            add!(len, div, duo);
-                            for i0 in 0..len {
-                                match quo[i0].overflowing_add(Digit::ONE) {
-                                    (v, false) => {
-                                        duo[i0] = v;
-                                        for i1 in (i0 + 1)..len {
-                                            duo[i1] = quo[i1];
-                                        }
-                                        break
-                                    }
-                                    (v, true) => {
-                                        duo[i0] = v;
-                                    }
-                                }
-                            }
-                            return
-                        },
-                        {
-                            // the quotient should be `quo` and remainder should be `duo`
-                            for i in 0..len {
-                                div[i] = duo[i];
-                                duo[i] = quo[i];
-                            }
-                            return
-                        }
-                    );
-                }
-                // more 0 cases check
-                if div_sb > duo_sb {
-                    // the quotient should be `quo` and remainder should be `duo`
-                    for i in 0..len {
-                        div[i] = duo[i];
-                        duo[i] = quo[i];
-                    }
-                    return
-                }
-                // this can only happen if `div_sd < 2` (because of above branches),
-                // but it is not worth it to unroll further
-                if duo_sd < 2 {
-                    // duo_sd < 2 because of the "if `duo >= div`" branch above
-                    // simple division and addition
-                    let duo_dd = DoubleDigit::from_lo_hi(duo[0], duo[1]);
-                    let div_dd = DoubleDigit::from_lo_hi(div[0], div[1]);
-                    let (mul, rem) = duo_dd.wrapping_divrem(div_dd);
-                    // the quotient should be `quo + mul` and remainder should be `rem`
-                    div[0] = rem.lo();
-                    div[1] = rem.hi();
-                    let (temp, mut carry) = quo[0].carrying_add(mul.lo());
-                    duo[0] = temp;
-                    let temp = quo[1]
-                        .dd()
-                        .wrapping_add(mul.hi().dd())
-                        .wrapping_add(carry.dd());
-                    duo[1] = temp.lo();
-                    carry = temp.hi();
-                    for i0 in 2..len {
-                        if carry.is_zero() {
-                            duo[i0..len].clone_from_slice(&quo[i0..len]);
-                            break
-                        }
-                        let temp = quo[i0].carrying_add(carry);
-                        duo[i0] = temp.0;
-                        carry = temp.1;
-                    }
-                    return
-                }
             }
         }
 
@@ -1391,13 +2197,13 @@ impl ApInt {
                             div[1] = temp.1.hi();
                             return true
                         }
-                        large_div_by_large(duo.len(), duo, ini_duo_sd, div, div_sd);
+                        large_div_by_large(duo, ini_duo_sd, div, div_sd);
                         true
                     }
                     (true, false) => unreachable!(),
                     (false, true) => {
-                        if div[0].leading_zeros() >= 32 {
-                            large_div_by_u32(duo, ini_duo_sd, div);
+                        if div[0].leading_zeros() as usize >= Digit::BITS / 2 {
+                            large_div_by_half_digit(duo, ini_duo_sd, div);
                             true
                         } else {
                             large_div_by_small(duo, ini_duo_sd, div);
@@ -1437,6 +2243,94 @@ impl ApInt {
         }
     }
 
+    /// Quotient-assigns `self` by the single `Digit` `div` using **unsigned**
+    /// interpretation and returns the remainder.
+    ///
+    /// This is a simple schoolbook long division which does **not** allocate
+    /// memory. The caller must ensure that `div` is not zero.
+    pub(crate) fn wrapping_udivrem_digit_assign(&mut self, div: Digit) -> Digit {
+        debug_assert!(!div.is_zero());
+        match self.access_data_mut() {
+            DataAccessMut::Inl(duo) => {
+                let (quo, rem) = duo.wrapping_divrem(div);
+                *duo = quo;
+                rem
+            }
+            DataAccessMut::Ext(duo) => {
+                let mut rem = Digit::ZERO;
+                for d in duo.iter_mut().rev() {
+                    let (quo, r) =
+                        DoubleDigit::from_lo_hi(*d, rem).wrapping_divrem(div.dd());
+                    *d = quo.lo();
+                    rem = r.lo();
+                }
+                rem
+            }
+        }
+    }
+
+    /// Quotient-assigns `self` by `divisor` inplace using **unsigned**
+    /// interpretation and returns the remainder.
+    ///
+    /// This is a single pass over the digits of `self` from the most
+    /// significant to the least significant one and does **not** allocate
+    /// memory.
+    ///
+    /// # Errors
+    ///
+    /// - If division by zero is attempted
+    pub fn div_rem_u64_assign(&mut self, divisor: u64) -> Result<u64> {
+        if divisor == 0 {
+            return Err(Error::division_by_zero(DivOp::UnsignedDivRem, self.clone()))
+        }
+        if divisor <= u64::from(Digit::ONES.repr()) {
+            let rem = self.wrapping_udivrem_digit_assign(Digit(divisor as DigitRepr));
+            return Ok(u64::from(rem.repr()))
+        }
+        // the divisor does not fit into a single `Digit`
+        let divisor = u128::from(divisor);
+        let mut rem = 0;
+        for digit in self.as_digit_slice_mut().iter_mut().rev() {
+            let duo = (rem << Digit::BITS) | u128::from(digit.repr());
+            *digit = Digit((duo / divisor) as DigitRepr);
+            rem = duo % divisor;
+        }
+        Ok(rem as u64)
+    }
+
+    /// Returns the remainder of `self` by `divisor` using **unsigned**
+    /// interpretation.
+    ///
+    /// Like `div_rem_u64_assign` this is a single pass over the digits of
+    /// `self`, but the quotient is not stored. This does **not** allocate
+    /// memory.
+    ///
+    /// # Errors
+    ///
+    /// - If division by zero is attempted
+    pub fn rem_u64(&self, divisor: u64) -> Result<u64> {
+        if divisor == 0 {
+            return Err(Error::division_by_zero(DivOp::UnsignedRem, self.clone()))
+        }
+        if divisor <= u64::from(Digit::ONES.repr()) {
+            let div = Digit(divisor as DigitRepr).dd();
+            let rem = self
+                .as_digit_slice()
+                .iter()
+                .rev()
+                .fold(Digit::ZERO, |rem, d| {
+                    DoubleDigit::from_lo_hi(*d, rem).wrapping_divrem(div).1.lo()
+                });
+            return Ok(u64::from(rem.repr()))
+        }
+        // the divisor does not fit into a single `Digit`
+        let divisor = u128::from(divisor);
+        let rem = self.as_digit_slice().iter().rev().fold(0, |rem, d| {
+            ((rem << Digit::BITS) | u128::from(d.repr())) % divisor
+        });
+        Ok(rem as u64)
+    }
+
     /// Divides `lhs` by `rhs` using **unsigned** interpretation and sets `lhs`
     /// equal to the quotient and `rhs` equal to the remainder. This
     /// function **may** allocate memory.
@@ -1496,6 +2390,113 @@ impl ApInt {
         Err(Error::division_by_zero(DivOp::UnsignedRemDiv, lhs.clone()))
     }
 
+    /// Divides `self` by `rhs` using **unsigned** interpretation and returns
+    /// the quotient and the remainder, in that order.
+    ///
+    /// Both results are produced by a single division. The quotient reuses the
+    /// allocation of `self` and the remainder is built in a copy of `rhs`. Use
+    /// `wrapping_udivrem_assign` to reuse both existing allocations.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn into_wrapping_udivrem(mut self, rhs: &ApInt) -> Result<(ApInt, ApInt)> {
+        let mut rem = rhs.clone();
+        ApInt::wrapping_udivrem_assign(&mut self, &mut rem)?;
+        Ok((self, rem))
+    }
+
+    /// Divides the double-width integer `hi:lo` by `divisor` using **unsigned**
+    /// interpretation and returns the quotient and the remainder, in that
+    /// order.
+    ///
+    /// `hi` is the most significant half of the dividend. The quotient fits
+    /// into the bit width of `divisor` because `hi` must be less than
+    /// `divisor`.
+    ///
+    /// # Note
+    ///
+    /// - If `hi` is zero this is a plain division of `lo` by `divisor`.
+    /// - If `divisor` fits into a single `Digit` the quotient is computed by a
+    ///   single pass of short division over the digits of `lo` that starts out
+    ///   with `hi` as the remainder.
+    /// - Otherwise, if the bit width is a multiple of the bits of a `Digit`,
+    ///   the halves are laid out directly as the digits of the dividend, else a
+    ///   temporary `ApInt` of twice the bit width is constructed.
+    ///
+    /// This function allocates memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `hi`, `lo` and `divisor` have unmatching bit widths.
+    /// - If division by zero is attempted
+    /// - If `hi` is not less than `divisor`.
+    pub fn div_wide(hi: &ApInt, lo: &ApInt, divisor: &ApInt) -> Result<(ApInt, ApInt)> {
+        if hi.width() != lo.width() {
+            return Error::unmatching_bitwidths(hi.width(), lo.width()).into()
+        }
+        if hi.width() != divisor.width() {
+            return Error::unmatching_bitwidths(hi.width(), divisor.width()).into()
+        }
+        if divisor.is_zero() {
+            return Err(Error::division_by_zero(DivOp::UnsignedDivWide, lo.clone()))
+        }
+        if !hi.checked_ult(divisor)? {
+            return Err(Error::quotient_overflow())
+        }
+        if hi.is_zero() {
+            return lo.clone().into_wrapping_udivrem(divisor)
+        }
+        let width = divisor.width();
+        let div = divisor.least_significant_digit();
+        if divisor.as_digit_slice()[1..].iter().all(|d| d.is_zero()) {
+            // `hi < divisor` fits into a single `Digit` and the partial
+            // remainders stay less than `divisor`, so every quotient digit fits
+            // into a single `Digit` as well
+            let mut quo = lo.clone();
+            let top_bits = width.excess_bits().unwrap_or(Digit::BITS);
+            let mut rem = hi.least_significant_digit();
+            let mut bits = top_bits;
+            for digit in quo.as_digit_slice_mut().iter_mut().rev() {
+                let duo = (rem.dd() << bits) | digit.dd();
+                let (q, r) = duo.wrapping_divrem(div.dd());
+                *digit = q.lo();
+                rem = r.lo();
+                bits = Digit::BITS;
+            }
+            let mut rem_apint = ApInt::zero(width);
+            rem_apint.as_digit_slice_mut()[0] = rem;
+            return Ok((quo, rem_apint))
+        }
+        if width.excess_bits().is_some() {
+            let double_width = BitWidth::new(width.to_usize() * 2)?;
+            let mut duo = hi
+                .clone()
+                .into_zero_extend(double_width)?
+                .into_wrapping_shl(width.to_usize())?;
+            duo.bitor_assign(&lo.clone().into_zero_extend(double_width)?)?;
+            let (quo, rem) = duo.into_wrapping_udivrem(
+                &divisor.clone().into_zero_extend(double_width)?,
+            )?;
+            return Ok((quo.into_truncate(width)?, rem.into_truncate(width)?))
+        }
+        let len = width.required_digits();
+        let mut duo: Vec<Digit> = lo
+            .as_digit_slice()
+            .iter()
+            .chain(hi.as_digit_slice())
+            .cloned()
+            .collect();
+        let mut div: Vec<Digit> = divisor.as_digit_slice().to_vec();
+        div.resize(2 * len, Digit::ZERO);
+        ApInt::aarons_algorithm_divrem(&mut duo, &mut div);
+        Ok((
+            ApInt::from_iter(duo[..len].iter().cloned())?,
+            ApInt::from_iter(div[..len].iter().cloned())?,
+        ))
+    }
+
     /// Quotient-assigns `lhs` by `rhs` inplace using **unsigned**
     /// interpretation. This function **may** allocate memory.
     ///
@@ -1575,6 +2576,9 @@ impl ApInt {
     /// - If `lhs` and `rhs` have unmatching bit widths.
     /// - If division by zero is attempted
     pub fn wrapping_sdivrem_assign(lhs: &mut ApInt, rhs: &mut ApInt) -> Result<()> {
+        if lhs.width() != rhs.width() {
+            return Error::unmatching_bitwidths(lhs.width(), rhs.width()).into()
+        }
         if rhs.is_zero() {
             return Err(Error::division_by_zero(DivOp::SignedDivRem, lhs.clone()))
         }
@@ -1614,6 +2618,9 @@ impl ApInt {
     /// - If `lhs` and `rhs` have unmatching bit widths.
     /// - If division by zero is attempted
     pub fn wrapping_sremdiv_assign(lhs: &mut ApInt, rhs: &mut ApInt) -> Result<()> {
+        if lhs.width() != rhs.width() {
+            return Error::unmatching_bitwidths(lhs.width(), rhs.width()).into()
+        }
         if rhs.is_zero() {
             return Err(Error::division_by_zero(DivOp::SignedRemDiv, lhs.clone()))
         }
@@ -1644,14 +2651,37 @@ impl ApInt {
         Ok(())
     }
 
+    /// Divides `self` by `rhs` using **signed** interpretation and returns
+    /// the quotient and the remainder, in that order.
+    ///
+    /// Both results are produced by a single division. The quotient reuses the
+    /// allocation of `self` and the remainder is built in a copy of `rhs`. Use
+    /// `wrapping_sdivrem_assign` to reuse both existing allocations.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn into_wrapping_sdivrem(mut self, rhs: &ApInt) -> Result<(ApInt, ApInt)> {
+        let mut rem = rhs.clone();
+        ApInt::wrapping_sdivrem_assign(&mut self, &mut rem)?;
+        Ok((self, rem))
+    }
+
     /// Quotient-assigns `lhs` by `rhs` inplace using **signed** interpretation.
     /// This function **may** allocate memory.
     ///
+    /// **Note:** The quotient of `signed_min_value` by `-1` wraps around to
+    /// `signed_min_value`. See `overflowing_sdiv_assign` for detecting this.
+    ///
     /// # Errors
     ///
     /// - If `lhs` and `rhs` have unmatching bit widths.
     /// - If division by zero is attempted
     pub fn wrapping_sdiv_assign(&mut self, rhs: &ApInt) -> Result<()> {
+        if self.width() != rhs.width() {
+            return Error::unmatching_bitwidths(self.width(), rhs.width()).into()
+        }
         if rhs.is_zero() {
             return Err(Error::division_by_zero(DivOp::SignedDiv, self.clone()))
         }
@@ -1694,11 +2724,16 @@ impl ApInt {
     /// Remainder-assigns `lhs` by `rhs` inplace using **signed**
     /// interpretation. This function **may** allocate memory.
     ///
+    /// **Note:** The remainder of `signed_min_value` by `-1` is zero.
+    ///
     /// # Errors
     ///
     /// - If `lhs` and `rhs` have unmatching bit widths.
     /// - If division by zero is attempted
     pub fn wrapping_srem_assign(&mut self, rhs: &ApInt) -> Result<()> {
+        if self.width() != rhs.width() {
+            return Error::unmatching_bitwidths(self.width(), rhs.width()).into()
+        }
         if rhs.is_zero() {
             return Err(Error::division_by_zero(DivOp::SignedRem, self.clone()))
         }
@@ -1737,659 +2772,4683 @@ impl ApInt {
     pub fn into_wrapping_srem(self, rhs: &ApInt) -> Result<ApInt> {
         try_forward_bin_mut_impl(self, rhs, ApInt::wrapping_srem_assign)
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
-    mod inc {
-        use super::*;
-        use core::u64;
+    /// Returns `true` if the **signed** division of `self` by `rhs` does not
+    /// fit into the bit width, which is only the case for `signed_min_value`
+    /// divided by `-1`.
+    fn is_sdiv_overflow(&self, rhs: &ApInt) -> bool {
+        rhs.is_all_set() && self.msb() && self.count_ones() == 1
+    }
 
-        #[test]
-        fn test() {
-            assert_eq!(ApInt::from(14u8).into_wrapping_inc(), ApInt::from(15u8));
-            assert_eq!(ApInt::from(15u8).into_wrapping_inc(), ApInt::from(16u8));
-            assert_eq!(ApInt::from(16u8).into_wrapping_inc(), ApInt::from(17u8));
-            assert_eq!(ApInt::from(17u8).into_wrapping_inc(), ApInt::from(18u8));
-            assert_eq!(
-                ApInt::from([0u64, 0, 0]).into_wrapping_inc(),
-                ApInt::from([0u64, 0, 1])
-            );
-            assert_eq!(
-                ApInt::from([0, 7, u64::MAX]).into_wrapping_inc(),
-                ApInt::from([0u64, 8, 0])
-            );
-            assert_eq!(
-                ApInt::from([u64::MAX, u64::MAX]).into_wrapping_inc(),
-                ApInt::from([0u64, 0])
-            );
-            assert_eq!(
-                ApInt::from([0, u64::MAX, u64::MAX - 1]).into_wrapping_inc(),
-                ApInt::from([0, u64::MAX, u64::MAX])
-            );
-            assert_eq!(
-                ApInt::from([0, u64::MAX, 0]).into_wrapping_inc(),
-                ApInt::from([0, u64::MAX, 1])
-            );
+    /// Quotient-assigns `self` by `rhs` inplace using **signed**
+    /// interpretation and returns `true` if the quotient was not
+    /// representable, in which case `self` is `signed_min_value`. This
+    /// function **may** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn overflowing_sdiv_assign(&mut self, rhs: &ApInt) -> Result<bool> {
+        if self.width() != rhs.width() {
+            return Error::unmatching_bitwidths(self.width(), rhs.width()).into()
         }
+        let overflow = self.is_sdiv_overflow(rhs);
+        self.wrapping_sdiv_assign(rhs)?;
+        Ok(overflow)
     }
 
-    mod wrapping_neg {
-        use super::*;
-        use crate::bitwidth::BitWidth;
-
-        fn assert_symmetry(input: ApInt, expected: ApInt) {
-            assert_eq!(input.clone().into_wrapping_neg(), expected.clone());
-            assert_eq!(expected.into_wrapping_neg(), input);
-        }
+    /// Divides `self` by `rhs` using **signed** interpretation and returns the
+    /// quotient as well as a boolean indicating if the quotient was not
+    /// representable. This function **may** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn into_overflowing_sdiv(mut self, rhs: &ApInt) -> Result<(ApInt, bool)> {
+        let overflow = self.overflowing_sdiv_assign(rhs)?;
+        Ok((self, overflow))
+    }
 
-        fn test_vals() -> impl Iterator<Item = i128> {
-            [
-                0_i128, 1, 2, 4, 5, 7, 10, 42, 50, 100, 128, 150, 1337, 123123, 999999,
-                987432, 77216417,
+    /// Divides `self` by `rhs` using **signed** interpretation and returns the
+    /// quotient, or `None` if the quotient was not representable. This
+    /// function **may** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn checked_sdiv(&self, rhs: &ApInt) -> Result<Option<ApInt>> {
+        let (quo, overflow) = self.clone().into_overflowing_sdiv(rhs)?;
+        Ok(if overflow { None } else { Some(quo) })
+    }
+
+    /// Remainder-assigns `self` by `rhs` inplace using **signed**
+    /// interpretation and returns `true` if the corresponding quotient was not
+    /// representable, in which case `self` is zero. This function **may**
+    /// allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn overflowing_srem_assign(&mut self, rhs: &ApInt) -> Result<bool> {
+        if self.width() != rhs.width() {
+            return Error::unmatching_bitwidths(self.width(), rhs.width()).into()
+        }
+        let overflow = self.is_sdiv_overflow(rhs);
+        self.wrapping_srem_assign(rhs)?;
+        Ok(overflow)
+    }
+
+    /// Divides `self` by `rhs` using **signed** interpretation and returns the
+    /// remainder as well as a boolean indicating if the corresponding quotient
+    /// was not representable. This function **may** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn into_overflowing_srem(mut self, rhs: &ApInt) -> Result<(ApInt, bool)> {
+        let overflow = self.overflowing_srem_assign(rhs)?;
+        Ok((self, overflow))
+    }
+
+    /// Divides `self` by `rhs` using **signed** interpretation and returns the
+    /// remainder, or `None` if the corresponding quotient was not
+    /// representable. This function **may** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn checked_srem(&self, rhs: &ApInt) -> Result<Option<ApInt>> {
+        let (rem, overflow) = self.clone().into_overflowing_srem(rhs)?;
+        Ok(if overflow { None } else { Some(rem) })
+    }
+
+    /// Quotient-assigns `lhs` by `rhs` using **signed** interpretation and
+    /// returns the remainder, rounding the quotient such that the remainder is
+    /// never negative if `floor` is `false` (euclidean division), or such that
+    /// the remainder has the sign of `rhs` if `floor` is `true` (floored
+    /// division).
+    ///
+    /// Both are computed as corrections of the truncating division. `op` is
+    /// the operation reported on division by zero.
+    fn wrapping_sdivrem_rounded(
+        lhs: &mut ApInt,
+        rhs: &ApInt,
+        op: DivOp,
+        floor: bool,
+    ) -> Result<ApInt> {
+        if lhs.width() != rhs.width() {
+            return Error::unmatching_bitwidths(lhs.width(), rhs.width()).into()
+        }
+        if rhs.is_zero() {
+            return Err(Error::division_by_zero(op, lhs.clone()))
+        }
+        let mut rem = rhs.clone();
+        ApInt::wrapping_sdivrem_assign(lhs, &mut rem)?;
+        if rem.is_zero() || (rem.msb() == (floor && rhs.msb())) {
+            return Ok(rem)
+        }
+        // the remainder is nonzero and has the wrong sign, so one `rhs` is
+        // moved from the remainder to the quotient or vice versa
+        if floor || !rhs.msb() {
+            lhs.wrapping_dec();
+            rem.wrapping_add_assign(rhs)?;
+        } else {
+            lhs.wrapping_inc();
+            rem.wrapping_sub_assign(rhs)?;
+        }
+        Ok(rem)
+    }
+
+    /// Euclidean quotient-assigns `self` by `rhs` inplace using **signed**
+    /// interpretation.
+    ///
+    /// The quotient is rounded such that the remainder is never negative, like
+    /// `div_euclid` of the primitive integers. This function **may** allocate
+    /// memory.
+    ///
+    /// # Note
+    ///
+    /// The quotient of `signed_min_value` by `-1` wraps around to
+    /// `signed_min_value`.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn sdiv_euclid_assign(&mut self, rhs: &ApInt) -> Result<()> {
+        ApInt::wrapping_sdivrem_rounded(self, rhs, DivOp::SignedDivEuclid, false)
+            .map(|_| ())
+    }
+
+    /// Divides `self` by `rhs` using **signed** interpretation and returns
+    /// the euclidean quotient. See `sdiv_euclid_assign` for more information.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn into_sdiv_euclid(self, rhs: &ApInt) -> Result<ApInt> {
+        try_forward_bin_mut_impl(self, rhs, ApInt::sdiv_euclid_assign)
+    }
+
+    /// Euclidean remainder-assigns `self` by `rhs` inplace using **signed**
+    /// interpretation.
+    ///
+    /// The remainder is never negative, like `rem_euclid` of the primitive
+    /// integers. This function **may** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn srem_euclid_assign(&mut self, rhs: &ApInt) -> Result<()> {
+        *self =
+            ApInt::wrapping_sdivrem_rounded(self, rhs, DivOp::SignedRemEuclid, false)?;
+        Ok(())
+    }
+
+    /// Divides `self` by `rhs` using **signed** interpretation and returns
+    /// the euclidean remainder. See `srem_euclid_assign` for more information.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn into_srem_euclid(self, rhs: &ApInt) -> Result<ApInt> {
+        try_forward_bin_mut_impl(self, rhs, ApInt::srem_euclid_assign)
+    }
+
+    /// Floored quotient-assigns `self` by `rhs` inplace using **signed**
+    /// interpretation.
+    ///
+    /// The quotient is rounded towards negative infinity, so the remainder has
+    /// the sign of `rhs`. This function **may** allocate memory.
+    ///
+    /// # Note
+    ///
+    /// The quotient of `signed_min_value` by `-1` wraps around to
+    /// `signed_min_value`.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn sdiv_floor_assign(&mut self, rhs: &ApInt) -> Result<()> {
+        ApInt::wrapping_sdivrem_rounded(self, rhs, DivOp::SignedDivFloor, true)
+            .map(|_| ())
+    }
+
+    /// Divides `self` by `rhs` using **signed** interpretation and returns
+    /// the floored quotient. See `sdiv_floor_assign` for more information.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn into_sdiv_floor(self, rhs: &ApInt) -> Result<ApInt> {
+        try_forward_bin_mut_impl(self, rhs, ApInt::sdiv_floor_assign)
+    }
+
+    /// Floored remainder-assigns `self` by `rhs` inplace using **signed**
+    /// interpretation.
+    ///
+    /// The remainder has the sign of `rhs`. This function **may** allocate
+    /// memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn srem_floor_assign(&mut self, rhs: &ApInt) -> Result<()> {
+        *self = ApInt::wrapping_sdivrem_rounded(self, rhs, DivOp::SignedRemFloor, true)?;
+        Ok(())
+    }
+
+    /// Divides `self` by `rhs` using **signed** interpretation and returns
+    /// the floored remainder. See `srem_floor_assign` for more information.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn into_srem_floor(self, rhs: &ApInt) -> Result<ApInt> {
+        try_forward_bin_mut_impl(self, rhs, ApInt::srem_floor_assign)
+    }
+
+    /// Floored quotient-assigns `self` by `rhs` inplace using **unsigned**
+    /// interpretation.
+    ///
+    /// This is the same as `wrapping_udiv_assign`, since the unsigned quotient
+    /// is always rounded down. This function **may** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn div_floor_assign(&mut self, rhs: &ApInt) -> Result<()> {
+        self.wrapping_udiv_assign(rhs)
+    }
+
+    /// Divides `self` by `rhs` using **unsigned** interpretation and returns
+    /// the floored quotient. See `div_floor_assign` for more information.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn into_div_floor(self, rhs: &ApInt) -> Result<ApInt> {
+        try_forward_bin_mut_impl(self, rhs, ApInt::div_floor_assign)
+    }
+
+    /// Ceiled quotient-assigns `self` by `rhs` inplace using **unsigned**
+    /// interpretation.
+    ///
+    /// The quotient is rounded towards positive infinity. This uses the
+    /// remainder of a single division instead of `(self + rhs - 1) / rhs`,
+    /// which could overflow. This function **may** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn div_ceil_assign(&mut self, rhs: &ApInt) -> Result<()> {
+        if self.width() != rhs.width() {
+            return Error::unmatching_bitwidths(self.width(), rhs.width()).into()
+        }
+        if rhs.is_zero() {
+            return Err(Error::division_by_zero(
+                DivOp::UnsignedDivCeil,
+                self.clone(),
+            ))
+        }
+        let mut rem = rhs.clone();
+        ApInt::wrapping_udivrem_assign(self, &mut rem)?;
+        // the quotient can only be all ones if `rhs` is one, in which case the
+        // remainder is zero
+        if !rem.is_zero() {
+            self.wrapping_inc();
+        }
+        Ok(())
+    }
+
+    /// Divides `self` by `rhs` using **unsigned** interpretation and returns
+    /// the ceiled quotient. See `div_ceil_assign` for more information.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn into_div_ceil(self, rhs: &ApInt) -> Result<ApInt> {
+        try_forward_bin_mut_impl(self, rhs, ApInt::div_ceil_assign)
+    }
+
+    /// Ceiled quotient-assigns `self` by `rhs` inplace using **signed**
+    /// interpretation.
+    ///
+    /// The quotient is rounded towards positive infinity, so the remainder has
+    /// the opposite sign of `rhs`. This function **may** allocate memory.
+    ///
+    /// # Note
+    ///
+    /// The quotient of `signed_min_value` by `-1` wraps around to
+    /// `signed_min_value`.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn sdiv_ceil_assign(&mut self, rhs: &ApInt) -> Result<()> {
+        if self.width() != rhs.width() {
+            return Error::unmatching_bitwidths(self.width(), rhs.width()).into()
+        }
+        if rhs.is_zero() {
+            return Err(Error::division_by_zero(DivOp::SignedDivCeil, self.clone()))
+        }
+        let mut rem = rhs.clone();
+        ApInt::wrapping_sdivrem_assign(self, &mut rem)?;
+        // the truncated quotient was rounded down if the remainder has the sign
+        // of `rhs`
+        if !rem.is_zero() && rem.msb() == rhs.msb() {
+            self.wrapping_inc();
+        }
+        Ok(())
+    }
+
+    /// Divides `self` by `rhs` using **signed** interpretation and returns
+    /// the ceiled quotient. See `sdiv_ceil_assign` for more information.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn into_sdiv_ceil(self, rhs: &ApInt) -> Result<ApInt> {
+        try_forward_bin_mut_impl(self, rhs, ApInt::sdiv_ceil_assign)
+    }
+
+    /// Returns the smallest multiple of `rhs` that is greater than or equal to
+    /// `self` using **unsigned** interpretation, or `None` if it is not
+    /// representable by the bit width.
+    ///
+    /// **Note:** This function **may** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn checked_next_multiple_of(&self, rhs: &ApInt) -> Result<Option<ApInt>> {
+        if self.width() != rhs.width() {
+            return Error::unmatching_bitwidths(self.width(), rhs.width()).into()
+        }
+        if rhs.is_zero() {
+            return Err(Error::division_by_zero(
+                DivOp::UnsignedNextMultipleOf,
+                self.clone(),
+            ))
+        }
+        let rem = self.clone().into_wrapping_urem(rhs)?;
+        if rem.is_zero() {
+            return Ok(Some(self.clone()))
+        }
+        // `rhs - rem` is the distance to the next multiple
+        let mut next = rhs.clone().into_wrapping_sub(&rem)?;
+        if next.overflowing_uadd_assign(self)? {
+            return Ok(None)
+        }
+        Ok(Some(next))
+    }
+
+    /// Returns the next multiple of `rhs` using **signed** interpretation, or
+    /// `None` if it is not representable by the bit width.
+    ///
+    /// For positive `rhs` this is the smallest multiple greater than or equal
+    /// to `self`, and for negative `rhs` the largest multiple less than or
+    /// equal to `self`. This function **may** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn checked_snext_multiple_of(&self, rhs: &ApInt) -> Result<Option<ApInt>> {
+        if self.width() != rhs.width() {
+            return Error::unmatching_bitwidths(self.width(), rhs.width()).into()
+        }
+        if rhs.is_zero() {
+            return Err(Error::division_by_zero(
+                DivOp::SignedNextMultipleOf,
+                self.clone(),
+            ))
+        }
+        // the floored remainder has the sign of `rhs`
+        let rem = self.clone().into_srem_floor(rhs)?;
+        if rem.is_zero() {
+            return Ok(Some(self.clone()))
+        }
+        // `rhs - rem` has the sign of `rhs` and cannot overflow since `rem` is
+        // strictly between zero and `rhs`
+        let mut next = rhs.clone().into_wrapping_sub(&rem)?;
+        if next.overflowing_sadd_assign(self)? {
+            return Ok(None)
+        }
+        Ok(Some(next))
+    }
+
+    /// Returns `true` if `self` is divisible by `divisor` using **unsigned**
+    /// interpretation, i.e. if the remainder of `self` by `divisor` is zero.
+    ///
+    /// # Note
+    ///
+    /// - If `divisor` is a power of two this is a simple check of the trailing
+    ///   zeros of `self` and no division is performed.
+    /// - If `divisor` fits into a `u64` the remainder is computed by a single
+    ///   pass over the digits of `self` without allocating memory.
+    /// - Otherwise both operands are copied into a single scratch buffer which
+    ///   is divided inplace.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `divisor` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn is_multiple_of(&self, divisor: &ApInt) -> Result<bool> {
+        if self.width() != divisor.width() {
+            return Error::unmatching_bitwidths(self.width(), divisor.width()).into()
+        }
+        if divisor.is_zero() {
+            return Err(Error::division_by_zero(DivOp::UnsignedRem, self.clone()))
+        }
+        if self.is_zero() {
+            return Ok(true)
+        }
+        if divisor.count_ones() == 1 {
+            // `trailing_zeros` of a nonzero `self` never counts the excess bits
+            return Ok(self.trailing_zeros() >= divisor.trailing_zeros())
+        }
+        if self.checked_ult(divisor)? {
+            return Ok(false)
+        }
+        if divisor.required_bits(false) <= 64 {
+            return Ok(self.rem_u64(divisor.resize_to_u64())? == 0)
+        }
+        // `aarons_algorithm_divrem` leaves the remainder in the divisor digits
+        let len = self.len_digits();
+        let mut scratch = Vec::with_capacity(2 * len);
+        scratch.extend_from_slice(self.as_digit_slice());
+        scratch.extend_from_slice(divisor.as_digit_slice());
+        let (duo, div) = scratch.split_at_mut(len);
+        ApInt::aarons_algorithm_divrem(duo, div);
+        Ok(div.iter().all(|d| d.is_zero()))
+    }
+
+    /// Returns the greatest common divisor of `self` and `other` using
+    /// **unsigned** interpretation.
+    ///
+    /// # Note
+    ///
+    /// - The gcd of zero and any `x` is `x`, so the gcd of zero and zero is
+    ///   zero.
+    /// - This uses the binary (Stein's) algorithm, which only needs shifts and
+    ///   subtractions. This function allocates memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `other` have unmatching bit widths.
+    pub fn gcd(&self, other: &ApInt) -> Result<ApInt> {
+        if self.width() != other.width() {
+            return Error::unmatching_bitwidths(self.width(), other.width()).into()
+        }
+        if self.is_zero() {
+            return Ok(other.clone())
+        }
+        if other.is_zero() {
+            return Ok(self.clone())
+        }
+        let mut lhs = self.clone();
+        let mut rhs = other.clone();
+        // the common factors of two
+        let shift = lhs.trailing_zeros().min(rhs.trailing_zeros());
+        let lhs_tz = lhs.trailing_zeros();
+        lhs.wrapping_lshr_assign(lhs_tz)?;
+        // `lhs` is always odd from here on
+        loop {
+            let rhs_tz = rhs.trailing_zeros();
+            rhs.wrapping_lshr_assign(rhs_tz)?;
+            if rhs.checked_ult(&lhs)? {
+                mem::swap(&mut lhs, &mut rhs);
+            }
+            // the difference of two odd numbers is even, so it is shifted again
+            // in the next iteration
+            rhs.wrapping_sub_assign(&lhs)?;
+            if rhs.is_zero() {
+                break
+            }
+        }
+        lhs.wrapping_shl_assign(shift)?;
+        Ok(lhs)
+    }
+
+    /// Returns the greatest common divisor `g` of `self` and `other` using
+    /// **unsigned** interpretation, together with Bézout coefficients `x` and
+    /// `y` such that `self * x + other * y == g`.
+    ///
+    /// The result is returned as `(g, x, y)`.
+    ///
+    /// # Note
+    ///
+    /// - `x` and `y` are to be interpreted as **signed** values of the same bit
+    ///   width. They are the minimal coefficients found by the extended
+    ///   euclidean algorithm, which always fit into the **signed**
+    ///   interpretation except for bit width 1, where a coefficient of `1`
+    ///   wraps around to `-1`. The identity above always holds using wrapping
+    ///   arithmetic.
+    /// - For a zero `other` the result is `(self, 1, 0)` and otherwise for a
+    ///   zero `self` the result is `(other, 0, 1)`.
+    /// - This function allocates memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `other` have unmatching bit widths.
+    pub fn extended_gcd(&self, other: &ApInt) -> Result<(ApInt, ApInt, ApInt)> {
+        if self.width() != other.width() {
+            return Error::unmatching_bitwidths(self.width(), other.width()).into()
+        }
+        let width = self.width();
+        let (mut old_rem, mut rem) = (self.clone(), other.clone());
+        let (mut old_x, mut x) = (ApInt::one(width), ApInt::zero(width));
+        let (mut old_y, mut y) = (ApInt::zero(width), ApInt::one(width));
+        while !rem.is_zero() {
+            let (quo, next_rem) = old_rem.into_wrapping_udivrem(&rem)?;
+            old_rem = mem::replace(&mut rem, next_rem);
+            // `(old_x, x) = (x, old_x - quo * x)`, wrapping arithmetic keeps the
+            // final coefficients exact since they fit into the bit width
+            let next_x = old_x.into_wrapping_sub(&x.clone().into_wrapping_mul(&quo)?)?;
+            old_x = mem::replace(&mut x, next_x);
+            let next_y = old_y.into_wrapping_sub(&y.clone().into_wrapping_mul(&quo)?)?;
+            old_y = mem::replace(&mut y, next_y);
+        }
+        Ok((old_rem, old_x, old_y))
+    }
+
+    /// Returns the least common multiple of `self` and `other` using
+    /// **unsigned** interpretation or `None` if it is not representable by
+    /// the bit width of `self` and `other`.
+    ///
+    /// # Note
+    ///
+    /// - The lcm of zero and any `x` is zero.
+    /// - This computes `self / gcd * other`, so no intermediate value exceeds
+    ///   the result. This function allocates memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `other` have unmatching bit widths.
+    pub fn checked_lcm(&self, other: &ApInt) -> Result<Option<ApInt>> {
+        let gcd = self.gcd(other)?;
+        if self.is_zero() || other.is_zero() {
+            return Ok(Some(ApInt::zero(self.width())))
+        }
+        let quo = self.clone().into_wrapping_udiv(&gcd)?;
+        // `gcd` is reused as the buffer for the result
+        let mut lcm = gcd;
+        if ApInt::overflowing_umul_into(&mut lcm, &quo, other) {
+            return Ok(None)
+        }
+        Ok(Some(lcm))
+    }
+
+    /// Returns the least common multiple of `self` and `other` using
+    /// **unsigned** interpretation with a bit width of twice the bit width of
+    /// `self` and `other`, which can always represent it.
+    ///
+    /// **Note:** The lcm of zero and any `x` is zero. This function allocates
+    /// memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `other` have unmatching bit widths.
+    pub fn widening_lcm(&self, other: &ApInt) -> Result<ApInt> {
+        let gcd = self.gcd(other)?;
+        let double_width = BitWidth::new(self.width().to_usize() * 2)?;
+        if self.is_zero() || other.is_zero() {
+            return Ok(ApInt::zero(double_width))
+        }
+        self.clone()
+            .into_wrapping_udiv(&gcd)?
+            .into_zero_extend(double_width)?
+            .into_wrapping_mul(&other.clone().into_zero_extend(double_width)?)
+    }
+
+    /// Returns the floor of the square root of `self` using **unsigned**
+    /// interpretation with the same bit width as `self`.
+    ///
+    /// # Note
+    ///
+    /// This uses Newton's method starting from a power of two that is at
+    /// least the square root, so that the iteration decreases monotonically
+    /// until it reaches the floor of the square root. This function allocates
+    /// memory.
+    pub fn into_isqrt(self) -> ApInt {
+        let sig_bits = self.width().to_usize() - self.leading_zeros();
+        if sig_bits <= 1 {
+            // zero and one are their own square roots
+            return self
+        }
+        // `2^ceil(sig_bits / 2)` squared is greater than `self`
+        let mut x = ApInt::zero(self.width());
+        x.set_bit_at(sig_bits / 2 + sig_bits % 2).unwrap();
+        loop {
+            let q = self.clone().into_wrapping_udiv(&x).unwrap();
+            if !q.checked_ult(&x).unwrap() {
+                return x
+            }
+            // `(x + q) / 2` computed as `q + (x - q) / 2` to avoid overflow
+            let mut next = x.clone().into_wrapping_sub(&q).unwrap();
+            next.wrapping_lshr_assign(1).unwrap();
+            next.wrapping_add_assign(&q).unwrap();
+            x = next;
+        }
+    }
+
+    /// Returns the floor of the `n`-th root of `self` using **unsigned**
+    /// interpretation with the same bit width as `self`.
+    ///
+    /// # Errors
+    ///
+    /// - If `n` is zero.
+    ///
+    /// # Note
+    ///
+    /// This uses Newton's method starting from a power of two that is at
+    /// least the `n`-th root. The iteration is stopped as soon as it no longer
+    /// decreases, since past the floor of the root it may overshoot by one.
+    /// This function allocates memory.
+    pub fn into_nth_root(self, n: u32) -> Result<ApInt> {
+        if n == 0 {
+            return Error::invalid_root_degree(n).into()
+        }
+        let sig_bits = self.width().to_usize() - self.leading_zeros();
+        if n == 1 || sig_bits <= 1 {
+            return Ok(self)
+        }
+        let n_usize = n as usize;
+        if n_usize >= sig_bits {
+            // `self < 2^n` so the root is one
+            return Ok(ApInt::one(self.width()))
+        }
+        // `2^ceil(sig_bits / n)` to the `n`-th power is greater than `self`
+        let mut x = ApInt::zero(self.width());
+        x.set_bit_at((sig_bits - 1) / n_usize + 1).unwrap();
+        let n_apint = ApInt::from_u32(n).into_zero_resize(self.width());
+        loop {
+            let (pow, overflow) = x.clone().into_overflowing_upow(n - 1);
+            let q = if overflow {
+                ApInt::zero(self.width())
+            } else {
+                self.clone().into_wrapping_udiv(&pow).unwrap()
+            };
+            if !q.checked_ult(&x).unwrap() {
+                return Ok(x)
+            }
+            // `((n - 1) * x + q) / n` computed as `x - ceil((x - q) / n)` to
+            // avoid overflow
+            let (mut step, rem) = x
+                .clone()
+                .into_wrapping_sub(&q)
+                .unwrap()
+                .into_wrapping_udivrem(&n_apint)
+                .unwrap();
+            if !rem.is_zero() {
+                step.wrapping_inc();
+            }
+            x.wrapping_sub_assign(&step).unwrap();
+        }
+    }
+
+    /// Returns `true` if `self` is a perfect power using **unsigned**
+    /// interpretation, meaning that there are integers `a` and `k >= 2` with
+    /// `a^k == self`.
+    ///
+    /// Zero and one are considered to be perfect powers.
+    ///
+    /// **Note:** This function allocates memory.
+    pub fn is_perfect_power(&self) -> bool {
+        let sig_bits = self.width().to_usize() - self.leading_zeros();
+        if sig_bits <= 1 {
+            return true
+        }
+        // `a^(p * q) == (a^q)^p`, so it suffices to check prime exponents, and
+        // exponents `k >= sig_bits` can only produce roots of one
+        let is_prime = |k: usize| (2..).take_while(|d| d * d <= k).all(|d| !k.is_multiple_of(d));
+        (2..sig_bits).filter(|&k| is_prime(k)).any(|k| {
+            let k = k as u32;
+            let root = self.clone().into_nth_root(k).unwrap();
+            let (pow, overflow) = root.into_overflowing_upow(k);
+            !overflow && pow == *self
+        })
+    }
+
+    /// Returns the base 2 logarithm of `self` rounded down using **unsigned**
+    /// interpretation, or `None` if `self` is zero.
+    pub fn checked_ilog2(&self) -> Option<usize> {
+        if self.is_zero() {
+            return None
+        }
+        Some(self.width().to_usize() - 1 - self.leading_zeros())
+    }
+
+    /// Returns the base 10 logarithm of `self` rounded down using **unsigned**
+    /// interpretation, or `None` if `self` is zero.
+    ///
+    /// **Note:** This function allocates memory.
+    pub fn checked_ilog10(&self) -> Option<usize> {
+        self.checked_ilog(10)
+    }
+
+    /// Returns the logarithm of `self` with respect to `base` rounded down
+    /// using **unsigned** interpretation, or `None` if `self` is zero or
+    /// `base` is less than 2.
+    ///
+    /// # Note
+    ///
+    /// Instead of repeatedly dividing `self` by `base`, this builds a table of
+    /// the powers `base^(2^i)` that do not exceed `self` by repeated squaring
+    /// and then determines the bits of the logarithm from the most significant
+    /// one downwards. This needs a number of multiplications that is only
+    /// logarithmic in the result. This function allocates memory.
+    pub fn checked_ilog(&self, base: u64) -> Option<usize> {
+        if self.is_zero() || base < 2 {
+            return None
+        }
+        let width = self.width();
+        let base_bits = 64 - base.leading_zeros() as usize;
+        if base_bits > width.to_usize() - self.leading_zeros() {
+            // `base` is greater than `self`
+            return Some(0)
+        }
+        // `powers[i] == base^(2^i)`
+        let mut powers = Vec::new();
+        let mut power = ApInt::from_u64(base).into_zero_resize(width);
+        while power.checked_ule(self).unwrap() {
+            let mut squared = ApInt::zero(width);
+            let overflow = ApInt::overflowing_umul_into(&mut squared, &power, &power);
+            powers.push(power);
+            if overflow {
+                break
+            }
+            power = squared;
+        }
+        let mut ilog = 0;
+        let mut acc = ApInt::one(width);
+        let mut temp = ApInt::zero(width);
+        for (i, power) in powers.iter().enumerate().rev() {
+            if !ApInt::overflowing_umul_into(&mut temp, &acc, power)
+                && temp.checked_ule(self).unwrap()
+            {
+                mem::swap(&mut acc, &mut temp);
+                ilog += 1 << i;
+            }
+        }
+        Some(ilog)
+    }
+
+    /// Returns `self` to the power of `exponent` modulo `modulus` using
+    /// **unsigned** interpretation for all operands.
+    ///
+    /// # Note
+    ///
+    /// - Zero to the power of zero yields one modulo `modulus`.
+    /// - This uses left-to-right exponentiation by squaring. Each product is
+    ///   computed at twice the bit width of `self` and immediately reduced
+    ///   modulo `modulus`, and all intermediates live in buffers that are
+    ///   allocated once up front. This function allocates memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self`, `exponent` and `modulus` have unmatching bit widths.
+    /// - If `modulus` is zero.
+    pub fn modpow(&self, exponent: &ApInt, modulus: &ApInt) -> Result<ApInt> {
+        if self.width() != exponent.width() {
+            return Error::unmatching_bitwidths(self.width(), exponent.width()).into()
+        }
+        if self.width() != modulus.width() {
+            return Error::unmatching_bitwidths(self.width(), modulus.width()).into()
+        }
+        if modulus.is_zero() {
+            return Err(Error::division_by_zero(DivOp::ModPow, self.clone()))
+        }
+        let width = self.width();
+        let double_width = BitWidth::new(width.to_usize() * 2).unwrap();
+        let modulus = modulus.clone().into_zero_extend(double_width).unwrap();
+        // the quotients of the reductions end up in `scratch`
+        let mut scratch = ApInt::zero(double_width);
+        let reduce = |x: &mut ApInt, scratch: &mut ApInt| {
+            scratch
+                .as_digit_slice_mut()
+                .copy_from_slice(modulus.as_digit_slice());
+            ApInt::wrapping_uremdiv_assign(x, scratch).unwrap();
+        };
+        let mut base = self.clone().into_zero_extend(double_width).unwrap();
+        reduce(&mut base, &mut scratch);
+        let mut acc = ApInt::one(double_width);
+        reduce(&mut acc, &mut scratch);
+        let mut prod = ApInt::zero(double_width);
+        let exp_bits = width.to_usize() - exponent.leading_zeros();
+        for i in (0..exp_bits).rev() {
+            // both factors are less than `modulus`, so the products never
+            // overflow the double bit width
+            ApInt::overflowing_umul_into(&mut prod, &acc, &acc);
+            reduce(&mut prod, &mut scratch);
+            mem::swap(&mut acc, &mut prod);
+            if exponent.get_bit_at(i).unwrap() {
+                ApInt::overflowing_umul_into(&mut prod, &acc, &base);
+                reduce(&mut prod, &mut scratch);
+                mem::swap(&mut acc, &mut prod);
+            }
+        }
+        Ok(acc.into_truncate(width).unwrap())
+    }
+
+    /// Returns the multiplicative inverse of `self` modulo `modulus` using
+    /// **unsigned** interpretation, or `None` if `self` and `modulus` are not
+    /// coprime.
+    ///
+    /// # Note
+    ///
+    /// - The result is normalized into the range `[0, modulus)`.
+    /// - This uses the extended euclidean algorithm. This function allocates
+    ///   memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `modulus` have unmatching bit widths.
+    /// - If `modulus` is zero.
+    pub fn mod_inverse(&self, modulus: &ApInt) -> Result<Option<ApInt>> {
+        if self.width() != modulus.width() {
+            return Error::unmatching_bitwidths(self.width(), modulus.width()).into()
+        }
+        if modulus.is_zero() {
+            return Err(Error::division_by_zero(DivOp::ModInverse, self.clone()))
+        }
+        let width = self.width();
+        let one = ApInt::one(width);
+        if *modulus == one {
+            // everything is congruent to zero
+            return Ok(Some(ApInt::zero(width)))
+        }
+        let rem = self.clone().into_wrapping_urem(modulus)?;
+        let (gcd, mut x, _) = rem.extended_gcd(modulus)?;
+        if gcd != one {
+            return Ok(None)
+        }
+        // `|x| <= modulus / 2` holds for the coefficient, so a negative `x` is
+        // brought into range by a single addition
+        if x.msb() {
+            x.wrapping_add_assign(modulus)?;
+        }
+        Ok(Some(x))
+    }
+
+    /// Returns the multiplicative inverse of `self` modulo `2^width`, or `None`
+    /// if `self` is even.
+    ///
+    /// # Note
+    ///
+    /// This uses Newton-Hensel lifting: the inverse of the least significant
+    /// digit is found by iterating on a `Digit`, and each following step
+    /// `x = x * (2 - self * x)` doubles the number of correct bits. This is
+    /// much faster than `mod_inverse`. This function allocates memory.
+    pub fn mod_inverse_pow2(&self) -> Option<ApInt> {
+        let lsd = self.as_digit_slice()[0];
+        if lsd.repr() & 1 == 0 {
+            return None
+        }
+        let width = self.width();
+        // `lsd * lsd == 1 (mod 8)` for odd `lsd`, so this starts out with 3
+        // correct bits which are doubled by every iteration
+        let mut inv = lsd;
+        let mut bits = 3;
+        while bits < Digit::BITS {
+            let prod = lsd.wrapping_mul(inv);
+            inv = inv.wrapping_mul(Digit(2).wrapping_sub(prod));
+            bits *= 2;
+        }
+        // the precision is limited by the size of a `Digit`
+        bits = Digit::BITS;
+        let mut x = ApInt::from_u64(u64::from(inv.repr())).into_zero_resize(width);
+        let two = ApInt::from_u8(2).into_zero_resize(width);
+        let mut temp = ApInt::zero(width);
+        while bits < width.to_usize() {
+            // `temp = 2 - self * x`
+            temp.as_digit_slice_mut()
+                .copy_from_slice(self.as_digit_slice());
+            temp.wrapping_mul_assign(&x).unwrap();
+            temp.wrapping_neg();
+            temp.wrapping_add_assign(&two).unwrap();
+            x.wrapping_mul_assign(&temp).unwrap();
+            bits *= 2;
+        }
+        Some(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::apint::test_utils::test_rng;
+
+    mod inc {
+        use super::*;
+        use crate::bitwidth::BitWidth;
+        use core::u64;
+
+        #[test]
+        fn test() {
+            assert_eq!(ApInt::from(14u8).into_wrapping_inc(), ApInt::from(15u8));
+            assert_eq!(ApInt::from(15u8).into_wrapping_inc(), ApInt::from(16u8));
+            assert_eq!(ApInt::from(16u8).into_wrapping_inc(), ApInt::from(17u8));
+            assert_eq!(ApInt::from(17u8).into_wrapping_inc(), ApInt::from(18u8));
+            assert_eq!(
+                ApInt::from([0u64, 0, 0]).into_wrapping_inc(),
+                ApInt::from([0u64, 0, 1])
+            );
+            assert_eq!(
+                ApInt::from([0, 7, u64::MAX]).into_wrapping_inc(),
+                ApInt::from([0u64, 8, 0])
+            );
+            assert_eq!(
+                ApInt::from([u64::MAX, u64::MAX]).into_wrapping_inc(),
+                ApInt::from([0u64, 0])
+            );
+            assert_eq!(
+                ApInt::from([0, u64::MAX, u64::MAX - 1]).into_wrapping_inc(),
+                ApInt::from([0, u64::MAX, u64::MAX])
+            );
+            assert_eq!(
+                ApInt::from([0, u64::MAX, 0]).into_wrapping_inc(),
+                ApInt::from([0, u64::MAX, 1])
+            );
+        }
+
+        #[test]
+        fn wrap_around() {
+            for &width in &[1, 7, 64, 65, 128, 129, 192] {
+                let width = BitWidth::new(width).unwrap();
+                let zero = ApInt::zero(width);
+                let max = ApInt::unsigned_max_value(width);
+                assert_eq!(max.clone().into_wrapping_inc(), zero);
+                assert_eq!(zero.clone().into_wrapping_dec(), max);
+                assert_eq!(max.clone().into_overflowing_inc(), (zero.clone(), true));
+                assert_eq!(zero.clone().into_overflowing_dec(), (max.clone(), true));
+                assert_eq!(
+                    zero.clone().into_overflowing_inc(),
+                    (ApInt::one(width), false)
+                );
+                assert_eq!(
+                    ApInt::one(width).into_overflowing_dec(),
+                    (zero.clone(), false)
+                );
+                let below_max = max.clone().into_wrapping_dec();
+                assert_eq!(
+                    below_max.clone().into_overflowing_inc(),
+                    (max.clone(), false)
+                );
+                assert_eq!(max.into_overflowing_dec(), (below_max, false));
+            }
+        }
+
+        #[test]
+        fn partial_top_digit() {
+            // the carry ripples into the partial top digit of a 129 bit integer
+            let width = BitWidth::new(129).unwrap();
+            let x = ApInt::from([0u64, u64::MAX, u64::MAX])
+                .into_truncate(width)
+                .unwrap();
+            let top = ApInt::from([1u64, 0, 0]).into_truncate(width).unwrap();
+            assert_eq!(x.clone().into_overflowing_inc(), (top.clone(), false));
+            assert_eq!(top.into_overflowing_dec(), (x, false));
+        }
+    }
+
+    mod neg {
+        use super::*;
+        use crate::bitwidth::BitWidth;
+
+        #[test]
+        fn small() {
+            for x in i8::MIN..=i8::MAX {
+                let apint = ApInt::from(x);
+                assert_eq!(
+                    apint.clone().into_wrapping_neg(),
+                    ApInt::from(x.wrapping_neg())
+                );
+                let (neg, overflow) = x.overflowing_neg();
+                assert_eq!(
+                    apint.clone().into_overflowing_neg(),
+                    (ApInt::from(neg), overflow)
+                );
+                assert_eq!(apint.into_checked_neg(), x.checked_neg().map(ApInt::from));
+            }
+        }
+
+        #[test]
+        fn width_1() {
+            let zero = ApInt::zero(BitWidth::w1());
+            let minus_one = ApInt::all_set(BitWidth::w1());
+            assert_eq!(zero.clone().into_overflowing_neg(), (zero.clone(), false));
+            assert_eq!(zero.into_checked_neg(), Some(ApInt::zero(BitWidth::w1())));
+            // `-1` is `signed_min_value` for width 1
+            assert_eq!(
+                minus_one.clone().into_overflowing_neg(),
+                (minus_one.clone(), true)
+            );
+            assert_eq!(minus_one.into_checked_neg(), None);
+        }
+
+        #[test]
+        fn signed_min_value() {
+            for &width in &[64, 65, 128, 200] {
+                let width = BitWidth::new(width).unwrap();
+                let min = ApInt::signed_min_value(width);
+                let max = ApInt::signed_max_value(width);
+                assert_eq!(min.clone().into_overflowing_neg(), (min.clone(), true));
+                assert_eq!(min.clone().into_checked_neg(), None);
+                assert_eq!(
+                    max.clone().into_checked_neg(),
+                    Some(min.into_wrapping_inc())
+                );
+                let zero = ApInt::zero(width);
+                assert_eq!(zero.clone().into_overflowing_neg(), (zero, false));
+            }
+        }
+    }
+
+    mod abs {
+        use super::*;
+        use crate::bitwidth::BitWidth;
+
+        #[test]
+        fn small() {
+            for x in i8::MIN..=i8::MAX {
+                let apint = ApInt::from(x);
+                assert_eq!(
+                    apint.clone().into_wrapping_abs(),
+                    ApInt::from(x.wrapping_abs())
+                );
+                let (abs, overflow) = x.overflowing_abs();
+                assert_eq!(
+                    apint.clone().into_overflowing_abs(),
+                    (ApInt::from(abs), overflow)
+                );
+                assert_eq!(
+                    apint.clone().into_checked_abs(),
+                    x.checked_abs().map(ApInt::from)
+                );
+                assert_eq!(apint.into_unsigned_abs(), ApInt::from(x.unsigned_abs()));
+            }
+        }
+
+        #[test]
+        fn width_1() {
+            let zero = ApInt::zero(BitWidth::w1());
+            let minus_one = ApInt::all_set(BitWidth::w1());
+            assert_eq!(zero.clone().into_overflowing_abs(), (zero.clone(), false));
+            // `-1` is `signed_min_value` for width 1
+            assert_eq!(
+                minus_one.clone().into_overflowing_abs(),
+                (minus_one.clone(), true)
+            );
+            assert_eq!(minus_one.clone().into_checked_abs(), None);
+            assert_eq!(minus_one.into_unsigned_abs(), ApInt::one(BitWidth::w1()));
+        }
+
+        #[test]
+        fn signed_min_value() {
+            for &width in &[64, 65, 128, 200] {
+                let width = BitWidth::new(width).unwrap();
+                let min = ApInt::signed_min_value(width);
+                assert_eq!(min.clone().into_overflowing_abs(), (min.clone(), true));
+                assert_eq!(min.clone().into_checked_abs(), None);
+                assert_eq!(min.clone().into_unsigned_abs(), min);
+                let max = ApInt::signed_max_value(width);
+                assert_eq!(
+                    max.clone().into_wrapping_neg().into_checked_abs(),
+                    Some(max)
+                );
+            }
+        }
+    }
+
+    mod wrapping_neg {
+        use super::*;
+        use crate::bitwidth::BitWidth;
+
+        fn assert_symmetry(input: ApInt, expected: ApInt) {
+            assert_eq!(input.clone().into_wrapping_neg(), expected.clone());
+            assert_eq!(expected.into_wrapping_neg(), input);
+        }
+
+        fn test_vals() -> impl Iterator<Item = i128> {
+            [
+                0_i128, 1, 2, 4, 5, 7, 10, 42, 50, 100, 128, 150, 1337, 123123, 999999,
+                987432, 77216417,
             ]
             .iter()
             .map(|v| *v)
         }
 
         #[test]
-        fn simple() {
-            assert_symmetry(ApInt::zero(BitWidth::w1()), ApInt::zero(BitWidth::w1()));
-            assert_symmetry(
-                ApInt::unsigned_max_value(BitWidth::w1()),
-                ApInt::all_set(BitWidth::w1()),
-            );
+        fn simple() {
+            assert_symmetry(ApInt::zero(BitWidth::w1()), ApInt::zero(BitWidth::w1()));
+            assert_symmetry(
+                ApInt::unsigned_max_value(BitWidth::w1()),
+                ApInt::all_set(BitWidth::w1()),
+            );
+        }
+
+        #[test]
+        fn range() {
+            for v in test_vals() {
+                assert_symmetry(ApInt::from_i8(v as i8), ApInt::from_i8(-v as i8));
+                assert_symmetry(ApInt::from_i16(v as i16), ApInt::from_i16(-v as i16));
+                assert_symmetry(ApInt::from_i32(v as i32), ApInt::from_i32(-v as i32));
+                assert_symmetry(ApInt::from_i64(v as i64), ApInt::from_i64(-v as i64));
+                assert_symmetry(ApInt::from_i128(v), ApInt::from_i128(-v));
+            }
+        }
+    }
+
+    mod mul {
+        use super::*;
+        use crate::bitwidth::BitWidth;
+        use core::{
+            u64,
+            u8,
+        };
+
+        #[test]
+        fn rigorous() {
+            // there are many special case and size optimization paths, so this test must
+            // be very rigorous.
+
+            // multiplication of apints composed of only u8::MAX in their least
+            // significant digits only works for num_u8 > 1
+            fn nine_test(num_u8: usize) {
+                let mut lhs;
+                let mut rhs =
+                    ApInt::from(0u8).into_zero_resize(BitWidth::new(num_u8 * 8).unwrap());
+                let nine = ApInt::from(u8::MAX)
+                    .into_zero_resize(BitWidth::new(num_u8 * 8).unwrap());
+                for rhs_nine in 0..num_u8 {
+                    rhs.wrapping_shl_assign(8usize).unwrap();
+                    rhs |= &nine;
+                    lhs = ApInt::from(0u8)
+                        .into_zero_resize(BitWidth::new(num_u8 * 8).unwrap());
+                    'outer: for lhs_nine in 0..num_u8 {
+                        lhs.wrapping_shl_assign(8usize).unwrap();
+                        lhs |= &nine;
+                        // imagine multiplying a string of base 10 nines together.
+                        // It will produce things like 998001, 8991, 98901, 9989001.
+                        // this uses a formula for the number of nines, eights, and zeros
+                        // except here nine is u8::MAX, eight is
+                        // u8::MAX - 1, and zero is 0u8
+                        let zeros_after_one = if lhs_nine < rhs_nine {
+                            lhs_nine
+                        } else {
+                            rhs_nine
+                        };
+                        let nines_before_eight = if lhs_nine > rhs_nine {
+                            lhs_nine - rhs_nine
+                        } else {
+                            rhs_nine - lhs_nine
+                        };
+                        let nines_after_eight = if lhs_nine < rhs_nine {
+                            lhs_nine
+                        } else {
+                            rhs_nine
+                        };
+                        let mut result = lhs.clone().into_wrapping_mul(&rhs).unwrap();
+                        assert_eq!(result.clone().resize_to_u8(), 1u8);
+                        for i in 0..zeros_after_one {
+                            if i >= num_u8 - 1 {
+                                continue 'outer
+                            }
+                            result.wrapping_lshr_assign(8usize).unwrap();
+                            assert_eq!(result.clone().resize_to_u8(), 0);
+                        }
+                        for i in 0..nines_before_eight {
+                            if zeros_after_one + i >= num_u8 - 1 {
+                                continue 'outer
+                            }
+                            result.wrapping_lshr_assign(8usize).unwrap();
+                            assert_eq!(result.clone().resize_to_u8(), u8::MAX);
+                        }
+                        if zeros_after_one + nines_before_eight >= num_u8 - 1 {
+                            continue 'outer
+                        }
+                        result.wrapping_lshr_assign(8usize).unwrap();
+                        assert_eq!(result.clone().resize_to_u8(), u8::MAX - 1);
+                        for i in 0..nines_after_eight {
+                            if 1 + zeros_after_one + nines_before_eight + i >= num_u8 - 1
+                            {
+                                continue 'outer
+                            }
+                            result.wrapping_lshr_assign(8usize).unwrap();
+                            assert_eq!(result.clone().resize_to_u8(), u8::MAX);
+                        }
+                    }
+                }
+            }
+            // test inl apints
+            assert_eq!(
+                ApInt::from(u8::MAX)
+                    .into_wrapping_mul(&ApInt::from(u8::MAX))
+                    .unwrap(),
+                ApInt::from(1u8)
+            );
+            nine_test(2);
+            nine_test(3);
+            nine_test(4);
+            nine_test(7);
+            nine_test(8);
+            // test ext apints
+            nine_test(9);
+            nine_test(16);
+            // 5 digits wide
+            nine_test(40);
+            nine_test(63);
+            // non overflowing test
+            let resize = [
+                7usize, 8, 9, 15, 16, 17, 31, 32, 33, 63, 64, 65, 127, 128, 129, 137,
+                200, 255, 256, 700, 907, 1024, 2018, 2019,
+            ];
+            let lhs_shl = [
+                0usize, 1, 0, 1, 4, 7, 4, 10, 13, 0, 31, 25, 7, 17, 32, 50, 0, 64, 249,
+                8, 777, 0, 1000, 0,
+            ];
+            let rhs_shl = [
+                0usize, 0, 1, 1, 3, 6, 4, 14, 10, 0, 0, 25, 0, 18, 32, 49, 100, 64, 0,
+                256, 64, 900, 1000, 0,
+            ];
+            for (i, _) in resize.iter().enumerate() {
+                let lhs = ApInt::from(5u8)
+                    .into_zero_resize(BitWidth::new(resize[i]).unwrap())
+                    .into_wrapping_shl(lhs_shl[i])
+                    .unwrap();
+                let rhs = ApInt::from(11u8)
+                    .into_zero_resize(BitWidth::new(resize[i]).unwrap())
+                    .into_wrapping_shl(rhs_shl[i])
+                    .unwrap();
+                let zero =
+                    ApInt::from(0u8).into_zero_resize(BitWidth::new(resize[i]).unwrap());
+                let one =
+                    ApInt::from(1u8).into_zero_resize(BitWidth::new(resize[i]).unwrap());
+                let expected = ApInt::from(55u8)
+                    .into_zero_resize(BitWidth::new(resize[i]).unwrap())
+                    .into_wrapping_shl(rhs_shl[i] + lhs_shl[i])
+                    .unwrap();
+                assert_eq!(lhs.clone().into_wrapping_mul(&zero).unwrap(), zero);
+                assert_eq!(zero.clone().into_wrapping_mul(&rhs).unwrap(), zero);
+                assert_eq!(lhs.clone().into_wrapping_mul(&one).unwrap(), lhs);
+                assert_eq!(one.clone().into_wrapping_mul(&rhs).unwrap(), rhs);
+                assert_eq!(lhs.clone().into_wrapping_mul(&rhs).unwrap(), expected);
+            }
+            assert_eq!(
+                ApInt::from([0, 0, 0, 0, u64::MAX, 0, u64::MAX, u64::MAX])
+                    .into_wrapping_mul(&ApInt::from([
+                        0,
+                        0,
+                        0,
+                        0,
+                        u64::MAX,
+                        u64::MAX,
+                        0,
+                        u64::MAX
+                    ]))
+                    .unwrap(),
+                ApInt::from([u64::MAX, 0, 1, u64::MAX - 3, 1, u64::MAX, u64::MAX, 1])
+            );
+        }
+    }
+
+    mod div_rem {
+        use super::*;
+        use crate::bitwidth::BitWidth;
+        use core::u64;
+
+        #[test]
+        fn simple() {
+            /// does all of the simple division tests
+            /// - `$signed`: if the functions are signed divisions or not
+            /// - `$fun_assign`: a division function such as
+            ///   `wrapping_udiv_assign` with that signature
+            /// - `$fun_into`: a division function such as `into_wrapping_udiv`
+            ///   with that signature
+            /// - `$fun`: a division function such as `wrapping_udivrem_assign`
+            ///   with that signature
+            /// - `$r0`: the quotient or remainder or both of 80 by 7, depending
+            ///   on division function type
+            /// - `$r1`, `$r2`, `$r3`: 80 by -7, -80 by 7, -80 by -7. These can
+            ///   be 0 if `$signed` is false.
+            macro_rules! s {
+                (
+                    $signed:expr,
+                    $fun_assign:ident,
+                    $fun_into:ident,
+                    $r0:expr,
+                    $r1:expr,
+                    $r2:expr,
+                    $r3:expr
+                ) => {
+                    // match $fun_assign
+                    // match ApInt::from(123u8).$fun_into(&ApInt::from(0u8)) {
+                    // Err(Error{kind: ErrorKind::DivisionByZero{op: DivOp::$div_op, lhs:
+                    // x}, message: _, annotation: _}) => { assert_eq!(x,ApInt::
+                    // from(123u8)); },
+                    // _ => unreachable!(),
+                    // }
+                    // match ApInt::from(12345678912345689123456789123456789u128).
+                    {
+                        let lhs = ApInt::from(80i8);
+                        let rhs = ApInt::from(7i8);
+                        let mut temp = lhs.clone();
+                        temp.$fun_assign(&rhs).unwrap();
+                        assert_eq!(temp, ApInt::from($r0));
+                        assert_eq!(lhs.$fun_into(&rhs).unwrap(), ApInt::from($r0));
+                    }
+                    if $signed {
+                        {
+                            let lhs = ApInt::from(80i8);
+                            let rhs = ApInt::from(-7i8);
+                            let mut temp = lhs.clone();
+                            temp.$fun_assign(&rhs).unwrap();
+                            assert_eq!(temp, ApInt::from($r1));
+                            assert_eq!(lhs.$fun_into(&rhs).unwrap(), ApInt::from($r1));
+                        }
+                        {
+                            let lhs = ApInt::from(-80i8);
+                            let rhs = ApInt::from(7i8);
+                            let mut temp = lhs.clone();
+                            temp.$fun_assign(&rhs).unwrap();
+                            assert_eq!(temp, ApInt::from($r2));
+                            assert_eq!(lhs.$fun_into(&rhs).unwrap(), ApInt::from($r2));
+                        }
+                        {
+                            let lhs = ApInt::from(-80i8);
+                            let rhs = ApInt::from(-7i8);
+                            let mut temp = lhs.clone();
+                            temp.$fun_assign(&rhs).unwrap();
+                            assert_eq!(temp, ApInt::from($r3));
+                            assert_eq!(lhs.$fun_into(&rhs).unwrap(), ApInt::from($r3));
+                        }
+                    }
+                };
+                ($signed:expr, $fun:ident, $r0:expr, $r1:expr, $r2:expr, $r3:expr) => {{
+                    let mut lhs = ApInt::from(80i8);
+                    let mut rhs = ApInt::from(7i8);
+                    ApInt::$fun(&mut lhs, &mut rhs).unwrap();
+                    assert_eq!(lhs, ApInt::from($r0.0));
+                    assert_eq!(rhs, ApInt::from($r0.1));
+                }
+                if $signed {
+                    {
+                        let mut lhs = ApInt::from(80i8);
+                        let mut rhs = ApInt::from(-7i8);
+                        ApInt::$fun(&mut lhs, &mut rhs).unwrap();
+                        assert_eq!(lhs, ApInt::from($r1.0));
+                        assert_eq!(rhs, ApInt::from($r1.1));
+                    }
+                    {
+                        let mut lhs = ApInt::from(-80i8);
+                        let mut rhs = ApInt::from(7i8);
+                        ApInt::$fun(&mut lhs, &mut rhs).unwrap();
+                        assert_eq!(lhs, ApInt::from($r2.0));
+                        assert_eq!(rhs, ApInt::from($r2.1));
+                    }
+                    {
+                        let mut lhs = ApInt::from(-80i8);
+                        let mut rhs = ApInt::from(-7i8);
+                        ApInt::$fun(&mut lhs, &mut rhs).unwrap();
+                        assert_eq!(lhs, ApInt::from($r3.0));
+                        assert_eq!(rhs, ApInt::from($r3.1));
+                    }
+                }};
+            }
+            s!(
+                false,
+                wrapping_udiv_assign,
+                into_wrapping_udiv,
+                11i8,
+                0,
+                0,
+                0
+            );
+            s!(
+                false,
+                wrapping_urem_assign,
+                into_wrapping_urem,
+                3i8,
+                0,
+                0,
+                0
+            );
+            s!(
+                true,
+                wrapping_sdiv_assign,
+                into_wrapping_sdiv,
+                11i8,
+                -11i8,
+                -11i8,
+                11i8
+            );
+            s!(
+                true,
+                wrapping_srem_assign,
+                into_wrapping_srem,
+                3i8,
+                3i8,
+                -3i8,
+                -3i8
+            );
+            s!(
+                false,
+                wrapping_udivrem_assign,
+                (11i8, 3i8),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            );
+            s!(
+                false,
+                wrapping_uremdiv_assign,
+                (3i8, 11i8),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            );
+            s!(
+                true,
+                wrapping_sdivrem_assign,
+                (11i8, 3i8),
+                (-11i8, 3i8),
+                (-11i8, -3i8),
+                (11i8, -3i8)
+            );
+            s!(
+                true,
+                wrapping_sremdiv_assign,
+                (3i8, 11i8),
+                (3i8, -11i8),
+                (-3i8, -11i8),
+                (-3i8, 11i8)
+            );
+        }
+
+        // NOTE: this test only works if multiplication and a few other functions work
+        #[test]
+        fn complex() {
+            // there are many special case and size optimization paths,
+            // so this test must be very rigorous.
+            assert_eq!(
+                ApInt::from(123u8)
+                    .into_wrapping_udiv(&ApInt::from(7u8))
+                    .unwrap(),
+                ApInt::from(17u8)
+            );
+            assert_eq!(
+                ApInt::from([0u64, 0, 0, 123])
+                    .into_wrapping_udiv(&ApInt::from([0u64, 0, 0, 7]))
+                    .unwrap(),
+                ApInt::from([0u64, 0, 0, 17])
+            );
+            assert_eq!(
+                ApInt::from([0u64, 0, 0, 0])
+                    .into_wrapping_udiv(&ApInt::from([0u64, 0, 0, 7]))
+                    .unwrap(),
+                ApInt::from([0u64, 0, 0, 0])
+            );
+            assert_eq!(
+                ApInt::from([0u64, 0, 0, 3])
+                    .into_wrapping_udiv(&ApInt::from([0u64, 0, 0, 7]))
+                    .unwrap(),
+                ApInt::from([0u64, 0, 0, 0])
+            );
+            assert_eq!(
+                ApInt::from([0u64, 0, 0, 0])
+                    .into_wrapping_udiv(&ApInt::from([0u64, 7, 0, 0]))
+                    .unwrap(),
+                ApInt::from([0u64, 0, 0, 0])
+            );
+            assert_eq!(
+                ApInt::from([0u64, 0, 0, 7])
+                    .into_wrapping_udiv(&ApInt::from([0u64, 4, 0, 0]))
+                    .unwrap(),
+                ApInt::from([0u64, 0, 0, 0])
+            );
+            assert_eq!(
+                ApInt::from([0u64, 0, 3, 0])
+                    .into_wrapping_udiv(&ApInt::from([0u64, 4, 0, 0]))
+                    .unwrap(),
+                ApInt::from([0u64, 0, 0, 0])
+            );
+            assert_eq!(
+                ApInt::from([0u64, 1, 0, 0])
+                    .into_wrapping_udiv(&ApInt::from([0u64, 0, 0, 4]))
+                    .unwrap(),
+                ApInt::from([0u64, 0, u64::MAX / 4 + 1, 0])
+            );
+            assert_eq!(
+                // this one
+                ApInt::from([0u64, 1, 0, 0])
+                    .into_wrapping_udiv(&ApInt::from([0u64, 0, 1, 0]))
+                    .unwrap(),
+                ApInt::from([0u64, 0, 1, 0])
+            );
+            assert_eq!(
+                ApInt::from([1u64, 2, 3, 4])
+                    .into_wrapping_udiv(&ApInt::from([1u64, 2, 3, 4]))
+                    .unwrap(),
+                ApInt::from([0u64, 0, 0, 1])
+            );
+            assert_eq!(
+                ApInt::from([
+                    0u64,
+                    1,
+                    u64::MAX,
+                    u64::MAX,
+                    u64::MAX,
+                    u64::MAX,
+                    u64::MAX,
+                    u64::MAX
+                ])
+                .into_wrapping_udiv(&ApInt::from([0u64, 0, 0, 0, 0, 0, 0, 2]))
+                .unwrap(),
+                ApInt::from([
+                    0u64,
+                    0,
+                    u64::MAX,
+                    u64::MAX,
+                    u64::MAX,
+                    u64::MAX,
+                    u64::MAX,
+                    u64::MAX
+                ])
+            );
+            assert_eq!(
+                ApInt::from([
+                    u64::MAX,
+                    u64::MAX - 1,
+                    1,
+                    u64::MAX - 1,
+                    u64::MAX - 1,
+                    2,
+                    u64::MAX - 1,
+                    1
+                ])
+                .into_wrapping_udiv(&ApInt::from([
+                    0,
+                    0,
+                    0,
+                    0,
+                    u64::MAX,
+                    u64::MAX,
+                    0,
+                    u64::MAX
+                ]))
+                .unwrap(),
+                ApInt::from([0, 0, 0, 0, u64::MAX, u64::MAX, 0, u64::MAX])
+            );
+            assert_eq!(
+                ApInt::from(61924494876344321u128)
+                    .into_wrapping_urem(&ApInt::from(167772160u128))
+                    .unwrap(),
+                ApInt::from(1u128)
+            );
+            assert_eq!(
+                ApInt::from([
+                    18446744073709551615u64,
+                    18446744073709551615,
+                    1048575,
+                    18446462598732840960
+                ])
+                .into_wrapping_urem(&ApInt::from([0u64, 0, 140668768878592, 0]))
+                .unwrap(),
+                ApInt::from([0, 0, 136545601323007, 18446462598732840960u64])
+            );
+            assert_eq!(
+                ApInt::from([1u64, 17293821508111564796, 2305843009213693952])
+                    .into_wrapping_urem(&ApInt::from([0u64, 1, 18446742978492891132]))
+                    .unwrap(),
+                ApInt::from([0u64, 0, 0])
+            );
+            assert_eq!(
+                ApInt::from([1u64, 18446744073692774368, 268435456])
+                    .into_wrapping_add(&ApInt::from([0u64, 1, 18446744073709519359]))
+                    .unwrap()
+                    .into_wrapping_udiv(&ApInt::from([0u64, 1, 18446744073709551584]))
+                    .unwrap(),
+                ApInt::from([0u64, 0, 18446744073701163008])
+            );
+            assert_eq!(
+                ApInt::from([
+                    18446744073709551615u64,
+                    18446744073709551615,
+                    18446739675663040512,
+                    2199023255552
+                ])
+                .into_wrapping_urem(&ApInt::from([
+                    18446744073709551615u64,
+                    18446744073709551615,
+                    18446739675663040512,
+                    2199023255552
+                ]))
+                .unwrap(),
+                ApInt::from([0u64, 0, 0, 0])
+            );
+            assert_eq!(
+                ApInt::from([1u64, 18446462598730776592, 1047972020113])
+                    .into_wrapping_udiv(&ApInt::from([0u64, 16383, 18446744056529682433]))
+                    .unwrap(),
+                ApInt::from([0u64, 0, 2251782633816065])
+            );
+            assert_eq!(
+                ApInt::from([
+                    54467619767447688u64,
+                    18446739675392512496,
+                    5200531536562092095,
+                    18446744073709551615
+                ])
+                .into_wrapping_udiv(&ApInt::from([0u64, 8255, 18446462598732840960, 0]))
+                .unwrap(),
+                ApInt::from([0u64, 0, 6597337677824, 288230376151678976])
+            );
+            let resize = [
+                7usize, 8, 9, 15, 16, 17, 31, 32, 33, 63, 64, 65, 127, 128, 129, 137,
+                200, 255, 256, 700, 907, 1024, 2018, 2019,
+            ];
+            let lhs_shl = [
+                0usize, 1, 0, 1, 4, 6, 4, 10, 13, 0, 31, 25, 7, 17, 32, 50, 0, 64, 249,
+                8, 777, 0, 900, 0,
+            ];
+            let rhs_shl = [
+                0usize, 0, 1, 1, 3, 5, 4, 14, 10, 0, 0, 25, 0, 18, 32, 49, 100, 64, 0,
+                256, 64, 900, 1000, 0,
+            ];
+            for (i, _) in resize.iter().enumerate() {
+                let lhs = ApInt::from(5u8)
+                    .into_zero_resize(BitWidth::new(resize[i]).unwrap())
+                    .into_wrapping_shl(lhs_shl[i])
+                    .unwrap();
+                let rhs = ApInt::from(11u8)
+                    .into_zero_resize(BitWidth::new(resize[i]).unwrap())
+                    .into_wrapping_shl(rhs_shl[i])
+                    .unwrap();
+                let zero =
+                    ApInt::from(0u8).into_zero_resize(BitWidth::new(resize[i]).unwrap());
+                let one =
+                    ApInt::from(1u8).into_zero_resize(BitWidth::new(resize[i]).unwrap());
+                let product = lhs.clone().into_wrapping_mul(&rhs).unwrap();
+                assert_eq!(zero.clone().into_wrapping_udiv(&lhs).unwrap(), zero);
+                assert_eq!(zero.clone().into_wrapping_udiv(&rhs).unwrap(), zero);
+                assert_eq!(lhs.clone().into_wrapping_udiv(&one).unwrap(), lhs);
+                assert_eq!(rhs.clone().into_wrapping_udiv(&one).unwrap(), rhs);
+                assert_eq!(lhs.clone().into_wrapping_udiv(&lhs).unwrap(), one);
+                assert_eq!(rhs.clone().into_wrapping_udiv(&rhs).unwrap(), one);
+                let temp = product.clone().into_wrapping_udiv(&lhs).unwrap();
+                if temp != rhs {
+                    panic!(
+                        "lhs_shl:{:?}\nrhs_shl:{:?}\nlhs:{:?}\nrhs:{:?}\n={:?}\ntemp:{:?\
+                         }",
+                        lhs_shl[i], rhs_shl[i], lhs, rhs, product, temp
+                    );
+                }
+                assert_eq!(product.clone().into_wrapping_udiv(&rhs).unwrap(), lhs);
+                assert_eq!(zero.clone().into_wrapping_urem(&lhs).unwrap(), zero);
+                assert_eq!(zero.clone().into_wrapping_urem(&rhs).unwrap(), zero);
+                assert_eq!(lhs.clone().into_wrapping_urem(&one).unwrap(), zero);
+                assert_eq!(rhs.clone().into_wrapping_urem(&one).unwrap(), zero);
+                assert_eq!(lhs.clone().into_wrapping_urem(&lhs).unwrap(), zero);
+                assert_eq!(rhs.clone().into_wrapping_urem(&rhs).unwrap(), zero);
+                assert_eq!(product.clone().into_wrapping_urem(&lhs).unwrap(), zero);
+                assert_eq!(product.clone().into_wrapping_urem(&rhs).unwrap(), zero);
+                assert_eq!(
+                    product
+                        .clone()
+                        .into_wrapping_add(&one)
+                        .unwrap()
+                        .into_wrapping_urem(&lhs)
+                        .unwrap(),
+                    one
+                );
+                assert_eq!(
+                    product
+                        .clone()
+                        .into_wrapping_add(&one)
+                        .unwrap()
+                        .into_wrapping_urem(&rhs)
+                        .unwrap(),
+                    one
+                );
+            }
+        }
+
+        #[test]
+        fn knuth_add_back() {
+            // inputs for which the refined quotient digit estimate of Algorithm D
+            // is still one too large, so that the divisor has to be added back
+            let mut duo = ApInt::from([(1u64 << 63) - 1, 1 << 63, 0, 0]);
+            let mut div = ApInt::from([0u64, 1 << 63, 0, 1]);
+            ApInt::wrapping_udivrem_assign(&mut duo, &mut div).unwrap();
+            assert_eq!(duo, ApInt::from([0u64, 0, 0, u64::MAX - 1]));
+            assert_eq!(div, ApInt::from([0u64, (1 << 63) - 1, u64::MAX, 2]));
+            let mut duo = ApInt::from([1u64, 0, 0, 0]);
+            let mut div = ApInt::from([0u64, 1, 0, 1]);
+            ApInt::wrapping_udivrem_assign(&mut duo, &mut div).unwrap();
+            assert_eq!(duo, ApInt::from([0, 0, 0, u64::MAX]));
+            assert_eq!(div, ApInt::from([0u64, 0, u64::MAX, 1]));
+        }
+
+        #[test]
+        fn knuth_fuzz() {
+            use rand::RngCore;
+            use rand_xorshift::XorShiftRng;
+            let mut rng = test_rng();
+            // digits near the edges make the quotient digit estimates and the add
+            // back correction far more likely than uniformly random digits
+            let digit = |rng: &mut XorShiftRng| {
+                match rng.next_u32() % 8 {
+                    0 => Digit::ZERO,
+                    1 => Digit::ONE,
+                    2 => Digit::ONES,
+                    3 => Digit::ONE << (Digit::BITS - 1),
+                    4 => Digit::ONES >> 1,
+                    _ => Digit(rng.next_u64() as DigitRepr),
+                }
+            };
+            for &len in &[2, 3, 4, 5, 8, 16] {
+                let width = BitWidth::new(len * Digit::BITS).unwrap();
+                for _ in 0..1000 {
+                    let duo_len = 1 + (rng.next_u32() as usize % len);
+                    let div_len = 1 + (rng.next_u32() as usize % len);
+                    let mut duo_digits = vec![Digit::ZERO; len];
+                    let mut div_digits = vec![Digit::ZERO; len];
+                    for d in duo_digits[..duo_len].iter_mut() {
+                        *d = digit(&mut rng);
+                    }
+                    for d in div_digits[..div_len].iter_mut() {
+                        *d = digit(&mut rng);
+                    }
+                    let duo = ApInt::from_iter(duo_digits).unwrap();
+                    let div = ApInt::from_iter(div_digits).unwrap();
+                    if div.is_zero() {
+                        continue
+                    }
+                    let mut quo = duo.clone();
+                    let mut rem = div.clone();
+                    ApInt::wrapping_udivrem_assign(&mut quo, &mut rem).unwrap();
+                    assert!(rem.checked_ult(&div).unwrap());
+                    assert_eq!(
+                        quo.clone()
+                            .into_wrapping_mul(&div)
+                            .unwrap()
+                            .into_wrapping_add(&rem)
+                            .unwrap(),
+                        duo
+                    );
+                    if width == BitWidth::w128() {
+                        let (duo, div) = (duo.resize_to_u128(), div.resize_to_u128());
+                        assert_eq!(quo.resize_to_u128(), duo / div);
+                        assert_eq!(rem.resize_to_u128(), duo % div);
+                    }
+                }
+            }
+        }
+    }
+
+    mod divrem_pair {
+        use super::*;
+        use crate::bitwidth::BitWidth;
+
+        #[test]
+        fn identity() {
+            let mut rng = test_rng();
+            for &width in &[1, 8, 64, 65, 128, 200, 512] {
+                let width = BitWidth::new(width).unwrap();
+                for _ in 0..100 {
+                    let duo = ApInt::random_with_width_using(width, &mut rng);
+                    let mut div = ApInt::random_with_width_using(width, &mut rng);
+                    // also test small divisors
+                    div.wrapping_lshr_assign(duo.count_ones() % width.to_usize())
+                        .unwrap();
+                    if div.is_zero() {
+                        continue
+                    }
+                    let (quo, rem) = duo.clone().into_wrapping_udivrem(&div).unwrap();
+                    assert!(rem.checked_ult(&div).unwrap());
+                    assert_eq!(
+                        quo.clone()
+                            .into_wrapping_mul(&div)
+                            .unwrap()
+                            .into_wrapping_add(&rem)
+                            .unwrap(),
+                        duo
+                    );
+                    assert_eq!(quo, duo.clone().into_wrapping_udiv(&div).unwrap());
+                    let (quo, rem) = duo.clone().into_wrapping_sdivrem(&div).unwrap();
+                    assert_eq!(
+                        quo.clone()
+                            .into_wrapping_mul(&div)
+                            .unwrap()
+                            .into_wrapping_add(&rem)
+                            .unwrap(),
+                        duo
+                    );
+                    assert_eq!(quo, duo.clone().into_wrapping_sdiv(&div).unwrap());
+                    assert_eq!(rem, duo.into_wrapping_srem(&div).unwrap());
+                }
+            }
+        }
+
+        #[test]
+        fn signed() {
+            assert_eq!(
+                ApInt::from(-80i8).into_wrapping_sdivrem(&ApInt::from(7i8)),
+                Ok((ApInt::from(-11i8), ApInt::from(-3i8)))
+            );
+            assert_eq!(
+                ApInt::from(80i8).into_wrapping_sdivrem(&ApInt::from(-7i8)),
+                Ok((ApInt::from(-11i8), ApInt::from(3i8)))
+            );
+        }
+
+        #[test]
+        fn division_by_zero() {
+            assert!(ApInt::from(1u128)
+                .into_wrapping_udivrem(&ApInt::from(0u128))
+                .is_err());
+            assert!(ApInt::from(1u8)
+                .into_wrapping_sdivrem(&ApInt::from(0u8))
+                .is_err());
+            assert!(ApInt::from(1u8)
+                .into_wrapping_udivrem(&ApInt::from(1u16))
+                .is_err());
+        }
+    }
+
+    mod euclid_floor {
+        use super::*;
+        use crate::bitwidth::BitWidth;
+
+        /// Python-style floored division of primitive integers.
+        fn div_floor(x: i8, y: i8) -> (i8, i8) {
+            let (q, r) = (x.wrapping_div(y), x.wrapping_rem(y));
+            if r != 0 && ((r < 0) != (y < 0)) {
+                (q.wrapping_sub(1), r.wrapping_add(y))
+            } else {
+                (q, r)
+            }
+        }
+
+        #[test]
+        fn small() {
+            for x in i8::MIN..=i8::MAX {
+                for y in (i8::MIN..=i8::MAX).step_by(3) {
+                    if y == 0 {
+                        continue
+                    }
+                    let (x_ap, y_ap) = (ApInt::from(x), ApInt::from(y));
+                    assert_eq!(
+                        x_ap.clone().into_sdiv_euclid(&y_ap),
+                        Ok(ApInt::from(x.wrapping_div_euclid(y)))
+                    );
+                    assert_eq!(
+                        x_ap.clone().into_srem_euclid(&y_ap),
+                        Ok(ApInt::from(x.wrapping_rem_euclid(y)))
+                    );
+                    let (q, r) = div_floor(x, y);
+                    assert_eq!(x_ap.clone().into_sdiv_floor(&y_ap), Ok(ApInt::from(q)));
+                    assert_eq!(x_ap.into_srem_floor(&y_ap), Ok(ApInt::from(r)));
+                }
+            }
+        }
+
+        #[test]
+        fn signs() {
+            let (x, y) = (ApInt::from(-7i64), ApInt::from(2i64));
+            assert_eq!(x.clone().into_sdiv_euclid(&y), Ok(ApInt::from(-4i64)));
+            assert_eq!(x.clone().into_srem_euclid(&y), Ok(ApInt::from(1i64)));
+            assert_eq!(x.clone().into_sdiv_floor(&y), Ok(ApInt::from(-4i64)));
+            assert_eq!(x.into_srem_floor(&y), Ok(ApInt::from(1i64)));
+            let (x, y) = (ApInt::from(7i128), ApInt::from(-2i128));
+            assert_eq!(x.clone().into_sdiv_euclid(&y), Ok(ApInt::from(-3i128)));
+            assert_eq!(x.clone().into_srem_euclid(&y), Ok(ApInt::from(1i128)));
+            assert_eq!(x.clone().into_sdiv_floor(&y), Ok(ApInt::from(-4i128)));
+            assert_eq!(x.into_srem_floor(&y), Ok(ApInt::from(-1i128)));
+            let (x, y) = (ApInt::from(-7i128), ApInt::from(-2i128));
+            assert_eq!(x.clone().into_sdiv_euclid(&y), Ok(ApInt::from(4i128)));
+            assert_eq!(x.clone().into_srem_euclid(&y), Ok(ApInt::from(1i128)));
+            assert_eq!(x.clone().into_sdiv_floor(&y), Ok(ApInt::from(3i128)));
+            assert_eq!(x.into_srem_floor(&y), Ok(ApInt::from(-1i128)));
+        }
+
+        #[test]
+        fn signed_min_by_minus_one() {
+            for &width in &[8, 64, 100] {
+                let width = BitWidth::new(width).unwrap();
+                let min = ApInt::signed_min_value(width);
+                let minus_one = ApInt::all_set(width);
+                let zero = ApInt::zero(width);
+                assert_eq!(min.clone().into_sdiv_euclid(&minus_one), Ok(min.clone()));
+                assert_eq!(min.clone().into_srem_euclid(&minus_one), Ok(zero.clone()));
+                assert_eq!(min.clone().into_sdiv_floor(&minus_one), Ok(min.clone()));
+                assert_eq!(min.into_srem_floor(&minus_one), Ok(zero));
+            }
+        }
+
+        #[test]
+        fn width_1() {
+            let zero = ApInt::zero(BitWidth::w1());
+            let minus_one = ApInt::all_set(BitWidth::w1());
+            assert_eq!(zero.clone().into_sdiv_euclid(&minus_one), Ok(zero.clone()));
+            assert_eq!(zero.clone().into_srem_floor(&minus_one), Ok(zero.clone()));
+            // `-1 / -1` wraps around to `-1`
+            assert_eq!(
+                minus_one.clone().into_sdiv_euclid(&minus_one),
+                Ok(minus_one.clone())
+            );
+            assert_eq!(
+                minus_one.clone().into_sdiv_floor(&minus_one),
+                Ok(minus_one.clone())
+            );
+            assert_eq!(
+                minus_one.clone().into_srem_euclid(&minus_one),
+                Ok(zero.clone())
+            );
+            assert_eq!(
+                minus_one.clone().into_srem_floor(&minus_one),
+                Ok(zero.clone())
+            );
+            assert!(minus_one.into_sdiv_euclid(&zero).is_err());
+        }
+
+        #[test]
+        fn division_by_zero() {
+            use crate::errors::ErrorKind;
+            match ApInt::from(5i8).into_srem_floor(&ApInt::from(0i8)) {
+                Err(err) => {
+                    assert_eq!(
+                        err.kind(),
+                        &ErrorKind::DivisionByZero {
+                            op: DivOp::SignedRemFloor,
+                            lhs: ApInt::from(5i8)
+                        }
+                    )
+                }
+                Ok(_) => panic!(),
+            }
+        }
+
+        #[test]
+        fn unmatching_widths_before_division_by_zero() {
+            use crate::errors::ErrorKind;
+            match ApInt::from(5i8).into_sdiv_euclid(&ApInt::from(0i16)) {
+                Err(err) => {
+                    assert_eq!(
+                        err.kind(),
+                        &ErrorKind::UnmatchingBitwidth(BitWidth::w8(), BitWidth::w16())
+                    )
+                }
+                Ok(_) => panic!(),
+            }
+        }
+    }
+
+    mod division_by_zero {
+        use super::*;
+        use crate::{
+            bitwidth::BitWidth,
+            errors::ErrorKind,
+        };
+
+        type DivRemAssign = fn(&mut ApInt, &mut ApInt) -> Result<()>;
+
+        fn assert_div_by_zero(
+            result: Result<impl core::fmt::Debug>,
+            op: DivOp,
+            lhs: &ApInt,
+        ) {
+            match result {
+                Err(err) => {
+                    assert_eq!(
+                        err.kind(),
+                        &ErrorKind::DivisionByZero {
+                            op,
+                            lhs: lhs.clone()
+                        }
+                    )
+                }
+                Ok(ok) => panic!("expected division by zero error, got {:?}", ok),
+            }
+        }
+
+        #[test]
+        fn all_entry_points() {
+            // covers both `Inl` and `Ext` storage
+            for &width in &[1, 8, 64, 65, 128, 200] {
+                let width = BitWidth::new(width).unwrap();
+                let zero = ApInt::zero(width);
+                for lhs in &[ApInt::zero(width), ApInt::all_set(width)] {
+                    let mut temp = lhs.clone();
+                    assert_div_by_zero(
+                        temp.wrapping_udiv_assign(&zero),
+                        DivOp::UnsignedDiv,
+                        lhs,
+                    );
+                    assert_eq!(&temp, lhs);
+                    assert_div_by_zero(
+                        temp.wrapping_urem_assign(&zero),
+                        DivOp::UnsignedRem,
+                        lhs,
+                    );
+                    assert_div_by_zero(
+                        temp.wrapping_sdiv_assign(&zero),
+                        DivOp::SignedDiv,
+                        lhs,
+                    );
+                    assert_div_by_zero(
+                        temp.wrapping_srem_assign(&zero),
+                        DivOp::SignedRem,
+                        lhs,
+                    );
+                    assert_eq!(&temp, lhs);
+                    let op = DivOp::UnsignedDiv;
+                    assert_div_by_zero(lhs.clone().into_wrapping_udiv(&zero), op, lhs);
+                    let op = DivOp::UnsignedRem;
+                    assert_div_by_zero(lhs.clone().into_wrapping_urem(&zero), op, lhs);
+                    let op = DivOp::SignedDiv;
+                    assert_div_by_zero(lhs.clone().into_wrapping_sdiv(&zero), op, lhs);
+                    let op = DivOp::SignedRem;
+                    assert_div_by_zero(lhs.clone().into_wrapping_srem(&zero), op, lhs);
+                    let op = DivOp::UnsignedDivRem;
+                    assert_div_by_zero(lhs.clone().into_wrapping_udivrem(&zero), op, lhs);
+                    let op = DivOp::SignedDivRem;
+                    assert_div_by_zero(lhs.clone().into_wrapping_sdivrem(&zero), op, lhs);
+                    let op = DivOp::SignedDivEuclid;
+                    assert_div_by_zero(lhs.clone().into_sdiv_euclid(&zero), op, lhs);
+                    let op = DivOp::SignedRemEuclid;
+                    assert_div_by_zero(lhs.clone().into_srem_euclid(&zero), op, lhs);
+                    let op = DivOp::SignedDivFloor;
+                    assert_div_by_zero(lhs.clone().into_sdiv_floor(&zero), op, lhs);
+                    let op = DivOp::SignedRemFloor;
+                    assert_div_by_zero(lhs.clone().into_srem_floor(&zero), op, lhs);
+                    assert_div_by_zero(
+                        lhs.is_multiple_of(&zero),
+                        DivOp::UnsignedRem,
+                        lhs,
+                    );
+                    let mut rhs = zero.clone();
+                    let ops: [(DivRemAssign, DivOp); 4] = [
+                        (ApInt::wrapping_udivrem_assign, DivOp::UnsignedDivRem),
+                        (ApInt::wrapping_uremdiv_assign, DivOp::UnsignedRemDiv),
+                        (ApInt::wrapping_sdivrem_assign, DivOp::SignedDivRem),
+                        (ApInt::wrapping_sremdiv_assign, DivOp::SignedRemDiv),
+                    ];
+                    for &(f, op) in &ops {
+                        assert_div_by_zero(f(&mut temp, &mut rhs), op, lhs);
+                        assert_eq!(&temp, lhs);
+                        assert_eq!(rhs, zero);
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn distinct_from_unmatching_bitwidths() {
+            let err = ApInt::from(1u8)
+                .into_wrapping_udiv(&ApInt::from(0u16))
+                .unwrap_err();
+            assert_eq!(
+                err.kind(),
+                &ErrorKind::UnmatchingBitwidth(BitWidth::w8(), BitWidth::w16())
+            );
+        }
+
+        #[test]
+        fn signed_unmatching_bitwidths() {
+            let expected = ErrorKind::UnmatchingBitwidth(BitWidth::w8(), BitWidth::w16());
+            let lhs = ApInt::from(-1i8);
+            let rhs = ApInt::from(-1i16);
+            let mut temp = lhs.clone();
+            assert_eq!(temp.wrapping_sdiv_assign(&rhs).unwrap_err().kind(), &expected);
+            assert_eq!(temp.wrapping_srem_assign(&rhs).unwrap_err().kind(), &expected);
+            assert_eq!(temp, lhs);
+            let ops: [DivRemAssign; 2] =
+                [ApInt::wrapping_sdivrem_assign, ApInt::wrapping_sremdiv_assign];
+            for f in &ops {
+                let mut rhs = rhs.clone();
+                assert_eq!(f(&mut temp, &mut rhs).unwrap_err().kind(), &expected);
+                assert_eq!(temp, lhs);
+            }
+        }
+    }
+
+    mod gcd {
+        use super::*;
+        use crate::bitwidth::BitWidth;
+        use rand::RngCore;
+
+        fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
+            while b != 0 {
+                let t = a % b;
+                a = b;
+                b = t;
+            }
+            a
+        }
+
+        #[test]
+        fn zero() {
+            for &width in &[1, 64, 256] {
+                let width = BitWidth::new(width).unwrap();
+                let zero = ApInt::zero(width);
+                let x = ApInt::all_set(width);
+                assert_eq!(zero.gcd(&zero), Ok(zero.clone()));
+                assert_eq!(zero.gcd(&x), Ok(x.clone()));
+                assert_eq!(x.gcd(&zero), Ok(x.clone()));
+                let one = ApInt::one(width);
+                assert_eq!(
+                    zero.extended_gcd(&zero),
+                    Ok((zero.clone(), one.clone(), zero.clone()))
+                );
+                assert_eq!(
+                    x.extended_gcd(&zero),
+                    Ok((x.clone(), one.clone(), zero.clone()))
+                );
+                assert_eq!(zero.extended_gcd(&x), Ok((x, zero, one)));
+            }
+        }
+
+        #[test]
+        fn small() {
+            let mut rng = test_rng();
+            for _ in 0..1000 {
+                // common factors of two make the binary algorithm shift
+                let common = 1 << (rng.next_u32() % 8);
+                let a = (rng.next_u64() >> (rng.next_u32() % 64)).wrapping_mul(common);
+                let b = (rng.next_u64() >> (rng.next_u32() % 64)).wrapping_mul(common);
+                let g = gcd_u64(a, b);
+                assert_eq!(ApInt::from(a).gcd(&ApInt::from(b)), Ok(ApInt::from(g)));
+                let (eg, x, y) = ApInt::from(a).extended_gcd(&ApInt::from(b)).unwrap();
+                assert_eq!(eg, ApInt::from(g));
+                let (x, y) = (x.resize_to_i64(), y.resize_to_i64());
+                assert_eq!(
+                    i128::from(a) * i128::from(x) + i128::from(b) * i128::from(y),
+                    i128::from(g)
+                );
+            }
+        }
+
+        #[test]
+        fn large() {
+            let mut rng = test_rng();
+            let width = BitWidth::new(256).unwrap();
+            for _ in 0..100 {
+                let common = ApInt::random_with_width_using(width, &mut rng)
+                    .into_wrapping_lshr(128 + (rng.next_u32() % 128) as usize)
+                    .unwrap();
+                let mut a = ApInt::random_with_width_using(width, &mut rng)
+                    .into_wrapping_lshr((rng.next_u32() % 128) as usize)
+                    .unwrap();
+                let mut b = ApInt::random_with_width_using(width, &mut rng)
+                    .into_wrapping_lshr((rng.next_u32() % 128) as usize)
+                    .unwrap();
+                a.wrapping_mul_assign(&common).unwrap();
+                b.wrapping_mul_assign(&common).unwrap();
+                let g = a.gcd(&b).unwrap();
+                assert!(a.is_multiple_of(&g).unwrap());
+                assert!(b.is_multiple_of(&g).unwrap());
+                // the cofactors are coprime
+                let a_cofactor = a.clone().into_wrapping_udiv(&g).unwrap();
+                let b_cofactor = b.clone().into_wrapping_udiv(&g).unwrap();
+                assert_eq!(a_cofactor.gcd(&b_cofactor), Ok(ApInt::one(width)));
+                let (eg, x, y) = a.extended_gcd(&b).unwrap();
+                assert_eq!(eg, g);
+                assert_eq!(
+                    a.into_wrapping_mul(&x)
+                        .unwrap()
+                        .into_wrapping_add(&b.into_wrapping_mul(&y).unwrap())
+                        .unwrap(),
+                    g
+                );
+            }
+        }
+
+        #[test]
+        fn errors() {
+            assert!(ApInt::from(1u8).gcd(&ApInt::from(1u16)).is_err());
+            assert!(ApInt::from(1u8).extended_gcd(&ApInt::from(1u16)).is_err());
+        }
+    }
+
+    mod lcm {
+        use super::*;
+        use rand::RngCore;
+
+        #[test]
+        fn small() {
+            let mut rng = test_rng();
+            for _ in 0..1000 {
+                let a = u64::from(rng.next_u32()) >> (rng.next_u32() % 32);
+                let b = u64::from(rng.next_u32()) >> (rng.next_u32() % 32);
+                let (a_ap, b_ap) = (ApInt::from(a as u32), ApInt::from(b as u32));
+                let lcm = if a == 0 || b == 0 {
+                    0
+                } else {
+                    let (mut x, mut y) = (a, b);
+                    while y != 0 {
+                        let t = x % y;
+                        x = y;
+                        y = t;
+                    }
+                    a / x * b
+                };
+                assert_eq!(a_ap.widening_lcm(&b_ap), Ok(ApInt::from(lcm)));
+                let expected = if lcm > u64::from(u32::MAX) {
+                    None
+                } else {
+                    Some(ApInt::from(lcm as u32))
+                };
+                assert_eq!(a_ap.checked_lcm(&b_ap), Ok(expected));
+            }
+        }
+
+        #[test]
+        fn edge_cases() {
+            let zero = ApInt::from(0u128);
+            let x = ApInt::from(12345u128);
+            assert_eq!(zero.checked_lcm(&x), Ok(Some(zero.clone())));
+            assert_eq!(x.checked_lcm(&zero), Ok(Some(zero.clone())));
+            assert_eq!(zero.checked_lcm(&zero), Ok(Some(zero.clone())));
+            assert_eq!(
+                x.widening_lcm(&zero),
+                Ok(ApInt::zero(BitWidth::new(256).unwrap()))
+            );
+            let max = ApInt::from(u128::MAX);
+            assert_eq!(max.checked_lcm(&max), Ok(Some(max.clone())));
+            assert_eq!(max.checked_lcm(&ApInt::from(1u128)), Ok(Some(max.clone())));
+            // `u128::MAX` is odd
+            assert_eq!(max.checked_lcm(&ApInt::from(2u128)), Ok(None));
+            assert_eq!(
+                max.widening_lcm(&ApInt::from(2u128)),
+                Ok(ApInt::from([0u64, 1, u64::MAX, u64::MAX - 1]))
+            );
+            assert!(x.checked_lcm(&ApInt::from(1u8)).is_err());
+            assert!(x.widening_lcm(&ApInt::from(1u8)).is_err());
+        }
+    }
+
+    mod isqrt {
+        use super::*;
+        use rand::RngCore;
+
+        /// Asserts that `r * r <= n < (r + 1) * (r + 1)`.
+        fn assert_isqrt(n: &ApInt, r: &ApInt) {
+            let double = BitWidth::new(n.width().to_usize() * 2 + 2).unwrap();
+            let n = n.clone().into_zero_extend(double).unwrap();
+            let r = r.clone().into_zero_extend(double).unwrap();
+            let r_inc = r.clone().into_wrapping_inc();
+            assert!(r
+                .clone()
+                .into_wrapping_mul(&r)
+                .unwrap()
+                .checked_ule(&n)
+                .unwrap());
+            assert!(n
+                .checked_ult(&r_inc.clone().into_wrapping_mul(&r_inc).unwrap())
+                .unwrap());
+        }
+
+        #[test]
+        fn small() {
+            for n in 0..=u16::MAX {
+                let expected = (f64::from(n)).sqrt() as u16;
+                assert_eq!(ApInt::from(n).into_isqrt(), ApInt::from(expected));
+            }
+            for &width in &[1, 2, 3] {
+                let width = BitWidth::new(width).unwrap();
+                let mut n = ApInt::zero(width);
+                loop {
+                    assert_isqrt(&n, &n.clone().into_isqrt());
+                    n.wrapping_inc();
+                    if n.is_zero() {
+                        break
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn random() {
+            let mut rng = test_rng();
+            for &width in &[64, 65, 128, 200, 512, 1024] {
+                let width = BitWidth::new(width).unwrap();
+                for _ in 0..50 {
+                    let n = ApInt::random_with_width_using(width, &mut rng)
+                        .into_wrapping_lshr(rng.next_u32() as usize % width.to_usize())
+                        .unwrap();
+                    assert_isqrt(&n, &n.clone().into_isqrt());
+                    // exact squares and their neighbors
+                    let half = BitWidth::new(width.to_usize() / 2).unwrap();
+                    let r = ApInt::random_with_width_using(half, &mut rng)
+                        .into_zero_extend(width)
+                        .unwrap();
+                    if r.is_zero() {
+                        continue
+                    }
+                    let square = r.clone().into_wrapping_mul(&r).unwrap();
+                    assert_eq!(square.clone().into_isqrt(), r);
+                    assert_eq!(
+                        square.clone().into_wrapping_dec().into_isqrt(),
+                        r.clone().into_wrapping_dec()
+                    );
+                    if square != ApInt::all_set(width) {
+                        assert_eq!(square.into_wrapping_inc().into_isqrt(), r);
+                    }
+                }
+                let max = ApInt::all_set(width);
+                assert_isqrt(&max, &max.clone().into_isqrt());
+            }
+        }
+    }
+
+    mod nth_root {
+        use super::*;
+        use crate::errors::ErrorKind;
+
+        /// Asserts that `r^n <= x < (r + 1)^n`.
+        fn assert_nth_root(x: &ApInt, n: u32, r: &ApInt) {
+            let wide = BitWidth::new(x.width().to_usize() * n as usize + 1).unwrap();
+            let x = x.clone().into_zero_extend(wide).unwrap();
+            let r = r.clone().into_zero_extend(wide).unwrap();
+            let (lower, overflow) = r.clone().into_overflowing_upow(n);
+            assert!(!overflow);
+            assert!(lower.checked_ule(&x).unwrap());
+            let (upper, overflow) = r.into_wrapping_inc().into_overflowing_upow(n);
+            assert!(!overflow);
+            assert!(x.checked_ult(&upper).unwrap());
+        }
+
+        #[test]
+        fn zero_degree() {
+            assert_eq!(
+                ApInt::from(27u8).into_nth_root(0).unwrap_err().kind(),
+                &ErrorKind::InvalidRootDegree(0)
+            );
+        }
+
+        #[test]
+        fn small() {
+            for n in 1..=10 {
+                for x in (0..=u16::MAX).step_by(7) {
+                    let x = ApInt::from(x);
+                    assert_nth_root(&x, n, &x.clone().into_nth_root(n).unwrap());
+                }
+            }
+            for &width in &[1, 2, 3, 5] {
+                let width = BitWidth::new(width).unwrap();
+                let max = ApInt::all_set(width);
+                for n in 1..8 {
+                    assert_nth_root(&max, n, &max.clone().into_nth_root(n).unwrap());
+                }
+            }
+        }
+
+        #[test]
+        fn powers_and_neighbors() {
+            let mut rng = test_rng();
+            let width = BitWidth::new(1000).unwrap();
+            for &n in &[3, 5] {
+                let root_width = BitWidth::new(200).unwrap();
+                for _ in 0..30 {
+                    let r = ApInt::random_with_width_using(root_width, &mut rng)
+                        .into_zero_extend(width)
+                        .unwrap();
+                    let (x, overflow) = r.clone().into_overflowing_upow(n);
+                    assert!(!overflow);
+                    assert_eq!(x.clone().into_nth_root(n).unwrap(), r);
+                    assert!(x.is_perfect_power());
+                    let below = x.clone().into_wrapping_dec();
+                    assert_eq!(
+                        below.clone().into_nth_root(n).unwrap(),
+                        r.clone().into_wrapping_dec()
+                    );
+                    let above = x.clone().into_wrapping_inc();
+                    assert_eq!(above.clone().into_nth_root(n).unwrap(), r);
+                    assert_nth_root(&below, n, &below.clone().into_nth_root(n).unwrap());
+                    assert_nth_root(&above, n, &above.clone().into_nth_root(n).unwrap());
+                    // random values are almost surely not perfect powers
+                    let random = ApInt::random_with_width_using(width, &mut rng);
+                    for k in &[2, 3, 7, 64, 999] {
+                        assert_nth_root(
+                            &random,
+                            *k,
+                            &random.clone().into_nth_root(*k).unwrap(),
+                        );
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn perfect_power() {
+            let perfect = [0u64, 1, 4, 8, 9, 16, 25, 27, 32, 36, 1 << 62, 3u64.pow(40)];
+            for &x in &perfect {
+                assert!(ApInt::from(x).is_perfect_power(), "{}", x);
+            }
+            let imperfect = [2u64, 3, 5, 6, 7, 10, 12, 26, 28, (1 << 62) + 1];
+            for &x in &imperfect {
+                assert!(!ApInt::from(x).is_perfect_power(), "{}", x);
+            }
+            let naive = |x: u64| {
+                (2..64u32).any(|k| {
+                    (0..=x)
+                        .map_while(|a| a.checked_pow(k))
+                        .take_while(|&p| p <= x)
+                        .any(|p| p == x)
+                })
+            };
+            for x in 0..2000u64 {
+                assert_eq!(ApInt::from(x).is_perfect_power(), naive(x), "{}", x);
+            }
+        }
+    }
+
+    mod ilog {
+        use super::*;
+        use rand::RngCore;
+
+        fn naive_ilog(x: &ApInt, base: u64) -> Option<usize> {
+            if x.is_zero() || base < 2 {
+                return None
+            }
+            let width = BitWidth::new(x.width().to_usize().max(64)).unwrap();
+            let mut x = x.clone().into_zero_resize(width);
+            let base = ApInt::from_u64(base).into_zero_resize(width);
+            let mut ilog = 0;
+            loop {
+                x.wrapping_udiv_assign(&base).unwrap();
+                if x.is_zero() {
+                    return Some(ilog)
+                }
+                ilog += 1;
+            }
+        }
+
+        #[test]
+        fn zero_and_invalid_base() {
+            let zero = ApInt::zero(BitWidth::new(100).unwrap());
+            assert_eq!(zero.checked_ilog2(), None);
+            assert_eq!(zero.checked_ilog10(), None);
+            assert_eq!(zero.checked_ilog(3), None);
+            let x = ApInt::from(1000u32);
+            assert_eq!(x.checked_ilog(0), None);
+            assert_eq!(x.checked_ilog(1), None);
+        }
+
+        #[test]
+        fn primitive() {
+            for x in (1..=u16::MAX).step_by(3) {
+                let apint = ApInt::from(x);
+                assert_eq!(apint.checked_ilog2(), Some(x.ilog2() as usize));
+                assert_eq!(apint.checked_ilog10(), Some(x.ilog10() as usize));
+                for &base in &[2u16, 3, 7, 16, 255, 1000, u16::MAX] {
+                    assert_eq!(
+                        apint.checked_ilog(u64::from(base)),
+                        Some(x.ilog(base) as usize)
+                    );
+                }
+                assert_eq!(apint.checked_ilog(u64::MAX), Some(0));
+            }
+            assert_eq!(ApInt::from(1u8).checked_ilog10(), Some(0));
+            assert_eq!(ApInt::from(u64::MAX).checked_ilog(u64::MAX), Some(1));
+            assert_eq!(ApInt::from(u64::MAX).checked_ilog10(), Some(19));
+            assert_eq!(ApInt::from(u128::MAX).checked_ilog10(), Some(38));
+        }
+
+        #[test]
+        fn random() {
+            let mut rng = test_rng();
+            for &width in &[1, 3, 8, 64, 65, 128, 200, 1024] {
+                let width = BitWidth::new(width).unwrap();
+                for _ in 0..20 {
+                    let x = ApInt::random_with_width_using(width, &mut rng)
+                        .into_wrapping_lshr(rng.next_u32() as usize % width.to_usize())
+                        .unwrap();
+                    assert_eq!(x.checked_ilog2(), naive_ilog(&x, 2));
+                    assert_eq!(x.checked_ilog10(), naive_ilog(&x, 10));
+                    for &base in &[3, 5, 1 << 32, u64::MAX, rng.next_u64()] {
+                        assert_eq!(x.checked_ilog(base), naive_ilog(&x, base));
+                    }
+                }
+                // exact powers and their neighbors
+                for &base in &[3u64, 10, 12345] {
+                    if 64 - base.leading_zeros() as usize > width.to_usize() {
+                        continue
+                    }
+                    let base_apint = ApInt::from_u64(base).into_zero_resize(width);
+                    let mut power = ApInt::one(width);
+                    let mut next = ApInt::zero(width);
+                    let mut exp = 0;
+                    loop {
+                        assert_eq!(power.checked_ilog(base), Some(exp));
+                        if exp > 0 {
+                            let below = power.clone().into_wrapping_dec();
+                            assert_eq!(below.checked_ilog(base), Some(exp - 1));
+                        }
+                        if ApInt::overflowing_umul_into(&mut next, &power, &base_apint) {
+                            break
+                        }
+                        mem::swap(&mut power, &mut next);
+                        exp += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    mod modpow {
+        use super::*;
+        use crate::errors::ErrorKind;
+        use rand::RngCore;
+
+        /// Right-to-left binary exponentiation on `u128` with 64 bit operands.
+        fn naive_modpow(base: u64, mut exp: u64, modulus: u64) -> u64 {
+            let m = u128::from(modulus);
+            let mut base = u128::from(base) % m;
+            let mut acc = 1 % m;
+            while exp != 0 {
+                if exp & 1 != 0 {
+                    acc = acc * base % m;
+                }
+                base = base * base % m;
+                exp >>= 1;
+            }
+            acc as u64
+        }
+
+        #[test]
+        fn errors() {
+            let x = ApInt::from(5u64);
+            assert_eq!(
+                x.modpow(&ApInt::from(3u64), &ApInt::from(0u64))
+                    .unwrap_err()
+                    .kind(),
+                &ErrorKind::DivisionByZero {
+                    op: DivOp::ModPow,
+                    lhs: x.clone()
+                }
+            );
+            assert!(x.modpow(&ApInt::from(3u32), &ApInt::from(7u64)).is_err());
+            assert!(x.modpow(&ApInt::from(3u64), &ApInt::from(7u32)).is_err());
+        }
+
+        #[test]
+        fn small() {
+            let x = ApInt::from(5u64);
+            let zero = ApInt::from(0u64);
+            assert_eq!(
+                x.modpow(&zero, &ApInt::from(7u64)).unwrap(),
+                ApInt::from(1u64)
+            );
+            assert_eq!(x.modpow(&zero, &ApInt::from(1u64)).unwrap(), zero);
+            assert_eq!(
+                zero.modpow(&zero, &ApInt::from(7u64)).unwrap(),
+                ApInt::from(1u64)
+            );
+            for b in 0..12u8 {
+                for e in 0..12u8 {
+                    for m in 1..12u8 {
+                        assert_eq!(
+                            ApInt::from(b)
+                                .modpow(&ApInt::from(e), &ApInt::from(m))
+                                .unwrap(),
+                            ApInt::from(naive_modpow(
+                                u64::from(b),
+                                u64::from(e),
+                                u64::from(m)
+                            ) as u8)
+                        );
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn u128_cross_check() {
+            let mut rng = test_rng();
+            for _ in 0..1000 {
+                let b = rng.next_u64();
+                let e = rng.next_u64();
+                let m = rng.next_u64() >> (rng.next_u32() % 64);
+                if m == 0 {
+                    continue
+                }
+                assert_eq!(
+                    ApInt::from(b)
+                        .modpow(&ApInt::from(e), &ApInt::from(m))
+                        .unwrap(),
+                    ApInt::from(naive_modpow(b, e, m))
+                );
+            }
+        }
+
+        #[test]
+        fn rfc_2409_group_2() {
+            let width = BitWidth::new(1024).unwrap();
+            let parse = |s: &str| ApInt::from_str_radix(s, 16, width).unwrap();
+            // the 1024 bit prime of the Oakley Default Group 2 from RFC 2409
+            let p = parse(
+                "ffffffffffffffffc90fdaa22168c234c4c6628b80dc1cd129024e088a67cc74\
+                 020bbea63b139b22514a08798e3404ddef9519b3cd3a431b302b0a6df25f1437\
+                 4fe1356d6d51c245e485b576625e7ec6f44c42e9a637ed6b0bff5cb6f406b7ed\
+                 ee386bfb5a899fa5ae9f24117c4b1fe649286651ece65381ffffffffffffffff",
+            );
+            let b = parse(
+                "5267768822ee624d48fce15ec5ca79cbd602cb7f4c2157a516556991f22ef8c7\
+                 b5ef7b18d1ff41c59370efb0858651d44a936c11b7b144c48fe04df3c6a3e8da\
+                 5267768822ee624d48fce15ec5ca79cbd602cb7f4c2157a516556991f22ef8c7\
+                 b5ef7b18d1ff41c59370efb0858651d44a936c11b7b144c48fe04df3c6a3e8da",
+            );
+            let e = parse(
+                "87c568e037a5fa50b1bc911e8ee19a77c4dd3c22bce9932f86fdd8a216afe168\
+                 1c89737fada6859e91047eece711ec16da62d6ccb9fd0de2c51f132347350d8c\
+                 87c568e037a5fa50b1bc911e8ee19a77c4dd3c22bce9932f86fdd8a216afe168\
+                 1c89737fada6859e91047eece711ec16da62d6ccb9fd0de2c51f132347350d8c",
+            );
+            let expected = parse(
+                "6329e2983d23c53635f8b851d7a85a474db78fedc29da6697b86f2e1aeb4cdc7\
+                 fbb988eb34ef072ff22a7bfc4d7f90c67ebd1e3b3cb00ec86ec7f185645edf8d\
+                 c4067631551f3e8af5d5616138b13117a9ae508e914117cfcdc46f33d3c70da0\
+                 d012a27fcdc8ded20030966d9d7d65c008fb476a94983533c987cbef6ae97e28",
+            );
+            assert_eq!(b.modpow(&e, &p).unwrap(), expected);
+            // Fermat's little theorem
+            let p_dec = p.clone().into_wrapping_dec();
+            assert_eq!(b.modpow(&p_dec, &p).unwrap(), ApInt::one(width));
+            // `2^(p - 2)` is the inverse of 2, which is `(p + 1) / 2`
+            let two = ApInt::from(2u8).into_zero_resize(width);
+            let p_dec2 = p_dec.clone().into_wrapping_dec();
+            assert_eq!(
+                two.modpow(&p_dec2, &p).unwrap(),
+                p.clone().into_wrapping_inc().into_wrapping_lshr(1).unwrap()
+            );
+        }
+    }
+
+    mod mod_inverse {
+        use super::*;
+        use crate::errors::ErrorKind;
+
+        /// Asserts that `x * inv == 1 (mod m)` and `inv < m`.
+        fn assert_inverse(x: &ApInt, inv: &ApInt, m: &ApInt) {
+            assert!(inv.checked_ult(m).unwrap());
+            let double_width = BitWidth::new(x.width().to_usize() * 2).unwrap();
+            let ext = |x: &ApInt| x.clone().into_zero_extend(double_width).unwrap();
+            let m = ext(m);
+            let prod = ext(x)
+                .into_wrapping_mul(&ext(inv))
+                .unwrap()
+                .into_wrapping_urem(&m)
+                .unwrap();
+            assert_eq!(prod, ApInt::one(double_width));
+        }
+
+        #[test]
+        fn errors() {
+            let x = ApInt::from(3u16);
+            assert_eq!(
+                x.mod_inverse(&ApInt::from(0u16)).unwrap_err().kind(),
+                &ErrorKind::DivisionByZero {
+                    op: DivOp::ModInverse,
+                    lhs: x.clone()
+                }
+            );
+            assert!(x.mod_inverse(&ApInt::from(7u32)).is_err());
+        }
+
+        #[test]
+        fn small() {
+            assert_eq!(
+                ApInt::from(3u8).mod_inverse(&ApInt::from(1u8)).unwrap(),
+                Some(ApInt::from(0u8))
+            );
+            assert_eq!(
+                ApInt::from(0u8).mod_inverse(&ApInt::from(7u8)).unwrap(),
+                None
+            );
+            for m in 2..=u8::MAX {
+                for x in 0..=u8::MAX {
+                    let inv = ApInt::from(x).mod_inverse(&ApInt::from(m)).unwrap();
+                    let naive =
+                        (0..m).find(|&y| u16::from(x) * u16::from(y) % u16::from(m) == 1);
+                    assert_eq!(inv, naive.map(ApInt::from));
+                }
+            }
+        }
+
+        #[test]
+        fn random() {
+            let mut rng = test_rng();
+            for &width in &[64, 128, 256, 512] {
+                let width = BitWidth::new(width).unwrap();
+                let mut found = 0;
+                while found < 50 {
+                    let mut m = ApInt::random_with_width_using(width, &mut rng);
+                    m.set_bit_at(0).unwrap();
+                    let x = ApInt::random_with_width_using(width, &mut rng);
+                    let coprime = x.gcd(&m).unwrap() == ApInt::one(width);
+                    match x.mod_inverse(&m).unwrap() {
+                        Some(inv) => {
+                            assert!(coprime);
+                            assert_inverse(&x, &inv, &m);
+                            found += 1;
+                        }
+                        None => assert!(!coprime),
+                    }
+                    // even/even pairs are never coprime
+                    let mut even_m = m.clone();
+                    even_m.unset_bit_at(0).unwrap();
+                    let mut even_x = x.clone();
+                    even_x.unset_bit_at(0).unwrap();
+                    if !even_m.is_zero() {
+                        assert_eq!(even_x.mod_inverse(&even_m).unwrap(), None);
+                    }
+                }
+                // `m - 1` is its own inverse
+                let m = ApInt::all_set(width);
+                let inv = m
+                    .clone()
+                    .into_wrapping_dec()
+                    .mod_inverse(&m)
+                    .unwrap()
+                    .unwrap();
+                assert_eq!(inv, m.clone().into_wrapping_dec());
+            }
+        }
+    }
+
+    mod mod_inverse_pow2 {
+        use super::*;
+
+        #[test]
+        fn even() {
+            assert_eq!(ApInt::from(0u8).mod_inverse_pow2(), None);
+            assert_eq!(ApInt::from(6u8).mod_inverse_pow2(), None);
+            assert_eq!(
+                ApInt::zero(BitWidth::new(4096).unwrap()).mod_inverse_pow2(),
+                None
+            );
+        }
+
+        #[test]
+        fn small() {
+            for x in (1..=u16::MAX).step_by(2) {
+                let inv = ApInt::from(x).mod_inverse_pow2().unwrap();
+                assert_eq!(
+                    inv.into_wrapping_mul(&ApInt::from(x)).unwrap(),
+                    ApInt::from(1u16)
+                );
+            }
+            assert_eq!(
+                ApInt::from(true).mod_inverse_pow2(),
+                Some(ApInt::from(true))
+            );
+        }
+
+        #[test]
+        fn random() {
+            let mut rng = test_rng();
+            for &width in &[7, 64, 65, 127, 128, 1000, 4096] {
+                let width = BitWidth::new(width).unwrap();
+                for _ in 0..20 {
+                    let mut x = ApInt::random_with_width_using(width, &mut rng);
+                    x.set_bit_at(0).unwrap();
+                    let inv = x.mod_inverse_pow2().unwrap();
+                    assert_eq!(inv.into_wrapping_mul(&x).unwrap(), ApInt::one(width));
+                    x.unset_bit_at(0).unwrap();
+                    assert_eq!(x.mod_inverse_pow2(), None);
+                }
+            }
+        }
+    }
+
+    mod abs_diff {
+        use super::*;
+
+        #[test]
+        fn primitive() {
+            for a in 0..=u8::MAX {
+                for b in 0..=u8::MAX {
+                    let (x, y) = (ApInt::from(a), ApInt::from(b));
+                    assert_eq!(x.abs_diff(&y).unwrap(), ApInt::from(a.abs_diff(b)));
+                    let (sa, sb) = (a as i8, b as i8);
+                    assert_eq!(x.sabs_diff(&y).unwrap(), ApInt::from(sa.abs_diff(sb)));
+                }
+            }
+        }
+
+        #[test]
+        fn equal() {
+            for &width in &[1, 64, 65, 128, 300] {
+                let width = BitWidth::new(width).unwrap();
+                for x in &[
+                    ApInt::zero(width),
+                    ApInt::all_set(width),
+                    ApInt::signed_min_value(width),
+                    ApInt::signed_max_value(width),
+                ] {
+                    assert_eq!(x.abs_diff(x).unwrap(), ApInt::zero(width));
+                    assert_eq!(x.sabs_diff(x).unwrap(), ApInt::zero(width));
+                }
+            }
+        }
+
+        #[test]
+        fn straddling_signed_min_value() {
+            for &width in &[1, 7, 64, 65, 128, 300] {
+                let width = BitWidth::new(width).unwrap();
+                let min = ApInt::signed_min_value(width);
+                let max = ApInt::signed_max_value(width);
+                let all_set = ApInt::all_set(width);
+                // `max` and `min` are adjacent in the unsigned interpretation
+                assert_eq!(min.abs_diff(&max).unwrap(), ApInt::one(width));
+                assert_eq!(max.abs_diff(&min).unwrap(), ApInt::one(width));
+                // and as far apart as possible in the signed interpretation
+                assert_eq!(min.sabs_diff(&max).unwrap(), all_set);
+                assert_eq!(max.sabs_diff(&min).unwrap(), all_set);
+                assert_eq!(min.abs_diff(&ApInt::zero(width)).unwrap(), min);
+                assert_eq!(min.sabs_diff(&ApInt::zero(width)).unwrap(), min);
+                assert_eq!(min.sabs_diff(&all_set).unwrap(), max);
+            }
         }
 
         #[test]
-        fn range() {
-            for v in test_vals() {
-                assert_symmetry(ApInt::from_i8(v as i8), ApInt::from_i8(-v as i8));
-                assert_symmetry(ApInt::from_i16(v as i16), ApInt::from_i16(-v as i16));
-                assert_symmetry(ApInt::from_i32(v as i32), ApInt::from_i32(-v as i32));
-                assert_symmetry(ApInt::from_i64(v as i64), ApInt::from_i64(-v as i64));
-                assert_symmetry(ApInt::from_i128(v), ApInt::from_i128(-v));
+        fn random() {
+            let mut rng = test_rng();
+            for &width in &[64, 65, 128, 200] {
+                let width = BitWidth::new(width).unwrap();
+                for _ in 0..100 {
+                    let x = ApInt::random_with_width_using(width, &mut rng);
+                    let y = ApInt::random_with_width_using(width, &mut rng);
+                    let expected = if x.checked_ult(&y).unwrap() {
+                        y.clone().into_wrapping_sub(&x).unwrap()
+                    } else {
+                        x.clone().into_wrapping_sub(&y).unwrap()
+                    };
+                    assert_eq!(x.abs_diff(&y).unwrap(), expected);
+                    let expected = if x.checked_slt(&y).unwrap() {
+                        y.clone().into_wrapping_sub(&x).unwrap()
+                    } else {
+                        x.clone().into_wrapping_sub(&y).unwrap()
+                    };
+                    assert_eq!(x.sabs_diff(&y).unwrap(), expected);
+                }
             }
+            let x = ApInt::from(1u8);
+            assert!(x.abs_diff(&ApInt::from(1u16)).is_err());
+            assert!(x.sabs_diff(&ApInt::from(1u16)).is_err());
         }
     }
 
-    mod mul {
+    mod midpoint {
         use super::*;
-        use crate::bitwidth::BitWidth;
-        use core::{
-            u64,
-            u8,
-        };
+
+        /// Computes the midpoints with an intermediate that is 1 bit wider.
+        fn reference(x: &ApInt, y: &ApInt) -> (ApInt, ApInt) {
+            let width = x.width();
+            let wider = BitWidth::new(width.to_usize() + 1).unwrap();
+            let zext = |x: &ApInt| x.clone().into_zero_extend(wider).unwrap();
+            let sext = |x: &ApInt| x.clone().into_sign_extend(wider).unwrap();
+            let unsigned = zext(x)
+                .into_wrapping_add(&zext(y))
+                .unwrap()
+                .into_wrapping_lshr(1)
+                .unwrap()
+                .into_truncate(width)
+                .unwrap();
+            let two = ApInt::from(2u8).into_zero_resize(wider);
+            let signed = sext(x)
+                .into_wrapping_add(&sext(y))
+                .unwrap()
+                .into_wrapping_sdiv(&two)
+                .unwrap()
+                .into_truncate(width)
+                .unwrap();
+            (unsigned, signed)
+        }
 
         #[test]
-        fn rigorous() {
-            // there are many special case and size optimization paths, so this test must
-            // be very rigorous.
+        fn primitive() {
+            for a in 0..=u8::MAX {
+                for b in 0..=u8::MAX {
+                    let (x, y) = (ApInt::from(a), ApInt::from(b));
+                    assert_eq!(x.midpoint(&y).unwrap(), ApInt::from(a.midpoint(b)));
+                    assert_eq!(
+                        x.smidpoint(&y).unwrap(),
+                        ApInt::from((a as i8).midpoint(b as i8))
+                    );
+                }
+            }
+        }
 
-            // multiplication of apints composed of only u8::MAX in their least
-            // significant digits only works for num_u8 > 1
-            fn nine_test(num_u8: usize) {
-                let mut lhs;
-                let mut rhs =
-                    ApInt::from(0u8).into_zero_resize(BitWidth::new(num_u8 * 8).unwrap());
-                let nine = ApInt::from(u8::MAX)
-                    .into_zero_resize(BitWidth::new(num_u8 * 8).unwrap());
-                for rhs_nine in 0..num_u8 {
-                    rhs.wrapping_shl_assign(8usize).unwrap();
-                    rhs |= &nine;
-                    lhs = ApInt::from(0u8)
-                        .into_zero_resize(BitWidth::new(num_u8 * 8).unwrap());
-                    'outer: for lhs_nine in 0..num_u8 {
-                        lhs.wrapping_shl_assign(8usize).unwrap();
-                        lhs |= &nine;
-                        // imagine multiplying a string of base 10 nines together.
-                        // It will produce things like 998001, 8991, 98901, 9989001.
-                        // this uses a formula for the number of nines, eights, and zeros
-                        // except here nine is u8::MAX, eight is
-                        // u8::MAX - 1, and zero is 0u8
-                        let zeros_after_one = if lhs_nine < rhs_nine {
-                            lhs_nine
-                        } else {
-                            rhs_nine
-                        };
-                        let nines_before_eight = if lhs_nine > rhs_nine {
-                            lhs_nine - rhs_nine
+        #[test]
+        fn extremes() {
+            for &width in &[1, 2, 63, 64, 65, 128, 300] {
+                let width = BitWidth::new(width).unwrap();
+                let max = ApInt::unsigned_max_value(width);
+                let zero = ApInt::zero(width);
+                assert_eq!(max.midpoint(&max).unwrap(), max);
+                assert_eq!(max.midpoint(&zero).unwrap(), reference(&max, &zero).0);
+                assert_eq!(zero.midpoint(&max).unwrap(), max.midpoint(&zero).unwrap());
+                let smin = ApInt::signed_min_value(width);
+                let smax = ApInt::signed_max_value(width);
+                assert_eq!(smin.smidpoint(&smin).unwrap(), smin);
+                assert_eq!(smax.smidpoint(&smax).unwrap(), smax);
+                assert_eq!(smin.smidpoint(&smax).unwrap(), reference(&smin, &smax).1);
+                assert_eq!(max.smidpoint(&zero).unwrap(), zero);
+            }
+        }
+
+        #[test]
+        fn random() {
+            let mut rng = test_rng();
+            for &width in &[7, 64, 65, 100, 128, 256] {
+                let width = BitWidth::new(width).unwrap();
+                for _ in 0..100 {
+                    let x = ApInt::random_with_width_using(width, &mut rng);
+                    let y = ApInt::random_with_width_using(width, &mut rng);
+                    let (unsigned, signed) = reference(&x, &y);
+                    assert_eq!(x.midpoint(&y).unwrap(), unsigned);
+                    assert_eq!(x.smidpoint(&y).unwrap(), signed);
+                }
+            }
+            let x = ApInt::from(1u8);
+            assert!(x.midpoint(&ApInt::from(1u16)).is_err());
+            assert!(x.smidpoint(&ApInt::from(1u16)).is_err());
+        }
+    }
+
+    mod div_ceil {
+        use super::*;
+        use crate::errors::ErrorKind;
+
+        #[test]
+        fn primitive() {
+            for a in 0..=u8::MAX {
+                for b in 1..=u8::MAX {
+                    let (x, y) = (ApInt::from(a), ApInt::from(b));
+                    assert_eq!(
+                        x.clone().into_div_ceil(&y).unwrap(),
+                        ApInt::from(a.div_ceil(b))
+                    );
+                    assert_eq!(
+                        x.clone().into_div_floor(&y).unwrap(),
+                        ApInt::from(a / b)
+                    );
+                    assert_eq!(
+                        x.checked_next_multiple_of(&y).unwrap(),
+                        a.checked_next_multiple_of(b).map(ApInt::from)
+                    );
+                    let (sa, sb) = (a as i8, b as i8);
+                    let q = i16::from(sa) / i16::from(sb);
+                    let r = i16::from(sa) % i16::from(sb);
+                    let ceil = if r != 0 && (r > 0) == (sb > 0) {
+                        q + 1
+                    } else {
+                        q
+                    };
+                    assert_eq!(
+                        x.clone().into_sdiv_ceil(&y).unwrap(),
+                        ApInt::from(ceil as i8)
+                    );
+                    let m = i16::from(sb);
+                    let r = i16::from(sa).rem_euclid(m);
+                    let r = if r != 0 && m < 0 { r + m } else { r };
+                    let next = if r == 0 {
+                        i16::from(sa)
+                    } else {
+                        i16::from(sa) + (m - r)
+                    };
+                    let expected =
+                        if next < i16::from(i8::MIN) || next > i16::from(i8::MAX) {
+                            None
                         } else {
-                            rhs_nine - lhs_nine
+                            Some(ApInt::from(next as i8))
                         };
-                        let nines_after_eight = if lhs_nine < rhs_nine {
-                            lhs_nine
+                    assert_eq!(x.checked_snext_multiple_of(&y).unwrap(), expected);
+                }
+            }
+        }
+
+        #[test]
+        fn near_unsigned_max_value() {
+            let mut rng = test_rng();
+            for &width in &[8, 64, 65, 128, 300] {
+                let width = BitWidth::new(width).unwrap();
+                let max = ApInt::unsigned_max_value(width);
+                let one = ApInt::one(width);
+                assert_eq!(max.clone().into_div_ceil(&max).unwrap(), one);
+                assert_eq!(max.clone().into_div_ceil(&one).unwrap(), max);
+                assert_eq!(
+                    max.checked_next_multiple_of(&max).unwrap(),
+                    Some(max.clone())
+                );
+                assert_eq!(
+                    max.checked_next_multiple_of(&one).unwrap(),
+                    Some(max.clone())
+                );
+                for _ in 0..50 {
+                    let rhs = ApInt::random_with_width_using(width, &mut rng)
+                        .into_wrapping_lshr(width.to_usize() / 2)
+                        .unwrap()
+                        .into_wrapping_inc();
+                    // values within `rhs` of `unsigned_max_value`
+                    let below = ApInt::random_with_width_using(width, &mut rng)
+                        .into_wrapping_urem(&rhs)
+                        .unwrap();
+                    let x = max.clone().into_wrapping_sub(&below).unwrap();
+                    let (quo, rem) = x.clone().into_wrapping_udivrem(&rhs).unwrap();
+                    let ceil = x.clone().into_div_ceil(&rhs).unwrap();
+                    if rem.is_zero() {
+                        assert_eq!(ceil, quo);
+                        assert_eq!(
+                            x.checked_next_multiple_of(&rhs).unwrap(),
+                            Some(x.clone())
+                        );
+                    } else {
+                        assert_eq!(ceil, quo.clone().into_wrapping_inc());
+                        // the next multiple is `(quo + 1) * rhs`
+                        let double = BitWidth::new(width.to_usize() * 2).unwrap();
+                        let next = quo
+                            .into_wrapping_inc()
+                            .into_zero_extend(double)
+                            .unwrap()
+                            .into_wrapping_mul(
+                                &rhs.clone().into_zero_extend(double).unwrap(),
+                            )
+                            .unwrap();
+                        let expected = if next
+                            .checked_ule(&max.clone().into_zero_extend(double).unwrap())
+                            .unwrap()
+                        {
+                            Some(next.into_truncate(width).unwrap())
                         } else {
-                            rhs_nine
+                            None
                         };
-                        let mut result = lhs.clone().into_wrapping_mul(&rhs).unwrap();
-                        assert_eq!(result.clone().resize_to_u8(), 1u8);
-                        for i in 0..zeros_after_one {
-                            if i >= num_u8 - 1 {
-                                continue 'outer
-                            }
-                            result.wrapping_lshr_assign(8usize).unwrap();
-                            assert_eq!(result.clone().resize_to_u8(), 0);
-                        }
-                        for i in 0..nines_before_eight {
-                            if zeros_after_one + i >= num_u8 - 1 {
-                                continue 'outer
-                            }
-                            result.wrapping_lshr_assign(8usize).unwrap();
-                            assert_eq!(result.clone().resize_to_u8(), u8::MAX);
-                        }
-                        if zeros_after_one + nines_before_eight >= num_u8 - 1 {
-                            continue 'outer
-                        }
-                        result.wrapping_lshr_assign(8usize).unwrap();
-                        assert_eq!(result.clone().resize_to_u8(), u8::MAX - 1);
-                        for i in 0..nines_after_eight {
-                            if 1 + zeros_after_one + nines_before_eight + i >= num_u8 - 1
-                            {
-                                continue 'outer
-                            }
-                            result.wrapping_lshr_assign(8usize).unwrap();
-                            assert_eq!(result.clone().resize_to_u8(), u8::MAX);
-                        }
+                        assert_eq!(x.checked_next_multiple_of(&rhs).unwrap(), expected);
                     }
                 }
             }
-            // test inl apints
+        }
+
+        #[test]
+        fn signed_extremes() {
+            for &width in &[8, 64, 65, 128] {
+                let width = BitWidth::new(width).unwrap();
+                let smin = ApInt::signed_min_value(width);
+                let smax = ApInt::signed_max_value(width);
+                let neg_one = ApInt::all_set(width);
+                let two = ApInt::from(2u8).into_zero_resize(width);
+                assert_eq!(smin.clone().into_sdiv_ceil(&neg_one).unwrap(), smin);
+                assert_eq!(
+                    smax.clone().into_sdiv_ceil(&two).unwrap(),
+                    smax.clone()
+                        .into_wrapping_lshr(1)
+                        .unwrap()
+                        .into_wrapping_inc()
+                );
+                assert_eq!(smax.checked_snext_multiple_of(&two).unwrap(), None);
+                assert_eq!(
+                    smin.checked_snext_multiple_of(&neg_one).unwrap(),
+                    Some(smin.clone())
+                );
+                assert_eq!(
+                    smin.checked_snext_multiple_of(&smax).unwrap(),
+                    Some(smax.clone().into_wrapping_neg())
+                );
+                let neg_two = two.clone().into_wrapping_neg();
+                assert_eq!(
+                    smin.clone()
+                        .into_wrapping_inc()
+                        .checked_snext_multiple_of(&neg_two)
+                        .unwrap(),
+                    Some(smin.clone())
+                );
+                // the next multiple of `-smax` below `smin` is `2 * -smax`
+                assert_eq!(
+                    smin.checked_snext_multiple_of(&smax.clone().into_wrapping_neg())
+                        .unwrap(),
+                    None
+                );
+            }
+        }
+
+        #[test]
+        fn division_by_zero() {
+            let x = ApInt::from(5u32);
+            let zero = ApInt::from(0u32);
+            let ops: [(DivOp, Result<ApInt>); 4] = [
+                (DivOp::UnsignedDivCeil, x.clone().into_div_ceil(&zero)),
+                (DivOp::SignedDivCeil, x.clone().into_sdiv_ceil(&zero)),
+                (
+                    DivOp::UnsignedNextMultipleOf,
+                    x.checked_next_multiple_of(&zero).map(|_| x.clone()),
+                ),
+                (
+                    DivOp::SignedNextMultipleOf,
+                    x.checked_snext_multiple_of(&zero).map(|_| x.clone()),
+                ),
+            ];
+            for (op, result) in ops.iter() {
+                assert_eq!(
+                    result.as_ref().unwrap_err().kind(),
+                    &ErrorKind::DivisionByZero {
+                        op: *op,
+                        lhs: x.clone()
+                    }
+                );
+            }
+            assert!(x.clone().into_div_floor(&zero).is_err());
+        }
+
+        #[test]
+        fn unmatching_widths() {
+            let x = ApInt::from(5u32);
+            let zero = ApInt::from(0u64);
+            let expected = ErrorKind::UnmatchingBitwidth(BitWidth::w32(), BitWidth::w64());
+            assert_eq!(x.clone().into_div_ceil(&zero).unwrap_err().kind(), &expected);
+            assert_eq!(x.clone().into_sdiv_ceil(&zero).unwrap_err().kind(), &expected);
+            assert_eq!(x.checked_next_multiple_of(&zero).unwrap_err().kind(), &expected);
+            assert_eq!(x.checked_snext_multiple_of(&zero).unwrap_err().kind(), &expected);
+        }
+    }
+
+    mod mul_u64 {
+        use super::*;
+        use rand::RngCore;
+
+        #[test]
+        fn primitive() {
+            for &(x, y) in &[
+                (0u64, 0u64),
+                (1, u64::MAX),
+                (u64::MAX, u64::MAX),
+                (0x1234_5678_9abc_def0, 10),
+                (1 << 63, 2),
+            ] {
+                let mut apint = ApInt::from(x);
+                let overflow = apint.carrying_mul_u64_assign(y);
+                let product = u128::from(x) * u128::from(y);
+                assert_eq!(apint, ApInt::from(product as u64));
+                assert_eq!(overflow, (product >> 64) as u64);
+            }
+            // unaligned widths
+            let mut apint = ApInt::from(0xffu8);
+            assert_eq!(apint.carrying_mul_u64_assign(0x1_0001), 0xff00);
+            assert_eq!(apint, ApInt::from(0xffu8));
+        }
+
+        #[test]
+        fn random() {
+            let mut rng = test_rng();
+            for &width in &[7, 64, 100, 128, 1024] {
+                let width = BitWidth::new(width).unwrap();
+                let wider = BitWidth::new(width.to_usize() + 64).unwrap();
+                for _ in 0..100 {
+                    let x = ApInt::random_with_width_using(width, &mut rng);
+                    let y = rng.next_u64() >> (rng.next_u32() % 64);
+                    let mut product = x.clone();
+                    let overflow = product.carrying_mul_u64_assign(y);
+                    let scalar = ApInt::from(y).into_zero_resize(width);
+                    assert_eq!(product, x.clone().into_wrapping_mul(&scalar).unwrap());
+                    let mut wrapping = x.clone();
+                    wrapping.wrapping_mul_u64_assign(y);
+                    assert_eq!(wrapping, product);
+                    let expected = x
+                        .into_zero_extend(wider)
+                        .unwrap()
+                        .into_wrapping_mul(&ApInt::from(y).into_zero_resize(wider))
+                        .unwrap()
+                        .into_wrapping_lshr(width.to_usize())
+                        .unwrap()
+                        .resize_to_u64();
+                    assert_eq!(overflow, expected);
+                }
+            }
+        }
+    }
+
+    mod add_sub_u64 {
+        use super::*;
+        use rand::RngCore;
+
+        #[test]
+        fn ripple() {
+            // the carry ripples across all digits into the unaligned top digit
+            let width = BitWidth::new(200).unwrap();
+            let mut x = ApInt::from([0x7fu64, u64::MAX, u64::MAX, u64::MAX - 4])
+                .into_truncate(width)
+                .unwrap();
+            assert!(!x.overflowing_add_u64_assign(10));
             assert_eq!(
-                ApInt::from(u8::MAX)
-                    .into_wrapping_mul(&ApInt::from(u8::MAX))
-                    .unwrap(),
-                ApInt::from(1u8)
+                x,
+                ApInt::from([0x80u64, 0, 0, 5])
+                    .into_truncate(width)
+                    .unwrap()
             );
-            nine_test(2);
-            nine_test(3);
-            nine_test(4);
-            nine_test(7);
-            nine_test(8);
-            // test ext apints
-            nine_test(9);
-            nine_test(16);
-            // 5 digits wide
-            nine_test(40);
-            nine_test(63);
-            // non overflowing test
-            let resize = [
-                7usize, 8, 9, 15, 16, 17, 31, 32, 33, 63, 64, 65, 127, 128, 129, 137,
-                200, 255, 256, 700, 907, 1024, 2018, 2019,
-            ];
-            let lhs_shl = [
-                0usize, 1, 0, 1, 4, 7, 4, 10, 13, 0, 31, 25, 7, 17, 32, 50, 0, 64, 249,
-                8, 777, 0, 1000, 0,
-            ];
-            let rhs_shl = [
-                0usize, 0, 1, 1, 3, 6, 4, 14, 10, 0, 0, 25, 0, 18, 32, 49, 100, 64, 0,
-                256, 64, 900, 1000, 0,
-            ];
-            for (i, _) in resize.iter().enumerate() {
-                let lhs = ApInt::from(5u8)
-                    .into_zero_resize(BitWidth::new(resize[i]).unwrap())
-                    .into_wrapping_shl(lhs_shl[i])
-                    .unwrap();
-                let rhs = ApInt::from(11u8)
-                    .into_zero_resize(BitWidth::new(resize[i]).unwrap())
-                    .into_wrapping_shl(rhs_shl[i])
-                    .unwrap();
-                let zero =
-                    ApInt::from(0u8).into_zero_resize(BitWidth::new(resize[i]).unwrap());
-                let one =
-                    ApInt::from(1u8).into_zero_resize(BitWidth::new(resize[i]).unwrap());
-                let expected = ApInt::from(55u8)
-                    .into_zero_resize(BitWidth::new(resize[i]).unwrap())
-                    .into_wrapping_shl(rhs_shl[i] + lhs_shl[i])
-                    .unwrap();
-                assert_eq!(lhs.clone().into_wrapping_mul(&zero).unwrap(), zero);
-                assert_eq!(zero.clone().into_wrapping_mul(&rhs).unwrap(), zero);
-                assert_eq!(lhs.clone().into_wrapping_mul(&one).unwrap(), lhs);
-                assert_eq!(one.clone().into_wrapping_mul(&rhs).unwrap(), rhs);
-                assert_eq!(lhs.clone().into_wrapping_mul(&rhs).unwrap(), expected);
+            // and back
+            assert!(!x.overflowing_sub_u64_assign(10));
+            assert_eq!(
+                x,
+                ApInt::from([0x7fu64, u64::MAX, u64::MAX, u64::MAX - 4])
+                    .into_truncate(width)
+                    .unwrap()
+            );
+            // out of the unaligned top digit
+            let mut max = ApInt::unsigned_max_value(width);
+            assert!(max.overflowing_add_u64_assign(1));
+            assert!(max.is_zero());
+            assert!(max.overflowing_sub_u64_assign(1));
+            assert_eq!(max, ApInt::unsigned_max_value(width));
+            let mut x = ApInt::from([0x7fu64, u64::MAX, u64::MAX, u64::MAX])
+                .into_truncate(width)
+                .unwrap();
+            assert!(!x.overflowing_add_u64_assign(1));
+            assert_eq!(
+                x,
+                ApInt::from([0x80u64, 0, 0, 0])
+                    .into_truncate(width)
+                    .unwrap()
+            );
+            // `rhs` not fitting into small widths
+            let mut x = ApInt::from(5u8);
+            assert!(x.overflowing_add_u64_assign(256));
+            assert_eq!(x, ApInt::from(5u8));
+            assert!(x.overflowing_sub_u64_assign(256));
+            assert_eq!(x, ApInt::from(5u8));
+            assert!(!x.overflowing_sub_u64_assign(5));
+            assert!(x.is_zero());
+        }
+
+        #[test]
+        fn random() {
+            let mut rng = test_rng();
+            for &width in &[7, 64, 65, 100, 128, 1024] {
+                let width = BitWidth::new(width).unwrap();
+                for _ in 0..100 {
+                    let x = ApInt::random_with_width_using(width, &mut rng);
+                    let y = rng.next_u64() >> (rng.next_u32() % 64);
+                    let wider = BitWidth::new(width.to_usize().max(64) + 1).unwrap();
+                    let wide_x = x.clone().into_zero_extend(wider).unwrap();
+                    let wide_y = ApInt::from(y).into_zero_resize(wider);
+                    let sum = wide_x.clone().into_wrapping_add(&wide_y).unwrap();
+                    let mut z = x.clone();
+                    let overflow = z.overflowing_add_u64_assign(y);
+                    assert_eq!(z, sum.clone().into_truncate(width).unwrap());
+                    assert_eq!(
+                        overflow,
+                        !sum.into_wrapping_lshr(width.to_usize()).unwrap().is_zero()
+                    );
+                    let mut z = x.clone();
+                    let overflow = z.overflowing_sub_u64_assign(y);
+                    let diff = wide_x.clone().into_wrapping_sub(&wide_y).unwrap();
+                    assert_eq!(z, diff.into_truncate(width).unwrap());
+                    assert_eq!(overflow, wide_x.checked_ult(&wide_y).unwrap());
+                    let mut w = x.clone();
+                    w.wrapping_add_u64_assign(y);
+                    w.wrapping_sub_u64_assign(y);
+                    assert_eq!(w, x);
+                }
             }
+        }
+    }
+
+    mod try_sum {
+        use super::*;
+
+        #[test]
+        fn empty() {
+            let width = BitWidth::new(256).unwrap();
+            let empty: [ApInt; 0] = [];
+            assert_eq!(ApInt::try_sum(width, &empty).unwrap(), ApInt::zero(width));
             assert_eq!(
-                ApInt::from([0, 0, 0, 0, u64::MAX, 0, u64::MAX, u64::MAX])
-                    .into_wrapping_mul(&ApInt::from([
-                        0,
-                        0,
-                        0,
-                        0,
-                        u64::MAX,
-                        u64::MAX,
-                        0,
-                        u64::MAX
-                    ]))
-                    .unwrap(),
-                ApInt::from([u64::MAX, 0, 1, u64::MAX - 3, 1, u64::MAX, u64::MAX, 1])
+                ApInt::try_product(width, &empty).unwrap(),
+                ApInt::one(width)
+            );
+        }
+
+        #[test]
+        fn values() {
+            let width = BitWidth::new(256).unwrap();
+            let max = ApInt::unsigned_max_value(width);
+            let values = vec![max.clone(), ApInt::one(width), max.clone()];
+            assert_eq!(ApInt::try_sum(width, &values).unwrap(), max);
+            assert_eq!(ApInt::try_sum(width, values.clone()).unwrap(), max);
+            assert_eq!(
+                ApInt::try_product(width, &values).unwrap(),
+                ApInt::one(width)
             );
+            let values = vec![ApInt::from(3u8), ApInt::from(3u16)];
+            assert!(ApInt::try_sum(BitWidth::w8(), &values).is_err());
+            assert!(ApInt::try_product(BitWidth::w8(), &values).is_err());
         }
     }
 
-    mod div_rem {
+    mod carry_chain {
         use super::*;
-        use crate::bitwidth::BitWidth;
-        use core::u64;
 
-        // TODO: add division by zero testing after error refactoring is finished
-        // use errors::ErrorKind;
+        /// Concatenates the limbs, starting with the least significant one.
+        fn concat(limbs: &[ApInt]) -> ApInt {
+            let width = limbs[0].width().to_usize();
+            let total = BitWidth::new(width * limbs.len()).unwrap();
+            let mut acc = ApInt::zero(total);
+            for (i, limb) in limbs.iter().enumerate() {
+                let limb = limb
+                    .clone()
+                    .into_zero_extend(total)
+                    .unwrap()
+                    .into_wrapping_shl(i * width)
+                    .unwrap();
+                acc.bitor_assign(&limb).unwrap();
+            }
+            acc
+        }
+
         #[test]
-        fn simple() {
-            /// does all of the simple division tests
-            /// - `$signed`: if the functions are signed divisions or not
-            /// - `$fun_assign`: a division function such as
-            ///   `wrapping_udiv_assign` with that signature
-            /// - `$fun_into`: a division function such as `into_wrapping_udiv`
-            ///   with that signature
-            /// - `$fun`: a division function such as `wrapping_udivrem_assign`
-            ///   with that signature
-            /// - `$r0`: the quotient or remainder or both of 80 by 7, depending
-            ///   on division function type
-            /// - `$r1`, `$r2`, `$r3`: 80 by -7, -80 by 7, -80 by -7. These can
-            ///   be 0 if `$signed` is false.
-            macro_rules! s {
-                (
-                    $signed:expr,
-                    $fun_assign:ident,
-                    $fun_into:ident,
-                    $r0:expr,
-                    $r1:expr,
-                    $r2:expr,
-                    $r3:expr
-                ) => {
-                    // match $fun_assign
-                    // match ApInt::from(123u8).$fun_into(&ApInt::from(0u8)) {
-                    // Err(Error{kind: ErrorKind::DivisionByZero{op: DivOp::$div_op, lhs:
-                    // x}, message: _, annotation: _}) => { assert_eq!(x,ApInt::
-                    // from(123u8)); },
-                    // _ => unreachable!(),
-                    // }
-                    // match ApInt::from(12345678912345689123456789123456789u128).
-                    {
-                        let lhs = ApInt::from(80i8);
-                        let rhs = ApInt::from(7i8);
-                        let mut temp = lhs.clone();
-                        temp.$fun_assign(&rhs).unwrap();
-                        assert_eq!(temp, ApInt::from($r0));
-                        assert_eq!(lhs.$fun_into(&rhs).unwrap(), ApInt::from($r0));
-                    }
-                    if $signed {
-                        {
-                            let lhs = ApInt::from(80i8);
-                            let rhs = ApInt::from(-7i8);
-                            let mut temp = lhs.clone();
-                            temp.$fun_assign(&rhs).unwrap();
-                            assert_eq!(temp, ApInt::from($r1));
-                            assert_eq!(lhs.$fun_into(&rhs).unwrap(), ApInt::from($r1));
-                        }
-                        {
-                            let lhs = ApInt::from(-80i8);
-                            let rhs = ApInt::from(7i8);
-                            let mut temp = lhs.clone();
-                            temp.$fun_assign(&rhs).unwrap();
-                            assert_eq!(temp, ApInt::from($r2));
-                            assert_eq!(lhs.$fun_into(&rhs).unwrap(), ApInt::from($r2));
-                        }
-                        {
-                            let lhs = ApInt::from(-80i8);
-                            let rhs = ApInt::from(-7i8);
-                            let mut temp = lhs.clone();
-                            temp.$fun_assign(&rhs).unwrap();
-                            assert_eq!(temp, ApInt::from($r3));
-                            assert_eq!(lhs.$fun_into(&rhs).unwrap(), ApInt::from($r3));
-                        }
-                    }
-                };
-                ($signed:expr, $fun:ident, $r0:expr, $r1:expr, $r2:expr, $r3:expr) => {{
-                    let mut lhs = ApInt::from(80i8);
-                    let mut rhs = ApInt::from(7i8);
-                    ApInt::$fun(&mut lhs, &mut rhs).unwrap();
-                    assert_eq!(lhs, ApInt::from($r0.0));
-                    assert_eq!(rhs, ApInt::from($r0.1));
-                }
-                if $signed {
-                    {
-                        let mut lhs = ApInt::from(80i8);
-                        let mut rhs = ApInt::from(-7i8);
-                        ApInt::$fun(&mut lhs, &mut rhs).unwrap();
-                        assert_eq!(lhs, ApInt::from($r1.0));
-                        assert_eq!(rhs, ApInt::from($r1.1));
+        fn three_limbs() {
+            let mut rng = test_rng();
+            for &width in &[1, 8, 64, 100, 128, 4096] {
+                let width = BitWidth::new(width).unwrap();
+                for i in 0..20 {
+                    let mut lhs: Vec<ApInt> = (0..3)
+                        .map(|_| ApInt::random_with_width_using(width, &mut rng))
+                        .collect();
+                    let rhs: Vec<ApInt> = (0..3)
+                        .map(|_| ApInt::random_with_width_using(width, &mut rng))
+                        .collect();
+                    if i == 0 {
+                        // force the carry and borrow to ripple through all limbs
+                        lhs = vec![ApInt::all_set(width); 3];
                     }
-                    {
-                        let mut lhs = ApInt::from(-80i8);
-                        let mut rhs = ApInt::from(7i8);
-                        ApInt::$fun(&mut lhs, &mut rhs).unwrap();
-                        assert_eq!(lhs, ApInt::from($r2.0));
-                        assert_eq!(rhs, ApInt::from($r2.1));
+                    let (wide_lhs, wide_rhs) = (concat(&lhs), concat(&rhs));
+                    let mut sum = lhs.clone();
+                    let mut carry = false;
+                    for (l, r) in sum.iter_mut().zip(&rhs) {
+                        carry = l.carrying_add_assign(r, carry).unwrap();
                     }
-                    {
-                        let mut lhs = ApInt::from(-80i8);
-                        let mut rhs = ApInt::from(-7i8);
-                        ApInt::$fun(&mut lhs, &mut rhs).unwrap();
-                        assert_eq!(lhs, ApInt::from($r3.0));
-                        assert_eq!(rhs, ApInt::from($r3.1));
+                    let (expected, overflow) =
+                        wide_lhs.clone().into_overflowing_uadd(&wide_rhs).unwrap();
+                    assert_eq!(concat(&sum), expected);
+                    assert_eq!(carry, overflow);
+                    let mut diff = rhs.clone();
+                    let mut borrow = false;
+                    for (r, l) in diff.iter_mut().zip(&lhs) {
+                        borrow = r.borrowing_sub_assign(l, borrow).unwrap();
                     }
-                }};
+                    let (expected, overflow) =
+                        wide_rhs.into_overflowing_usub(&wide_lhs).unwrap();
+                    assert_eq!(concat(&diff), expected);
+                    assert_eq!(borrow, overflow);
+                }
             }
-            s!(
-                false,
-                wrapping_udiv_assign,
-                into_wrapping_udiv,
-                11i8,
-                0,
-                0,
-                0
-            );
-            s!(
-                false,
-                wrapping_urem_assign,
-                into_wrapping_urem,
-                3i8,
-                0,
-                0,
-                0
-            );
-            s!(
-                true,
-                wrapping_sdiv_assign,
-                into_wrapping_sdiv,
-                11i8,
-                -11i8,
-                -11i8,
-                11i8
-            );
-            s!(
-                true,
-                wrapping_srem_assign,
-                into_wrapping_srem,
-                3i8,
-                3i8,
-                -3i8,
-                -3i8
-            );
-            s!(
-                false,
-                wrapping_udivrem_assign,
-                (11i8, 3i8),
-                (0, 0),
-                (0, 0),
-                (0, 0)
-            );
-            s!(
-                false,
-                wrapping_uremdiv_assign,
-                (3i8, 11i8),
-                (0, 0),
-                (0, 0),
-                (0, 0)
+        }
+
+        #[test]
+        fn incoming_flags() {
+            let mut x = ApInt::from(u8::MAX);
+            assert!(x.carrying_add_assign(&ApInt::from(0u8), true).unwrap());
+            assert!(x.is_zero());
+            assert!(x.borrowing_sub_assign(&ApInt::from(0u8), true).unwrap());
+            assert_eq!(x, ApInt::from(u8::MAX));
+            assert!(!x
+                .borrowing_sub_assign(&ApInt::from(u8::MAX - 1), true)
+                .unwrap());
+            assert!(x.is_zero());
+            assert!(x.carrying_add_assign(&ApInt::from(0u16), false).is_err());
+            assert!(x.borrowing_sub_assign(&ApInt::from(0u16), false).is_err());
+        }
+    }
+
+    mod div_wide {
+        use super::*;
+        use crate::errors::ErrorKind;
+
+        #[test]
+        fn errors() {
+            let (hi, lo, div) = (ApInt::from(3u64), ApInt::from(5u64), ApInt::from(7u64));
+            assert!(ApInt::div_wide(&hi, &ApInt::from(5u32), &div).is_err());
+            assert!(ApInt::div_wide(&hi, &lo, &ApInt::from(7u32)).is_err());
+            assert_eq!(
+                ApInt::div_wide(&hi, &lo, &ApInt::from(0u64))
+                    .unwrap_err()
+                    .kind(),
+                &ErrorKind::DivisionByZero {
+                    op: DivOp::UnsignedDivWide,
+                    lhs: lo.clone()
+                }
             );
-            s!(
-                true,
-                wrapping_sdivrem_assign,
-                (11i8, 3i8),
-                (-11i8, 3i8),
-                (-11i8, -3i8),
-                (11i8, -3i8)
+            assert_eq!(
+                ApInt::div_wide(&div, &lo, &div).unwrap_err().kind(),
+                &ErrorKind::QuotientOverflow
             );
-            s!(
-                true,
-                wrapping_sremdiv_assign,
-                (3i8, 11i8),
-                (3i8, -11i8),
-                (-3i8, -11i8),
-                (-3i8, 11i8)
+            assert_eq!(
+                ApInt::div_wide(&ApInt::from(8u64), &lo, &div)
+                    .unwrap_err()
+                    .kind(),
+                &ErrorKind::QuotientOverflow
             );
         }
 
-        // NOTE: this test only works if multiplication and a few other functions work
         #[test]
-        fn complex() {
-            // there are many special case and size optimization paths,
-            // so this test must be very rigorous.
+        fn primitive() {
+            let (q, r) = ApInt::div_wide(
+                &ApInt::from(6u64),
+                &ApInt::from(u64::MAX),
+                &ApInt::from(7u64),
+            )
+            .unwrap();
+            let duo = (6u128 << 64) | u128::from(u64::MAX);
+            assert_eq!(q, ApInt::from((duo / 7) as u64));
+            assert_eq!(r, ApInt::from((duo % 7) as u64));
+        }
+
+        #[test]
+        fn random() {
+            use rand::RngCore;
+            let mut rng = test_rng();
+            for &width in &[1, 8, 64, 100, 128, 320] {
+                let width = BitWidth::new(width).unwrap();
+                let double_width = BitWidth::new(width.to_usize() * 2).unwrap();
+                let ext = |x: &ApInt| x.clone().into_zero_extend(double_width).unwrap();
+                for i in 0..300 {
+                    // also exercise the fast paths for a zero `hi` and for
+                    // divisors that fit into a single `Digit`
+                    let mut div = ApInt::random_with_width_using(width, &mut rng);
+                    if i % 3 == 1 {
+                        div.wrapping_lshr_assign(width.to_usize() - 1).unwrap();
+                        div.wrapping_add_u64_assign(rng.next_u64() & 0xFF);
+                    }
+                    if div.is_zero() {
+                        div.set_bit_at(0).unwrap();
+                    }
+                    let mut hi = ApInt::random_with_width_using(width, &mut rng)
+                        .into_wrapping_urem(&div)
+                        .unwrap();
+                    if i % 3 == 2 {
+                        hi.unset_all();
+                    }
+                    let lo = ApInt::random_with_width_using(width, &mut rng);
+                    let (q, r) = ApInt::div_wide(&hi, &lo, &div).unwrap();
+                    assert!(r.checked_ult(&div).unwrap());
+                    // `hi:lo == q * div + r`
+                    let mut duo = ext(&hi).into_wrapping_shl(width.to_usize()).unwrap();
+                    duo.bitor_assign(&ext(&lo)).unwrap();
+                    let mut recon = ext(&q).into_wrapping_mul(&ext(&div)).unwrap();
+                    recon.wrapping_add_assign(&ext(&r)).unwrap();
+                    assert_eq!(recon, duo);
+                }
+            }
+        }
+    }
+
+    mod sdiv_overflow {
+        use super::*;
+        use crate::errors::ErrorKind;
+
+        #[test]
+        fn exhaustive_8() {
+            for x in i8::MIN..=i8::MAX {
+                for y in (i8::MIN..=i8::MAX).filter(|&y| y != 0) {
+                    let (x_ap, y_ap) = (ApInt::from(x), ApInt::from(y));
+                    let (quo, overflow) = x.overflowing_div(y);
+                    assert_eq!(
+                        x_ap.clone().into_overflowing_sdiv(&y_ap),
+                        Ok((ApInt::from(quo), overflow))
+                    );
+                    assert_eq!(
+                        x_ap.checked_sdiv(&y_ap),
+                        Ok(x.checked_div(y).map(ApInt::from))
+                    );
+                    let (rem, overflow) = x.overflowing_rem(y);
+                    assert_eq!(
+                        x_ap.clone().into_overflowing_srem(&y_ap),
+                        Ok((ApInt::from(rem), overflow))
+                    );
+                    assert_eq!(
+                        x_ap.checked_srem(&y_ap),
+                        Ok(x.checked_rem(y).map(ApInt::from))
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn boundary() {
+            for &width in &[1, 128] {
+                let width = BitWidth::new(width).unwrap();
+                let min = ApInt::signed_min_value(width);
+                let minus_one = ApInt::all_set(width);
+                let zero = ApInt::zero(width);
+                assert_eq!(min.clone().into_wrapping_sdiv(&minus_one), Ok(min.clone()));
+                assert_eq!(min.clone().into_wrapping_srem(&minus_one), Ok(zero.clone()));
+                assert_eq!(
+                    min.clone().into_overflowing_sdiv(&minus_one),
+                    Ok((min.clone(), true))
+                );
+                assert_eq!(
+                    min.clone().into_overflowing_srem(&minus_one),
+                    Ok((zero.clone(), true))
+                );
+                assert_eq!(min.checked_sdiv(&minus_one), Ok(None));
+                assert_eq!(min.checked_srem(&minus_one), Ok(None));
+                // overflow is reported distinctly from division by zero
+                assert_eq!(
+                    min.checked_sdiv(&zero).unwrap_err().kind(),
+                    &ErrorKind::DivisionByZero {
+                        op: DivOp::SignedDiv,
+                        lhs: min.clone()
+                    }
+                );
+                assert_eq!(
+                    min.checked_srem(&zero).unwrap_err().kind(),
+                    &ErrorKind::DivisionByZero {
+                        op: DivOp::SignedRem,
+                        lhs: min.clone()
+                    }
+                );
+                if width.to_usize() > 1 {
+                    assert_eq!(minus_one.checked_sdiv(&min), Ok(Some(zero.clone())));
+                    assert_eq!(
+                        min.checked_sdiv(&ApInt::one(width)),
+                        Ok(Some(min.clone()))
+                    );
+                    assert_eq!(
+                        min.checked_srem(
+                            &ApInt::one(width).into_wrapping_shl(1).unwrap()
+                        ),
+                        Ok(Some(zero.clone()))
+                    );
+                }
+                assert!(min.checked_sdiv(&ApInt::from(1u8)).is_err());
+            }
+        }
+    }
+
+    mod saturating_mul {
+        use super::*;
+
+        #[test]
+        fn exhaustive_8() {
+            for x in 0..=u8::MAX {
+                for y in 0..=u8::MAX {
+                    let (x_ap, y_ap) = (ApInt::from(x), ApInt::from(y));
+                    assert_eq!(
+                        x_ap.clone().into_saturating_umul(&y_ap),
+                        Ok(ApInt::from(x.saturating_mul(y)))
+                    );
+                    let (x, y) = (x as i8, y as i8);
+                    assert_eq!(
+                        x_ap.into_saturating_smul(&y_ap),
+                        Ok(ApInt::from(x.saturating_mul(y)))
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn wide() {
+            let w = BitWidth::new(100).unwrap();
+            let smin = ApInt::signed_min_value(w);
+            let smax = ApInt::signed_max_value(w);
+            let umax = ApInt::unsigned_max_value(w);
+            let neg = |x: i64| ApInt::from(x).into_sign_extend(w).unwrap();
+            // `-2^49 * -2^50` saturates to the signed maximum
+            let a = neg(-(1 << 49));
+            let b = ApInt::one(w)
+                .into_wrapping_shl(50)
+                .unwrap()
+                .into_wrapping_neg();
+            assert_eq!(a.clone().into_saturating_smul(&b), Ok(smax.clone()));
             assert_eq!(
-                ApInt::from(123u8)
-                    .into_wrapping_udiv(&ApInt::from(7u8))
-                    .unwrap(),
-                ApInt::from(17u8)
+                smin.clone().into_saturating_smul(&neg(-1)),
+                Ok(smax.clone())
             );
             assert_eq!(
-                ApInt::from([0u64, 0, 0, 123])
-                    .into_wrapping_udiv(&ApInt::from([0u64, 0, 0, 7]))
-                    .unwrap(),
-                ApInt::from([0u64, 0, 0, 17])
+                a.clone().into_saturating_smul(&neg(1 << 51)),
+                Ok(smin.clone())
             );
+            // `2^49 * -2^50` is exactly the signed minimum
             assert_eq!(
-                ApInt::from([0u64, 0, 0, 0])
-                    .into_wrapping_udiv(&ApInt::from([0u64, 0, 0, 7]))
-                    .unwrap(),
-                ApInt::from([0u64, 0, 0, 0])
+                a.clone().into_wrapping_neg().into_saturating_smul(&b),
+                Ok(smin.clone())
             );
+            assert_eq!(smin.clone().into_saturating_smul(&neg(1)), Ok(smin.clone()));
             assert_eq!(
-                ApInt::from([0u64, 0, 0, 3])
-                    .into_wrapping_udiv(&ApInt::from([0u64, 0, 0, 7]))
-                    .unwrap(),
-                ApInt::from([0u64, 0, 0, 0])
+                neg(-3).into_saturating_smul(&neg(-5)),
+                Ok(ApInt::from(15u64).into_zero_extend(w).unwrap())
             );
+            // `2^50 * 2^49` fits and `2^50 * 2^50` does not
+            let c = ApInt::one(w).into_wrapping_shl(50).unwrap();
+            let d = ApInt::one(w).into_wrapping_shl(49).unwrap();
             assert_eq!(
-                ApInt::from([0u64, 0, 0, 0])
-                    .into_wrapping_udiv(&ApInt::from([0u64, 7, 0, 0]))
-                    .unwrap(),
-                ApInt::from([0u64, 0, 0, 0])
+                c.clone().into_saturating_umul(&d),
+                Ok(ApInt::one(w).into_wrapping_shl(99).unwrap())
             );
+            assert_eq!(c.clone().into_saturating_umul(&c), Ok(umax.clone()));
             assert_eq!(
-                ApInt::from([0u64, 0, 0, 7])
-                    .into_wrapping_udiv(&ApInt::from([0u64, 4, 0, 0]))
-                    .unwrap(),
-                ApInt::from([0u64, 0, 0, 0])
+                umax.clone().into_saturating_umul(&ApInt::one(w)),
+                Ok(umax.clone())
             );
+            assert_eq!(umax.clone().into_saturating_umul(&umax), Ok(umax.clone()));
             assert_eq!(
-                ApInt::from([0u64, 0, 3, 0])
-                    .into_wrapping_udiv(&ApInt::from([0u64, 4, 0, 0]))
-                    .unwrap(),
-                ApInt::from([0u64, 0, 0, 0])
+                umax.clone().into_saturating_umul(&ApInt::zero(w)),
+                Ok(ApInt::zero(w))
             );
+            assert!(umax
+                .clone()
+                .into_saturating_umul(&ApInt::from(1u8))
+                .is_err());
+            assert!(umax.into_saturating_smul(&ApInt::from(1u8)).is_err());
+        }
+    }
+
+    mod combinatorics {
+        use super::*;
+        use crate::errors::ErrorKind;
+
+        #[test]
+        fn factorial() {
+            let w64 = BitWidth::w64();
+            assert_eq!(ApInt::factorial(w64, 0).unwrap(), ApInt::from(1u64));
+            assert_eq!(ApInt::factorial(w64, 1).unwrap(), ApInt::from(1u64));
             assert_eq!(
-                ApInt::from([0u64, 1, 0, 0])
-                    .into_wrapping_udiv(&ApInt::from([0u64, 0, 0, 4]))
-                    .unwrap(),
-                ApInt::from([0u64, 0, u64::MAX / 4 + 1, 0])
+                ApInt::factorial(w64, 20).unwrap(),
+                ApInt::from(2_432_902_008_176_640_000u64)
             );
             assert_eq!(
-                // this one
-                ApInt::from([0u64, 1, 0, 0])
-                    .into_wrapping_udiv(&ApInt::from([0u64, 0, 1, 0]))
-                    .unwrap(),
-                ApInt::from([0u64, 0, 1, 0])
+                ApInt::factorial(w64, 21).unwrap_err().kind(),
+                &ErrorKind::ResultOverflow(w64)
             );
+            assert!(ApInt::factorial(BitWidth::new(65).unwrap(), 21).is_err());
+            assert!(ApInt::factorial(BitWidth::new(66).unwrap(), 21).is_ok());
+            // `100!` has a bit length of 525
+            let x = ApInt::factorial(BitWidth::new(525).unwrap(), 100).unwrap();
+            assert!(x.msb());
+            assert_eq!(x.trailing_zeros(), 97);
+            assert!(ApInt::factorial(BitWidth::new(524).unwrap(), 100).is_err());
+        }
+
+        #[test]
+        fn binomial() {
+            let w8 = BitWidth::w8();
+            assert_eq!(ApInt::binomial(w8, 5, 6).unwrap(), ApInt::from(0u8));
+            assert_eq!(ApInt::binomial(w8, 5, 0).unwrap(), ApInt::from(1u8));
+            assert_eq!(ApInt::binomial(w8, 5, 5).unwrap(), ApInt::from(1u8));
+            assert_eq!(ApInt::binomial(w8, 5, 2).unwrap(), ApInt::from(10u8));
+            assert_eq!(ApInt::binomial(w8, 10, 5).unwrap(), ApInt::from(252u8));
             assert_eq!(
-                ApInt::from([1u64, 2, 3, 4])
-                    .into_wrapping_udiv(&ApInt::from([1u64, 2, 3, 4]))
-                    .unwrap(),
-                ApInt::from([0u64, 0, 0, 1])
+                ApInt::binomial(w8, 12, 4).unwrap_err().kind(),
+                &ErrorKind::ResultOverflow(w8)
             );
+            // Pascal's rule
+            let w = BitWidth::new(100).unwrap();
+            for n in 1..60 {
+                for k in 1..n {
+                    assert_eq!(
+                        ApInt::binomial(w, n, k).unwrap(),
+                        ApInt::binomial(w, n - 1, k - 1)
+                            .unwrap()
+                            .into_wrapping_add(&ApInt::binomial(w, n - 1, k).unwrap())
+                            .unwrap()
+                    );
+                }
+            }
             assert_eq!(
-                ApInt::from([
-                    0u64,
-                    1,
-                    u64::MAX,
-                    u64::MAX,
-                    u64::MAX,
-                    u64::MAX,
-                    u64::MAX,
-                    u64::MAX
-                ])
-                .into_wrapping_udiv(&ApInt::from([0u64, 0, 0, 0, 0, 0, 0, 2]))
-                .unwrap(),
-                ApInt::from([
-                    0u64,
-                    0,
-                    u64::MAX,
-                    u64::MAX,
-                    u64::MAX,
-                    u64::MAX,
-                    u64::MAX,
-                    u64::MAX
-                ])
+                ApInt::binomial(BitWidth::w64(), 67, 33).unwrap(),
+                ApInt::from(0xc56e_c13c_4b95_e372u64)
             );
+            assert!(ApInt::binomial(BitWidth::new(63).unwrap(), 67, 34).is_err());
+            // `C(200, 100)` has a bit length of 196
+            let w196 = BitWidth::new(196).unwrap();
             assert_eq!(
+                ApInt::binomial(w196, 200, 100).unwrap(),
                 ApInt::from([
-                    u64::MAX,
-                    u64::MAX - 1,
-                    1,
-                    u64::MAX - 1,
-                    u64::MAX - 1,
-                    2,
-                    u64::MAX - 1,
-                    1
+                    0xe_u64,
+                    0x6cda_9a86_2b57_0591,
+                    0x145b_adcc_1f49_f11a,
+                    0x32fd_c379_06d9_5c68
                 ])
-                .into_wrapping_udiv(&ApInt::from([
-                    0,
-                    0,
-                    0,
-                    0,
-                    u64::MAX,
-                    u64::MAX,
-                    0,
-                    u64::MAX
-                ]))
-                .unwrap(),
-                ApInt::from([0, 0, 0, 0, u64::MAX, u64::MAX, 0, u64::MAX])
+                .into_truncate(w196)
+                .unwrap()
             );
+            assert!(ApInt::binomial(BitWidth::new(195).unwrap(), 200, 100).is_err());
+            // at the exact bit width of the result the products before each
+            // division exceed it by more bits than a small `Digit` has
+            let wide = BitWidth::new(8600).unwrap();
+            let half = ApInt::factorial(wide, 500).unwrap();
+            let expected = ApInt::factorial(wide, 1000)
+                .unwrap()
+                .into_wrapping_udiv(&half.clone().into_wrapping_mul(&half).unwrap())
+                .unwrap();
+            let width = BitWidth::new(expected.required_bits(false)).unwrap();
             assert_eq!(
-                ApInt::from(61924494876344321u128)
-                    .into_wrapping_urem(&ApInt::from(167772160u128))
-                    .unwrap(),
-                ApInt::from(1u128)
+                ApInt::binomial(width, 1000, 500).unwrap(),
+                expected.into_truncate(width).unwrap()
             );
+            let narrow = BitWidth::new(width.to_usize() - 1).unwrap();
+            assert!(ApInt::binomial(narrow, 1000, 500).is_err());
+        }
+    }
+
+    mod div_rem_u64 {
+        use super::*;
+        use crate::errors::ErrorKind;
+
+        #[test]
+        fn zero_divisor() {
+            let mut x = ApInt::from(5u32);
             assert_eq!(
-                ApInt::from([
-                    18446744073709551615u64,
-                    18446744073709551615,
-                    1048575,
-                    18446462598732840960
-                ])
-                .into_wrapping_urem(&ApInt::from([0u64, 0, 140668768878592, 0]))
-                .unwrap(),
-                ApInt::from([0, 0, 136545601323007, 18446462598732840960u64])
+                x.rem_u64(0).unwrap_err().kind(),
+                &ErrorKind::DivisionByZero {
+                    op: DivOp::UnsignedRem,
+                    lhs: ApInt::from(5u32)
+                }
             );
             assert_eq!(
-                ApInt::from([1u64, 17293821508111564796, 2305843009213693952])
-                    .into_wrapping_urem(&ApInt::from([0u64, 1, 18446742978492891132]))
-                    .unwrap(),
-                ApInt::from([0u64, 0, 0])
+                x.div_rem_u64_assign(0).unwrap_err().kind(),
+                &ErrorKind::DivisionByZero {
+                    op: DivOp::UnsignedDivRem,
+                    lhs: ApInt::from(5u32)
+                }
+            );
+            assert_eq!(x, ApInt::from(5u32));
+        }
+
+        #[test]
+        fn random() {
+            let mut rng = test_rng();
+            for &width in &[7, 64, 65, 128, 200, 1024] {
+                let width = BitWidth::new(width).unwrap();
+                let wider = BitWidth::new(width.to_usize().max(64)).unwrap();
+                for _ in 0..50 {
+                    let x = ApInt::random_with_width_using(width, &mut rng);
+                    for &divisor in &[1, 7, 10, 1 << 63, u64::MAX] {
+                        let wide_div = ApInt::from(divisor).into_zero_resize(wider);
+                        let (quo, rem) = x
+                            .clone()
+                            .into_zero_resize(wider)
+                            .into_wrapping_udivrem(&wide_div)
+                            .unwrap();
+                        assert_eq!(x.rem_u64(divisor).unwrap(), rem.resize_to_u64());
+                        let mut y = x.clone();
+                        assert_eq!(
+                            y.div_rem_u64_assign(divisor).unwrap(),
+                            rem.resize_to_u64()
+                        );
+                        assert_eq!(y, quo.into_zero_resize(width));
+                    }
+                }
+            }
+        }
+    }
+
+    mod overflowing_uadd {
+        use super::*;
+        use crate::bitwidth::BitWidth;
+
+        #[test]
+        fn small() {
+            for x in 0..=u8::MAX {
+                for y in (0..=u8::MAX).step_by(7) {
+                    let (result, overflow) = ApInt::from(x)
+                        .into_overflowing_uadd(&ApInt::from(y))
+                        .unwrap();
+                    let (expected, expected_overflow) = x.overflowing_add(y);
+                    assert_eq!(result, ApInt::from(expected));
+                    assert_eq!(overflow, expected_overflow);
+                }
+            }
+        }
+
+        #[test]
+        fn unaligned() {
+            let width = BitWidth::new(100).unwrap();
+            let max = ApInt::all_set(width);
+            let one = ApInt::one(width);
+            assert_eq!(
+                max.clone().into_overflowing_uadd(&one),
+                Ok((ApInt::zero(width), true))
             );
             assert_eq!(
-                ApInt::from([1u64, 18446744073692774368, 268435456])
-                    .into_wrapping_add(&ApInt::from([0u64, 1, 18446744073709519359]))
-                    .unwrap()
-                    .into_wrapping_udiv(&ApInt::from([0u64, 1, 18446744073709551584]))
-                    .unwrap(),
-                ApInt::from([0u64, 0, 18446744073701163008])
+                max.clone().into_overflowing_uadd(&ApInt::zero(width)),
+                Ok((max.clone(), false))
+            );
+            let min = ApInt::signed_min_value(width);
+            assert_eq!(
+                min.clone().into_overflowing_uadd(&min),
+                Ok((ApInt::zero(width), true))
+            );
+            let below = min.clone().into_wrapping_dec();
+            assert_eq!(below.into_overflowing_uadd(&min), Ok((max, false)));
+        }
+
+        #[test]
+        fn random() {
+            let mut rng = test_rng();
+            for &width in &[1, 7, 63, 64, 65, 100, 128, 200] {
+                let width = BitWidth::new(width).unwrap();
+                let wide = BitWidth::new(width.to_usize() + 1).unwrap();
+                for _ in 0..32 {
+                    let lhs = ApInt::random_with_width_using(width, &mut rng);
+                    let rhs = ApInt::random_with_width_using(width, &mut rng);
+                    let (sum, overflow) =
+                        lhs.clone().into_overflowing_uadd(&rhs).unwrap();
+                    let wide_sum = lhs
+                        .clone()
+                        .into_zero_extend(wide)
+                        .unwrap()
+                        .into_wrapping_add(&rhs.clone().into_zero_extend(wide).unwrap())
+                        .unwrap();
+                    assert_eq!(overflow, wide_sum.msb());
+                    assert_eq!(sum, wide_sum.into_truncate(width).unwrap());
+                }
+            }
+        }
+
+        #[test]
+        fn errors() {
+            assert!(ApInt::from(1_u8)
+                .into_overflowing_uadd(&ApInt::from(1_u16))
+                .is_err());
+        }
+    }
+
+    mod overflowing_signed {
+        use super::*;
+        use crate::bitwidth::BitWidth;
+
+        #[test]
+        fn small() {
+            for x in i8::MIN..=i8::MAX {
+                for y in (i8::MIN..=i8::MAX).step_by(5) {
+                    let (x_ap, y_ap) = (ApInt::from(x), ApInt::from(y));
+                    let (sum, overflow) = x.overflowing_add(y);
+                    assert_eq!(
+                        x_ap.clone().into_overflowing_sadd(&y_ap),
+                        Ok((ApInt::from(sum), overflow))
+                    );
+                    let (diff, overflow) = x.overflowing_sub(y);
+                    assert_eq!(
+                        x_ap.into_overflowing_ssub(&y_ap),
+                        Ok((ApInt::from(diff), overflow))
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn width_1() {
+            let zero = ApInt::zero(BitWidth::w1());
+            let minus_one = ApInt::all_set(BitWidth::w1());
+            let sadd = |a: &ApInt, b: &ApInt| a.clone().into_overflowing_sadd(b).unwrap();
+            let ssub = |a: &ApInt, b: &ApInt| a.clone().into_overflowing_ssub(b).unwrap();
+            assert_eq!(sadd(&zero, &zero), (zero.clone(), false));
+            assert_eq!(sadd(&zero, &minus_one), (minus_one.clone(), false));
+            assert_eq!(sadd(&minus_one, &minus_one), (zero.clone(), true));
+            assert_eq!(ssub(&zero, &zero), (zero.clone(), false));
+            assert_eq!(ssub(&minus_one, &zero), (minus_one.clone(), false));
+            assert_eq!(ssub(&minus_one, &minus_one), (zero.clone(), false));
+            assert_eq!(ssub(&zero, &minus_one), (minus_one, true));
+        }
+
+        #[test]
+        fn boundaries() {
+            for &width in &[8, 64, 100] {
+                let width = BitWidth::new(width).unwrap();
+                let min = ApInt::signed_min_value(width);
+                let max = ApInt::signed_max_value(width);
+                let zero = ApInt::zero(width);
+                let one = ApInt::one(width);
+                let minus_one = ApInt::all_set(width);
+                let sadd =
+                    |a: &ApInt, b: &ApInt| a.clone().into_overflowing_sadd(b).unwrap();
+                let ssub =
+                    |a: &ApInt, b: &ApInt| a.clone().into_overflowing_ssub(b).unwrap();
+                assert_eq!(sadd(&max, &one), (min.clone(), true));
+                assert_eq!(sadd(&max, &zero), (max.clone(), false));
+                assert_eq!(sadd(&min, &minus_one), (max.clone(), true));
+                assert_eq!(sadd(&min, &max), (minus_one.clone(), false));
+                assert_eq!(sadd(&min, &min), (zero.clone(), true));
+                assert_eq!(ssub(&min, &one), (max.clone(), true));
+                assert_eq!(ssub(&max, &minus_one), (min.clone(), true));
+                assert_eq!(ssub(&zero, &min), (min.clone(), true));
+                assert_eq!(ssub(&minus_one, &min), (max.clone(), false));
+                assert_eq!(ssub(&max, &max), (zero.clone(), false));
+                assert_eq!(ssub(&min, &min), (zero, false));
+            }
+        }
+
+        #[test]
+        fn errors() {
+            let (a, b) = (ApInt::from(1_u8), ApInt::from(1_u16));
+            assert!(a.clone().into_overflowing_sadd(&b).is_err());
+            assert!(a.into_overflowing_ssub(&b).is_err());
+        }
+    }
+
+    mod overflowing_mul {
+        use super::*;
+        use crate::bitwidth::BitWidth;
+
+        #[test]
+        fn exhaustive_8() {
+            for x in 0..=u8::MAX {
+                for y in 0..=u8::MAX {
+                    let (x_ap, y_ap) = (ApInt::from(x), ApInt::from(y));
+                    let (product, overflow) = x.overflowing_mul(y);
+                    assert_eq!(
+                        x_ap.clone().into_overflowing_umul(&y_ap),
+                        Ok((ApInt::from(product), overflow))
+                    );
+                    let (x, y) = (x as i8, y as i8);
+                    let (product, overflow) = x.overflowing_mul(y);
+                    assert_eq!(
+                        x_ap.into_overflowing_smul(&y_ap),
+                        Ok((ApInt::from(product), overflow))
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn boundaries_64() {
+            let values = [
+                0,
+                1,
+                2,
+                3,
+                u64::from(u32::MAX),
+                1 << 32,
+                (1 << 32) + 1,
+                i64::MAX as u64,
+                i64::MIN as u64,
+                i64::MIN as u64 + 1,
+                u64::MAX - 1,
+                u64::MAX,
+            ];
+            for &x in &values {
+                for &y in &values {
+                    let (x_ap, y_ap) = (ApInt::from(x), ApInt::from(y));
+                    let (product, overflow) = x.overflowing_mul(y);
+                    assert_eq!(
+                        x_ap.clone().into_overflowing_umul(&y_ap),
+                        Ok((ApInt::from(product), overflow))
+                    );
+                    let (x, y) = (x as i64, y as i64);
+                    let (product, overflow) = x.overflowing_mul(y);
+                    assert_eq!(
+                        x_ap.into_overflowing_smul(&y_ap),
+                        Ok((ApInt::from(product), overflow))
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn boundaries_128() {
+            let values = [
+                0,
+                1,
+                2,
+                3,
+                u128::from(u64::MAX),
+                1 << 64,
+                (1 << 64) + 1,
+                1 << 63,
+                (1 << 65) - 1,
+                i128::MAX as u128,
+                i128::MIN as u128,
+                i128::MIN as u128 + 1,
+                u128::MAX - 1,
+                u128::MAX,
+            ];
+            for &x in &values {
+                for &y in &values {
+                    let (x_ap, y_ap) = (ApInt::from(x), ApInt::from(y));
+                    let (product, overflow) = x.overflowing_mul(y);
+                    assert_eq!(
+                        x_ap.clone().into_overflowing_umul(&y_ap),
+                        Ok((ApInt::from(product), overflow))
+                    );
+                    let (x, y) = (x as i128, y as i128);
+                    let (product, overflow) = x.overflowing_mul(y);
+                    assert_eq!(
+                        x_ap.into_overflowing_smul(&y_ap),
+                        Ok((ApInt::from(product), overflow))
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn min_times_minus_one() {
+            for &width in &[1, 8, 63, 64, 65, 128, 200] {
+                let width = BitWidth::new(width).unwrap();
+                let min = ApInt::signed_min_value(width);
+                let minus_one = ApInt::all_set(width);
+                assert_eq!(
+                    min.clone().into_overflowing_smul(&minus_one),
+                    Ok((min.clone(), true))
+                );
+                assert_eq!(
+                    minus_one.into_overflowing_smul(&min),
+                    Ok((min.clone(), true))
+                );
+            }
+        }
+
+        #[test]
+        fn random() {
+            let mut rng = test_rng();
+            for &width in &[1, 7, 63, 64, 65, 100, 128, 200] {
+                let width = BitWidth::new(width).unwrap();
+                let wide = BitWidth::new(width.to_usize() * 2).unwrap();
+                for i in 0..32 {
+                    let lhs = ApInt::random_with_width_using(width, &mut rng);
+                    // also cover products with a bit count close to the width
+                    let shift = i * width.to_usize() / 32;
+                    let rhs = ApInt::random_with_width_using(width, &mut rng)
+                        .into_wrapping_lshr(shift)
+                        .unwrap();
+                    let (product, overflow) =
+                        lhs.clone().into_overflowing_umul(&rhs).unwrap();
+                    let wide_product = lhs
+                        .clone()
+                        .into_zero_extend(wide)
+                        .unwrap()
+                        .into_wrapping_mul(&rhs.clone().into_zero_extend(wide).unwrap())
+                        .unwrap();
+                    let truncated = wide_product.clone().into_truncate(width).unwrap();
+                    let bits = wide.to_usize() - wide_product.leading_zeros();
+                    assert_eq!(overflow, bits > width.to_usize());
+                    assert_eq!(product, truncated);
+                    let (product, overflow) =
+                        lhs.clone().into_overflowing_smul(&rhs).unwrap();
+                    let wide_product = lhs
+                        .into_sign_extend(wide)
+                        .unwrap()
+                        .into_wrapping_mul(&rhs.into_sign_extend(wide).unwrap())
+                        .unwrap();
+                    let truncated = wide_product.clone().into_truncate(width).unwrap();
+                    let roundtrip = truncated.clone().into_sign_extend(wide).unwrap();
+                    assert_eq!(overflow, roundtrip != wide_product);
+                    assert_eq!(product, truncated);
+                }
+            }
+        }
+
+        #[test]
+        fn errors() {
+            let (a, b) = (ApInt::from(1_u8), ApInt::from(1_u16));
+            assert!(a.clone().into_overflowing_umul(&b).is_err());
+            assert!(a.into_overflowing_smul(&b).is_err());
+        }
+    }
+
+    mod overflow_checked {
+        use super::*;
+        use crate::bitwidth::BitWidth;
+
+        #[test]
+        fn small() {
+            for x in (i8::MIN..=i8::MAX).step_by(3) {
+                for y in i8::MIN..=i8::MAX {
+                    let (x_ap, y_ap) = (ApInt::from(x), ApInt::from(y));
+                    assert_eq!(
+                        x_ap.clone().into_overflow_checked_sadd(&y_ap),
+                        Ok(x.checked_add(y).map(ApInt::from))
+                    );
+                    assert_eq!(
+                        x_ap.clone().into_overflow_checked_ssub(&y_ap),
+                        Ok(x.checked_sub(y).map(ApInt::from))
+                    );
+                    let (x, y) = (x as u8, y as u8);
+                    assert_eq!(
+                        x_ap.clone().into_overflow_checked_uadd(&y_ap),
+                        Ok(x.checked_add(y).map(ApInt::from))
+                    );
+                    assert_eq!(
+                        x_ap.into_overflow_checked_usub(&y_ap),
+                        Ok(x.checked_sub(y).map(ApInt::from))
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn mul() {
+            for x in i8::MIN..=i8::MAX {
+                for y in i8::MIN..=i8::MAX {
+                    let (x_ap, y_ap) = (ApInt::from(x), ApInt::from(y));
+                    assert_eq!(
+                        x_ap.clone().into_overflow_checked_smul(&y_ap),
+                        Ok(x.checked_mul(y).map(ApInt::from))
+                    );
+                    let (x, y) = (x as u8, y as u8);
+                    assert_eq!(
+                        x_ap.into_overflow_checked_umul(&y_ap),
+                        Ok(x.checked_mul(y).map(ApInt::from))
+                    );
+                }
+            }
+            let width = BitWidth::new(100).unwrap();
+            let min = ApInt::signed_min_value(width);
+            let minus_one = ApInt::all_set(width);
+            let two = ApInt::from(2_u8).into_zero_resize(width);
+            let half_min = min.clone().into_wrapping_ashr(1).unwrap();
+            assert_eq!(
+                half_min.clone().into_overflow_checked_smul(&two),
+                Ok(Some(min.clone()))
             );
+            assert_eq!(min.clone().into_overflow_checked_smul(&minus_one), Ok(None));
+            assert_eq!(min.clone().into_overflow_checked_umul(&two), Ok(None));
+            // `half_min` has its two most significant bits set
+            assert_eq!(half_min.into_overflow_checked_umul(&two), Ok(None));
+            assert!(min
+                .into_overflow_checked_smul(&ApInt::one(BitWidth::w64()))
+                .is_err());
+        }
+
+        #[test]
+        fn large() {
+            let width = BitWidth::new(100).unwrap();
+            let umax = ApInt::unsigned_max_value(width);
+            let min = ApInt::signed_min_value(width);
+            let max = ApInt::signed_max_value(width);
+            let zero = ApInt::zero(width);
+            let one = ApInt::one(width);
+            assert_eq!(umax.clone().into_overflow_checked_uadd(&one), Ok(None));
             assert_eq!(
-                ApInt::from([
-                    18446744073709551615u64,
-                    18446744073709551615,
-                    18446739675663040512,
-                    2199023255552
-                ])
-                .into_wrapping_urem(&ApInt::from([
-                    18446744073709551615u64,
-                    18446744073709551615,
-                    18446739675663040512,
-                    2199023255552
-                ]))
-                .unwrap(),
-                ApInt::from([0u64, 0, 0, 0])
+                max.clone().into_overflow_checked_uadd(&one),
+                Ok(Some(min.clone()))
             );
+            assert_eq!(zero.clone().into_overflow_checked_usub(&one), Ok(None));
             assert_eq!(
-                ApInt::from([1u64, 18446462598730776592, 1047972020113])
-                    .into_wrapping_udiv(&ApInt::from([0u64, 16383, 18446744056529682433]))
-                    .unwrap(),
-                ApInt::from([0u64, 0, 2251782633816065])
+                umax.clone().into_overflow_checked_usub(&one),
+                Ok(Some(umax.into_wrapping_dec()))
             );
+            assert_eq!(max.clone().into_overflow_checked_sadd(&one), Ok(None));
+            assert_eq!(min.clone().into_overflow_checked_ssub(&one), Ok(None));
             assert_eq!(
-                ApInt::from([
-                    54467619767447688u64,
-                    18446739675392512496,
-                    5200531536562092095,
-                    18446744073709551615
-                ])
-                .into_wrapping_udiv(&ApInt::from([0u64, 8255, 18446462598732840960, 0]))
-                .unwrap(),
-                ApInt::from([0u64, 0, 6597337677824, 288230376151678976])
+                min.clone().into_overflow_checked_sadd(&max),
+                Ok(Some(ApInt::all_set(width)))
             );
-            let resize = [
-                7usize, 8, 9, 15, 16, 17, 31, 32, 33, 63, 64, 65, 127, 128, 129, 137,
-                200, 255, 256, 700, 907, 1024, 2018, 2019,
-            ];
-            let lhs_shl = [
-                0usize, 1, 0, 1, 4, 6, 4, 10, 13, 0, 31, 25, 7, 17, 32, 50, 0, 64, 249,
-                8, 777, 0, 900, 0,
-            ];
-            let rhs_shl = [
-                0usize, 0, 1, 1, 3, 5, 4, 14, 10, 0, 0, 25, 0, 18, 32, 49, 100, 64, 0,
-                256, 64, 900, 1000, 0,
-            ];
-            for (i, _) in resize.iter().enumerate() {
-                let lhs = ApInt::from(5u8)
-                    .into_zero_resize(BitWidth::new(resize[i]).unwrap())
-                    .into_wrapping_shl(lhs_shl[i])
-                    .unwrap();
-                let rhs = ApInt::from(11u8)
-                    .into_zero_resize(BitWidth::new(resize[i]).unwrap())
-                    .into_wrapping_shl(rhs_shl[i])
-                    .unwrap();
-                let zero =
-                    ApInt::from(0u8).into_zero_resize(BitWidth::new(resize[i]).unwrap());
-                let one =
-                    ApInt::from(1u8).into_zero_resize(BitWidth::new(resize[i]).unwrap());
-                let product = lhs.clone().into_wrapping_mul(&rhs).unwrap();
-                assert_eq!(zero.clone().into_wrapping_udiv(&lhs).unwrap(), zero);
-                assert_eq!(zero.clone().into_wrapping_udiv(&rhs).unwrap(), zero);
-                assert_eq!(lhs.clone().into_wrapping_udiv(&one).unwrap(), lhs);
-                assert_eq!(rhs.clone().into_wrapping_udiv(&one).unwrap(), rhs);
-                assert_eq!(lhs.clone().into_wrapping_udiv(&lhs).unwrap(), one);
-                assert_eq!(rhs.clone().into_wrapping_udiv(&rhs).unwrap(), one);
-                let temp = product.clone().into_wrapping_udiv(&lhs).unwrap();
-                if temp != rhs {
-                    panic!(
-                        "lhs_shl:{:?}\nrhs_shl:{:?}\nlhs:{:?}\nrhs:{:?}\n={:?}\ntemp:{:?\
-                         }",
-                        lhs_shl[i], rhs_shl[i], lhs, rhs, product, temp
+            assert_eq!(zero.into_overflow_checked_ssub(&min), Ok(None));
+            assert!(one
+                .into_overflow_checked_uadd(&ApInt::one(BitWidth::w64()))
+                .is_err());
+        }
+    }
+
+    mod saturating {
+        use super::*;
+        use crate::bitwidth::BitWidth;
+
+        #[test]
+        fn small() {
+            for x in (i8::MIN..=i8::MAX).step_by(3) {
+                for y in i8::MIN..=i8::MAX {
+                    let (x_ap, y_ap) = (ApInt::from(x), ApInt::from(y));
+                    assert_eq!(
+                        x_ap.clone().into_saturating_sadd(&y_ap),
+                        Ok(ApInt::from(x.saturating_add(y)))
+                    );
+                    assert_eq!(
+                        x_ap.clone().into_saturating_ssub(&y_ap),
+                        Ok(ApInt::from(x.saturating_sub(y)))
+                    );
+                    let (x, y) = (x as u8, y as u8);
+                    assert_eq!(
+                        x_ap.clone().into_saturating_uadd(&y_ap),
+                        Ok(ApInt::from(x.saturating_add(y)))
+                    );
+                    assert_eq!(
+                        x_ap.into_saturating_usub(&y_ap),
+                        Ok(ApInt::from(x.saturating_sub(y)))
                     );
                 }
-                assert_eq!(product.clone().into_wrapping_udiv(&rhs).unwrap(), lhs);
-                assert_eq!(zero.clone().into_wrapping_urem(&lhs).unwrap(), zero);
-                assert_eq!(zero.clone().into_wrapping_urem(&rhs).unwrap(), zero);
-                assert_eq!(lhs.clone().into_wrapping_urem(&one).unwrap(), zero);
-                assert_eq!(rhs.clone().into_wrapping_urem(&one).unwrap(), zero);
-                assert_eq!(lhs.clone().into_wrapping_urem(&lhs).unwrap(), zero);
-                assert_eq!(rhs.clone().into_wrapping_urem(&rhs).unwrap(), zero);
-                assert_eq!(product.clone().into_wrapping_urem(&lhs).unwrap(), zero);
-                assert_eq!(product.clone().into_wrapping_urem(&rhs).unwrap(), zero);
+            }
+        }
+
+        #[test]
+        fn width_1() {
+            let zero = ApInt::zero(BitWidth::w1());
+            let minus_one = ApInt::all_set(BitWidth::w1());
+            let sadd = |a: &ApInt, b: &ApInt| a.clone().into_saturating_sadd(b).unwrap();
+            let ssub = |a: &ApInt, b: &ApInt| a.clone().into_saturating_ssub(b).unwrap();
+            assert_eq!(sadd(&minus_one, &minus_one), minus_one);
+            assert_eq!(sadd(&zero, &minus_one), minus_one);
+            assert_eq!(ssub(&zero, &minus_one), zero);
+            assert_eq!(ssub(&minus_one, &zero), minus_one);
+            assert_eq!(ssub(&minus_one, &minus_one), zero);
+        }
+
+        #[test]
+        fn boundaries() {
+            for &width in &[64, 100, 128, 200] {
+                let width = BitWidth::new(width).unwrap();
+                let umax = ApInt::unsigned_max_value(width);
+                let min = ApInt::signed_min_value(width);
+                let max = ApInt::signed_max_value(width);
+                let zero = ApInt::zero(width);
+                let one = ApInt::one(width);
+                let minus_one = ApInt::all_set(width);
+                assert_eq!(umax.clone().into_saturating_uadd(&one), Ok(umax.clone()));
+                assert_eq!(max.clone().into_saturating_uadd(&one), Ok(min.clone()));
+                assert_eq!(zero.clone().into_saturating_usub(&one), Ok(zero.clone()));
+                assert_eq!(umax.clone().into_saturating_usub(&umax), Ok(zero.clone()));
+                assert_eq!(max.clone().into_saturating_sadd(&one), Ok(max.clone()));
+                assert_eq!(min.clone().into_saturating_sadd(&min), Ok(min.clone()));
                 assert_eq!(
-                    product
+                    min.clone().into_saturating_sadd(&max),
+                    Ok(minus_one.clone())
+                );
+                assert_eq!(min.clone().into_saturating_ssub(&one), Ok(min.clone()));
+                assert_eq!(zero.clone().into_saturating_ssub(&min), Ok(max.clone()));
+                assert_eq!(
+                    minus_one.clone().into_saturating_ssub(&min),
+                    Ok(max.clone())
+                );
+                assert_eq!(min.clone().into_saturating_ssub(&min), Ok(zero));
+            }
+        }
+    }
+
+    mod mul_add {
+        use super::*;
+        use crate::bitwidth::BitWidth;
+        use rand::RngCore;
+
+        #[test]
+        fn small() {
+            for acc in (0..=u8::MAX).step_by(7) {
+                for a in (0..=u8::MAX).step_by(3) {
+                    for b in (0..=u8::MAX).step_by(5) {
+                        let expected = u16::from(acc) + u16::from(a) * u16::from(b);
+                        assert_eq!(
+                            ApInt::from(acc)
+                                .into_mul_add(&ApInt::from(a), &ApInt::from(b)),
+                            Ok((ApInt::from(expected as u8), expected > 0xFF))
+                        );
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn fuzz() {
+            let mut rng = test_rng();
+            for &width in &[1, 63, 64, 65, 100, 128, 192, 256, 1000] {
+                let width = BitWidth::new(width).unwrap();
+                let double = BitWidth::new(width.to_usize() * 2 + 1).unwrap();
+                for _ in 0..100 {
+                    let mut acc = ApInt::random_with_width_using(width, &mut rng);
+                    let mut a = ApInt::random_with_width_using(width, &mut rng);
+                    let b = ApInt::random_with_width_using(width, &mut rng);
+                    // exercise products that do not overflow as well
+                    a.wrapping_lshr_assign(rng.next_u32() as usize % width.to_usize())
+                        .unwrap();
+                    let expected = acc
                         .clone()
-                        .into_wrapping_add(&one)
+                        .into_zero_extend(double)
                         .unwrap()
-                        .into_wrapping_urem(&lhs)
-                        .unwrap(),
-                    one
+                        .into_wrapping_add(
+                            &a.clone()
+                                .into_zero_extend(double)
+                                .unwrap()
+                                .into_wrapping_mul(
+                                    &b.clone().into_zero_extend(double).unwrap(),
+                                )
+                                .unwrap(),
+                        )
+                        .unwrap();
+                    let overflow = acc.mul_add_assign(&a, &b).unwrap();
+                    assert_eq!(acc, expected.clone().into_truncate(width).unwrap());
+                    assert_eq!(
+                        overflow,
+                        expected.leading_zeros() < (double.to_usize() - width.to_usize())
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn errors() {
+            let mut acc = ApInt::from(0_u8);
+            assert!(acc
+                .mul_add_assign(&ApInt::from(1_u16), &ApInt::from(1_u8))
+                .is_err());
+            assert!(acc
+                .mul_add_assign(&ApInt::from(1_u8), &ApInt::from(1_u16))
+                .is_err());
+            assert!(acc
+                .wrapping_mul_add_assign(&ApInt::from(1_u8), &ApInt::from(1_u16))
+                .is_err());
+        }
+    }
+
+    mod pow {
+        use super::*;
+        use crate::bitwidth::BitWidth;
+        use rand::RngCore;
+
+        #[test]
+        fn exp_zero() {
+            for &width in &[1, 8, 64, 65, 128, 200] {
+                let width = BitWidth::new(width).unwrap();
+                let one = ApInt::one(width);
+                for base in &[ApInt::zero(width), ApInt::all_set(width)] {
+                    assert_eq!(base.clone().into_wrapping_pow(0), one);
+                    assert_eq!(
+                        base.clone().into_overflowing_upow(0),
+                        (one.clone(), false)
+                    );
+                    assert_eq!(base.checked_upow(0), Some(one.clone()));
+                }
+            }
+            // one is not representable by the signed interpretation of width 1
+            let minus_one = ApInt::all_set(BitWidth::w1());
+            assert_eq!(
+                ApInt::zero(BitWidth::w1()).into_overflowing_spow(0),
+                (minus_one.clone(), true)
+            );
+            assert_eq!(minus_one.checked_spow(0), None);
+        }
+
+        #[test]
+        fn u128_reference() {
+            let mut rng = test_rng();
+            for _ in 0..1000 {
+                // small bases so that not every power overflows
+                let base = u128::from(rng.next_u64()) >> (rng.next_u32() % 64);
+                let exp = rng.next_u32() % 130;
+                let apint = ApInt::from(base);
+                let (expected, overflow) = base.overflowing_pow(exp);
+                assert_eq!(apint.clone().into_wrapping_pow(exp), ApInt::from(expected));
+                assert_eq!(
+                    apint.clone().into_overflowing_upow(exp),
+                    (ApInt::from(expected), overflow)
                 );
                 assert_eq!(
-                    product
-                        .clone()
-                        .into_wrapping_add(&one)
+                    apint.checked_upow(exp),
+                    base.checked_pow(exp).map(ApInt::from)
+                );
+            }
+        }
+
+        #[test]
+        fn signed() {
+            for base in i8::MIN..=i8::MAX {
+                for exp in 0..10 {
+                    let (expected, overflow) = base.overflowing_pow(exp);
+                    assert_eq!(
+                        ApInt::from(base).into_overflowing_spow(exp),
+                        (ApInt::from(expected), overflow)
+                    );
+                    assert_eq!(
+                        ApInt::from(base).checked_spow(exp),
+                        base.checked_pow(exp).map(ApInt::from)
+                    );
+                    let (expected, overflow) = (base as u8).overflowing_pow(exp);
+                    assert_eq!(
+                        ApInt::from(base).into_overflowing_upow(exp),
+                        (ApInt::from(expected), overflow)
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn large() {
+            let width = BitWidth::new(1000).unwrap();
+            let three = ApInt::from(3u8).into_zero_extend(width).unwrap();
+            let mut expected = ApInt::one(width);
+            for exp in 0..700 {
+                let (pow, overflow) = three.clone().into_overflowing_upow(exp);
+                assert_eq!(pow, expected);
+                // `3^630 < 2^1000 < 3^631`
+                assert_eq!(overflow, exp > 630);
+                expected.wrapping_mul_assign(&three).unwrap();
+            }
+        }
+    }
+
+    mod carry_save_add {
+        use super::*;
+        use crate::bitwidth::BitWidth;
+
+        #[test]
+        fn small() {
+            let (sum, carry) = ApInt::carry_save_add(
+                &ApInt::from(0b1100_u8),
+                &ApInt::from(0b1010_u8),
+                &ApInt::from(0b1001_u8),
+            )
+            .unwrap();
+            assert_eq!(sum, ApInt::from(0b1111_u8));
+            assert_eq!(carry, ApInt::from(0b1000_u8));
+        }
+
+        #[test]
+        fn matches_add() {
+            let mut rng = test_rng();
+            for &width in &[1, 7, 64, 65, 128, 200] {
+                let width = BitWidth::new(width).unwrap();
+                for _ in 0..32 {
+                    let a = ApInt::random_with_width_using(width, &mut rng);
+                    let b = ApInt::random_with_width_using(width, &mut rng);
+                    let c = ApInt::random_with_width_using(width, &mut rng);
+                    let (sum, carry) = ApInt::carry_save_add(&a, &b, &c).unwrap();
+                    assert_eq!(
+                        sum.clone().into_bitand(&carry).unwrap(),
+                        a.clone().into_bitand(&b).unwrap().into_bitand(&c).unwrap()
+                    );
+                    let expected = a
+                        .into_wrapping_add(&b)
                         .unwrap()
-                        .into_wrapping_urem(&rhs)
-                        .unwrap(),
-                    one
+                        .into_wrapping_add(&c)
+                        .unwrap();
+                    let carry = if width.to_usize() == 1 {
+                        ApInt::zero(width)
+                    } else {
+                        carry.into_wrapping_shl(1).unwrap()
+                    };
+                    assert_eq!(sum.into_wrapping_add(&carry).unwrap(), expected);
+                }
+            }
+        }
+
+        #[test]
+        fn errors() {
+            let (a, b) = (ApInt::from(1_u8), ApInt::from(1_u16));
+            assert!(ApInt::carry_save_add(&a, &a, &b).is_err());
+            assert!(ApInt::carry_save_add(&a, &b, &a).is_err());
+            assert!(ApInt::carry_save_add(&b, &a, &a).is_err());
+        }
+    }
+
+    mod next_power_of_two {
+        use super::*;
+        use crate::bitwidth::BitWidth;
+
+        #[test]
+        fn small() {
+            for x in 0..=u8::MAX {
+                let apint = ApInt::from(x);
+                assert_eq!(
+                    apint.clone().into_next_power_of_two(),
+                    ApInt::from(x.checked_next_power_of_two().unwrap_or(0))
+                );
+                assert_eq!(
+                    apint.checked_next_power_of_two(),
+                    x.checked_next_power_of_two().map(ApInt::from)
+                );
+            }
+        }
+
+        #[test]
+        fn boundaries() {
+            for &width in &[1, 64, 65, 128, 200] {
+                let width = BitWidth::new(width).unwrap();
+                let one = ApInt::one(width);
+                let min = ApInt::signed_min_value(width);
+                assert_eq!(ApInt::zero(width).into_next_power_of_two(), one);
+                assert_eq!(ApInt::zero(width).checked_next_power_of_two(), Some(one));
+                assert_eq!(min.clone().into_next_power_of_two(), min);
+                assert_eq!(min.checked_next_power_of_two(), Some(min.clone()));
+                if width.to_usize() > 1 {
+                    let above = min.clone().into_wrapping_inc();
+                    assert_eq!(above.checked_next_power_of_two(), None);
+                    assert_eq!(above.into_next_power_of_two(), ApInt::zero(width));
+                    let below = min.clone().into_wrapping_dec();
+                    assert_eq!(below.into_next_power_of_two(), min);
+                }
+                assert_eq!(
+                    ApInt::all_set(width).into_next_power_of_two(),
+                    if width.to_usize() == 1 {
+                        ApInt::one(width)
+                    } else {
+                        ApInt::zero(width)
+                    }
                 );
             }
         }
+
+        #[test]
+        fn large() {
+            let x = ApInt::from([0_u64, 5, 1]);
+            assert_eq!(x.into_next_power_of_two(), ApInt::from([0_u64, 8, 0]));
+            let x = ApInt::from([0_u64, 1 << 63, 1]);
+            assert_eq!(x.into_next_power_of_two(), ApInt::from([1_u64, 0, 0]));
+            let x = ApInt::from([0_u64, 1 << 63, 0]);
+            assert_eq!(x.clone().into_next_power_of_two(), x);
+        }
+    }
+
+    mod clmul {
+        use super::*;
+        use crate::bitwidth::BitWidth;
+
+        /// Carry-less multiplication using a naive loop over the bits.
+        fn naive_clmul(lhs: &ApInt, rhs: &ApInt) -> ApInt {
+            let (lw, rw) = (lhs.width().to_usize(), rhs.width().to_usize());
+            let mut result = ApInt::zero(BitWidth::new(lw + rw).unwrap());
+            for i in 0..lw {
+                for j in 0..rw {
+                    if lhs.get_bit_at(i).unwrap() && rhs.get_bit_at(j).unwrap() {
+                        result.flip_bit_at(i + j).unwrap();
+                    }
+                }
+            }
+            result
+        }
+
+        #[test]
+        fn small() {
+            // (x + 1) * (x + 1) = x^2 + 1 over GF(2)
+            assert_eq!(
+                ApInt::from(0b11_u8).clmul(&ApInt::from(0b11_u8)),
+                ApInt::from(0b101_u16)
+            );
+            assert_eq!(
+                ApInt::from(0b1011_u8).clmul(&ApInt::from(0b0111_u8)),
+                ApInt::from(0b11_0001_u16)
+            );
+            assert_eq!(
+                ApInt::from(u64::MAX).clmul(&ApInt::from(1_u8)),
+                ApInt::from(u64::MAX).into_zero_resize(BitWidth::new(72).unwrap())
+            );
+            for x in 0..16_u8 {
+                for y in 0..16_u8 {
+                    let lhs = ApInt::from(x).into_truncate(4).unwrap();
+                    let rhs = ApInt::from(y).into_truncate(5).unwrap();
+                    assert_eq!(lhs.clmul(&rhs), naive_clmul(&lhs, &rhs));
+                }
+            }
+        }
+
+        #[test]
+        fn random() {
+            let mut rng = test_rng();
+            let widths = [1, 7, 64, 65, 100, 128, 200, 512];
+            for &lw in &widths {
+                for &rw in &widths {
+                    let lhs = ApInt::random_with_width_using(
+                        BitWidth::new(lw).unwrap(),
+                        &mut rng,
+                    );
+                    let rhs = ApInt::random_with_width_using(
+                        BitWidth::new(rw).unwrap(),
+                        &mut rng,
+                    );
+                    let product = lhs.clmul(&rhs);
+                    assert_eq!(product, rhs.clmul(&lhs));
+                    if lw <= 128 && rw <= 128 {
+                        assert_eq!(product, naive_clmul(&lhs, &rhs));
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn wrapping() {
+            let mut rng = test_rng();
+            for &width in &[1, 64, 65, 200, 512] {
+                let width = BitWidth::new(width).unwrap();
+                let lhs = ApInt::random_with_width_using(width, &mut rng);
+                let rhs = ApInt::random_with_width_using(width, &mut rng);
+                let expected = lhs.clmul(&rhs).into_truncate(width).unwrap();
+                assert_eq!(lhs.clone().into_wrapping_clmul(&rhs).unwrap(), expected);
+                assert_eq!(rhs.into_wrapping_clmul(&lhs).unwrap(), expected);
+            }
+            assert!(ApInt::from(1_u8)
+                .into_wrapping_clmul(&ApInt::from(1_u16))
+                .is_err());
+        }
+    }
+
+    mod is_multiple_of {
+        use super::*;
+        use crate::bitwidth::BitWidth;
+
+        #[test]
+        fn small() {
+            let v = |x: u8| ApInt::from(x);
+            assert_eq!(v(0).is_multiple_of(&v(7)), Ok(true));
+            assert_eq!(v(42).is_multiple_of(&v(7)), Ok(true));
+            assert_eq!(v(43).is_multiple_of(&v(7)), Ok(false));
+            assert_eq!(v(5).is_multiple_of(&v(7)), Ok(false));
+            assert_eq!(v(48).is_multiple_of(&v(16)), Ok(true));
+            assert_eq!(v(40).is_multiple_of(&v(16)), Ok(false));
+            assert_eq!(v(128).is_multiple_of(&v(128)), Ok(true));
+            assert_eq!(v(1).is_multiple_of(&v(1)), Ok(true));
+        }
+
+        #[test]
+        fn large() {
+            let width = BitWidth::new(200).unwrap();
+            let x = ApInt::from([1u64, 0, 0, 0]).into_truncate(width).unwrap();
+            let pow = ApInt::from(1u128 << 100).into_zero_resize(width);
+            assert_eq!(x.is_multiple_of(&pow), Ok(true));
+            assert_eq!(pow.is_multiple_of(&x), Ok(false));
+            let three = ApInt::from(3u8).into_zero_resize(width);
+            let x = ApInt::from([0u64, 0, 3, 0]).into_truncate(width).unwrap();
+            assert_eq!(x.is_multiple_of(&three), Ok(true));
+            assert_eq!(x.into_wrapping_inc().is_multiple_of(&three), Ok(false));
+        }
+
+        #[test]
+        fn wide_divisor() {
+            let mut rng = test_rng();
+            let width = BitWidth::new(200).unwrap();
+            let random = |bits: usize, rng: &mut rand_xorshift::XorShiftRng| {
+                ApInt::random_with_width_using(BitWidth::new(bits).unwrap(), rng)
+                    .into_zero_resize(width)
+            };
+            for _ in 0..100 {
+                let mut div = random(130, &mut rng);
+                div.set_bit_at(129).unwrap();
+                let x = div.clone().into_wrapping_mul(&random(60, &mut rng)).unwrap();
+                assert_eq!(x.is_multiple_of(&div), Ok(true));
+                let y = x.into_wrapping_inc();
+                assert_eq!(y.is_multiple_of(&div), Ok(false));
+            }
+        }
+
+        #[test]
+        fn errors() {
+            assert!(ApInt::from(5u8).is_multiple_of(&ApInt::from(5u16)).is_err());
+            assert_eq!(
+                ApInt::from(5u8).is_multiple_of(&ApInt::from(0u8)),
+                Err(Error::division_by_zero(
+                    DivOp::UnsignedRem,
+                    ApInt::from(5u8)
+                ))
+            );
+        }
     }
 
     mod megafuzz {
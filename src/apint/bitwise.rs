@@ -4,6 +4,7 @@ use crate::{
         DataAccessMut,
     },
     checks,
+    digit::DigitRepr,
     utils::{
         forward_mut_impl,
         try_forward_bin_mut_impl,
@@ -11,6 +12,7 @@ use crate::{
     ApInt,
     BitPos,
     Digit,
+    Error,
     Result,
     Width,
 };
@@ -90,6 +92,121 @@ impl ApInt {
     pub fn bitxor_assign(&mut self, rhs: &ApInt) -> Result<()> {
         self.modify_zipped_digits(rhs, |l, r| *l ^= r)
     }
+
+    /// Replaces the bits of this `ApInt` that are set in `mask` with the
+    /// corresponding bits of `other`.
+    ///
+    /// This computes `(other & mask) | (self & !mask)` inplace of `self`.
+    ///
+    /// **Note:** This operation is inplace of `self` and won't allocate memory.
+    /// The digit loop has no data-dependent branches which makes it suitable
+    /// for constant-time code.
+    ///
+    /// # Errors
+    ///
+    /// If `self`, `other` and `mask` have unmatching bit widths.
+    pub fn merge_by_mask(&mut self, other: &ApInt, mask: &ApInt) -> Result<()> {
+        if self.width() != other.width() {
+            return Error::unmatching_bitwidths(self.width(), other.width()).into()
+        }
+        if self.width() != mask.width() {
+            return Error::unmatching_bitwidths(self.width(), mask.width()).into()
+        }
+        let rhs = other.as_digit_slice().iter().zip(mask.as_digit_slice());
+        for (l, (&o, &m)) in self.as_digit_slice_mut().iter_mut().zip(rhs) {
+            *l ^= (*l ^ o) & m;
+        }
+        Ok(())
+    }
+
+    /// Bit-and assigns all bits of this `ApInt` with the bits of `rhs`
+    /// zero-extended to the width of `self`.
+    ///
+    /// If `rhs` is wider than `self` its bits beyond the width of `self` are
+    /// ignored.
+    ///
+    /// **Note:** This operation is inplace of `self` and won't allocate memory.
+    pub fn bitand_assign_zext(&mut self, rhs: &ApInt) {
+        // The excess bits of `self` are zero and thus stay zero.
+        self.modify_zipped_digits_zext(rhs, |l, r| *l &= r)
+    }
+
+    /// Bit-or assigns all bits of this `ApInt` with the bits of `rhs`
+    /// zero-extended to the width of `self`.
+    ///
+    /// **Note:** This operation is inplace of `self` and won't allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// If `rhs` is wider than `self`.
+    pub fn bitor_assign_zext(&mut self, rhs: &ApInt) -> Result<()> {
+        if rhs.width() > self.width() {
+            return Error::unmatching_bitwidths(self.width(), rhs.width()).into()
+        }
+        self.modify_zipped_digits_zext(rhs, |l, r| *l |= r);
+        Ok(())
+    }
+
+    /// Bit-xor assigns all bits of this `ApInt` with the bits of `rhs`
+    /// zero-extended to the width of `self`.
+    ///
+    /// **Note:** This operation is inplace of `self` and won't allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// If `rhs` is wider than `self`.
+    pub fn bitxor_assign_zext(&mut self, rhs: &ApInt) -> Result<()> {
+        if rhs.width() > self.width() {
+            return Error::unmatching_bitwidths(self.width(), rhs.width()).into()
+        }
+        self.modify_zipped_digits_zext(rhs, |l, r| *l ^= r);
+        Ok(())
+    }
+
+    /// Isolates the least significant set bit of this `ApInt` inplace so that
+    /// only this bit remains set.
+    ///
+    /// This is equal to `self & self.wrapping_neg()` but does not require a
+    /// temporary `ApInt`.
+    ///
+    /// **Note:** This is a no-op if `self` is zero.
+    pub fn isolate_lsb(&mut self) {
+        let mut digits = self.as_digit_slice_mut().iter_mut();
+        if let Some(digit) = digits.find(|d| !d.is_zero()) {
+            let repr = digit.repr();
+            *digit = Digit(repr & repr.wrapping_neg());
+            digits.for_each(|d| *d = Digit::ZERO);
+        }
+    }
+
+    /// Isolates the least significant set bit of this `ApInt` and returns the
+    /// result.
+    ///
+    /// **Note:** This is a no-op if `self` is zero.
+    pub fn into_isolate_lsb(self) -> ApInt {
+        forward_mut_impl(self, ApInt::isolate_lsb)
+    }
+
+    /// Clears the least significant set bit of this `ApInt` inplace.
+    ///
+    /// This is equal to `self & (self - 1)` but does not require a temporary
+    /// `ApInt`.
+    ///
+    /// **Note:** This is a no-op if `self` is zero.
+    pub fn clear_lsb(&mut self) {
+        if let Some(digit) = self.as_digit_slice_mut().iter_mut().find(|d| !d.is_zero()) {
+            let repr = digit.repr();
+            *digit = Digit(repr & (repr - 1));
+        }
+    }
+
+    /// Clears the least significant set bit of this `ApInt` and returns the
+    /// result.
+    ///
+    /// **Note:** This is a no-op if `self` is zero.
+    pub fn into_clear_lsb(self) -> ApInt {
+        forward_mut_impl(self, ApInt::clear_lsb)
+    }
 }
 
 /// # Bitwise Access
@@ -188,12 +305,11 @@ impl ApInt {
     /// Returns``true` if all bits in the `ApInt` are set.
     pub fn is_all_set(&self) -> bool {
         let (msb, rest) = self.split_most_significant_digit();
-        if let Some(excess_bits) = self.width().excess_bits() {
-            if msb.repr().count_ones() as usize != excess_bits {
-                return false
-            }
-        }
-        rest.iter().all(|d| *d == Digit::ONES)
+        let msb_set = match self.width().excess_bits() {
+            Some(excess_bits) => msb.repr().count_ones() as usize == excess_bits,
+            None => msb == Digit::ONES,
+        };
+        msb_set && rest.iter().all(|d| *d == Digit::ONES)
     }
 
     /// Sets all bits of this `ApInt` to zero (`0`).
@@ -248,14 +364,60 @@ impl ApInt {
     }
 }
 
+/// The number of digits above which `ApInt::count_ones` uses `POPCOUNT_TABLE`.
+#[cfg(feature = "popcount_table_lookup")]
+const POPCOUNT_TABLE_MIN_DIGITS: usize = 1024 / Digit::BITS;
+
+/// Lookup table for the number of set bits of every `u8` value.
+#[cfg(feature = "popcount_table_lookup")]
+static POPCOUNT_TABLE: [u8; 256] = {
+    let mut table = [0; 256];
+    let mut byte = 1;
+    while byte < 256 {
+        table[byte] = (byte & 1) as u8 + table[byte / 2];
+        byte += 1;
+    }
+    table
+};
+
 /// # Bitwise utility methods.
 impl ApInt {
     /// Returns the number of ones in the binary representation of this `ApInt`.
+    ///
+    /// # Note
+    ///
+    /// The counting strategy is picked based on the number of digits:
+    ///
+    /// - `ApInt`s with one or two digits use an unrolled popcount.
+    /// - If the `popcount_table_lookup` crate feature is enabled, `ApInt`s with
+    ///   more than `1024` bits use a per-byte lookup table which may outperform
+    ///   the popcount loop on targets without a native popcount instruction.
+    /// - All other `ApInt`s use a popcount loop over their digits.
     pub fn count_ones(&self) -> usize {
-        self.as_digit_slice()
-            .iter()
-            .map(|d| d.repr().count_ones() as usize)
-            .sum::<usize>()
+        let digits = self.as_digit_slice();
+        match digits.len() {
+            1 => digits[0].repr().count_ones() as usize,
+            2 => (digits[0].repr().count_ones() + digits[1].repr().count_ones()) as usize,
+            #[cfg(feature = "popcount_table_lookup")]
+            n if n > POPCOUNT_TABLE_MIN_DIGITS => {
+                digits
+                    .iter()
+                    .map(|d| {
+                        d.repr()
+                            .to_le_bytes()
+                            .iter()
+                            .map(|&byte| POPCOUNT_TABLE[usize::from(byte)] as usize)
+                            .sum::<usize>()
+                    })
+                    .sum::<usize>()
+            }
+            _ => {
+                digits
+                    .iter()
+                    .map(|d| d.repr().count_ones() as usize)
+                    .sum::<usize>()
+            }
+        }
     }
 
     /// Returns the number of zeros in the binary representation of this
@@ -302,13 +464,218 @@ impl ApInt {
         }
         zeros
     }
+
+    /// Returns the position of the least significant set bit of this `ApInt`
+    /// or `None` if this `ApInt` is zero.
+    pub fn lsb_set_pos(&self) -> Option<BitPos> {
+        self.as_digit_slice()
+            .iter()
+            .enumerate()
+            .find(|(_, d)| !d.is_zero())
+            .map(|(i, d)| {
+                BitPos::from(i * Digit::BITS + d.repr().trailing_zeros() as usize)
+            })
+    }
+
+    /// Returns the position of the most significant set bit of this `ApInt`
+    /// or `None` if this `ApInt` is zero.
+    pub fn msb_set_pos(&self) -> Option<BitPos> {
+        // Since the unused excess bits are always zero they are skipped
+        // by `leading_zeros` of the most significant digit.
+        self.as_digit_slice()
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, d)| !d.is_zero())
+            .map(|(i, d)| {
+                BitPos::from(
+                    i * Digit::BITS + Digit::BITS - 1 - d.repr().leading_zeros() as usize,
+                )
+            })
+    }
+
+    /// Returns the position of the sign bit of the shortest two's complement
+    /// representation of this `ApInt` interpreted as signed integer.
+    ///
+    /// This ignores all redundant copies of the sign bit, so the returned
+    /// position plus one is the minimum number of bits required to represent
+    /// the signed value of this `ApInt`.
+    ///
+    /// # Examples
+    ///
+    /// - `0` and `-1` return position `0`.
+    /// - `5` (`0b0101`) returns position `3`.
+    /// - `-6` (`0b1010`) returns position `3`.
+    pub fn msb_significant_pos(&self) -> BitPos {
+        let sign = self.msb();
+        let (msd, rest) = self.split_most_significant_digit();
+        let mut msd = if sign { !msd.repr() } else { msd.repr() };
+        if let Some(bits) = self.width().excess_bits() {
+            msd &= Digit::ONES.repr() >> (Digit::BITS - bits);
+        }
+        let digits = rest.len();
+        core::iter::once((digits, msd))
+            .chain(
+                rest.iter()
+                    .map(|d| if sign { !d.repr() } else { d.repr() })
+                    .enumerate()
+                    .rev(),
+            )
+            .find(|&(_, repr)| repr != 0)
+            .map(|(i, repr)| {
+                BitPos::from(
+                    i * Digit::BITS + Digit::BITS - repr.leading_zeros() as usize,
+                )
+            })
+            .unwrap_or_else(|| BitPos::from(0))
+    }
+
+    /// Returns the XOR of all bits of this `ApInt`.
+    ///
+    /// This is `true` if the number of set bits in the binary representation
+    /// of this `ApInt` is odd and equivalent to
+    /// [`parity`](struct.ApInt.html#method.parity).
+    ///
+    /// **Note:** This XOR-folds all digits into a single digit first and is
+    /// thus cheaper than `count_ones() % 2 == 1` for large `ApInt`s.
+    pub fn xor_all_bits(&self) -> bool {
+        if let Some(bits) = self.width().excess_bits() {
+            debug_assert_eq!(self.most_significant_digit().repr() >> bits, 0);
+        }
+        let folded = self
+            .as_digit_slice()
+            .iter()
+            .fold(0, |acc, d| acc ^ d.repr());
+        folded.count_ones() % 2 == 1
+    }
+
+    /// Returns `true` if the number of set bits in the binary representation
+    /// of this `ApInt` is odd.
+    ///
+    /// This is an alias for
+    /// [`xor_all_bits`](struct.ApInt.html#method.xor_all_bits).
+    pub fn parity(&self) -> bool {
+        self.xor_all_bits()
+    }
+
+    /// Returns an iterator over the bit positions of all set bits (ones) of
+    /// this `ApInt` in ascending order.
+    ///
+    /// The iterator is double-ended, so `.rev()` yields the positions in
+    /// descending order starting with the most significant set bit.
+    ///
+    /// **Note:** Whole digits without set bits are skipped at once, so this is
+    /// efficient for sparse bit patterns.
+    pub fn iter_ones(&self) -> impl DoubleEndedIterator<Item = usize> + '_ {
+        BitPositions::new(self, false)
+    }
+
+    /// Returns an iterator over the bit positions of all unset bits (zeros) of
+    /// this `ApInt` in ascending order.
+    ///
+    /// The iterator is double-ended, so `.rev()` yields the positions in
+    /// descending order starting with the most significant unset bit.
+    ///
+    /// **Note:** The unused excess bits of `ApInt`s with bit widths that are
+    /// not a multiple of a `Digit` are never reported.
+    pub fn iter_zeros(&self) -> impl DoubleEndedIterator<Item = usize> + '_ {
+        BitPositions::new(self, true)
+    }
+}
+
+/// Iterator over the positions of either the set or unset bits of an `ApInt`.
+///
+/// Yields the positions within `lo..hi` from the front and from the back.
+#[derive(Debug, Clone)]
+struct BitPositions<'a> {
+    digits: &'a [Digit],
+    /// If `true` the unset bits are yielded instead of the set bits.
+    invert: bool,
+    lo: usize,
+    hi: usize,
+}
+
+impl<'a> BitPositions<'a> {
+    fn new(apint: &'a ApInt, invert: bool) -> BitPositions<'a> {
+        BitPositions {
+            digits: apint.as_digit_slice(),
+            invert,
+            lo: 0,
+            hi: apint.width().to_usize(),
+        }
+    }
+
+    /// Returns the `Digit` at `index` as it is seen by this iterator.
+    ///
+    /// Bits beyond the bit width are never looked at since `hi` never exceeds
+    /// the bit width of the `ApInt`.
+    fn word(&self, index: usize) -> DigitRepr {
+        let repr = self.digits[index].repr();
+        if self.invert {
+            !repr
+        } else {
+            repr
+        }
+    }
+}
+
+impl<'a> Iterator for BitPositions<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.lo < self.hi {
+            let index = self.lo / Digit::BITS;
+            let start = index * Digit::BITS;
+            let mut word = self.word(index) & (Digit::ONES.repr() << (self.lo - start));
+            if self.hi - start < Digit::BITS {
+                word &= !(Digit::ONES.repr() << (self.hi - start));
+            }
+            if word != 0 {
+                let pos = start + word.trailing_zeros() as usize;
+                self.lo = pos + 1;
+                return Some(pos)
+            }
+            self.lo = start + Digit::BITS;
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.hi.saturating_sub(self.lo)))
+    }
+}
+
+impl<'a> DoubleEndedIterator for BitPositions<'a> {
+    fn next_back(&mut self) -> Option<usize> {
+        while self.lo < self.hi {
+            let index = (self.hi - 1) / Digit::BITS;
+            let start = index * Digit::BITS;
+            let mut word = self.word(index);
+            if self.hi - start < Digit::BITS {
+                word &= !(Digit::ONES.repr() << (self.hi - start));
+            }
+            if self.lo > start {
+                word &= Digit::ONES.repr() << (self.lo - start);
+            }
+            if word != 0 {
+                let pos = start + (Digit::BITS - 1) - word.leading_zeros() as usize;
+                self.hi = pos;
+                return Some(pos)
+            }
+            self.hi = start;
+        }
+        None
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use crate::bitwidth::BitWidth;
+    use crate::{
+        apint::test_utils::test_rng,
+        bitwidth::BitWidth,
+    };
 
     // Note: there are more tests of the counting functions in `uint.rs`
 
@@ -424,6 +791,379 @@ mod tests {
         );
     }
 
+    #[test]
+    fn count_ones_strategies() {
+        let mut rng = test_rng();
+        for &width in &[1, 64, 65, 128, 129, 1024, 1025, 2048, 4000] {
+            let width = BitWidth::new(width).unwrap();
+            assert_eq!(ApInt::all_set(width).count_ones(), width.to_usize());
+            for _ in 0..8 {
+                let apint = ApInt::random_with_width_using(width, &mut rng);
+                let expected = (0..width.to_usize())
+                    .filter(|&pos| apint.get_bit_at(pos).unwrap())
+                    .count();
+                assert_eq!(apint.count_ones(), expected);
+            }
+        }
+    }
+
+    mod iter_ones_zeros {
+        use super::*;
+
+        fn test_apints(width: BitWidth) -> impl Iterator<Item = ApInt> {
+            let mut rng = test_rng();
+            vec![
+                ApInt::zero(width),
+                ApInt::all_set(width),
+                ApInt::signed_min_value(width),
+                ApInt::signed_max_value(width),
+            ]
+            .into_iter()
+            .chain((0..16).map(move |_| ApInt::random_with_width_using(width, &mut rng)))
+        }
+
+        fn assert_positions(apint: &ApInt) {
+            let ones = apint.iter_ones().collect::<Vec<_>>();
+            let zeros = apint.iter_zeros().collect::<Vec<_>>();
+            assert_eq!(ones.len(), apint.count_ones());
+            assert_eq!(zeros.len(), apint.count_zeros());
+            for &pos in &ones {
+                assert_eq!(apint.get_bit_at(pos), Ok(true));
+            }
+            for &pos in &zeros {
+                assert_eq!(apint.get_bit_at(pos), Ok(false));
+            }
+            assert!(ones.windows(2).all(|w| w[0] < w[1]));
+            assert!(zeros.windows(2).all(|w| w[0] < w[1]));
+            let mut rev_ones = apint.iter_ones().rev().collect::<Vec<_>>();
+            rev_ones.reverse();
+            assert_eq!(rev_ones, ones);
+            let mut rev_zeros = apint.iter_zeros().rev().collect::<Vec<_>>();
+            rev_zeros.reverse();
+            assert_eq!(rev_zeros, zeros);
+        }
+
+        #[test]
+        fn widths() {
+            for &width in &[1, 8, 63, 64, 65, 128, 320] {
+                for apint in test_apints(BitWidth::new(width).unwrap()) {
+                    assert_positions(&apint);
+                }
+            }
+        }
+
+        #[test]
+        fn double_ended() {
+            let apint = ApInt::from([1u64 << 3, 0, 0b101]);
+            let mut ones = apint.iter_ones();
+            assert_eq!(ones.next_back(), Some(131));
+            assert_eq!(ones.next(), Some(0));
+            assert_eq!(ones.next_back(), Some(2));
+            assert_eq!(ones.next(), None);
+            assert_eq!(ones.next_back(), None);
+            let apint = ApInt::from(0b1011_u8);
+            let mut zeros = apint.iter_zeros();
+            assert_eq!(zeros.next(), Some(2));
+            assert_eq!(zeros.next_back(), Some(7));
+            assert_eq!(zeros.count(), 3);
+        }
+    }
+
+    mod merge_by_mask {
+        use super::*;
+
+        #[test]
+        fn small() {
+            let mut apint = ApInt::from(0xAAAA_u16);
+            apint
+                .merge_by_mask(&ApInt::from(0x1234_u16), &ApInt::from(0x0FF0_u16))
+                .unwrap();
+            assert_eq!(apint, ApInt::from(0xA23A_u16));
+        }
+
+        #[test]
+        fn random() {
+            let mut rng = test_rng();
+            for &width in &[1, 7, 64, 65, 128, 200, 512] {
+                let width = BitWidth::new(width).unwrap();
+                for _ in 0..16 {
+                    let lhs = ApInt::random_with_width_using(width, &mut rng);
+                    let other = ApInt::random_with_width_using(width, &mut rng);
+                    let mask = ApInt::random_with_width_using(width, &mut rng);
+                    let mut zeros = lhs.clone();
+                    zeros.merge_by_mask(&other, &ApInt::zero(width)).unwrap();
+                    assert_eq!(zeros, lhs);
+                    let mut ones = lhs.clone();
+                    ones.merge_by_mask(&other, &ApInt::all_set(width)).unwrap();
+                    assert_eq!(ones, other);
+                    let mut merged = lhs.clone();
+                    merged.merge_by_mask(&other, &mask).unwrap();
+                    let expected = other
+                        .clone()
+                        .into_bitand(&mask)
+                        .unwrap()
+                        .into_bitor(
+                            &lhs.into_bitand(&mask.clone().into_bitnot()).unwrap(),
+                        )
+                        .unwrap();
+                    assert_eq!(merged, expected);
+                }
+            }
+        }
+
+        #[test]
+        fn errors() {
+            let (a, b) = (ApInt::from(1_u8), ApInt::from(1_u16));
+            assert!(a.clone().merge_by_mask(&b, &a).is_err());
+            assert!(a.clone().merge_by_mask(&a, &b).is_err());
+        }
+    }
+
+    mod zext_ops {
+        use super::*;
+
+        #[test]
+        fn small() {
+            let mut apint = ApInt::from(0xFF0F_u16);
+            apint.bitand_assign_zext(&ApInt::from(0x3C_u8));
+            assert_eq!(apint, ApInt::from(0x000C_u16));
+            let mut apint = ApInt::from(0xF0F0_u16);
+            apint.bitor_assign_zext(&ApInt::from(0x3C_u8)).unwrap();
+            assert_eq!(apint, ApInt::from(0xF0FC_u16));
+            let mut apint = ApInt::from(0xF0F0_u16);
+            apint.bitxor_assign_zext(&ApInt::from(0x3C_u8)).unwrap();
+            assert_eq!(apint, ApInt::from(0xF0CC_u16));
+        }
+
+        #[test]
+        fn wider_rhs() {
+            let mut apint = ApInt::from(0xF0_u8);
+            apint.bitand_assign_zext(&ApInt::from(0xFF3C_u16));
+            assert_eq!(apint, ApInt::from(0x30_u8));
+            let mut apint = ApInt::from(0xF0_u8);
+            apint.bitand_assign_zext(&ApInt::from([u64::MAX, 0x3C]));
+            assert_eq!(apint, ApInt::from(0x30_u8));
+            assert!(ApInt::from(0xF0_u8)
+                .bitor_assign_zext(&ApInt::from(0x3C_u16))
+                .is_err());
+            assert!(ApInt::from(0xF0_u8)
+                .bitxor_assign_zext(&ApInt::from(0x3C_u16))
+                .is_err());
+        }
+
+        #[test]
+        fn matches_zero_extended() {
+            let mut rng = test_rng();
+            let widths = [1, 7, 64, 65, 128, 200];
+            for &lw in &widths {
+                for &rw in widths.iter().filter(|&&rw| rw <= lw) {
+                    let lw = BitWidth::new(lw).unwrap();
+                    let lhs = ApInt::random_with_width_using(lw, &mut rng);
+                    let rhs = ApInt::random_with_width_using(
+                        BitWidth::new(rw).unwrap(),
+                        &mut rng,
+                    );
+                    let ext = rhs.clone().into_zero_extend(lw).unwrap();
+                    let mut and = lhs.clone();
+                    and.bitand_assign_zext(&rhs);
+                    assert_eq!(and, lhs.clone().into_bitand(&ext).unwrap());
+                    let mut or = lhs.clone();
+                    or.bitor_assign_zext(&rhs).unwrap();
+                    assert_eq!(or, lhs.clone().into_bitor(&ext).unwrap());
+                    let mut xor = lhs.clone();
+                    xor.bitxor_assign_zext(&rhs).unwrap();
+                    assert_eq!(xor, lhs.into_bitxor(&ext).unwrap());
+                }
+            }
+        }
+    }
+
+    mod isolate_clear_lsb {
+        use super::*;
+
+        #[test]
+        fn zero() {
+            for &width in &[1, 64, 65, 200] {
+                let zero = ApInt::zero(BitWidth::new(width).unwrap());
+                assert_eq!(zero.clone().into_isolate_lsb(), zero);
+                assert_eq!(zero.clone().into_clear_lsb(), zero);
+            }
+        }
+
+        #[test]
+        fn small() {
+            assert_eq!(
+                ApInt::from(0b0110_1000_u8).into_isolate_lsb(),
+                ApInt::from(0b0000_1000_u8)
+            );
+            assert_eq!(
+                ApInt::from(0b0110_1000_u8).into_clear_lsb(),
+                ApInt::from(0b0110_0000_u8)
+            );
+            assert_eq!(ApInt::from(true).into_isolate_lsb(), ApInt::from(true));
+            assert_eq!(ApInt::from(true).into_clear_lsb(), ApInt::from(false));
+        }
+
+        #[test]
+        fn large() {
+            let apint = ApInt::from([0xF0_u64, 1 << 63 | 1 << 40, 0]);
+            assert_eq!(
+                apint.clone().into_isolate_lsb(),
+                ApInt::from([0_u64, 1 << 40, 0])
+            );
+            assert_eq!(
+                apint.clone().into_clear_lsb(),
+                ApInt::from([0xF0_u64, 1 << 63, 0])
+            );
+            let width = BitWidth::new(200).unwrap();
+            let min = ApInt::signed_min_value(width);
+            assert_eq!(min.clone().into_isolate_lsb(), min);
+            assert_eq!(min.into_clear_lsb(), ApInt::zero(width));
+            let all_set = ApInt::all_set(width);
+            assert_eq!(all_set.clone().into_isolate_lsb(), ApInt::one(width));
+            assert_eq!(
+                all_set.into_clear_lsb(),
+                ApInt::all_set(width).into_wrapping_dec()
+            );
+        }
+
+        #[test]
+        fn matches_wrapping_ops() {
+            let mut rng = test_rng();
+            for &width in &[1, 7, 64, 65, 128, 200] {
+                let width = BitWidth::new(width).unwrap();
+                for _ in 0..32 {
+                    let apint = ApInt::random_with_width_using(width, &mut rng);
+                    let neg = apint.clone().into_wrapping_neg();
+                    let dec = apint.clone().into_wrapping_dec();
+                    assert_eq!(
+                        apint.clone().into_isolate_lsb(),
+                        apint.clone().into_bitand(&neg).unwrap()
+                    );
+                    assert_eq!(
+                        apint.clone().into_clear_lsb(),
+                        apint.into_bitand(&dec).unwrap()
+                    );
+                }
+            }
+        }
+    }
+
+    mod set_pos {
+        use super::*;
+
+        #[test]
+        fn zero() {
+            for &width in &[1, 8, 64, 65, 128, 200] {
+                let width = BitWidth::new(width).unwrap();
+                assert_eq!(ApInt::zero(width).lsb_set_pos(), None);
+                assert_eq!(ApInt::zero(width).msb_set_pos(), None);
+                assert_eq!(ApInt::zero(width).msb_significant_pos(), BitPos::from(0));
+                assert_eq!(ApInt::all_set(width).msb_significant_pos(), BitPos::from(0));
+            }
+        }
+
+        #[test]
+        fn small() {
+            let apint = ApInt::from(0b0010_1100_u8);
+            assert_eq!(apint.lsb_set_pos(), Some(BitPos::from(2)));
+            assert_eq!(apint.msb_set_pos(), Some(BitPos::from(5)));
+            assert_eq!(apint.msb_significant_pos(), BitPos::from(6));
+            assert_eq!(ApInt::from(5_i8).msb_significant_pos(), BitPos::from(3));
+            assert_eq!(ApInt::from(-6_i8).msb_significant_pos(), BitPos::from(3));
+            assert_eq!(ApInt::from(-128_i8).msb_significant_pos(), BitPos::from(7));
+            assert_eq!(ApInt::from(127_i8).msb_significant_pos(), BitPos::from(7));
+        }
+
+        #[test]
+        fn large() {
+            let apint = ApInt::from([0_u64, 1 << 7, 1 << 3]);
+            assert_eq!(apint.lsb_set_pos(), Some(BitPos::from(3)));
+            assert_eq!(apint.msb_set_pos(), Some(BitPos::from(71)));
+            assert_eq!(apint.msb_significant_pos(), BitPos::from(72));
+            let width = BitWidth::new(200).unwrap();
+            assert_eq!(
+                ApInt::signed_min_value(width).lsb_set_pos(),
+                Some(BitPos::from(199))
+            );
+            assert_eq!(
+                ApInt::signed_min_value(width).msb_set_pos(),
+                Some(BitPos::from(199))
+            );
+            assert_eq!(
+                ApInt::signed_min_value(width).msb_significant_pos(),
+                BitPos::from(199)
+            );
+            assert_eq!(
+                ApInt::signed_max_value(width).msb_set_pos(),
+                Some(BitPos::from(198))
+            );
+            assert_eq!(
+                ApInt::from(-6_i64)
+                    .into_sign_extend(width)
+                    .unwrap()
+                    .msb_significant_pos(),
+                BitPos::from(3)
+            );
+        }
+
+        #[test]
+        fn matches_zeros_counts() {
+            let mut rng = test_rng();
+            for &width in &[1, 7, 64, 65, 127, 200] {
+                let width = BitWidth::new(width).unwrap();
+                for _ in 0..32 {
+                    let apint = ApInt::random_with_width_using(width, &mut rng);
+                    if apint.is_zero() {
+                        continue
+                    }
+                    assert_eq!(
+                        apint.lsb_set_pos(),
+                        Some(BitPos::from(apint.trailing_zeros()))
+                    );
+                    assert_eq!(
+                        apint.msb_set_pos(),
+                        Some(BitPos::from(width.to_usize() - 1 - apint.leading_zeros()))
+                    );
+                    let abs_bits = if apint.msb() {
+                        apint.clone().into_bitnot().msb_set_pos()
+                    } else {
+                        apint.msb_set_pos()
+                    };
+                    let expected = abs_bits.map_or(0, |pos| pos.to_usize() + 1);
+                    assert_eq!(apint.msb_significant_pos(), BitPos::from(expected));
+                }
+            }
+        }
+    }
+
+    mod parity {
+        use super::*;
+
+        #[test]
+        fn simple() {
+            assert!(!ApInt::zero(BitWidth::w1()).parity());
+            assert!(ApInt::one(BitWidth::w1()).parity());
+            assert!(!ApInt::from(0b1011_0100_u8).parity());
+            assert!(ApInt::from(0b1011_0110_u8).parity());
+            assert!(!ApInt::all_set(BitWidth::w128()).parity());
+            assert!(ApInt::all_set(BitWidth::new(321).unwrap()).parity());
+        }
+
+        #[test]
+        fn matches_count_ones() {
+            let mut rng = test_rng();
+            for &width in &[1, 7, 8, 63, 64, 65, 127, 128, 200, 320] {
+                let width = BitWidth::new(width).unwrap();
+                for _ in 0..32 {
+                    let apint = ApInt::random_with_width_using(width, &mut rng);
+                    assert_eq!(apint.parity(), apint.count_ones() % 2 == 1);
+                    assert_eq!(apint.parity(), apint.xor_all_bits());
+                }
+            }
+        }
+    }
+
     mod is_all_set {
         use super::*;
 
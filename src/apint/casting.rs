@@ -1,11 +1,16 @@
 use crate::{
-    mem::format,
+    apint::utils::or_digits_at,
+    mem::{
+        format,
+        vec::Vec,
+    },
     storage::Storage,
     utils::{
         forward_bin_mut_impl,
         try_forward_bin_mut_impl,
     },
     ApInt,
+    BitPos,
     BitWidth,
     Digit,
     Error,
@@ -13,6 +18,11 @@ use crate::{
     Width,
 };
 
+use core::{
+    mem,
+    ptr::NonNull,
+};
+
 impl Clone for ApInt {
     fn clone(&self) -> Self {
         match self.storage() {
@@ -106,6 +116,56 @@ impl ApInt {
 
 /// # Casting: Truncation & Extension
 impl ApInt {
+    /// Changes the bit width of `self` to `target_width` where both require a
+    /// different amount of digits for their representation.
+    ///
+    /// The least significant digits are kept and new digits are set to
+    /// `fill`. Heap-allocated digits are resized by reallocating the existing
+    /// buffer instead of allocating a new one and copying the digits over,
+    /// which allows the allocator to shrink or grow the buffer inplace.
+    fn resize_digits(&mut self, target_width: BitWidth, fill: Digit) {
+        let actual_req_digits = self.len_digits();
+        let target_req_digits = target_width.required_digits();
+        debug_assert_ne!(actual_req_digits, target_req_digits);
+        let mut digits = match self.storage() {
+            Storage::Inl => {
+                let mut digits = Vec::with_capacity(target_req_digits);
+                digits.push(unsafe { self.data.inl });
+                digits
+            }
+            Storage::Ext => {
+                // Takes over the ownership of the digits of `self`, which is
+                // restored below before anything else can observe `self`.
+                let mut digits = unsafe {
+                    Vec::from_raw_parts(
+                        self.data.ext.as_ptr(),
+                        actual_req_digits,
+                        actual_req_digits,
+                    )
+                };
+                if target_req_digits > actual_req_digits {
+                    digits.reserve_exact(target_req_digits - actual_req_digits);
+                }
+                digits
+            }
+        };
+        digits.resize(target_req_digits, fill);
+        match target_width.storage() {
+            Storage::Inl => {
+                // The digits of `self` are deallocated when `digits` is dropped.
+                self.data.inl = digits[0];
+            }
+            Storage::Ext => {
+                let mut buffer = digits.into_boxed_slice();
+                self.data.ext = NonNull::new(buffer.as_mut_ptr())
+                    .expect("Boxed slices never return null pointers.");
+                mem::forget(buffer);
+            }
+        }
+        self.len = target_width;
+        self.clear_unused_bits();
+    }
+
     /// Tries to truncate this `ApInt` inplace to the given `target_width`
     /// and returns the result.
     ///
@@ -183,30 +243,389 @@ impl ApInt {
                 );
             self.len = target_width;
         } else {
-            // We need to copy the digits for a correct truncation, here!
+            // We need to shrink the digits for a correct truncation, here!
             //
             // For example this could be a truncation from `196` bits
             // to `100` bits. The former requires `3` digits whereas the
-            // latter requires only `2`. So allocation of a new sequence
-            // is required since no memory can be reused.
-            // The same applies to all bit widths that require a different
-            // amount of digits for their representation.
-            let mut truncated_copy = {
-                let req_digits = self.digits().take(target_req_digits);
-                ApInt::from_iter(req_digits).unwrap()
-            };
-            // We just truncated with digit precision, not with bit precision.
-            // The next step is to recursively truncate `truncated_digits`
-            // with bit precision.
-            // This will simply call the `then` branch of this method.
-            if truncated_copy.width() != target_width {
-                truncated_copy.truncate(target_width)?;
+            // latter requires only `2`. The existing buffer is shrunk
+            // which usually happens inplace without copying any digits.
+            self.resize_digits(target_width, Digit::ZERO);
+        }
+        Ok(())
+    }
+
+    // ========================================================================
+
+    /// Tries to truncate this `ApInt` to the given `target_width` using
+    /// **unsigned** interpretation and returns the result, clamped to the
+    /// unsigned maximum value of `target_width` if the value does not fit.
+    ///
+    /// # Note
+    ///
+    /// - This is useful for method chaining.
+    /// - For more details look into
+    ///   [`saturating_utruncate`](struct.ApInt.html#method.
+    ///   saturating_utruncate).
+    ///
+    /// # Errors
+    ///
+    /// - If the `target_width` is greater than the current width.
+    pub fn into_saturating_utruncate<W>(self, target_width: W) -> Result<ApInt>
+    where
+        W: Into<BitWidth>,
+    {
+        try_forward_bin_mut_impl(self, target_width, ApInt::saturating_utruncate)
+    }
+
+    /// Tries to truncate this `ApInt` inplace to the given `target_width` using
+    /// **unsigned** interpretation, clamping it to the unsigned maximum value
+    /// of `target_width` if any truncated bit was set.
+    ///
+    /// # Note
+    ///
+    /// - This is a no-op if `self.width()` and `target_width` are equal.
+    /// - This operation is inplace as long as `self.width()` and `target_width`
+    ///   require the same amount of digits for their representation.
+    ///
+    /// # Errors
+    ///
+    /// - If the `target_width` is greater than the current width.
+    pub fn saturating_utruncate<W>(&mut self, target_width: W) -> Result<()>
+    where
+        W: Into<BitWidth>,
+    {
+        let target_width = target_width.into();
+        let overflow = self.any_bit_in_range_differs(
+            target_width.to_usize()..self.width().to_usize(),
+            false,
+        );
+        self.truncate(target_width)?;
+        if overflow {
+            self.set_all();
+        }
+        Ok(())
+    }
+
+    /// Tries to truncate this `ApInt` to the given `target_width` using
+    /// **signed** interpretation and returns the result, clamped to the signed
+    /// minimum or maximum value of `target_width` if the value does not fit.
+    ///
+    /// # Note
+    ///
+    /// - This is useful for method chaining.
+    /// - For more details look into
+    ///   [`saturating_struncate`](struct.ApInt.html#method.
+    ///   saturating_struncate).
+    ///
+    /// # Errors
+    ///
+    /// - If the `target_width` is greater than the current width.
+    pub fn into_saturating_struncate<W>(self, target_width: W) -> Result<ApInt>
+    where
+        W: Into<BitWidth>,
+    {
+        try_forward_bin_mut_impl(self, target_width, ApInt::saturating_struncate)
+    }
+
+    /// Tries to truncate this `ApInt` inplace to the given `target_width` using
+    /// **signed** interpretation, clamping it to the signed minimum or maximum
+    /// value of `target_width` if it does not fit.
+    ///
+    /// # Note
+    ///
+    /// - This is a no-op if `self.width()` and `target_width` are equal.
+    /// - This operation is inplace as long as `self.width()` and `target_width`
+    ///   require the same amount of digits for their representation.
+    ///
+    /// # Errors
+    ///
+    /// - If the `target_width` is greater than the current width.
+    pub fn saturating_struncate<W>(&mut self, target_width: W) -> Result<()>
+    where
+        W: Into<BitWidth>,
+    {
+        let target_width = target_width.into();
+        let negative = self.msb();
+        // the value fits if all truncated bits and the new sign bit are equal to
+        // the old sign bit
+        let overflow = self.any_bit_in_range_differs(
+            (target_width.to_usize() - 1)..self.width().to_usize(),
+            negative,
+        );
+        self.truncate(target_width)?;
+        if overflow {
+            self.saturate_signed(negative);
+        }
+        Ok(())
+    }
+
+    // ========================================================================
+
+    /// Returns `true` if the **unsigned** value of this `ApInt` is
+    /// representable with the given `width`.
+    ///
+    /// **Note:** This does **not** allocate memory.
+    pub fn ufits_in<W>(&self, width: W) -> bool
+    where
+        W: Into<BitWidth>,
+    {
+        self.width().to_usize() - self.leading_zeros() <= width.into().to_usize()
+    }
+
+    /// Returns `true` if the **signed** value of this `ApInt` is
+    /// representable with the given `width`.
+    ///
+    /// **Note:** This does **not** allocate memory.
+    pub fn sfits_in<W>(&self, width: W) -> bool
+    where
+        W: Into<BitWidth>,
+    {
+        // all bits from the sign bit of `width` upwards must be redundant
+        // copies of the sign bit
+        let width = width.into().to_usize();
+        width >= self.width().to_usize()
+            || !self.any_bit_in_range_differs(
+                (width - 1)..self.width().to_usize(),
+                self.msb(),
+            )
+    }
+
+    /// Tries to truncate a copy of this `ApInt` to the given `target_width`
+    /// and returns it, or returns `None` if the truncation would change the
+    /// **unsigned** value.
+    ///
+    /// **Note:** This does **not** allocate memory if `None` is returned.
+    ///
+    /// # Errors
+    ///
+    /// - If the `target_width` is greater than the current width.
+    pub fn try_truncate<W>(&self, target_width: W) -> Result<Option<ApInt>>
+    where
+        W: Into<BitWidth>,
+    {
+        let target_width = target_width.into();
+        if target_width > self.width() {
+            return Error::truncation_bitwidth_too_large(target_width, self.width()).into()
+        }
+        if !self.ufits_in(target_width) {
+            return Ok(None)
+        }
+        self.clone().into_truncate(target_width).map(Some)
+    }
+
+    /// Tries to truncate a copy of this `ApInt` to the given `target_width`
+    /// and returns it, or returns `None` if the truncation would change the
+    /// **signed** value.
+    ///
+    /// **Note:** This does **not** allocate memory if `None` is returned.
+    ///
+    /// # Errors
+    ///
+    /// - If the `target_width` is greater than the current width.
+    pub fn try_struncate<W>(&self, target_width: W) -> Result<Option<ApInt>>
+    where
+        W: Into<BitWidth>,
+    {
+        let target_width = target_width.into();
+        if target_width > self.width() {
+            return Error::truncation_bitwidth_too_large(target_width, self.width()).into()
+        }
+        if !self.sfits_in(target_width) {
+            return Ok(None)
+        }
+        self.clone().into_truncate(target_width).map(Some)
+    }
+
+    // ========================================================================
+
+    /// Returns the `width` bits of `self` starting at the bit position `pos`
+    /// as an `ApInt`, where `pos + width` must not exceed the bit width of
+    /// `self`.
+    ///
+    /// If `pos` is a multiple of the digit size this copies whole digits.
+    fn extract_bits(&self, pos: usize, width: BitWidth) -> ApInt {
+        let digits = self.as_digit_slice();
+        let (start, shift) = (pos / Digit::BITS, pos % Digit::BITS);
+        let extracted = (start..(start + width.required_digits())).map(|i| {
+            if shift == 0 {
+                digits[i]
+            } else {
+                let next = digits.get(i + 1).cloned().unwrap_or(Digit::ZERO);
+                (digits[i] >> shift) | (next << (Digit::BITS - shift))
             }
-            *self = truncated_copy;
+        });
+        ApInt::from_iter(extracted)
+            .and_then(|extracted| extracted.into_truncate(width))
+            .unwrap()
+    }
+
+    /// Returns the bits of `self` starting at the bit position `pos` as an
+    /// `ApInt` with a bit width of `self.width() - pos`, where `pos` is
+    /// greater than zero and less than the bit width of `self`.
+    fn high_bits_from(&self, pos: usize) -> ApInt {
+        self.extract_bits(pos, BitWidth::new(self.width().to_usize() - pos).unwrap())
+    }
+
+    /// Checks that `pos` splits `self` into two parts of non-zero bit widths.
+    fn verify_split_pos(&self, pos: BitPos) -> Result<()> {
+        if pos.to_usize() > self.width().to_usize() {
+            return Error::invalid_bit_access(pos, self.width()).into()
+        }
+        if pos.to_usize() == 0 || pos.to_usize() == self.width().to_usize() {
+            return Error::invalid_zero_bitwidth()
+                .with_annotation(format!(
+                    "Cannot split an `ApInt` with a width of {:?} bits at bit position \
+                     {:?} since that would result in a part with a bit width of zero.",
+                    self.width().to_usize(),
+                    pos.to_usize()
+                ))
+                .into()
         }
         Ok(())
     }
 
+    /// Splits this `ApInt` at the bit position `pos` and returns the low
+    /// part with a bit width of `pos` and the high part with a bit width of
+    /// `self.width() - pos`, in that order.
+    ///
+    /// # Note
+    ///
+    /// - The low part reuses the memory of `self`.
+    /// - If `pos` is a multiple of the digit size this copies whole digits.
+    ///
+    /// # Errors
+    ///
+    /// - If `pos` is zero or not less than the bit width of `self`, since that
+    ///   would result in a part with a bit width of zero.
+    pub fn split_at<P>(mut self, pos: P) -> Result<(ApInt, ApInt)>
+    where
+        P: Into<BitPos>,
+    {
+        let pos = pos.into();
+        self.verify_split_pos(pos)?;
+        let high = self.high_bits_from(pos.to_usize());
+        self.truncate(pos.to_usize())?;
+        Ok((self, high))
+    }
+
+    /// Returns the low part of this `ApInt` below the bit position `pos` with
+    /// a bit width of `pos` and the high part with a bit width of
+    /// `self.width() - pos`, in that order.
+    ///
+    /// # Note
+    ///
+    /// - If `pos` is a multiple of the digit size this copies whole digits.
+    ///
+    /// # Errors
+    ///
+    /// - If `pos` is zero or not less than the bit width of `self`, since that
+    ///   would result in a part with a bit width of zero.
+    pub fn split_at_ref<P>(&self, pos: P) -> Result<(ApInt, ApInt)>
+    where
+        P: Into<BitPos>,
+    {
+        let pos = pos.into();
+        self.verify_split_pos(pos)?;
+        let low_width = BitWidth::new(pos.to_usize()).unwrap();
+        let low = ApInt::from_iter(
+            self.as_digit_slice()[..low_width.required_digits()]
+                .iter()
+                .cloned(),
+        )
+        .and_then(|low| low.into_truncate(low_width))?;
+        Ok((low, self.high_bits_from(pos.to_usize())))
+    }
+
+    // ========================================================================
+
+    /// Concatenates `hi` and `lo` into an `ApInt` with a bit width of
+    /// `hi.width() + lo.width()` that has `lo` in its low bits and `hi` in its
+    /// high bits.
+    ///
+    /// This is the inverse of `split_at` at the bit position `lo.width()`.
+    pub fn concat(hi: &ApInt, lo: &ApInt) -> ApInt {
+        ApInt::concat_all(&[hi, lo]).unwrap()
+    }
+
+    /// Concatenates all `parts` into a single `ApInt` whose bit width is the
+    /// sum of the bit widths of all `parts`.
+    ///
+    /// Like Verilog's concatenation the first part ends up in the most
+    /// significant bits and the last part in the least significant bits.
+    ///
+    /// # Errors
+    ///
+    /// - If `parts` is empty.
+    pub fn concat_all(parts: &[&ApInt]) -> Result<ApInt> {
+        let total_width = parts.iter().map(|part| part.width().to_usize()).sum();
+        if total_width == 0 {
+            return Error::invalid_zero_bitwidth()
+                .with_annotation("Cannot concatenate an empty sequence of `ApInt`s.")
+                .into()
+        }
+        let mut result = ApInt::zero(BitWidth::new(total_width)?);
+        let mut offset = 0;
+        for part in parts.iter().rev() {
+            or_digits_at(result.as_digit_slice_mut(), part.as_digit_slice(), offset);
+            offset += part.width().to_usize();
+        }
+        Ok(result)
+    }
+
+    // ========================================================================
+
+    /// Splits this `ApInt` into chunks with a bit width of `chunk_width` each
+    /// and returns them, starting with the least significant chunk.
+    ///
+    /// # Note
+    ///
+    /// - Chunks with a bit width that divides the digit size are extracted by
+    ///   shifting and masking a single digit.
+    /// - Chunks with a bit width that is a multiple of the digit size are
+    ///   copies of the respective digits.
+    ///
+    /// # Errors
+    ///
+    /// - If the bit width of `self` is not a multiple of `chunk_width`.
+    pub fn to_chunks<W>(&self, chunk_width: W) -> Result<Vec<ApInt>>
+    where
+        W: Into<BitWidth>,
+    {
+        let chunk_width = chunk_width.into();
+        let (width, chunk) = (self.width().to_usize(), chunk_width.to_usize());
+        if width % chunk != 0 {
+            return Error::unmatching_bitwidths(self.width(), chunk_width)
+                .with_annotation(format!(
+                    "Cannot split an `ApInt` with a width of {:?} bits into chunks of \
+                     {:?} bits since it is not a multiple of the chunk width.",
+                    width, chunk
+                ))
+                .into()
+        }
+        Ok((0..(width / chunk))
+            .map(|i| self.extract_bits(i * chunk, chunk_width))
+            .collect())
+    }
+
+    /// Concatenates `chunks` into a single `ApInt`, where the first chunk ends
+    /// up in the least significant bits.
+    ///
+    /// This is the inverse of `to_chunks`.
+    ///
+    /// # Errors
+    ///
+    /// - If `chunks` is empty.
+    /// - If `chunks` have unmatching bit widths.
+    pub fn from_chunks(chunks: &[ApInt]) -> Result<ApInt> {
+        if let Some(first) = chunks.first() {
+            if let Some(chunk) = chunks.iter().find(|c| c.width() != first.width()) {
+                return Error::unmatching_bitwidths(first.width(), chunk.width()).into()
+            }
+        }
+        let parts = chunks.iter().rev().collect::<Vec<_>>();
+        ApInt::concat_all(&parts)
+    }
+
     // ========================================================================
 
     /// Tries to zero-extend this `ApInt` inplace to the given `target_width`
@@ -275,19 +694,11 @@ impl ApInt {
             // and we are done.
             self.len = target_width;
         } else {
-            // In this case we cannot reuse the consumed `ApInt`'s heap memory but
-            // must allocate a new buffer that fits for the required amount of digits
-            // for the target width. Also we need to `memcpy` the digits of the
-            // extended `ApInt` to the newly allocated buffer.
-            use core::iter;
+            // In this case the buffer of the consumed `ApInt` has to grow to fit
+            // the required amount of digits for the target width. The additional
+            // digits are zeroed.
             assert!(target_req_digits > actual_req_digits);
-            let additional_digits = target_req_digits - actual_req_digits;
-            let extended_clone = ApInt::from_iter(
-                self.digits()
-                    .chain(iter::repeat(Digit::ZERO).take(additional_digits)),
-            )
-            .and_then(|apint| apint.into_truncate(target_width))?;
-            *self = extended_clone;
+            self.resize_digits(target_width, Digit::ZERO);
         }
         Ok(())
     }
@@ -373,13 +784,10 @@ impl ApInt {
             }
             self.clear_unused_bits();
         } else {
-            // In this case we cannot reuse the consumed `ApInt`'s heap memory but
-            // must allocate a new buffer that fits for the required amount of digits
-            // for the target width. Also we need to `memcpy` the digits of the
-            // extended `ApInt` to the newly allocated buffer.
-            use core::iter;
+            // In this case the buffer of the consumed `ApInt` has to grow to fit
+            // the required amount of digits for the target width. The additional
+            // digits are filled with `1`.
             assert!(target_req_digits > actual_req_digits);
-            let additional_digits = target_req_digits - actual_req_digits;
 
             // Fill most-significant-digit of `self` with `1` starting from its
             // most-significant bit.
@@ -387,15 +795,7 @@ impl ApInt {
                 self.most_significant_digit_mut()
                     .sign_extend_from(excess_width)?;
             }
-
-            let extended_copy = ApInt::from_iter(
-                self.digits()
-                    .chain(iter::repeat(Digit::ONES).take(additional_digits)),
-            )
-            .and_then(|apint| apint.into_truncate(target_width))?;
-
-            self.clear_unused_bits();
-            *self = extended_copy;
+            self.resize_digits(target_width, Digit::ONES);
         }
 
         Ok(())
@@ -495,6 +895,7 @@ impl ApInt {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::apint::test_utils::test_rng;
 
     fn test_apints() -> impl Iterator<Item = ApInt> {
         vec![
@@ -616,6 +1017,402 @@ mod tests {
         fn equal_to_assign() {}
     }
 
+    mod chunks {
+        use super::*;
+
+        #[test]
+        fn round_trip() {
+            let mut rng = test_rng();
+            let w1024 = BitWidth::new(1024).unwrap();
+            for _ in 0..10 {
+                let x = ApInt::random_with_width_using(w1024, &mut rng);
+                for &chunk_width in &[1, 8, 16, 64, 128, 256, 1024] {
+                    let chunks = x.to_chunks(chunk_width).unwrap();
+                    assert_eq!(chunks.len(), 1024 / chunk_width);
+                    for (i, chunk) in chunks.iter().enumerate() {
+                        let expected = x
+                            .clone()
+                            .into_wrapping_lshr(i * chunk_width)
+                            .unwrap()
+                            .into_truncate(chunk_width)
+                            .unwrap();
+                        assert_eq!(chunk, &expected);
+                    }
+                    assert_eq!(ApInt::from_chunks(&chunks).unwrap(), x);
+                }
+                // 17 bit chunks need a width that is a multiple of 17
+                let y = x.clone().into_truncate(17 * 60).unwrap();
+                let chunks = y.to_chunks(17).unwrap();
+                assert_eq!(chunks.len(), 60);
+                assert_eq!(ApInt::from_chunks(&chunks).unwrap(), y);
+                assert!(x.to_chunks(17).is_err());
+            }
+        }
+
+        #[test]
+        fn small() {
+            let x = ApInt::from_u32(0x1234_5678);
+            assert_eq!(
+                x.to_chunks(8).unwrap(),
+                vec![
+                    ApInt::from_u8(0x78),
+                    ApInt::from_u8(0x56),
+                    ApInt::from_u8(0x34),
+                    ApInt::from_u8(0x12)
+                ]
+            );
+            assert_eq!(x.to_chunks(32).unwrap(), vec![x.clone()]);
+            assert!(x.to_chunks(24).is_err());
+            assert!(ApInt::from_chunks(&[]).is_err());
+            assert!(ApInt::from_chunks(&[ApInt::from_u8(1), ApInt::from_u16(1)]).is_err());
+        }
+    }
+
+    mod concat {
+        use super::*;
+
+        #[test]
+        fn split_round_trip() {
+            let x = ApInt::from([
+                0x0123_4567_89ab_cdef_u64,
+                0xfedc_ba98_7654_3210,
+                0xdead_beef_cafe_babe,
+                0x0f0f_0f0f_f0f0_f0f0,
+            ]);
+            for &width in &[2, 64, 65, 100, 128, 200, 256] {
+                let x = x.clone().into_truncate(width).unwrap();
+                for pos in 1..width {
+                    let (low, high) = x.split_at_ref(pos).unwrap();
+                    assert_eq!(ApInt::concat(&high, &low), x);
+                    assert_eq!(ApInt::concat_all(&[&high, &low]).unwrap(), x);
+                }
+            }
+        }
+
+        #[test]
+        fn concat_all() {
+            let parts = [
+                ApInt::from_bool(true),
+                ApInt::from_u8(0xab),
+                ApInt::from_u64(0x0123_4567_89ab_cdef),
+                ApInt::zero(BitWidth::new(3).unwrap()),
+                ApInt::from_u16(0xffff),
+            ];
+            let refs = parts.iter().collect::<Vec<_>>();
+            let result = ApInt::concat_all(&refs).unwrap();
+            assert_eq!(result.width(), BitWidth::new(1 + 8 + 64 + 3 + 16).unwrap());
+            let mut expected = ApInt::zero(result.width());
+            let mut offset = 0;
+            for part in parts.iter().rev() {
+                let widened = part.clone().into_zero_extend(result.width()).unwrap();
+                expected
+                    .bitor_assign(&widened.into_wrapping_shl(offset).unwrap())
+                    .unwrap();
+                offset += part.width().to_usize();
+            }
+            assert_eq!(result, expected);
+            assert_eq!(ApInt::concat_all(&[&parts[2]]).unwrap(), parts[2]);
+            assert!(ApInt::concat_all(&[]).is_err());
+        }
+    }
+
+    mod split_at {
+        use super::*;
+        use crate::errors::ErrorKind;
+
+        /// Splits `x` at `pos` with the reference shifting approach.
+        fn split_reference(x: &ApInt, pos: usize) -> (ApInt, ApInt) {
+            let low = x.clone().into_truncate(pos).unwrap();
+            let high = x
+                .clone()
+                .into_wrapping_lshr(pos)
+                .unwrap()
+                .into_truncate(x.width().to_usize() - pos)
+                .unwrap();
+            (low, high)
+        }
+
+        #[test]
+        fn against_shifts() {
+            let x = ApInt::from([
+                0x0123_4567_89ab_cdef_u64,
+                0xfedc_ba98_7654_3210,
+                0xdead_beef_cafe_babe,
+                0x0f0f_0f0f_f0f0_f0f0,
+                0x1111_2222_3333_4444,
+                0x5555_6666_7777_8888,
+                0x9999_aaaa_bbbb_cccc,
+                0xdddd_eeee_ffff_0000,
+            ]);
+            for &width in &[2, 64, 65, 100, 128, 200, 511, 512] {
+                let x = x.clone().into_truncate(width).unwrap();
+                for pos in 1..width {
+                    let expected = split_reference(&x, pos);
+                    assert_eq!(x.split_at_ref(pos).unwrap(), expected);
+                    assert_eq!(x.clone().split_at(pos).unwrap(), expected);
+                }
+            }
+        }
+
+        #[test]
+        fn lanes() {
+            let x = ApInt::from([4u64, 3, 2, 1]);
+            let (low, high) = x.split_at(128).unwrap();
+            assert_eq!(low, ApInt::from([2u64, 1]));
+            assert_eq!(high, ApInt::from([4u64, 3]));
+            let (low, high) = low.split_at(64).unwrap();
+            assert_eq!(low, ApInt::from(1u64));
+            assert_eq!(high, ApInt::from(2u64));
+        }
+
+        #[test]
+        fn invalid_pos() {
+            let x = ApInt::from(42u32);
+            assert_eq!(
+                x.split_at_ref(0).unwrap_err().kind(),
+                &ErrorKind::InvalidBitWidth(0)
+            );
+            assert_eq!(
+                x.clone().split_at(32).unwrap_err().kind(),
+                &ErrorKind::InvalidBitWidth(0)
+            );
+            assert_eq!(
+                x.split_at_ref(33).unwrap_err().kind(),
+                &ErrorKind::InvalidBitAccess {
+                    pos: BitPos::from(33),
+                    width: BitWidth::w32()
+                }
+            );
+            assert!(ApInt::from_bool(true).split_at_ref(1).is_err());
+        }
+    }
+
+    mod try_truncate {
+        use super::*;
+
+        #[test]
+        fn exhaustive_16() {
+            for x in i16::MIN..=i16::MAX {
+                let x_ap = ApInt::from_i16(x);
+                for to in 1..=16 {
+                    let to = BitWidth::new(to).unwrap();
+                    let truncated = x_ap.clone().into_truncate(to).unwrap();
+                    let ufits = truncated.clone().into_zero_extend(16).unwrap() == x_ap;
+                    let sfits = truncated.clone().into_sign_extend(16).unwrap() == x_ap;
+                    assert_eq!(x_ap.ufits_in(to), ufits);
+                    assert_eq!(x_ap.sfits_in(to), sfits);
+                    assert_eq!(
+                        x_ap.try_truncate(to),
+                        Ok(if ufits { Some(truncated.clone()) } else { None })
+                    );
+                    assert_eq!(
+                        x_ap.try_struncate(to),
+                        Ok(if sfits { Some(truncated) } else { None })
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn large() {
+            let w300 = BitWidth::new(300).unwrap();
+            let x = ApInt::from_i16(-300).into_sign_extend(w300).unwrap();
+            assert!(x.sfits_in(BitWidth::w16()));
+            assert!(!x.sfits_in(BitWidth::new(9).unwrap()));
+            assert!(x.sfits_in(BitWidth::new(10).unwrap()));
+            assert!(!x.ufits_in(BitWidth::new(299).unwrap()));
+            assert_eq!(
+                x.try_struncate(BitWidth::w16()),
+                Ok(Some(ApInt::from_i16(-300)))
+            );
+            assert_eq!(x.try_truncate(BitWidth::w16()), Ok(None));
+            assert_eq!(x.try_struncate(BitWidth::w8()), Ok(None));
+            let y = ApInt::from_u64(u64::MAX).into_zero_extend(w300).unwrap();
+            assert!(y.ufits_in(BitWidth::w64()));
+            assert!(!y.sfits_in(BitWidth::w64()));
+            assert!(y.sfits_in(BitWidth::new(65).unwrap()));
+            assert!(y.ufits_in(BitWidth::new(1000).unwrap()));
+            assert_eq!(
+                y.try_truncate(BitWidth::w64()),
+                Ok(Some(ApInt::from(u64::MAX)))
+            );
+            assert_eq!(y.try_struncate(BitWidth::w64()), Ok(None));
+            assert!(y.try_truncate(BitWidth::new(301).unwrap()).is_err());
+            assert!(y.try_struncate(BitWidth::new(301).unwrap()).is_err());
+        }
+    }
+
+    mod saturating_truncate {
+        use super::*;
+
+        #[test]
+        fn unsigned() {
+            let widths = [
+                (16, 8),
+                (64, 7),
+                (128, 64),
+                (130, 100),
+                (200, 128),
+                (200, 65),
+            ];
+            for &(from, to) in &widths {
+                let (from, to) =
+                    (BitWidth::new(from).unwrap(), BitWidth::new(to).unwrap());
+                let max = ApInt::unsigned_max_value(to);
+                let at_boundary = max.clone().into_zero_extend(from).unwrap();
+                let past_boundary = at_boundary.clone().into_wrapping_inc();
+                assert_eq!(at_boundary.into_saturating_utruncate(to), Ok(max.clone()));
+                assert_eq!(past_boundary.into_saturating_utruncate(to), Ok(max.clone()));
+                assert_eq!(
+                    ApInt::all_set(from).into_saturating_utruncate(to),
+                    Ok(max.clone())
+                );
+                let mut high = ApInt::zero(from);
+                high.set_msb();
+                assert_eq!(high.into_saturating_utruncate(to), Ok(max.clone()));
+                let one = ApInt::one(from);
+                assert_eq!(one.into_saturating_utruncate(to), Ok(ApInt::one(to)));
+                assert!(ApInt::zero(to).into_saturating_utruncate(from).is_err());
+            }
+        }
+
+        #[test]
+        fn signed() {
+            let widths = [
+                (16, 8),
+                (64, 7),
+                (128, 64),
+                (130, 100),
+                (200, 128),
+                (200, 65),
+                (8, 1),
+            ];
+            for &(from, to) in &widths {
+                let (from, to) =
+                    (BitWidth::new(from).unwrap(), BitWidth::new(to).unwrap());
+                let min = ApInt::signed_min_value(to);
+                let max = ApInt::signed_max_value(to);
+                let min_ext = min.clone().into_sign_extend(from).unwrap();
+                let max_ext = max.clone().into_sign_extend(from).unwrap();
+                assert_eq!(
+                    min_ext.clone().into_saturating_struncate(to),
+                    Ok(min.clone())
+                );
+                assert_eq!(
+                    max_ext.clone().into_saturating_struncate(to),
+                    Ok(max.clone())
+                );
+                assert_eq!(
+                    min_ext.into_wrapping_dec().into_saturating_struncate(to),
+                    Ok(min.clone())
+                );
+                assert_eq!(
+                    max_ext.into_wrapping_inc().into_saturating_struncate(to),
+                    Ok(max.clone())
+                );
+                assert_eq!(
+                    ApInt::signed_min_value(from).into_saturating_struncate(to),
+                    Ok(min.clone())
+                );
+                assert_eq!(
+                    ApInt::signed_max_value(from).into_saturating_struncate(to),
+                    Ok(max.clone())
+                );
+                assert_eq!(
+                    ApInt::all_set(from).into_saturating_struncate(to),
+                    Ok(ApInt::all_set(to))
+                );
+                assert_eq!(
+                    ApInt::zero(from).into_saturating_struncate(to),
+                    Ok(ApInt::zero(to))
+                );
+            }
+        }
+
+        #[test]
+        fn exhaustive_16_to_8() {
+            for x in i16::MIN..=i16::MAX {
+                let x_ap = ApInt::from_i16(x);
+                let signed = x.max(i16::from(i8::MIN)).min(i16::from(i8::MAX)) as i8;
+                assert_eq!(
+                    x_ap.clone().into_saturating_struncate(BitWidth::w8()),
+                    Ok(ApInt::from_i8(signed))
+                );
+                let unsigned = (x as u16).min(u16::from(u8::MAX)) as u8;
+                assert_eq!(
+                    x_ap.into_saturating_utruncate(BitWidth::w8()),
+                    Ok(ApInt::from_u8(unsigned))
+                );
+            }
+        }
+    }
+
+    mod reuse_allocation {
+        use super::*;
+
+        fn digits_ptr(apint: &ApInt) -> *const Digit {
+            apint.as_digit_slice().as_ptr()
+        }
+
+        #[test]
+        fn same_digit_count() {
+            // all bit widths within `2 * Digit::BITS + 1..=3 * Digit::BITS` require
+            // three digits
+            let full = BitWidth::new(3 * Digit::BITS).unwrap();
+            let part = BitWidth::new(2 * Digit::BITS + 2).unwrap();
+            let a = ApInt::all_set(full).into_truncate(full.to_usize() - 2).unwrap();
+            let ptr = digits_ptr(&a);
+            let a = a.into_truncate(part).unwrap();
+            assert_eq!(digits_ptr(&a), ptr);
+            assert_eq!(a, ApInt::all_set(part));
+            let a = a.into_zero_extend(full).unwrap();
+            assert_eq!(digits_ptr(&a), ptr);
+            let low_ones = ApInt::one(full)
+                .into_wrapping_shl(part.to_usize())
+                .unwrap()
+                .into_wrapping_dec();
+            assert_eq!(a, low_ones);
+            let a = a
+                .into_truncate(2 * Digit::BITS + 1)
+                .unwrap()
+                .into_sign_extend(full)
+                .unwrap();
+            assert_eq!(digits_ptr(&a), ptr);
+            assert_eq!(a, ApInt::all_set(full));
+        }
+
+        #[test]
+        fn different_digit_count() {
+            let digits = [1u64, 2, 3, 4, 5, 6, 7, 8];
+            let a = ApInt::from(digits);
+            let a = a.into_truncate(256).unwrap();
+            assert_eq!(a, ApInt::from([5u64, 6, 7, 8]));
+            let a = a.into_truncate(100).unwrap();
+            assert_eq!(
+                a,
+                ApInt::from([0xf_ffff_ffff & 7_u64, 8])
+                    .into_truncate(100)
+                    .unwrap()
+            );
+            let a = a.into_truncate(64).unwrap();
+            assert_eq!(a, ApInt::from(8u64));
+            let a = a.into_zero_extend(200).unwrap();
+            assert_eq!(a, ApInt::from([0u64, 0, 0, 8]).into_truncate(200).unwrap());
+            let a = a.into_truncate(3).unwrap();
+            assert_eq!(a, ApInt::zero(BitWidth::new(3).unwrap()));
+            let a = ApInt::from_i8(-2).into_sign_extend(300).unwrap();
+            assert_eq!(a, ApInt::from_i8(-2).into_sign_extend(300).unwrap());
+            assert_eq!(a.count_zeros(), 1);
+            let a = a
+                .into_sign_extend(1000)
+                .unwrap()
+                .into_truncate(129)
+                .unwrap();
+            assert_eq!(a.count_zeros(), 1);
+            assert_eq!(a.width(), BitWidth::new(129).unwrap());
+        }
+    }
+
     mod into_truncate {
         // use super::*;
 
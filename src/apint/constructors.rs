@@ -1,17 +1,32 @@
 use crate::{
-    apint::ApIntData,
-    mem::vec::Vec,
+    apint::{
+        utils::{
+            digit_range_mask,
+            or_digits_at,
+        },
+        ApIntData,
+    },
+    mem::{
+        format,
+        vec::Vec,
+    },
     storage::Storage,
     ApInt,
     BitWidth,
     Digit,
+    DigitRepr,
     Error,
     Result,
+    Width,
 };
 
 use smallvec::SmallVec;
 
-use core::ptr::NonNull;
+use core::{
+    iter,
+    ops::Range,
+    ptr::NonNull,
+};
 
 impl ApInt {
     /// Deallocates memory that may be allocated by this `ApInt`.
@@ -47,7 +62,8 @@ impl ApInt {
     ///
     /// # Panics
     ///
-    /// - If the given `width` represents a `BitWidth` larger than `64` bits.
+    /// - If the given `width` represents a `BitWidth` larger than `Digit::BITS`
+    ///   bits.
     #[inline]
     pub(in crate::apint) fn new_inl(width: BitWidth, digit: Digit) -> ApInt {
         assert_eq!(width.storage(), Storage::Inl);
@@ -67,7 +83,7 @@ impl ApInt {
     /// # Panics
     ///
     /// - If the given `width` represents a `BitWidth` smaller than or equal to
-    ///   `64` bits.
+    ///   `Digit::BITS` bits.
     pub(in crate::apint) unsafe fn new_ext(
         width: BitWidth,
         ext_ptr: *mut Digit,
@@ -88,7 +104,7 @@ impl ApInt {
     /// are the same bits, so that the signed interpretation of `ApInt`s
     /// take a value of -1 for `ApInt::from_bool(true)`
     pub fn from_bool(bit: bool) -> ApInt {
-        ApInt::new_inl(BitWidth::w1(), Digit(bit as u64))
+        ApInt::new_inl(BitWidth::w1(), Digit(bit as DigitRepr))
     }
 
     /// Creates a new `ApInt` from a given `i8` value with a bit-width of 8.
@@ -100,7 +116,7 @@ impl ApInt {
     /// Creates a new `ApInt` from a given `u8` value with a bit-width of 8.
     #[inline]
     pub fn from_u8(val: u8) -> ApInt {
-        ApInt::new_inl(BitWidth::w8(), Digit(u64::from(val)))
+        ApInt::from_u64_limbs(BitWidth::w8(), iter::once(u64::from(val)))
     }
 
     /// Creates a new `ApInt` from a given `i16` value with a bit-width of 16.
@@ -112,7 +128,7 @@ impl ApInt {
     /// Creates a new `ApInt` from a given `u16` value with a bit-width of 16.
     #[inline]
     pub fn from_u16(val: u16) -> ApInt {
-        ApInt::new_inl(BitWidth::w16(), Digit(u64::from(val)))
+        ApInt::from_u64_limbs(BitWidth::w16(), iter::once(u64::from(val)))
     }
 
     /// Creates a new `ApInt` from a given `i32` value with a bit-width of 32.
@@ -124,7 +140,7 @@ impl ApInt {
     /// Creates a new `ApInt` from a given `u32` value with a bit-width of 32.
     #[inline]
     pub fn from_u32(val: u32) -> ApInt {
-        ApInt::new_inl(BitWidth::w32(), Digit(u64::from(val)))
+        ApInt::from_u64_limbs(BitWidth::w32(), iter::once(u64::from(val)))
     }
 
     /// Creates a new `ApInt` from a given `i64` value with a bit-width of 64.
@@ -136,7 +152,7 @@ impl ApInt {
     /// Creates a new `ApInt` from a given `u64` value with a bit-width of 64.
     #[inline]
     pub fn from_u64(val: u64) -> ApInt {
-        ApInt::new_inl(BitWidth::w64(), Digit(val))
+        ApInt::from_u64_limbs(BitWidth::w64(), iter::once(val))
     }
 
     /// Creates a new `ApInt` from a given `i128` value with a bit-width of 128.
@@ -147,9 +163,95 @@ impl ApInt {
 
     /// Creates a new `ApInt` from a given `u128` value with a bit-width of 128.
     pub fn from_u128(val: u128) -> ApInt {
-        let hi = (val >> Digit::BITS) as u64;
-        let lo = (val & ((1u128 << 64) - 1)) as u64;
-        ApInt::from([hi, lo])
+        let hi = (val >> 64) as u64;
+        let lo = val as u64;
+        ApInt::from_u64_limbs(BitWidth::w128(), [lo, hi].iter().cloned())
+    }
+
+    /// Creates a new `ApInt` from a given `isize` value with a bit-width equal
+    /// to the pointer width of the target platform.
+    #[inline]
+    pub fn from_isize(val: isize) -> ApInt {
+        ApInt::from_usize(val as usize)
+    }
+
+    /// Creates a new `ApInt` from a given `usize` value with a bit-width equal
+    /// to the pointer width of the target platform.
+    #[inline]
+    pub fn from_usize(val: usize) -> ApInt {
+        #[cfg(target_pointer_width = "16")]
+        return ApInt::from_u16(val as u16);
+        #[cfg(target_pointer_width = "32")]
+        return ApInt::from_u32(val as u32);
+        #[cfg(target_pointer_width = "64")]
+        return ApInt::from_u64(val as u64);
+    }
+
+    /// Creates a new `ApInt` with the given `width` from the given 64-bit
+    /// `limbs` in little-endian order, i.e. `limbs[0]` holds the least
+    /// significant bits.
+    ///
+    /// # Errors
+    ///
+    /// - If the number of `limbs` is not exactly the number of 64-bit limbs
+    ///   required to represent `width`.
+    /// - If any of the unused high bits of the most significant limb is set.
+    pub fn from_u64_slice(width: BitWidth, limbs: &[u64]) -> Result<ApInt> {
+        let required_limbs = width.required_u64s();
+        if limbs.len() != required_limbs {
+            return Err(Error::unmatching_buffer_length(required_limbs, limbs.len())
+                .with_annotation(format!(
+                    "A bit width of {:?} requires exactly {} limbs.",
+                    width, required_limbs
+                )))
+        }
+        let excess_bits = width.to_usize() - (required_limbs - 1) * 64;
+        if excess_bits < 64 && limbs[required_limbs - 1] >> excess_bits != 0 {
+            return Err(Error::result_overflow(width).with_annotation(
+                "The most significant limb has bits set beyond the bit width.",
+            ))
+        }
+        Ok(ApInt::from_u64_limbs(width, limbs.iter().cloned()))
+    }
+
+    /// Creates a new `ApInt` with a bit width of `64 * limbs.len()` from the
+    /// given 64-bit `limbs` in little-endian order, i.e. `limbs[0]` holds the
+    /// least significant bits.
+    ///
+    /// # Errors
+    ///
+    /// - If `limbs` is empty.
+    pub fn from_le_limbs(limbs: &[u64]) -> Result<ApInt> {
+        ApInt::from_iter(limbs.iter().cloned().flat_map(Digit::split_u64))
+    }
+
+    /// Creates a new `ApInt` with a bit width of `64 * limbs.len()` from the
+    /// given 64-bit `limbs` in big-endian order, i.e. `limbs[0]` holds the
+    /// most significant bits.
+    ///
+    /// # Errors
+    ///
+    /// - If `limbs` is empty.
+    pub fn from_be_limbs(limbs: &[u64]) -> Result<ApInt> {
+        ApInt::from_iter(limbs.iter().rev().cloned().flat_map(Digit::split_u64))
+    }
+
+    /// Creates a new `ApInt` with the given `width` from the given 64-bit
+    /// `limbs` in little-endian order.
+    ///
+    /// Missing limbs are treated as zero, superfluous limbs and bits beyond
+    /// `width` are ignored.
+    pub(in crate::apint) fn from_u64_limbs<I>(width: BitWidth, limbs: I) -> ApInt
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        let mut result = ApInt::zero(width);
+        let digits = limbs.into_iter().flat_map(Digit::split_u64);
+        for (dst, src) in result.as_digit_slice_mut().iter_mut().zip(digits) {
+            *dst = src;
+        }
+        result.clear_unused_bits();
+        result
     }
 
     /// Creates a new `ApInt` from the given iterator over `Digit`s.
@@ -179,7 +281,7 @@ impl ApInt {
                     "We have already asserted that `digits.len()` must be at exactly \
                      `1`.",
                 );
-                Ok(ApInt::new_inl(BitWidth::w64(), first_and_only))
+                Ok(ApInt::new_inl(BitWidth::from(Digit::BITS), first_and_only))
             }
             n => {
                 use core::mem;
@@ -205,8 +307,10 @@ impl ApInt {
         if val.len() == 0 {
             None
         } else {
-            let buffer = val.into_iter().rev().map(Digit).collect::<Vec<Digit>>();
-            Some(ApInt::from_iter(buffer).unwrap())
+            Some(
+                ApInt::from_iter(val.into_iter().rev().flat_map(Digit::split_u64))
+                    .unwrap(),
+            )
         }
     }
 
@@ -286,6 +390,96 @@ impl ApInt {
         result.unset_msb();
         result
     }
+
+    /// Creates a new `ApInt` with the given bit width that has its `n` least
+    /// significant bits set and all other bits unset.
+    ///
+    /// # Errors
+    ///
+    /// - If `n` is greater than the given `width`.
+    pub fn low_ones(width: BitWidth, n: usize) -> Result<ApInt> {
+        ApInt::range_mask(width, 0..n)
+    }
+
+    /// Creates a new `ApInt` with the given bit width that has its `n` most
+    /// significant bits set and all other bits unset.
+    ///
+    /// # Errors
+    ///
+    /// - If `n` is greater than the given `width`.
+    pub fn high_ones(width: BitWidth, n: usize) -> Result<ApInt> {
+        let len = width.to_usize();
+        if n > len {
+            return Err(Error::invalid_bit_access(n, width).with_annotation(
+                "Cannot set more high bits than the bit width of the `ApInt`.",
+            ))
+        }
+        ApInt::range_mask(width, (len - n)..len)
+    }
+
+    /// Creates a new `ApInt` with the given bit width that has all bits within
+    /// the bit position range `lo..hi` set and all other bits unset.
+    ///
+    /// The mask is built in a single pass over the digits without
+    /// intermediate allocations.
+    ///
+    /// # Errors
+    ///
+    /// - If `range.end` is greater than the given `width`.
+    /// - If `range.start` is greater than `range.end`.
+    pub fn range_mask(width: BitWidth, range: Range<usize>) -> Result<ApInt> {
+        if range.end > width.to_usize() {
+            return Err(Error::invalid_bit_access(range.end, width).with_annotation(
+                "The end of the bit range of a mask must not exceed the bit width of \
+                 the `ApInt`.",
+            ))
+        }
+        if range.start > range.end {
+            return Err(
+                Error::invalid_bit_access(range.start, width).with_annotation(
+                    "The start of the bit range of a mask must not be greater than its \
+                     end.",
+                ),
+            )
+        }
+        let digits = (0..width.required_digits()).map(|i| digit_range_mask(i, &range));
+        Ok(ApInt::from_iter(digits)
+            .expect(
+                "Since `required_digits` always returns `1` or more required digits we \
+                 can safely assume that this operation never fails.",
+            )
+            .into_truncate(width)
+            .expect(
+                "Since `ApInt::from_iter` uses exactly `BitWidth::required_digits` \
+                 digits the resulting `ApInt` is never smaller than `width`.",
+            ))
+    }
+
+    /// Creates a new `ApInt` with the given bit width that repeats the given
+    /// `pattern` starting from the least significant bit.
+    ///
+    /// The last copy of `pattern` is truncated if `target_width` is not a
+    /// multiple of the bit width of `pattern`.
+    pub fn repeat(pattern: &ApInt, target_width: BitWidth) -> ApInt {
+        let pattern_width = pattern.width().to_usize();
+        if pattern_width.is_power_of_two() && pattern_width <= Digit::BITS {
+            // the pattern fits into a single repeated digit
+            let digit = (0..(Digit::BITS / pattern_width)).fold(Digit::ZERO, |acc, i| {
+                acc | (pattern.as_digit_slice()[0] << (i * pattern_width))
+            });
+            return ApInt::repeat_digit(target_width, digit)
+        }
+        let mut result = ApInt::zero(target_width);
+        for offset in (0..target_width.to_usize()).step_by(pattern_width) {
+            or_digits_at(
+                result.as_digit_slice_mut(),
+                pattern.as_digit_slice(),
+                offset,
+            );
+        }
+        result.clear_unused_bits();
+        result
+    }
 }
 
 impl From<bool> for ApInt {
@@ -365,40 +559,54 @@ impl From<i128> for ApInt {
     }
 }
 
-// TODO: when https://github.com/rust-lang/rustfmt/issues/4036 is fixed remove this
-#[rustfmt::skip]
-macro_rules! impl_from_array_for_apint {
-    ($n:expr) => {
-        impl From<[i64; $n]> for ApInt {
-            fn from(val: [i64; $n]) -> ApInt {
-                <Self as From<[u64; $n]>>::from(unsafe {
-                    ::core::mem::transmute::<[i64; $n], [u64; $n]>(val)
-                })
-            }
-        }
+impl From<usize> for ApInt {
+    #[inline]
+    fn from(val: usize) -> ApInt {
+        ApInt::from_usize(val)
+    }
+}
 
-        impl From<[u64; $n]> for ApInt {
-            fn from(val: [u64; $n]) -> ApInt {
-                let buffer = val.iter().rev().cloned().map(Digit).collect::<Vec<Digit>>();
-                assert_eq!(buffer.len(), $n);
-                ApInt::from_iter(buffer).expect(
-                    "We asserted that `buffer.len()` is exactly `$n` so we can expect \
-                     `ApInt::from_iter` to be successful.",
-                )
-            }
-        }
-    };
+impl From<isize> for ApInt {
+    #[inline]
+    fn from(val: isize) -> ApInt {
+        ApInt::from_isize(val)
+    }
+}
+
+/// Creates a new `ApInt` with a bit width of `64 * N` from the given array of
+/// 64-bit limbs in big-endian order, i.e. `val[0]` holds the most significant
+/// bits.
+///
+/// Use [`from_le_limbs`](struct.ApInt.html#method.from_le_limbs) for limbs in
+/// little-endian order.
+///
+/// # Panics
+///
+/// - If `N` is zero.
+impl<const N: usize> From<[u64; N]> for ApInt {
+    fn from(val: [u64; N]) -> ApInt {
+        ApInt::from_be_limbs(&val)
+            .expect("Cannot create an `ApInt` from an empty array of limbs.")
+    }
 }
 
-impl_from_array_for_apint!(2); // 128 bits
-impl_from_array_for_apint!(3); // 192 bits
-impl_from_array_for_apint!(4); // 256 bits
-impl_from_array_for_apint!(5); // 320 bits
-impl_from_array_for_apint!(6); // 384 bits
-impl_from_array_for_apint!(7); // 448 bits
-impl_from_array_for_apint!(8); // 512 bits
-impl_from_array_for_apint!(16); // 1024 bits
-impl_from_array_for_apint!(32); // 2048 bits
+/// Creates a new `ApInt` with a bit width of `64 * N` from the given array of
+/// 64-bit limbs in big-endian order, i.e. `val[0]` holds the most significant
+/// bits.
+///
+/// # Panics
+///
+/// - If `N` is zero.
+impl<const N: usize> From<[i64; N]> for ApInt {
+    fn from(val: [i64; N]) -> ApInt {
+        ApInt::from_iter(
+            val.iter()
+                .rev()
+                .flat_map(|&limb| Digit::split_u64(limb as u64)),
+        )
+        .expect("Cannot create an `ApInt` from an empty array of limbs.")
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -483,12 +691,8 @@ mod tests {
             let explicit_i8 = ApInt::from_i8(val as i8);
             let implicit_u8 = ApInt::from(val);
             let implicit_i8 = ApInt::from(val as i8);
-            let expected = ApInt {
-                len: BitWidth::w8(),
-                data: ApIntData {
-                    inl: Digit(u64::from(val)),
-                },
-            };
+            let expected =
+                ApInt::from_u64_slice(BitWidth::w8(), &[u64::from(val)]).unwrap();
             assert_eq!(explicit_u8, explicit_i8);
             assert_eq!(explicit_u8, implicit_i8);
             assert_eq!(explicit_u8, implicit_u8);
@@ -514,12 +718,8 @@ mod tests {
             let explicit_i16 = ApInt::from_i16(val as i16);
             let implicit_u16 = ApInt::from(val);
             let implicit_i16 = ApInt::from(val as i16);
-            let expected = ApInt {
-                len: BitWidth::w16(),
-                data: ApIntData {
-                    inl: Digit(u64::from(val)),
-                },
-            };
+            let expected =
+                ApInt::from_u64_slice(BitWidth::w16(), &[u64::from(val)]).unwrap();
             assert_eq!(explicit_u16, explicit_i16);
             assert_eq!(explicit_u16, implicit_i16);
             assert_eq!(explicit_u16, implicit_u16);
@@ -545,12 +745,8 @@ mod tests {
             let explicit_i32 = ApInt::from_i32(val as i32);
             let implicit_u32 = ApInt::from(val);
             let implicit_i32 = ApInt::from(val as i32);
-            let expected = ApInt {
-                len: BitWidth::w32(),
-                data: ApIntData {
-                    inl: Digit(u64::from(val)),
-                },
-            };
+            let expected =
+                ApInt::from_u64_slice(BitWidth::w32(), &[u64::from(val)]).unwrap();
             assert_eq!(explicit_u32, explicit_i32);
             assert_eq!(explicit_u32, implicit_i32);
             assert_eq!(explicit_u32, implicit_u32);
@@ -581,12 +777,8 @@ mod tests {
             let explicit_i64 = ApInt::from_i64(val as i64);
             let implicit_u64 = ApInt::from(val);
             let implicit_i64 = ApInt::from(val as i64);
-            let expected = ApInt {
-                len: BitWidth::w64(),
-                data: ApIntData {
-                    inl: Digit(u64::from(val)),
-                },
-            };
+            let expected =
+                ApInt::from_u64_slice(BitWidth::w64(), &[u64::from(val)]).unwrap();
             assert_eq!(explicit_u64, explicit_i64);
             assert_eq!(explicit_u64, implicit_i64);
             assert_eq!(explicit_u64, implicit_u64);
@@ -612,20 +804,13 @@ mod tests {
 
     #[test]
     fn from_w128() {
-        use crate::digit::{
-            Digit,
-            DigitRepr,
-        };
         for val in test_values_u128() {
             let explicit_u128 = ApInt::from_u128(val);
             let explicit_i128 = ApInt::from_i128(val as i128);
             let implicit_u128 = ApInt::from(val);
             let implicit_i128 = ApInt::from(val as i128);
-            let expected = ApInt::from_iter(vec![
-                Digit((val & u128::from(u64::max_value())) as DigitRepr),
-                Digit((val >> 64) as DigitRepr),
-            ])
-            .unwrap();
+            let expected =
+                ApInt::from_le_limbs(&[val as u64, (val >> 64) as u64]).unwrap();
             assert_eq!(explicit_u128, explicit_i128);
             assert_eq!(explicit_u128, implicit_i128);
             assert_eq!(explicit_u128, implicit_u128);
@@ -633,6 +818,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn from_pointer_sized() {
+        #[cfg(target_pointer_width = "64")]
+        let width = BitWidth::w64();
+        #[cfg(target_pointer_width = "32")]
+        let width = BitWidth::w32();
+        #[cfg(target_pointer_width = "16")]
+        let width = BitWidth::w16();
+        for &val in &[0, 1, 42, usize::MAX / 2, usize::MAX] {
+            let explicit = ApInt::from_usize(val);
+            assert_eq!(explicit.width(), width);
+            assert_eq!(explicit, ApInt::from(val));
+            assert_eq!(explicit.try_to_usize(), Ok(val));
+        }
+        for &val in &[0, 1, -1, isize::MIN, isize::MAX] {
+            let explicit = ApInt::from_isize(val);
+            assert_eq!(explicit.width(), width);
+            assert_eq!(explicit, ApInt::from(val));
+            assert_eq!(explicit, ApInt::from_usize(val as usize));
+            assert_eq!(explicit.try_to_isize(), Ok(val));
+        }
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(ApInt::from_isize(-2), ApInt::from_i64(-2));
+        #[cfg(target_pointer_width = "32")]
+        assert_eq!(ApInt::from_isize(-2), ApInt::from_i32(-2));
+    }
+
+    #[test]
+    fn from_bool_width() {
+        assert_eq!(ApInt::from(true).width(), BitWidth::w1());
+        assert_eq!(ApInt::from(false).width(), BitWidth::w1());
+        assert_eq!(ApInt::from(true).try_to_bool(), Ok(true));
+        assert_eq!(ApInt::from(false).try_to_bool(), Ok(false));
+    }
+
     #[test]
     fn zero() {
         assert_eq!(ApInt::zero(BitWidth::w1()), ApInt::from_bool(false));
@@ -748,7 +968,7 @@ mod tests {
             let w10 = BitWidth::new(10).unwrap();
             assert_eq!(
                 ApInt::signed_min_value(w10),
-                ApInt::new_inl(w10, Digit(0x0000_0000_0000_0200))
+                ApInt::from_u16(0x0200).into_truncate(w10).unwrap()
             )
         }
         {
@@ -800,8 +1020,293 @@ mod tests {
             let w10 = BitWidth::new(10).unwrap();
             assert_eq!(
                 ApInt::signed_max_value(w10),
-                ApInt::new_inl(w10, Digit(0x0000_0000_0000_01FF))
+                ApInt::from_u16(0x01FF).into_truncate(w10).unwrap()
             )
         }
     }
+
+    mod u64_slice {
+        use super::*;
+        use crate::ErrorKind;
+
+        #[test]
+        fn round_trip() {
+            for &(width, limbs) in &[
+                (1, &[1_u64][..]),
+                (20, &[0xf_1234]),
+                (64, &[0xfedc_ba98_7654_3210]),
+                (65, &[0xfedc_ba98_7654_3210, 1]),
+                (200, &[1, 2, 3, 0xab]),
+                (256, &[1, 2, 3, 0xffff_ffff_ffff_ffff]),
+            ] {
+                let width = BitWidth::new(width).unwrap();
+                let value = ApInt::from_u64_slice(width, limbs).unwrap();
+                assert_eq!(value.width(), width);
+                assert_eq!(value.to_u64_vec(), limbs.to_vec());
+            }
+        }
+
+        #[test]
+        #[cfg(not(any(apint_digit = "u32", apint_digit = "u8")))]
+        fn as_u64_slice() {
+            assert_eq!(ApInt::from_u8(42).as_u64_slice(), &[42]);
+            assert_eq!(ApInt::from([1_u64, 2, 3]).as_u64_slice(), &[3, 2, 1]);
+            assert_eq!(ApInt::from_i8(-1).as_u64_slice(), &[0xff]);
+        }
+
+        #[test]
+        fn unmatching_limb_count() {
+            let w65 = BitWidth::new(65).unwrap();
+            for limbs in &[&[][..], &[1], &[1, 0, 0]] {
+                assert_eq!(
+                    ApInt::from_u64_slice(w65, limbs).unwrap_err().kind(),
+                    &ErrorKind::UnmatchingBufferLength {
+                        expected: 2,
+                        actual: limbs.len()
+                    }
+                );
+            }
+        }
+
+        #[test]
+        fn excess_bits_set() {
+            let w65 = BitWidth::new(65).unwrap();
+            assert_eq!(
+                ApInt::from_u64_slice(w65, &[0, 2]).unwrap_err().kind(),
+                &ErrorKind::ResultOverflow(w65)
+            );
+            assert_eq!(
+                ApInt::from_u64_slice(BitWidth::w8(), &[0x100])
+                    .unwrap_err()
+                    .kind(),
+                &ErrorKind::ResultOverflow(BitWidth::w8())
+            );
+            assert!(
+                ApInt::from_u64_slice(BitWidth::w64(), &[0xffff_ffff_ffff_ffff]).is_ok()
+            );
+        }
+    }
+
+    mod limbs {
+        use super::*;
+        use crate::ErrorKind;
+
+        #[test]
+        fn from_array() {
+            let value = ApInt::from([3_u64, 2, 1]);
+            assert_eq!(value.width(), BitWidth::new(192).unwrap());
+            assert_eq!(value.to_u64_vec(), vec![1, 2, 3]);
+            assert_eq!(ApInt::from([42_u64]), ApInt::from_u64(42));
+            assert_eq!(ApInt::from([-1_i64, 5]), ApInt::from([u64::MAX, 5]));
+            let wide = ApInt::from([7_u64; 40]);
+            assert_eq!(wide.width(), BitWidth::new(40 * 64).unwrap());
+            assert!(wide.to_u64_vec().iter().all(|&limb| limb == 7));
+        }
+
+        #[test]
+        #[should_panic]
+        fn from_empty_array() {
+            let _ = ApInt::from([0_u64; 0]);
+        }
+
+        #[test]
+        fn from_le_be_limbs() {
+            let limbs = [0x0123_4567_89ab_cdef_u64, 1, 0xffff_ffff_ffff_ffff];
+            let le = ApInt::from_le_limbs(&limbs).unwrap();
+            let be = ApInt::from_be_limbs(&limbs).unwrap();
+            assert_eq!(le.width(), BitWidth::new(192).unwrap());
+            assert_eq!(le.to_u64_vec(), limbs.to_vec());
+            assert_eq!(be, ApInt::from(limbs));
+            assert_eq!(
+                be.to_u64_vec(),
+                vec![0xffff_ffff_ffff_ffff, 1, 0x0123_4567_89ab_cdef]
+            );
+            assert_eq!(
+                ApInt::from_le_limbs(&limbs[..1]),
+                Ok(ApInt::from_u64(limbs[0]))
+            );
+            assert_eq!(
+                ApInt::from_le_limbs(&[]).unwrap_err().kind(),
+                &ErrorKind::ExpectedNonEmptyDigits
+            );
+            assert_eq!(
+                ApInt::from_be_limbs(&[]).unwrap_err().kind(),
+                &ErrorKind::ExpectedNonEmptyDigits
+            );
+        }
+
+        #[test]
+        fn to_limbs() {
+            let limbs = [0xab_u64, 0x0123_4567_89ab_cdef, 0];
+            assert_eq!(ApInt::from(limbs).to_limbs::<3>(), Ok(limbs));
+            assert_eq!(ApInt::from_u8(42).to_limbs::<1>(), Ok([42]));
+            let w100 = BitWidth::new(100).unwrap();
+            assert_eq!(
+                ApInt::all_set(w100).to_limbs::<2>(),
+                Ok([0xf_ffff_ffff, 0xffff_ffff_ffff_ffff])
+            );
+            assert_eq!(
+                ApInt::from(limbs).to_limbs::<2>().unwrap_err().kind(),
+                &ErrorKind::UnmatchingBufferLength {
+                    expected: 3,
+                    actual: 2
+                }
+            );
+            assert!(ApInt::from_u8(42).to_limbs::<0>().is_err());
+        }
+
+        #[test]
+        fn to_le_limbs() {
+            let limbs = [0xab_u64, 0x0123_4567_89ab_cdef, 0];
+            let apint = ApInt::from_le_limbs(&limbs).unwrap();
+            assert_eq!(apint.to_le_limbs::<3>(), Ok(limbs));
+            assert_eq!(apint.to_limbs::<3>(), Ok([0, 0x0123_4567_89ab_cdef, 0xab]));
+            assert_eq!(
+                ApInt::all_set(BitWidth::new(100).unwrap()).to_le_limbs::<2>(),
+                Ok([0xffff_ffff_ffff_ffff, 0xf_ffff_ffff])
+            );
+            assert!(apint.to_le_limbs::<2>().is_err());
+        }
+    }
+
+    mod repeat {
+        use super::*;
+
+        /// Repeats `pattern` bit by bit.
+        fn repeat_reference(pattern: &ApInt, target_width: BitWidth) -> ApInt {
+            let mut result = ApInt::zero(target_width);
+            let pattern_width = pattern.width().to_usize();
+            for pos in 0..target_width.to_usize() {
+                if pattern.get_bit_at(pos % pattern_width).unwrap() {
+                    result.set_bit_at(pos).unwrap();
+                }
+            }
+            result
+        }
+
+        #[test]
+        fn against_reference() {
+            let patterns = [
+                ApInt::from_bool(true),
+                ApInt::from_u8(0b101).into_truncate(3).unwrap(),
+                ApInt::from_u8(0b110).into_truncate(3).unwrap(),
+                ApInt::from_u8(0xA5),
+                ApInt::from_u8(0x01),
+                ApInt::from_u64(0x0123_4567_89ab_cdef),
+                ApInt::from_u64(0x8000_0000_0000_0001)
+                    .into_truncate(63)
+                    .unwrap(),
+                ApInt::from([0x1234_u64, 0x8000_0000_0000_0001])
+                    .into_truncate(77)
+                    .unwrap(),
+            ];
+            for pattern in &patterns {
+                for &width in &[1, 5, 63, 64, 65, 100, 129, 200, 1000] {
+                    let width = BitWidth::new(width).unwrap();
+                    assert_eq!(
+                        ApInt::repeat(pattern, width),
+                        repeat_reference(pattern, width)
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn masks() {
+            let w100 = BitWidth::new(100).unwrap();
+            assert_eq!(
+                ApInt::repeat(&ApInt::from_u8(0xAA), w100),
+                ApInt::from([0xA_AAAA_AAAA_u64, 0xAAAA_AAAA_AAAA_AAAA])
+                    .into_truncate(w100)
+                    .unwrap()
+            );
+            assert_eq!(
+                ApInt::repeat(&ApInt::from_u8(0x01), BitWidth::w32()),
+                ApInt::from_u32(0x0101_0101)
+            );
+            assert_eq!(
+                ApInt::repeat(&ApInt::from_u16(0xBEEF), BitWidth::w8()),
+                ApInt::from_u8(0xEF)
+            );
+        }
+    }
+
+    mod masks {
+        use super::*;
+        use crate::Width;
+
+        fn test_widths() -> impl Iterator<Item = BitWidth> {
+            [1_usize, 7, 8, 63, 64, 65, 100, 128, 150, 256]
+                .iter()
+                .map(|&w| BitWidth::new(w).unwrap())
+        }
+
+        fn assert_mask(mask: &ApInt, range: Range<usize>) {
+            for pos in 0..mask.width().to_usize() {
+                assert_eq!(mask.get_bit_at(pos).unwrap(), range.contains(&pos));
+            }
+        }
+
+        #[test]
+        fn low_ones() {
+            assert_eq!(
+                ApInt::low_ones(BitWidth::w32(), 12).unwrap(),
+                ApInt::from_u32(0x0FFF)
+            );
+            assert_eq!(
+                ApInt::low_ones(BitWidth::w128(), 68).unwrap(),
+                ApInt::from_u128((1 << 68) - 1)
+            );
+            for width in test_widths() {
+                let len = width.to_usize();
+                assert_eq!(ApInt::low_ones(width, 0).unwrap(), ApInt::zero(width));
+                assert_eq!(ApInt::low_ones(width, len).unwrap(), ApInt::all_set(width));
+                for n in 0..=len {
+                    assert_mask(&ApInt::low_ones(width, n).unwrap(), 0..n);
+                }
+                assert!(ApInt::low_ones(width, len + 1).is_err());
+            }
+        }
+
+        #[test]
+        fn high_ones() {
+            assert_eq!(
+                ApInt::high_ones(BitWidth::w32(), 12).unwrap(),
+                ApInt::from_u32(0xFFF0_0000)
+            );
+            for width in test_widths() {
+                let len = width.to_usize();
+                assert_eq!(ApInt::high_ones(width, 0).unwrap(), ApInt::zero(width));
+                assert_eq!(ApInt::high_ones(width, len).unwrap(), ApInt::all_set(width));
+                for n in 0..=len {
+                    assert_mask(&ApInt::high_ones(width, n).unwrap(), (len - n)..len);
+                }
+                assert!(ApInt::high_ones(width, len + 1).is_err());
+            }
+        }
+
+        #[test]
+        fn range_mask() {
+            assert_eq!(
+                ApInt::range_mask(BitWidth::w16(), 4..12).unwrap(),
+                ApInt::from_u16(0x0FF0)
+            );
+            assert_eq!(
+                ApInt::range_mask(BitWidth::w128(), 60..70).unwrap(),
+                ApInt::from_u128(0x3F_F000_0000_0000_0000)
+            );
+            for width in test_widths() {
+                let len = width.to_usize();
+                for lo in 0..=len {
+                    for hi in lo..=len {
+                        assert_mask(&ApInt::range_mask(width, lo..hi).unwrap(), lo..hi);
+                    }
+                }
+                assert!(ApInt::range_mask(width, 0..(len + 1)).is_err());
+            }
+            #[allow(clippy::reversed_empty_ranges)]
+            let reversed = 5..4;
+            assert!(ApInt::range_mask(BitWidth::w8(), reversed).is_err());
+        }
+    }
 }
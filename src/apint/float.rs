@@ -0,0 +1,588 @@
+use crate::{
+    mem::format,
+    ApInt,
+    BitWidth,
+    Digit,
+    Error,
+    Result,
+    Width,
+};
+
+/// The rounding mode used when converting floating point numbers into
+/// integers.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Round {
+    /// Rounds towards zero, i.e. truncates the fractional part.
+    TowardZero,
+    /// Rounds to the nearest integer and to the even one of both nearest
+    /// integers on ties.
+    NearestTiesToEven,
+}
+
+/// The number of explicitly stored fraction bits of an `f64`.
+const F64_FRACTION_BITS: u32 = 52;
+
+/// The exponent bias of an `f64`.
+const F64_EXPONENT_BIAS: i32 = 1023;
+
+/// A finite `f64` that has been rounded to an integer.
+///
+/// The magnitude of the rounded integer is `mantissa << shift`.
+struct RoundedF64 {
+    negative: bool,
+    mantissa: u64,
+    shift: usize,
+}
+
+impl RoundedF64 {
+    /// Decomposes the given `value` into its sign, mantissa and exponent and
+    /// rounds it to an integer with the given rounding mode.
+    ///
+    /// # Errors
+    ///
+    /// - If `value` is NaN or infinite.
+    fn new(value: f64, rounding: Round) -> Result<RoundedF64> {
+        if !value.is_finite() {
+            return Err(Error::non_finite_float(value))
+        }
+        let bits = value.to_bits();
+        let negative = (bits >> 63) != 0;
+        let biased_exponent = ((bits >> F64_FRACTION_BITS) & 0x7ff) as i32;
+        let fraction = bits & ((1 << F64_FRACTION_BITS) - 1);
+        // value = mantissa * 2^exponent
+        let (mantissa, exponent) = if biased_exponent == 0 {
+            // subnormal numbers have no implicit leading one
+            (fraction, 1 - F64_EXPONENT_BIAS - F64_FRACTION_BITS as i32)
+        } else {
+            (
+                fraction | (1 << F64_FRACTION_BITS),
+                biased_exponent - F64_EXPONENT_BIAS - F64_FRACTION_BITS as i32,
+            )
+        };
+        if exponent >= 0 {
+            return Ok(RoundedF64 {
+                negative,
+                mantissa,
+                shift: exponent as usize,
+            })
+        }
+        let frac_bits = (-exponent) as u32;
+        if frac_bits >= 64 {
+            // `mantissa < 2^53` so the value is less than a half
+            return Ok(RoundedF64 {
+                negative,
+                mantissa: 0,
+                shift: 0,
+            })
+        }
+        let mut integral = mantissa >> frac_bits;
+        if rounding == Round::NearestTiesToEven {
+            let remainder = mantissa & ((1 << frac_bits) - 1);
+            let half = 1 << (frac_bits - 1);
+            if remainder > half || (remainder == half && integral & 1 == 1) {
+                integral += 1;
+            }
+        }
+        Ok(RoundedF64 {
+            negative,
+            mantissa: integral,
+            shift: 0,
+        })
+    }
+
+    /// Returns the number of bits required to represent the magnitude.
+    fn magnitude_bits(&self) -> usize {
+        if self.mantissa == 0 {
+            return 0
+        }
+        (64 - self.mantissa.leading_zeros() as usize) + self.shift
+    }
+
+    /// Returns the magnitude with the given bit width.
+    ///
+    /// **Note:** The magnitude must fit into the given bit width.
+    fn magnitude(&self, width: BitWidth) -> ApInt {
+        debug_assert!(self.magnitude_bits() <= width.to_usize());
+        let mut result = ApInt::from_u64(self.mantissa).into_zero_resize(width);
+        if self.shift != 0 {
+            result.wrapping_shl_assign(self.shift).expect(
+                "The magnitude fits into `width` so its shift amount is always less \
+                 than `width`.",
+            );
+        }
+        result
+    }
+}
+
+/// # Conversions from floating point numbers
+impl ApInt {
+    /// Creates a new `ApInt` with the given `width` that represents the
+    /// **unsigned** integer obtained by rounding `value` with the given
+    /// `rounding` mode.
+    ///
+    /// Negative values that are rounded to zero, e.g. `-0.0`, produce zero.
+    ///
+    /// # Errors
+    ///
+    /// - If `value` is NaN or infinite.
+    /// - If the rounded `value` is negative.
+    /// - If the rounded `value` does not fit into `width`.
+    pub fn try_from_f64(value: f64, width: BitWidth, rounding: Round) -> Result<ApInt> {
+        let rounded = RoundedF64::new(value, rounding)?;
+        if rounded.negative && rounded.mantissa != 0 {
+            return Err(Error::result_overflow(width).with_annotation(format!(
+                "Cannot represent the negative value {} as an unsigned `ApInt`.",
+                value
+            )))
+        }
+        if rounded.magnitude_bits() > width.to_usize() {
+            return Err(Error::result_overflow(width).with_annotation(format!(
+                "The rounded value of {} does not fit into the bit width.",
+                value
+            )))
+        }
+        Ok(rounded.magnitude(width))
+    }
+
+    /// Creates a new `ApInt` with the given `width` that represents the
+    /// **signed** integer obtained by rounding `value` with the given
+    /// `rounding` mode. Negative values are represented in two's complement.
+    ///
+    /// # Errors
+    ///
+    /// - If `value` is NaN or infinite.
+    /// - If the rounded `value` does not fit into `width` as a signed integer.
+    pub fn try_from_f64_signed(
+        value: f64,
+        width: BitWidth,
+        rounding: Round,
+    ) -> Result<ApInt> {
+        let rounded = RoundedF64::new(value, rounding)?;
+        let magnitude_bits = rounded.magnitude_bits();
+        // the signed minimum value is the only value whose magnitude requires
+        // all bits of `width`
+        let fits = magnitude_bits < width.to_usize()
+            || (rounded.negative
+                && magnitude_bits == width.to_usize()
+                && rounded.mantissa.is_power_of_two());
+        if !fits {
+            return Err(Error::result_overflow(width).with_annotation(format!(
+                "The rounded value of {} does not fit into the bit width.",
+                value
+            )))
+        }
+        let mut result = rounded.magnitude(width);
+        if rounded.negative {
+            result.wrapping_neg();
+        }
+        Ok(result)
+    }
+}
+
+/// # Conversions to floating point numbers
+impl ApInt {
+    /// Returns the most significant bits of the **unsigned** value of this
+    /// `ApInt` as `u64` together with the number of bits below them, i.e.
+    /// `(top, shift)` where the value is approximately `top << shift`.
+    ///
+    /// If any of the bits below `top` is set the least significant bit of
+    /// `top` is set as sticky bit. Since `top` has more bits than the mantissa
+    /// of any primitive float type rounding `top` to a float rounds the same
+    /// way as rounding the exact value would.
+    fn top_bits_with_sticky(&self) -> (u64, usize) {
+        let significant_bits = self.width().to_usize() - self.leading_zeros();
+        if significant_bits <= 64 {
+            return (self.u64_limbs().next().unwrap_or(0), 0)
+        }
+        let shift = significant_bits - 64;
+        // the digits holding the top 64 bits always fit into a `u128`
+        let (start, end) = (shift / Digit::BITS, (significant_bits - 1) / Digit::BITS);
+        let window = self.as_digit_slice()[start..=end]
+            .iter()
+            .rev()
+            .fold(0, |acc, d| (acc << Digit::BITS) | u128::from(d.repr()));
+        let mut top = (window >> (shift % Digit::BITS)) as u64;
+        if self.any_bit_in_range_differs(0..shift, false) {
+            top |= 1;
+        }
+        (top, shift)
+    }
+
+    /// Converts the **unsigned** value of this `ApInt` into the nearest `f64`
+    /// with ties rounded to even.
+    ///
+    /// Values that exceed the range of `f64` are converted into infinity like
+    /// primitive casts with `as` are.
+    pub fn to_f64(&self) -> f64 {
+        let (top, shift) = self.top_bits_with_sticky();
+        // `top` is rounded before scaling, so the scaling is exact unless it
+        // overflows to infinity
+        let scale = if shift <= 1023 {
+            f64::from_bits((shift as u64 + 1023) << 52)
+        } else {
+            f64::INFINITY
+        };
+        top as f64 * scale
+    }
+
+    /// Converts the **signed** value of this `ApInt` into the nearest `f64`
+    /// with ties rounded to even.
+    ///
+    /// For more details look into
+    /// [`to_f64`](struct.ApInt.html#method.to_f64).
+    pub fn to_f64_signed(&self) -> f64 {
+        if self.msb() {
+            -self.clone().into_wrapping_neg().to_f64()
+        } else {
+            self.to_f64()
+        }
+    }
+
+    /// Converts the **unsigned** value of this `ApInt` into the nearest `f32`
+    /// with ties rounded to even.
+    ///
+    /// Values that exceed the range of `f32` are converted into infinity like
+    /// primitive casts with `as` are.
+    pub fn to_f32(&self) -> f32 {
+        let (top, shift) = self.top_bits_with_sticky();
+        // `top` is rounded before scaling, so the scaling is exact unless it
+        // overflows to infinity
+        let scale = if shift <= 127 {
+            f32::from_bits((shift as u32 + 127) << 23)
+        } else {
+            f32::INFINITY
+        };
+        top as f32 * scale
+    }
+
+    /// Converts the **signed** value of this `ApInt` into the nearest `f32`
+    /// with ties rounded to even.
+    ///
+    /// For more details look into
+    /// [`to_f32`](struct.ApInt.html#method.to_f32).
+    pub fn to_f32_signed(&self) -> f32 {
+        if self.msb() {
+            -self.clone().into_wrapping_neg().to_f32()
+        } else {
+            self.to_f32()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ErrorKind;
+
+    mod from_f64 {
+        use super::*;
+
+        fn w(width: usize) -> BitWidth {
+            BitWidth::new(width).unwrap()
+        }
+
+        fn assert_overflow(result: Result<ApInt>, width: BitWidth) {
+            assert_eq!(
+                result.unwrap_err().kind(),
+                &ErrorKind::ResultOverflow(width)
+            )
+        }
+
+        #[test]
+        fn small_values() {
+            use self::Round::*;
+            let cases: &[(f64, u64, u64)] = &[
+                // (value, toward zero, nearest ties to even)
+                (0.0, 0, 0),
+                (0.4, 0, 0),
+                (0.5, 0, 0),
+                (0.6, 0, 1),
+                (1.0, 1, 1),
+                (1.5, 1, 2),
+                (2.5, 2, 2),
+                (3.5, 3, 4),
+                (42.75, 42, 43),
+                (4503599627370495.5, 4503599627370495, 4503599627370496),
+                (9007199254740992.0, 9007199254740992, 9007199254740992),
+            ];
+            for &(value, toward_zero, nearest) in cases {
+                assert_eq!(
+                    ApInt::try_from_f64(value, w(64), TowardZero),
+                    Ok(ApInt::from_u64(toward_zero))
+                );
+                assert_eq!(
+                    ApInt::try_from_f64(value, w(64), NearestTiesToEven),
+                    Ok(ApInt::from_u64(nearest))
+                );
+                assert_eq!(
+                    ApInt::try_from_f64_signed(-value, w(64), TowardZero),
+                    Ok(ApInt::from_i64(-(toward_zero as i64)))
+                );
+                assert_eq!(
+                    ApInt::try_from_f64_signed(-value, w(64), NearestTiesToEven),
+                    Ok(ApInt::from_i64(-(nearest as i64)))
+                );
+            }
+        }
+
+        #[test]
+        fn large_values() {
+            let two_70 = ApInt::one(w(100)).into_wrapping_shl(70).unwrap();
+            let value = 2_f64.powi(70);
+            for &rounding in &[Round::TowardZero, Round::NearestTiesToEven] {
+                assert_eq!(
+                    ApInt::try_from_f64(value, w(100), rounding),
+                    Ok(two_70.clone())
+                );
+                // 2^70 + 1 is not representable and rounds to 2^70
+                assert_eq!(
+                    ApInt::try_from_f64(value + 1.0, w(100), rounding),
+                    Ok(two_70.clone())
+                );
+                assert_eq!(
+                    ApInt::try_from_f64_signed(-value, w(100), rounding),
+                    Ok(two_70.clone().into_wrapping_neg())
+                );
+                assert_eq!(
+                    ApInt::try_from_f64(value, w(71), rounding),
+                    Ok(two_70.clone().into_truncate(71).unwrap())
+                );
+                assert_overflow(ApInt::try_from_f64(value, w(70), rounding), w(70));
+                assert_overflow(
+                    ApInt::try_from_f64_signed(value, w(71), rounding),
+                    w(71),
+                );
+            }
+            assert_eq!(
+                ApInt::try_from_f64(u64::MAX as f64, w(65), Round::TowardZero),
+                Ok(ApInt::one(w(65)).into_wrapping_shl(64).unwrap())
+            );
+            assert_eq!(
+                ApInt::try_from_f64(f64::MAX, w(1024), Round::TowardZero)
+                    .unwrap()
+                    .leading_zeros(),
+                0
+            );
+            assert_overflow(
+                ApInt::try_from_f64(f64::MAX, w(1023), Round::TowardZero),
+                w(1023),
+            );
+        }
+
+        #[test]
+        fn negative_zero_and_subnormals() {
+            let subnormals = [f64::MIN_POSITIVE / 2.0, 4.9e-324, -4.9e-324];
+            for &rounding in &[Round::TowardZero, Round::NearestTiesToEven] {
+                assert_eq!(
+                    ApInt::try_from_f64(-0.0, w(8), rounding),
+                    Ok(ApInt::zero(w(8)))
+                );
+                assert_eq!(
+                    ApInt::try_from_f64_signed(-0.0, w(8), rounding),
+                    Ok(ApInt::zero(w(8)))
+                );
+                for &value in &subnormals {
+                    assert_eq!(
+                        ApInt::try_from_f64(value, w(1), rounding),
+                        Ok(ApInt::zero(w(1)))
+                    );
+                    assert_eq!(
+                        ApInt::try_from_f64_signed(value, w(1), rounding),
+                        Ok(ApInt::zero(w(1)))
+                    );
+                }
+            }
+            assert_eq!(
+                ApInt::try_from_f64(-0.4, w(8), Round::NearestTiesToEven),
+                Ok(ApInt::zero(w(8)))
+            );
+        }
+
+        #[test]
+        fn negative_values() {
+            assert_overflow(ApInt::try_from_f64(-1.0, w(64), Round::TowardZero), w(64));
+            assert_overflow(
+                ApInt::try_from_f64(-0.6, w(64), Round::NearestTiesToEven),
+                w(64),
+            );
+            assert_eq!(
+                ApInt::try_from_f64(-0.6, w(64), Round::TowardZero),
+                Ok(ApInt::zero(w(64)))
+            );
+        }
+
+        #[test]
+        fn signed_bounds() {
+            let rounding = Round::TowardZero;
+            assert_eq!(
+                ApInt::try_from_f64_signed(-128.0, w(8), rounding),
+                Ok(ApInt::from_i8(-128))
+            );
+            assert_eq!(
+                ApInt::try_from_f64_signed(127.0, w(8), rounding),
+                Ok(ApInt::from_i8(127))
+            );
+            assert_overflow(ApInt::try_from_f64_signed(-129.0, w(8), rounding), w(8));
+            assert_overflow(ApInt::try_from_f64_signed(128.0, w(8), rounding), w(8));
+            assert_eq!(
+                ApInt::try_from_f64_signed(-1.0, w(1), rounding),
+                Ok(ApInt::from_bool(true))
+            );
+            assert_overflow(ApInt::try_from_f64_signed(1.0, w(1), rounding), w(1));
+            assert_eq!(
+                ApInt::try_from_f64_signed(i64::MIN as f64, w(64), rounding),
+                Ok(ApInt::from_i64(i64::MIN))
+            );
+            assert_overflow(
+                ApInt::try_from_f64_signed(-(i64::MIN as f64), w(64), rounding),
+                w(64),
+            );
+        }
+
+        #[test]
+        fn non_finite() {
+            for &value in &[f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+                for &rounding in &[Round::TowardZero, Round::NearestTiesToEven] {
+                    assert_eq!(
+                        ApInt::try_from_f64(value, w(64), rounding)
+                            .unwrap_err()
+                            .kind(),
+                        &ErrorKind::NonFiniteFloat
+                    );
+                    assert_eq!(
+                        ApInt::try_from_f64_signed(value, w(64), rounding)
+                            .unwrap_err()
+                            .kind(),
+                        &ErrorKind::NonFiniteFloat
+                    );
+                }
+            }
+        }
+    }
+
+    mod to_float {
+        use super::*;
+
+        fn w(width: usize) -> BitWidth {
+            BitWidth::new(width).unwrap()
+        }
+
+        /// Returns `2^exp` with the given bit width.
+        fn pow2(exp: usize, width: usize) -> ApInt {
+            ApInt::one(w(width)).into_wrapping_shl(exp).unwrap()
+        }
+
+        fn sum(parts: &[ApInt]) -> ApInt {
+            parts.iter().skip(1).fold(parts[0].clone(), |acc, part| {
+                acc.into_wrapping_add(part).unwrap()
+            })
+        }
+
+        #[test]
+        fn against_primitive_casts() {
+            let values = [
+                0_u128,
+                1,
+                42,
+                (1 << 53) - 1,
+                1 << 53,
+                (1 << 53) + 1,
+                (1 << 54) + 3,
+                (1 << 24) + 1,
+                u128::from(u64::MAX),
+                0x0123_4567_89ab_cdef_fedc_ba98_7654_3210,
+                0x8000_0000_0000_0400_0000_0000_0000_0000,
+                0x8000_0000_0000_0c00_0000_0000_0000_0000,
+                0x8000_0000_0000_0400_0000_0000_0000_0001,
+                0xffff_ff80_0000_0000_0000_0000_0000_0000,
+                i128::MAX as u128,
+                u128::MAX,
+            ];
+            for &width in &[1, 7, 24, 53, 63, 64, 65, 100, 127, 128] {
+                let mask = u128::MAX >> (128 - width);
+                for &value in &values {
+                    let value = value & mask;
+                    let signed = ((value << (128 - width)) as i128) >> (128 - width);
+                    let apint = ApInt::from_u128(value).into_truncate(width).unwrap();
+                    assert_eq!(apint.to_f64(), value as f64);
+                    assert_eq!(apint.to_f32(), value as f32);
+                    assert_eq!(apint.to_f64_signed(), signed as f64);
+                    assert_eq!(apint.to_f32_signed(), signed as f32);
+                }
+            }
+        }
+
+        #[test]
+        fn rounding_boundaries() {
+            let base = 2_f64.powi(200);
+            let ulp = 2_f64.powi(200 - 52);
+            // exactly half an ulp above 2^200 rounds down to even
+            assert_eq!(sum(&[pow2(200, 256), pow2(147, 256)]).to_f64(), base);
+            // slightly more than half an ulp rounds up
+            assert_eq!(
+                sum(&[pow2(200, 256), pow2(147, 256), pow2(0, 256)]).to_f64(),
+                base + ulp
+            );
+            // slightly less than half an ulp rounds down
+            assert_eq!(
+                sum(&[pow2(200, 256), pow2(146, 256), pow2(145, 256)]).to_f64(),
+                base
+            );
+            // exactly half an ulp above an odd mantissa rounds up to even
+            assert_eq!(
+                sum(&[pow2(200, 256), pow2(148, 256), pow2(147, 256)]).to_f64(),
+                base + 2.0 * ulp
+            );
+            // set bits far below the mantissa don't affect rounding
+            assert_eq!(
+                sum(&[pow2(199, 256), pow2(0, 256)]).to_f64(),
+                2_f64.powi(199)
+            );
+            // all bits set rounds up to the next power of two
+            assert_eq!(ApInt::all_set(w(200)).to_f64(), base);
+            assert_eq!(ApInt::all_set(w(200)).to_f32(), f32::INFINITY);
+            assert_eq!(pow2(127, 200).to_f32(), 2_f32.powi(127));
+        }
+
+        #[test]
+        fn overflow() {
+            assert_eq!(pow2(1023, 1100).to_f64(), 2_f64.powi(1023));
+            assert_eq!(pow2(1024, 1100).to_f64(), f64::INFINITY);
+            assert_eq!(pow2(1024, 1100).to_f64_signed(), f64::INFINITY);
+            assert_eq!(ApInt::all_set(w(1100)).to_f64(), f64::INFINITY);
+            assert_eq!(ApInt::all_set(w(1024)).to_f64(), f64::INFINITY);
+            assert_eq!(
+                ApInt::signed_min_value(w(1100)).to_f64_signed(),
+                f64::NEG_INFINITY
+            );
+            assert_eq!(
+                ApInt::signed_min_value(w(1024)).to_f64_signed(),
+                -2_f64.powi(1023)
+            );
+            let f64_max =
+                ApInt::try_from_f64(f64::MAX, w(1024), Round::TowardZero).unwrap();
+            assert_eq!(f64_max.to_f64(), f64::MAX);
+            assert_eq!(ApInt::all_set(w(1100)).to_f64_signed(), -1.0);
+        }
+
+        #[test]
+        fn round_trip() {
+            for &value in &[
+                0.0,
+                1.0,
+                3.0,
+                2_f64.powi(70),
+                1.5 * 2_f64.powi(100),
+                f64::MAX,
+            ] {
+                let apint =
+                    ApInt::try_from_f64(value, w(1024), Round::TowardZero).unwrap();
+                assert_eq!(apint.to_f64(), value);
+                let apint =
+                    ApInt::try_from_f64_signed(-value, w(1025), Round::TowardZero)
+                        .unwrap();
+                assert_eq!(apint.to_f64_signed(), -value);
+            }
+        }
+    }
+}
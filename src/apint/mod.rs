@@ -2,25 +2,47 @@ mod arithmetic;
 mod bitwise;
 mod casting;
 mod constructors;
+mod float;
 mod relational;
 mod serialization;
 mod shift;
 mod to_primitive;
 mod utils;
 
+#[cfg(test)]
+mod test_utils;
+
 #[cfg(feature = "rand_support")]
 mod rand_impl;
 
 #[cfg(feature = "serde_support")]
 mod serde_impl;
 
+#[cfg(feature = "num-bigint")]
+mod num_bigint_impl;
+
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impl;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+
+#[cfg(feature = "num-traits")]
+mod num_traits_impl;
+
 use crate::{
     BitWidth,
     Digit,
 };
 
-pub use self::shift::ShiftAmount;
+#[cfg(feature = "rand_support")]
+pub use self::rand_impl::UniformApInt;
 pub(crate) use self::to_primitive::PrimitiveTy;
+pub use self::{
+    float::Round,
+    shift::ShiftAmount,
+    utils::Sign,
+};
 
 use core::ptr::NonNull;
 
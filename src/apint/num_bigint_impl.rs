@@ -0,0 +1,234 @@
+use crate::{
+    mem::{
+        format,
+        vec::Vec,
+    },
+    ApInt,
+    BitWidth,
+    Error,
+    Result,
+};
+use num_bigint::{
+    BigInt,
+    BigUint,
+    Sign,
+};
+
+/// Converts the **unsigned** value of the given `ApInt` into a `BigUint`.
+impl From<&ApInt> for BigUint {
+    fn from(value: &ApInt) -> BigUint {
+        BigUint::new(
+            value
+                .u64_limbs()
+                .flat_map(|limb| [limb as u32, (limb >> 32) as u32])
+                .collect::<Vec<u32>>(),
+        )
+    }
+}
+
+/// Converts the given `BigUint` into an `ApInt` with the minimum bit width
+/// required to represent its value, which is at least `1`.
+impl From<&BigUint> for ApInt {
+    fn from(value: &BigUint) -> ApInt {
+        let width = BitWidth::new(value.bits().max(1) as usize).expect(
+            "We have already asserted that the bit width is at least `1` so we can \
+             expect `BitWidth::new` to be successful.",
+        );
+        ApInt::try_from_biguint(value, width).expect(
+            "The bit width is chosen to fit the value of `value` so we can expect the \
+             conversion to be successful.",
+        )
+    }
+}
+
+/// # Conversions with `num-bigint`
+impl ApInt {
+    /// Creates a new `ApInt` with the given `width` that represents the value
+    /// of the given `BigUint`.
+    ///
+    /// # Errors
+    ///
+    /// - If the value of `value` does not fit into `width`.
+    pub fn try_from_biguint(value: &BigUint, width: BitWidth) -> Result<ApInt> {
+        if value.bits() > width.to_usize() as u64 {
+            return Err(Error::result_overflow(width).with_annotation(format!(
+                "The `BigUint` requires {} bits.",
+                value.bits()
+            )))
+        }
+        Ok(ApInt::from_u64_limbs(width, value.iter_u64_digits()))
+    }
+
+    /// Converts the **signed** value of this `ApInt` into a `BigInt`.
+    pub(crate) fn to_bigint_signed(&self) -> BigInt {
+        if self.msb() {
+            BigInt::from_biguint(
+                Sign::Minus,
+                BigUint::from(&self.clone().into_wrapping_neg()),
+            )
+        } else {
+            BigInt::from(BigUint::from(self))
+        }
+    }
+
+    /// Creates a new `ApInt` with the given `width` that represents the value
+    /// of the given `BigInt` in two's complement.
+    ///
+    /// # Errors
+    ///
+    /// - If the value of `value` does not fit into `width` as a signed integer.
+    pub(crate) fn try_from_bigint_signed(
+        value: &BigInt,
+        width: BitWidth,
+    ) -> Result<ApInt> {
+        if ApInt::bigint_signed_bits(value) > width.to_usize() as u64 {
+            return Err(Error::result_overflow(width).with_annotation(format!(
+                "The `BigInt` requires {} bits in two's complement.",
+                ApInt::bigint_signed_bits(value)
+            )))
+        }
+        let mut result = ApInt::try_from_biguint(value.magnitude(), width)?;
+        if value.sign() == Sign::Minus {
+            result.wrapping_neg();
+        }
+        Ok(result)
+    }
+
+    /// Returns the minimum number of bits required to represent the value of
+    /// the given `BigInt` in two's complement, which is at least `1`.
+    pub(crate) fn bigint_signed_bits(value: &BigInt) -> u64 {
+        let magnitude_bits = value.bits();
+        // negative powers of two do not require an additional sign bit
+        let is_negative_power_of_two = value.sign() == Sign::Minus
+            && value.trailing_zeros() == Some(magnitude_bits - 1);
+        if is_negative_power_of_two {
+            magnitude_bits
+        } else {
+            magnitude_bits + 1
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ErrorKind,
+        Int,
+        Width,
+    };
+    use core::convert::TryFrom;
+
+    fn test_widths() -> impl Iterator<Item = BitWidth> {
+        [1, 7, 32, 63, 64, 65, 100, 128, 200, 1000]
+            .iter()
+            .map(|&width| BitWidth::new(width).unwrap())
+    }
+
+    fn test_values(width: BitWidth) -> Vec<ApInt> {
+        vec![
+            ApInt::zero(width),
+            ApInt::one(width),
+            ApInt::all_set(width),
+            ApInt::signed_min_value(width),
+            ApInt::signed_max_value(width),
+            ApInt::repeat(&ApInt::from_u64(0x0123_4567_89ab_cdef), width),
+        ]
+    }
+
+    #[test]
+    fn biguint_round_trip() {
+        for width in test_widths() {
+            for value in test_values(width) {
+                let big = BigUint::from(&value);
+                assert_eq!(
+                    big.bits(),
+                    (width.to_usize() - value.leading_zeros()) as u64
+                );
+                assert_eq!(ApInt::try_from_biguint(&big, width), Ok(value.clone()));
+                let auto = ApInt::from(&big);
+                assert_eq!(auto.width().to_usize() as u64, big.bits().max(1));
+                assert_eq!(auto.into_zero_resize(width), value);
+            }
+        }
+    }
+
+    #[test]
+    fn biguint_digits() {
+        let value = ApInt::from([0x0123_4567_89ab_cdef_u64, 0xfedc_ba98_7654_3210]);
+        assert_eq!(
+            BigUint::from(&value),
+            BigUint::new(vec![0x7654_3210, 0xfedc_ba98, 0x89ab_cdef, 0x0123_4567])
+        );
+        assert_eq!(BigUint::from(&ApInt::from_u8(42)), BigUint::from(42_u32));
+    }
+
+    #[test]
+    fn biguint_overflow() {
+        let big = BigUint::from(256_u32);
+        assert_eq!(
+            ApInt::try_from_biguint(&big, BitWidth::w8())
+                .unwrap_err()
+                .kind(),
+            &ErrorKind::ResultOverflow(BitWidth::w8())
+        );
+        assert_eq!(
+            ApInt::try_from_biguint(&big, BitWidth::new(9).unwrap()),
+            Ok(ApInt::from_u16(256).into_truncate(9).unwrap())
+        );
+    }
+
+    #[test]
+    fn bigint_round_trip() {
+        for width in test_widths() {
+            for value in test_values(width) {
+                let int = Int::from(value.clone());
+                let big = BigInt::from(&int);
+                assert_eq!(big.sign() == Sign::Minus, value.msb());
+                assert_eq!(Int::try_from_bigint(&big, width), Ok(int.clone()));
+                let auto = Int::from(&big);
+                assert!(auto.width() <= width);
+                assert_eq!(BigInt::from(&auto), big);
+            }
+        }
+    }
+
+    #[test]
+    fn bigint_values() {
+        assert_eq!(BigInt::from(&Int::from_i8(-128)), BigInt::from(-128));
+        assert_eq!(
+            BigInt::from(&Int::from_i64(i64::MIN)),
+            BigInt::from(i64::MIN)
+        );
+        assert_eq!(BigInt::from(&Int::from_bool(true)), BigInt::from(-1));
+        assert_eq!(Int::from(&BigInt::from(-128)), Int::from_i8(-128));
+        assert_eq!(Int::from(&BigInt::from(127)), Int::from_i8(127));
+        assert_eq!(Int::from(&BigInt::from(-1)), Int::from_bool(true));
+        assert_eq!(Int::from(&BigInt::from(0)).width(), BitWidth::w1());
+        assert_eq!(
+            Int::from(&BigInt::from(128)).width(),
+            BitWidth::new(9).unwrap()
+        );
+    }
+
+    #[test]
+    fn bigint_overflow() {
+        let w8 = BitWidth::w8();
+        for &value in &[128, -129, 1000] {
+            assert_eq!(
+                Int::try_from_bigint(&BigInt::from(value), w8)
+                    .unwrap_err()
+                    .kind(),
+                &ErrorKind::ResultOverflow(w8)
+            );
+        }
+        assert_eq!(
+            Int::try_from_bigint(&BigInt::from(-128), w8),
+            Ok(Int::from_i8(-128))
+        );
+        assert_eq!(
+            i64::try_from(&ApInt::try_from_bigint_signed(&BigInt::from(-5), w8).unwrap()),
+            Ok(-5)
+        );
+    }
+}
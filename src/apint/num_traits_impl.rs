@@ -0,0 +1,292 @@
+//! Implementations of the `num-traits` traits that make sense for integers
+//! with a bit width that is only known at runtime.
+//!
+//! The checked traits return `None` on overflow, division by zero and also on
+//! unmatching bit widths of their operands. The wrapping traits panic on
+//! unmatching bit widths just like the `std::ops` operators they build upon.
+//! `ApInt` uses the **unsigned** interpretation like its `Div` operator.
+//!
+//! # Excluded traits
+//!
+//! - `Zero`, `One` and `Bounded` construct values without any operand to take
+//!   the bit width from. There is no sensible default bit width, so use
+//!   `ApInt::zero`, `ApInt::one` and `bounded_for_width` of `UInt` and `Int`
+//!   instead.
+//! - `Num`, `NumCast`, `FromPrimitive` and `PrimInt` require some of the
+//!   above traits or also construct values without a bit width.
+//! - `CheckedShl` and `CheckedShr` are implemented for shift amounts of type
+//!   `u32` only, which is too restrictive for arbitrary bit widths.
+//!
+//! # Example
+//!
+//! ```
+//! use apint::{ApInt, UInt};
+//! use num_traits::{CheckedAdd, WrappingSub};
+//!
+//! /// Returns `a + b - c` or `None` if the addition overflows.
+//! fn add_sub<T: CheckedAdd + WrappingSub>(a: &T, b: &T, c: &T) -> Option<T> {
+//!     a.checked_add(b).map(|sum| sum.wrapping_sub(c))
+//! }
+//!
+//! let (a, b, c) = (ApInt::from(200_u8), ApInt::from(50_u8), ApInt::from(1_u8));
+//! assert_eq!(add_sub(&a, &b, &c), Some(ApInt::from(249_u8)));
+//! assert_eq!(add_sub(&a, &a, &c), None);
+//! let (a, b, c) = (UInt::from(5_u32), UInt::from(1_u32), UInt::from(7_u32));
+//! assert_eq!(add_sub(&a, &b, &c), Some(UInt::from(u32::MAX)));
+//! ```
+
+use crate::{
+    ApInt,
+    BitWidth,
+    Int,
+    UInt,
+};
+use num_traits::{
+    CheckedAdd,
+    CheckedDiv,
+    CheckedMul,
+    CheckedRem,
+    CheckedSub,
+    WrappingAdd,
+    WrappingMul,
+    WrappingSub,
+};
+
+// Implements a checked trait in terms of a method that returns
+// `Result<Option<_>>` by treating errors like overflows.
+macro_rules! impl_checked {
+    ($ty:ty, $tr:ident::$fn:ident, $method:ident) => {
+        impl $tr for $ty {
+            fn $fn(&self, v: &$ty) -> Option<$ty> {
+                self.clone().$method(v).ok().flatten()
+            }
+        }
+    };
+}
+
+impl_checked!(ApInt, CheckedAdd::checked_add, into_overflow_checked_uadd);
+impl_checked!(ApInt, CheckedSub::checked_sub, into_overflow_checked_usub);
+impl_checked!(ApInt, CheckedMul::checked_mul, into_overflow_checked_umul);
+impl_checked!(UInt, CheckedAdd::checked_add, checked_add);
+impl_checked!(UInt, CheckedSub::checked_sub, checked_sub);
+impl_checked!(UInt, CheckedMul::checked_mul, checked_mul);
+impl_checked!(Int, CheckedAdd::checked_add, checked_add);
+impl_checked!(Int, CheckedSub::checked_sub, checked_sub);
+impl_checked!(Int, CheckedMul::checked_mul, checked_mul);
+
+// Implements a checked division trait in terms of a wrapping division method
+// that only fails on unmatching bit widths or a division by zero.
+macro_rules! impl_checked_div {
+    ($ty:ty, $tr:ident::$fn:ident, $method:ident) => {
+        impl $tr for $ty {
+            fn $fn(&self, v: &$ty) -> Option<$ty> {
+                self.clone().$method(v).ok()
+            }
+        }
+    };
+}
+
+impl_checked_div!(ApInt, CheckedDiv::checked_div, into_wrapping_udiv);
+impl_checked_div!(ApInt, CheckedRem::checked_rem, into_wrapping_urem);
+impl_checked_div!(UInt, CheckedDiv::checked_div, into_wrapping_div);
+impl_checked_div!(UInt, CheckedRem::checked_rem, into_wrapping_rem);
+
+/// Returns `true` if `lhs` is the minimum value and `rhs` is `-1` in which
+/// case the signed division overflows.
+fn overflows_division(lhs: &Int, rhs: &Int) -> bool {
+    lhs.is_negative() && lhs.count_ones() == 1 && rhs.is_all_set()
+}
+
+impl CheckedDiv for Int {
+    fn checked_div(&self, v: &Int) -> Option<Int> {
+        if overflows_division(self, v) {
+            return None
+        }
+        self.clone().into_wrapping_div(v).ok()
+    }
+}
+
+/// Like the primitive integers this returns `None` for the minimum value
+/// divided by `-1` since the corresponding division overflows.
+impl CheckedRem for Int {
+    fn checked_rem(&self, v: &Int) -> Option<Int> {
+        if overflows_division(self, v) {
+            return None
+        }
+        self.clone().into_wrapping_rem(v).ok()
+    }
+}
+
+// Implements a wrapping trait in terms of the corresponding `std::ops`
+// operator on references.
+macro_rules! impl_wrapping {
+    ($ty:ty, $tr:ident::$fn:ident, $op:tt) => {
+        impl $tr for $ty {
+            fn $fn(&self, v: &$ty) -> $ty {
+                self $op v
+            }
+        }
+    };
+}
+
+impl_wrapping!(ApInt, WrappingAdd::wrapping_add, +);
+impl_wrapping!(ApInt, WrappingSub::wrapping_sub, -);
+impl_wrapping!(ApInt, WrappingMul::wrapping_mul, *);
+impl_wrapping!(UInt, WrappingAdd::wrapping_add, +);
+impl_wrapping!(UInt, WrappingSub::wrapping_sub, -);
+impl_wrapping!(UInt, WrappingMul::wrapping_mul, *);
+impl_wrapping!(Int, WrappingAdd::wrapping_add, +);
+impl_wrapping!(Int, WrappingSub::wrapping_sub, -);
+impl_wrapping!(Int, WrappingMul::wrapping_mul, *);
+
+/// # Bounds with `num-traits`
+impl UInt {
+    /// Returns the minimum and maximum value of the given bit width.
+    ///
+    /// This replaces `num_traits::Bounded` which cannot be implemented since
+    /// the bounds depend on the bit width.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apint::{BitWidth, UInt};
+    ///
+    /// let (min, max) = UInt::bounded_for_width(BitWidth::w8());
+    /// assert_eq!(min, UInt::from(u8::MIN));
+    /// assert_eq!(max, UInt::from(u8::MAX));
+    /// ```
+    pub fn bounded_for_width(width: BitWidth) -> (UInt, UInt) {
+        (UInt::min_value(width), UInt::max_value(width))
+    }
+}
+
+/// # Bounds with `num-traits`
+impl Int {
+    /// Returns the minimum and maximum value of the given bit width.
+    ///
+    /// This replaces `num_traits::Bounded` which cannot be implemented since
+    /// the bounds depend on the bit width.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use apint::{BitWidth, Int};
+    ///
+    /// let (min, max) = Int::bounded_for_width(BitWidth::w8());
+    /// assert_eq!(min, Int::from(i8::MIN));
+    /// assert_eq!(max, Int::from(i8::MAX));
+    /// ```
+    pub fn bounded_for_width(width: BitWidth) -> (Int, Int) {
+        (Int::min_value(width), Int::max_value(width))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checked_ops<T>(a: &T, b: &T) -> [Option<T>; 5]
+    where
+        T: CheckedAdd + CheckedSub + CheckedMul + CheckedDiv + CheckedRem,
+    {
+        [
+            a.checked_add(b),
+            a.checked_sub(b),
+            a.checked_mul(b),
+            a.checked_div(b),
+            a.checked_rem(b),
+        ]
+    }
+
+    fn wrapping_ops<T>(a: &T, b: &T) -> [T; 3]
+    where
+        T: WrappingAdd + WrappingSub + WrappingMul,
+    {
+        [a.wrapping_add(b), a.wrapping_sub(b), a.wrapping_mul(b)]
+    }
+
+    #[test]
+    fn unsigned() {
+        for x in (0..=u8::MAX).step_by(3) {
+            for y in 0..=u8::MAX {
+                let expected = [
+                    x.checked_add(y),
+                    x.checked_sub(y),
+                    x.checked_mul(y),
+                    x.checked_div(y),
+                    x.checked_rem(y),
+                ];
+                let expected_ap = expected.map(|e| e.map(ApInt::from));
+                let expected_uint = expected.map(|e| e.map(UInt::from));
+                assert_eq!(checked_ops(&ApInt::from(x), &ApInt::from(y)), expected_ap);
+                assert_eq!(checked_ops(&UInt::from(x), &UInt::from(y)), expected_uint);
+                let expected = [x.wrapping_add(y), x.wrapping_sub(y), x.wrapping_mul(y)];
+                assert_eq!(
+                    wrapping_ops(&ApInt::from(x), &ApInt::from(y)),
+                    expected.map(ApInt::from)
+                );
+                assert_eq!(
+                    wrapping_ops(&UInt::from(x), &UInt::from(y)),
+                    expected.map(UInt::from)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn signed() {
+        for x in (i8::MIN..=i8::MAX).step_by(3) {
+            for y in i8::MIN..=i8::MAX {
+                let expected = [
+                    x.checked_add(y),
+                    x.checked_sub(y),
+                    x.checked_mul(y),
+                    x.checked_div(y),
+                    x.checked_rem(y),
+                ];
+                assert_eq!(
+                    checked_ops(&Int::from(x), &Int::from(y)),
+                    expected.map(|e| e.map(Int::from))
+                );
+                let expected = [x.wrapping_add(y), x.wrapping_sub(y), x.wrapping_mul(y)];
+                assert_eq!(
+                    wrapping_ops(&Int::from(x), &Int::from(y)),
+                    expected.map(Int::from)
+                );
+            }
+        }
+        let min = Int::from(i8::MIN);
+        let minus_one = Int::from(-1_i8);
+        assert_eq!(min.checked_div(&minus_one), None);
+        assert_eq!(min.checked_rem(&minus_one), None);
+    }
+
+    #[test]
+    fn large() {
+        let w200 = BitWidth::new(200).unwrap();
+        let (min, max) = Int::bounded_for_width(w200);
+        let one = Int::from(ApInt::one(w200));
+        assert_eq!(CheckedAdd::checked_add(&max, &one), None);
+        assert_eq!(CheckedSub::checked_sub(&min, &one), None);
+        assert_eq!(max.wrapping_add(&one), min);
+        assert_eq!(CheckedMul::checked_mul(&max, &one), Some(max.clone()));
+        let (zero, umax) = UInt::bounded_for_width(w200);
+        assert_eq!(CheckedMul::checked_mul(&umax, &umax), None);
+        assert_eq!(umax.checked_div(&zero), None);
+        assert_eq!(umax.wrapping_mul(&umax), UInt::from(ApInt::one(w200)));
+    }
+
+    #[test]
+    fn unmatching_widths() {
+        let (a, b) = (ApInt::from(1_u8), ApInt::from(1_u16));
+        assert_eq!(checked_ops(&a, &b), [None, None, None, None, None]);
+        let (a, b) = (Int::from(1_i8), Int::from(1_i16));
+        assert_eq!(checked_ops(&a, &b), [None, None, None, None, None]);
+    }
+
+    #[test]
+    #[should_panic(expected = "addition failed")]
+    fn unmatching_wrapping() {
+        let _ = WrappingAdd::wrapping_add(&UInt::from(1_u8), &UInt::from(1_u16));
+    }
+}
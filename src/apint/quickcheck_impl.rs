@@ -0,0 +1,201 @@
+use crate::{
+    mem::{
+        boxed::Box,
+        vec::Vec,
+    },
+    ApInt,
+    BitWidth,
+    Int,
+    UInt,
+    Width,
+};
+use quickcheck::{
+    Arbitrary,
+    Gen,
+};
+
+/// Bit widths that are generated more often since they are at the boundaries
+/// of `Digit`s or primitive integers.
+const INTERESTING_WIDTHS: [usize; 6] = [1, 63, 64, 65, 127, 128];
+
+/// Returns a random bit width of at most `max(g.size(), 1)` bits that is biased
+/// towards the `INTERESTING_WIDTHS`.
+fn arbitrary_width(g: &mut Gen) -> BitWidth {
+    let max_width = g.size().max(1);
+    let interesting = INTERESTING_WIDTHS
+        .iter()
+        .cloned()
+        .filter(|&width| width <= max_width)
+        .collect::<Vec<_>>();
+    let width = match g.choose(&[true, false]) {
+        Some(true) => *g.choose(&interesting).unwrap_or(&1),
+        _ => usize::arbitrary(g) % max_width + 1,
+    };
+    BitWidth::new(width).expect(
+        "The bit width is at least `1` by construction so we can expect `BitWidth::new` \
+         to be successful.",
+    )
+}
+
+/// Generates `ApInt`s with a random bit width and random bits.
+///
+/// The bit width is at most `max(g.size(), 1)`, so it can be configured via
+/// the size of the generator, e.g. through the `QUICKCHECK_GENERATOR_SIZE`
+/// environment variable. Bit widths at the boundaries of `Digit`s and
+/// primitive integers are generated more often.
+///
+/// Shrinking first reduces the **unsigned** value towards zero by clearing
+/// its high bits and then reduces the bit width by truncation.
+impl Arbitrary for ApInt {
+    fn arbitrary(g: &mut Gen) -> ApInt {
+        let width = arbitrary_width(g);
+        let limbs = (0..width.required_u64s())
+            .map(|_| u64::arbitrary(g))
+            .collect::<Vec<_>>();
+        ApInt::from_u64_limbs(width, limbs)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = ApInt>> {
+        let width = self.width();
+        let mut shrunk = Vec::new();
+        let bits = self.required_bits(false);
+        if bits > 0 {
+            shrunk.push(ApInt::zero(width));
+            // clear the upper half of the significant bits
+            if bits > 1 {
+                let mut lower = self.clone();
+                for pos in bits / 2..bits {
+                    lower.unset_bit_at(pos).unwrap();
+                }
+                shrunk.push(lower);
+            }
+            if width.to_usize() > 1 {
+                let mut half = self.clone();
+                half.wrapping_lshr_assign(1).unwrap();
+                shrunk.push(half);
+            }
+            let mut dec = self.clone();
+            dec.wrapping_dec();
+            shrunk.push(dec);
+        }
+        let width = width.to_usize();
+        let mut widths = vec![1, width / 2, width - 1];
+        widths.dedup();
+        for target in widths.into_iter().filter(|&w| 0 < w && w < width) {
+            shrunk.push(self.clone().into_truncate(target).unwrap());
+        }
+        shrunk.dedup();
+        Box::new(shrunk.into_iter())
+    }
+}
+
+/// For more details look into the `Arbitrary` implementation of `ApInt`.
+impl Arbitrary for UInt {
+    fn arbitrary(g: &mut Gen) -> UInt {
+        UInt::from(ApInt::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = UInt>> {
+        Box::new(self.clone().into_apint().shrink().map(UInt::from))
+    }
+}
+
+/// For more details look into the `Arbitrary` implementation of `ApInt`.
+impl Arbitrary for Int {
+    fn arbitrary(g: &mut Gen) -> Int {
+        Int::from(ApInt::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Int>> {
+        Box::new(self.clone().into_apint().shrink().map(Int::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck::{
+        QuickCheck,
+        TestResult,
+    };
+
+    /// Greedily shrinks `value` as long as a shrunk value still fails.
+    fn minimize<F>(mut value: ApInt, fails: F) -> ApInt
+    where
+        F: Fn(&ApInt) -> bool,
+    {
+        assert!(fails(&value));
+        while let Some(shrunk) = value.shrink().find(|shrunk| fails(shrunk)) {
+            value = shrunk;
+        }
+        value
+    }
+
+    #[test]
+    fn arbitrary_widths() {
+        let mut g = Gen::new(200);
+        let mut seen = [false; INTERESTING_WIDTHS.len()];
+        for _ in 0..1000 {
+            let width = ApInt::arbitrary(&mut g).width().to_usize();
+            assert!((1..=200).contains(&width));
+            if let Some(i) = INTERESTING_WIDTHS.iter().position(|&w| w == width) {
+                seen[i] = true;
+            }
+        }
+        assert!(seen.iter().all(|&seen| seen));
+        let mut g = Gen::new(0);
+        for _ in 0..100 {
+            assert_eq!(ApInt::arbitrary(&mut g).width(), BitWidth::w1());
+        }
+    }
+
+    #[test]
+    fn shrink_is_smaller() {
+        let mut g = Gen::new(300);
+        for _ in 0..100 {
+            let value = ApInt::arbitrary(&mut g);
+            for shrunk in value.shrink() {
+                assert!(
+                    shrunk.width() < value.width()
+                        || shrunk.checked_ult(&value).unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn shrink_to_minimal_counterexample() {
+        // fails for all values with bit 1 set, so the minimal counterexample is
+        // `0b10` with a bit width of 2
+        let fails = |value: &ApInt| value.get_bit_at(1).unwrap_or(false);
+        let mut value = ApInt::all_set(BitWidth::new(300).unwrap());
+        value.unset_bit_at(0).unwrap();
+        let minimal = minimize(value, fails);
+        assert_eq!(minimal, ApInt::from_u8(0b10).into_truncate(2).unwrap());
+    }
+
+    #[test]
+    fn add_sub_round_trip() {
+        fn prop(lhs: ApInt, rhs: ApInt) -> bool {
+            let rhs = rhs.into_zero_resize(lhs.width());
+            let mut result = lhs.clone();
+            result.wrapping_add_assign(&rhs).unwrap();
+            result.wrapping_sub_assign(&rhs).unwrap();
+            result == lhs
+        }
+        QuickCheck::new()
+            .rng(Gen::new(300))
+            .quickcheck(prop as fn(ApInt, ApInt) -> bool);
+    }
+
+    #[test]
+    fn signed_unsigned_round_trip() {
+        fn prop(value: Int) -> TestResult {
+            let unsigned: UInt = value.clone().into_unsigned();
+            TestResult::from_bool(unsigned.into_signed() == value)
+        }
+        QuickCheck::new()
+            .rng(Gen::new(300))
+            .quickcheck(prop as fn(Int) -> TestResult);
+    }
+}
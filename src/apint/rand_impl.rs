@@ -2,21 +2,115 @@ use crate::{
     ApInt,
     BitWidth,
     Digit,
+    Error,
+    Result,
+    Width,
 };
-use rand::SeedableRng as _;
+use core::iter;
+use rand::{
+    distributions::Distribution,
+    SeedableRng as _,
+};
+
+/// A distribution that samples `ApInt`s with the given `BitWidth` uniformly
+/// from all of their bit patterns.
+///
+/// # Example
+///
+/// ```
+/// # use apint::{ApInt, BitWidth, UniformApInt, Width};
+/// use rand::{Rng, SeedableRng};
+/// let mut rng = rand::rngs::SmallRng::seed_from_u64(42);
+/// let width = BitWidth::new(100).unwrap();
+/// let value: ApInt = rng.sample(UniformApInt(width));
+/// assert_eq!(value.width(), width);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct UniformApInt(pub BitWidth);
 
-impl rand::distributions::Distribution<Digit> for rand::distributions::Standard {
-    /// Creates a random `Digit` using the given random number generator.
-    fn sample<R>(&self, rng: &mut R) -> Digit
+impl Distribution<ApInt> for UniformApInt {
+    fn sample<R>(&self, rng: &mut R) -> ApInt
     where
         R: rand::Rng + ?Sized,
     {
-        Digit(rng.next_u64())
+        ApInt::random(self.0, rng)
     }
 }
 
 /// # Random Utilities using `rand` crate.
 impl ApInt {
+    /// Creates a new `ApInt` with the given `width` where every bit pattern is
+    /// equally likely using the given random number generator.
+    ///
+    /// The digits are filled from `rng.next_u64()` and the excess bits of the
+    /// most significant limb are cleared. So for a given seed the result is
+    /// deterministic and independent of the `Digit` size.
+    pub fn random<R>(width: BitWidth, rng: &mut R) -> ApInt
+    where
+        R: rand::Rng + ?Sized,
+    {
+        // digits are always generated from 64-bit limbs so that the result for a
+        // given random number generator is independent of the `Digit` size
+        let limbs = iter::repeat_with(|| rng.next_u64()).take(width.required_u64s());
+        ApInt::from_u64_limbs(width, limbs)
+    }
+
+    /// Creates a new `ApInt` with the bit width of `bound` and a value that
+    /// is uniformly distributed within `[0, bound)` using **unsigned**
+    /// interpretation.
+    ///
+    /// Candidates are sampled with only as many random bits as are required
+    /// to represent `bound` and rejected as long as they are not less than
+    /// `bound`. This avoids the modulo bias of a reduction and every
+    /// candidate is accepted with a probability greater than `1/2`.
+    ///
+    /// # Errors
+    ///
+    /// - If `bound` is zero.
+    pub fn random_below<R>(bound: &ApInt, rng: &mut R) -> Result<ApInt>
+    where
+        R: rand::Rng + ?Sized,
+    {
+        let bits = BitWidth::new(bound.required_bits(false)).map_err(|_| {
+            Error::empty_range()
+                .with_annotation("Cannot sample a value below a bound of zero.")
+        })?;
+        loop {
+            let candidate = ApInt::random(bits, rng).into_zero_resize(bound.width());
+            if candidate.checked_ult(bound)? {
+                return Ok(candidate)
+            }
+        }
+    }
+
+    /// Creates a new `ApInt` with the bit width of `lo` and `hi` and a value
+    /// that is uniformly distributed within `[lo, hi)` using **unsigned**
+    /// interpretation.
+    ///
+    /// For more details look into
+    /// [`random_below`](struct.ApInt.html#method.random_below).
+    ///
+    /// # Errors
+    ///
+    /// - If `lo` and `hi` have unmatching bit widths.
+    /// - If `lo` is not less than `hi`.
+    pub fn random_range<R>(lo: &ApInt, hi: &ApInt, rng: &mut R) -> Result<ApInt>
+    where
+        R: rand::Rng + ?Sized,
+    {
+        if !lo.checked_ult(hi)? {
+            return Err(Error::empty_range().with_annotation(
+                "Cannot sample a value within a range where the lower bound is not \
+                 less than the upper bound.",
+            ))
+        }
+        let mut span = hi.clone();
+        span.wrapping_sub_assign(lo)?;
+        let mut result = ApInt::random_below(&span, rng)?;
+        result.wrapping_add_assign(lo)?;
+        Ok(result)
+    }
+
     /// Creates a new `ApInt` with the given `BitWidth` and random `Digit`s.
     pub fn random_with_width(width: BitWidth) -> ApInt {
         ApInt::random_with_width_using(width, &mut rand::rngs::SmallRng::from_entropy())
@@ -26,28 +120,13 @@ impl ApInt {
     /// using the given random number generator.
     ///
     /// **Note:** This is useful for cryptographic or testing purposes.
+    ///
+    /// For more details look into [`random`](struct.ApInt.html#method.random).
     pub fn random_with_width_using<R>(width: BitWidth, rng: &mut R) -> ApInt
     where
         R: rand::Rng,
     {
-        let required_digits = width.required_digits();
-        assert!(required_digits >= 1);
-        use rand::distributions::{
-            Distribution,
-            Standard,
-        };
-        let random_digits = Standard.sample_iter(rng).take(required_digits);
-        // The truncation will be cheap always!
-        ApInt::from_iter(random_digits)
-            .expect(
-                "We asserted that `required_digits` is at least `1` or greater
-                     so it is safe to assume that `ApInt::from_iter` won't fail.",
-            )
-            .into_truncate(width)
-            .expect(
-                "`BitWidth::required_digits` returns an upper bound for the
-                     number of required digits, so it is safe to truncate.",
-            )
+        ApInt::random(width, rng)
     }
 
     /// Randomizes the digits of this `ApInt` inplace.
@@ -65,12 +144,11 @@ impl ApInt {
     where
         R: rand::Rng,
     {
-        use rand::distributions::{
-            Distribution,
-            Standard,
-        };
-        let std_dist = Standard.sample_iter(rng);
-        self.digits_mut().zip(std_dist).for_each(|(d, r)| *d = r);
+        let random_digits =
+            iter::repeat_with(|| rng.next_u64()).flat_map(Digit::split_u64);
+        self.digits_mut()
+            .zip(random_digits)
+            .for_each(|(d, r)| *d = r);
         self.clear_unused_bits();
     }
 }
@@ -111,6 +189,132 @@ mod tests {
         );
     }
 
+    #[test]
+    fn random_deterministic_under_seed() {
+        use rand::{
+            Rng,
+            RngCore,
+        };
+        let seed = [0x5A; 16];
+        let mut rng1 = XorShiftRng::from_seed(seed);
+        let mut rng2 = XorShiftRng::from_seed(seed);
+        for &width in &[1, 7, 64, 65, 130, 521] {
+            let width = BitWidth::new(width).unwrap();
+            let value = ApInt::random(width, &mut rng1);
+            assert_eq!(rng2.sample(UniformApInt(width)), value);
+        }
+        // limbs are consumed in little-endian order independent of the width
+        let mut rng1 = XorShiftRng::from_seed(seed);
+        let mut rng2 = XorShiftRng::from_seed(seed);
+        let w100 = BitWidth::new(100).unwrap();
+        let limbs = [rng2.next_u64(), rng2.next_u64() & ((1 << 36) - 1)];
+        assert_eq!(
+            ApInt::random(w100, &mut rng1),
+            ApInt::from_le_limbs(&limbs)
+                .unwrap()
+                .into_truncate(w100)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn random_bit_frequency() {
+        const SAMPLES: usize = 4000;
+        let mut rng = XorShiftRng::from_seed([0x3C; 16]);
+        for &width in &[1, 7, 64, 65, 130] {
+            let width = BitWidth::new(width).unwrap();
+            let mut counts = vec![0_usize; width.to_usize()];
+            for _ in 0..SAMPLES {
+                let value = ApInt::random(width, &mut rng);
+                for (pos, count) in counts.iter_mut().enumerate() {
+                    if value.get_bit_at(pos).unwrap() {
+                        *count += 1;
+                    }
+                }
+            }
+            for (pos, &count) in counts.iter().enumerate() {
+                let frequency = count as f64 / SAMPLES as f64;
+                assert!(
+                    (0.45..0.55).contains(&frequency),
+                    "bit {} of width {:?} has a frequency of {}",
+                    pos,
+                    width,
+                    frequency
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn random_below_bounds() {
+        let mut rng = XorShiftRng::from_seed([0x17; 16]);
+        for &(width, bound) in &[
+            (1, 1_u128),
+            (8, 3),
+            (8, 200),
+            (64, 1_000_000_007),
+            (65, 1 << 64),
+            (128, u128::MAX),
+        ] {
+            let width = BitWidth::new(width).unwrap();
+            let bound = ApInt::from_u128(bound).into_truncate(width).unwrap();
+            for _ in 0..1000 {
+                let value = ApInt::random_below(&bound, &mut rng).unwrap();
+                assert_eq!(value.width(), width);
+                assert!(value.checked_ult(&bound).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn random_below_uniform() {
+        const SAMPLES: usize = 30_000;
+        let mut rng = XorShiftRng::from_seed([0x71; 16]);
+        let bound = ApInt::from_u32(3);
+        let mut counts = [0_usize; 3];
+        for _ in 0..SAMPLES {
+            let value = ApInt::random_below(&bound, &mut rng).unwrap();
+            counts[value.resize_to_u32() as usize] += 1;
+        }
+        for &count in &counts {
+            let frequency = count as f64 / SAMPLES as f64;
+            assert!((0.32..0.347).contains(&frequency), "{:?}", counts);
+        }
+    }
+
+    #[test]
+    fn random_range() {
+        let mut rng = XorShiftRng::from_seed([0x42; 16]);
+        let lo = ApInt::from_u64(1000);
+        let hi = ApInt::from_u64(1010);
+        let mut seen = [false; 10];
+        for _ in 0..1000 {
+            let value = ApInt::random_range(&lo, &hi, &mut rng).unwrap();
+            assert!(value.checked_uge(&lo).unwrap() && value.checked_ult(&hi).unwrap());
+            seen[(value.resize_to_u64() - 1000) as usize] = true;
+        }
+        assert!(seen.iter().all(|&seen| seen));
+    }
+
+    #[test]
+    fn empty_range() {
+        use crate::ErrorKind;
+        let mut rng = XorShiftRng::from_seed([0x42; 16]);
+        let zero = ApInt::zero(BitWidth::w32());
+        assert_eq!(
+            ApInt::random_below(&zero, &mut rng).unwrap_err().kind(),
+            &ErrorKind::EmptyRange
+        );
+        let one = ApInt::one(BitWidth::w32());
+        for &(lo, hi) in &[(&zero, &zero), (&one, &zero), (&one, &one)] {
+            assert_eq!(
+                ApInt::random_range(lo, hi, &mut rng).unwrap_err().kind(),
+                &ErrorKind::EmptyRange
+            );
+        }
+        assert!(ApInt::random_range(&zero, &ApInt::from_u8(1), &mut rng).is_err());
+    }
+
     #[test]
     fn randomize_using() {
         let default_seed = <XorShiftRng as rand::SeedableRng>::Seed::default();
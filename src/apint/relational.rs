@@ -3,7 +3,10 @@ use crate::{
     mem::format,
     ApInt,
     Digit,
+    DigitRepr,
+    Error,
     Result,
+    SignedDigitRepr,
     Width,
 };
 use core::{
@@ -23,6 +26,19 @@ impl PartialEq for ApInt {
 
 impl Eq for ApInt {}
 
+/// Values of unmatching bit widths are never equal, this only depends on the
+/// public bit widths.
+#[cfg(feature = "subtle")]
+impl subtle::ConstantTimeEq for ApInt {
+    fn ct_eq(&self, other: &ApInt) -> subtle::Choice {
+        if self.width() != other.width() {
+            return subtle::Choice::from(0)
+        }
+        let mask = ApInt::ct_eq_mask(self.as_digit_slice(), other.as_digit_slice());
+        subtle::Choice::from(mask as u8)
+    }
+}
+
 /// # Comparison Operations
 impl ApInt {
     /// Unsigned less-than (`ult`) comparison between `self` and `rhs`.
@@ -142,8 +158,8 @@ impl ApInt {
                 match zipped {
                     ZipDataAccess::Inl(lhs, rhs) => {
                         let infate_abs = Digit::BITS - self.width().to_usize();
-                        let lhs = (lhs.repr() << infate_abs) as i64;
-                        let rhs = (rhs.repr() << infate_abs) as i64;
+                        let lhs = (lhs.repr() << infate_abs) as SignedDigitRepr;
+                        let rhs = (rhs.repr() << infate_abs) as SignedDigitRepr;
                         Ok(lhs < rhs)
                     }
                     ZipDataAccess::Ext(..) => {
@@ -230,6 +246,251 @@ impl ApInt {
             ))
         })
     }
+
+    /// Returns the digit at position `index` of `self` conceptually extended
+    /// to an infinite number of digits, either by zero-extension or by
+    /// sign-extension if `signed` is `true`.
+    fn extended_digit_at(&self, index: usize, signed: bool) -> Digit {
+        let digits = self.as_digit_slice();
+        let negative = signed && self.msb();
+        if index + 1 < digits.len() {
+            return digits[index]
+        }
+        if index >= digits.len() {
+            return if negative { Digit::ONES } else { Digit::ZERO }
+        }
+        match self.width().excess_bits() {
+            Some(bits) if negative => digits[index] | (Digit::ONES << bits),
+            _ => digits[index],
+        }
+    }
+
+    /// Compares the values of `self` and `rhs` using **unsigned**
+    /// interpretation.
+    ///
+    /// # Note
+    ///
+    /// - The bit widths of `self` and `rhs` may differ, the narrower value is
+    ///   conceptually zero-extended.
+    /// - This does **not** allocate memory.
+    pub fn ucmp_value(&self, rhs: &ApInt) -> Ordering {
+        let significant_len = |digits: &[Digit]| {
+            digits.len() - digits.iter().rev().take_while(|d| d.is_zero()).count()
+        };
+        let lhs = self.as_digit_slice();
+        let rhs = rhs.as_digit_slice();
+        let lhs = &lhs[..significant_len(lhs)];
+        let rhs = &rhs[..significant_len(rhs)];
+        lhs.len()
+            .cmp(&rhs.len())
+            .then_with(|| lhs.iter().rev().cmp(rhs.iter().rev()))
+    }
+
+    /// Compares the values of `self` and `rhs` using **signed**
+    /// interpretation.
+    ///
+    /// # Note
+    ///
+    /// - The bit widths of `self` and `rhs` may differ, the narrower value is
+    ///   conceptually sign-extended.
+    /// - This does **not** allocate memory.
+    pub fn scmp_value(&self, rhs: &ApInt) -> Ordering {
+        match (self.msb(), rhs.msb()) {
+            (false, true) => return Ordering::Greater,
+            (true, false) => return Ordering::Less,
+            _ => (),
+        }
+        // with equal signs the two's complement representations of both values
+        // sign-extended to a common width compare like unsigned values
+        let len = self.len_digits().max(rhs.len_digits());
+        (0..len)
+            .rev()
+            .map(|i| {
+                self.extended_digit_at(i, true)
+                    .cmp(&rhs.extended_digit_at(i, true))
+            })
+            .find(|ord| *ord != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }
+
+    /// Returns `true` if `self` and `rhs` represent the same **unsigned**
+    /// value.
+    ///
+    /// # Note
+    ///
+    /// - Unlike `==` the bit widths of `self` and `rhs` may differ, the
+    ///   narrower value is conceptually zero-extended.
+    /// - This does **not** allocate memory.
+    pub fn eq_value(&self, rhs: &ApInt) -> bool {
+        let lhs = self.as_digit_slice();
+        let rhs = rhs.as_digit_slice();
+        let common = lhs.len().min(rhs.len());
+        lhs[..common] == rhs[..common]
+            && lhs[common..].iter().all(|d| d.is_zero())
+            && rhs[common..].iter().all(|d| d.is_zero())
+    }
+
+    /// Compares the value of `self` with `rhs` using **unsigned**
+    /// interpretation regardless of the bit width of `self`.
+    ///
+    /// **Note:** This does **not** allocate memory.
+    pub fn ucmp_u128(&self, rhs: u128) -> Ordering {
+        let digits = self.as_digit_slice();
+        let (lo, hi) = digits.split_at(digits.len().min(128 / Digit::BITS));
+        if hi.iter().any(|d| !d.is_zero()) {
+            return Ordering::Greater
+        }
+        lo.iter()
+            .rev()
+            .fold(0, |acc, d| (acc << Digit::BITS) | u128::from(d.repr()))
+            .cmp(&rhs)
+    }
+
+    /// Compares the value of `self` with `rhs` using **unsigned**
+    /// interpretation regardless of the bit width of `self`.
+    ///
+    /// **Note:** This does **not** allocate memory.
+    pub fn ucmp_u64(&self, rhs: u64) -> Ordering {
+        self.ucmp_u128(u128::from(rhs))
+    }
+
+    /// Returns `true` if `self` represents the **unsigned** value `rhs`
+    /// regardless of the bit width of `self`.
+    ///
+    /// **Note:** This does **not** allocate memory.
+    pub fn eq_u64(&self, rhs: u64) -> bool {
+        self.ucmp_u64(rhs) == Ordering::Equal
+    }
+
+    /// Compares the value of `self` with `rhs` using **signed**
+    /// interpretation regardless of the bit width of `self`.
+    ///
+    /// **Note:** This does **not** allocate memory.
+    pub fn scmp_i128(&self, rhs: i128) -> Ordering {
+        match (self.msb(), rhs < 0) {
+            (false, true) => return Ordering::Greater,
+            (true, false) => return Ordering::Less,
+            _ => (),
+        }
+        let rhs_digit_at = |index: usize| {
+            if index < 128 / Digit::BITS {
+                Digit((rhs >> (index * Digit::BITS)) as DigitRepr)
+            } else if rhs < 0 {
+                Digit::ONES
+            } else {
+                Digit::ZERO
+            }
+        };
+        (0..self.len_digits().max(128 / Digit::BITS))
+            .rev()
+            .map(|i| self.extended_digit_at(i, true).cmp(&rhs_digit_at(i)))
+            .find(|ord| *ord != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }
+
+    /// Compares the value of `self` with `rhs` using **signed**
+    /// interpretation regardless of the bit width of `self`.
+    ///
+    /// **Note:** This does **not** allocate memory.
+    pub fn scmp_i64(&self, rhs: i64) -> Ordering {
+        self.scmp_i128(i128::from(rhs))
+    }
+
+    /// Returns `true` if `self` represents the **signed** value `rhs`
+    /// regardless of the bit width of `self`.
+    ///
+    /// **Note:** This does **not** allocate memory.
+    pub fn eq_i64(&self, rhs: i64) -> bool {
+        self.scmp_i64(rhs) == Ordering::Equal
+    }
+
+    /// Returns the digits of `self` and `rhs` or an error if their bit widths
+    /// do not match. This only depends on the public bit widths.
+    fn ct_zip_digits<'a>(&'a self, rhs: &'a ApInt) -> Result<(&'a [Digit], &'a [Digit])> {
+        if self.width() != rhs.width() {
+            return Error::unmatching_bitwidths(self.width(), rhs.width()).into()
+        }
+        Ok((self.as_digit_slice(), rhs.as_digit_slice()))
+    }
+
+    /// Returns `1` if `lhs` is less than `rhs` and `0` otherwise using
+    /// **unsigned** interpretation, where both slices have the same length.
+    ///
+    /// This walks all digits and computes the final borrow of `lhs - rhs`
+    /// without any data-dependent branches.
+    fn ct_borrow(lhs: &[Digit], rhs: &[Digit]) -> DigitRepr {
+        let mut borrow = 0;
+        for (l, r) in lhs.iter().zip(rhs.iter()) {
+            let (l, r) = (l.repr(), r.repr());
+            let diff = l.wrapping_sub(r).wrapping_sub(borrow);
+            borrow = ((!l & r) | (!(l ^ r) & diff)) >> (Digit::BITS - 1);
+        }
+        borrow
+    }
+
+    /// Returns `1` if `lhs` and `rhs` are equal and `0` otherwise, where both
+    /// slices have the same length.
+    ///
+    /// This walks all digits without any data-dependent branches.
+    fn ct_eq_mask(lhs: &[Digit], rhs: &[Digit]) -> DigitRepr {
+        let diff = lhs
+            .iter()
+            .zip(rhs.iter())
+            .fold(0, |acc, (l, r)| acc | (l.repr() ^ r.repr()));
+        ((diff | diff.wrapping_neg()) >> (Digit::BITS - 1)) ^ 1
+    }
+
+    /// Constant-time equality check between `self` and `rhs`.
+    ///
+    /// # Note
+    ///
+    /// - Unlike `==` this always inspects every digit and does not branch on
+    ///   the values of `self` or `rhs`.
+    /// - Whether the bit widths match is public information and is checked
+    ///   first.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn ct_eq(&self, rhs: &ApInt) -> Result<bool> {
+        let (lhs, rhs) = self.ct_zip_digits(rhs)?;
+        Ok(ApInt::ct_eq_mask(lhs, rhs) == 1)
+    }
+
+    /// Constant-time unsigned less-than comparison between `self` and `rhs`.
+    ///
+    /// # Note
+    ///
+    /// - Returns `Ok(true)` if `self < rhs`.
+    /// - Interprets both `ApInt` instances as **unsigned** values.
+    /// - Unlike `checked_ult` this always inspects every digit and does not
+    ///   branch on the values of `self` or `rhs`.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn ct_lt_u(&self, rhs: &ApInt) -> Result<bool> {
+        let (lhs, rhs) = self.ct_zip_digits(rhs)?;
+        Ok(ApInt::ct_borrow(lhs, rhs) == 1)
+    }
+
+    /// Constant-time unsigned less-equals comparison between `self` and
+    /// `rhs`.
+    ///
+    /// # Note
+    ///
+    /// - Returns `Ok(true)` if `self <= rhs`.
+    /// - Interprets both `ApInt` instances as **unsigned** values.
+    /// - Unlike `checked_ule` this always inspects every digit and does not
+    ///   branch on the values of `self` or `rhs`.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn ct_le_u(&self, rhs: &ApInt) -> Result<bool> {
+        let (lhs, rhs) = self.ct_zip_digits(rhs)?;
+        Ok(ApInt::ct_borrow(rhs, lhs) == 0)
+    }
 }
 
 #[cfg(test)]
@@ -267,4 +528,191 @@ mod tests {
             assert_ne!(c, d);
         }
     }
+
+    mod constant_time {
+        use super::*;
+        use crate::BitWidth;
+
+        #[test]
+        fn small() {
+            for x in 0..=u8::MAX {
+                for y in 0..=u8::MAX {
+                    let (x_ap, y_ap) = (ApInt::from(x), ApInt::from(y));
+                    assert_eq!(x_ap.ct_eq(&y_ap), Ok(x == y));
+                    assert_eq!(x_ap.ct_lt_u(&y_ap), Ok(x < y));
+                    assert_eq!(x_ap.ct_le_u(&y_ap), Ok(x <= y));
+                }
+            }
+        }
+
+        #[test]
+        fn large() {
+            let values = [
+                ApInt::from([0u64, 0, 0]),
+                ApInt::from([0u64, 0, 1]),
+                ApInt::from([0u64, 1, 0]),
+                ApInt::from([0u64, 1, u64::MAX]),
+                ApInt::from([1u64, 0, 0]),
+                ApInt::from([1u64, 0, 1]),
+                ApInt::from([u64::MAX, u64::MAX, u64::MAX - 1]),
+                ApInt::from([u64::MAX, u64::MAX, u64::MAX]),
+            ];
+            for x in &values {
+                for y in &values {
+                    assert_eq!(x.ct_eq(y), Ok(x == y));
+                    assert_eq!(x.ct_lt_u(y), x.checked_ult(y));
+                    assert_eq!(x.ct_le_u(y), x.checked_ule(y));
+                }
+            }
+        }
+
+        #[test]
+        fn unmatching_widths() {
+            let a = ApInt::zero(BitWidth::w64());
+            let b = ApInt::zero(BitWidth::w128());
+            assert!(a.ct_eq(&b).is_err());
+            assert!(a.ct_lt_u(&b).is_err());
+            assert!(b.ct_le_u(&a).is_err());
+        }
+
+        #[cfg(feature = "subtle")]
+        #[test]
+        fn subtle_choice() {
+            use subtle::ConstantTimeEq;
+            let a = ApInt::from([1u64, 2]);
+            assert!(bool::from(ConstantTimeEq::ct_eq(&a, &a.clone())));
+            assert!(!bool::from(ConstantTimeEq::ct_eq(
+                &a,
+                &ApInt::from([1u64, 3])
+            )));
+            assert!(!bool::from(ConstantTimeEq::ct_eq(&a, &ApInt::from(2u64))));
+        }
+    }
+
+    mod cmp_primitive {
+        use super::*;
+        use crate::BitWidth;
+
+        #[test]
+        fn unsigned() {
+            let w300 = BitWidth::new(300).unwrap();
+            let x = ApInt::from_u64(42).into_zero_extend(w300).unwrap();
+            assert!(x.eq_u64(42));
+            assert!(!x.eq_u64(43));
+            assert_eq!(x.ucmp_u64(41), Ordering::Greater);
+            assert_eq!(x.ucmp_u128(1 << 100), Ordering::Less);
+            // a set upper digit is greater than any primitive
+            let mut y = x.clone();
+            y.set_bit_at(299).unwrap();
+            assert!(!y.eq_u64(42));
+            assert_eq!(y.ucmp_u64(u64::MAX), Ordering::Greater);
+            assert_eq!(y.ucmp_u128(u128::MAX), Ordering::Greater);
+            let z = ApInt::from_u128(u128::MAX);
+            assert_eq!(z.ucmp_u128(u128::MAX), Ordering::Equal);
+            assert_eq!(z.ucmp_u64(u64::MAX), Ordering::Greater);
+            assert!(ApInt::from_u8(200).eq_u64(200));
+            assert!(ApInt::zero(BitWidth::w1()).eq_u64(0));
+            assert_eq!(ApInt::all_set(BitWidth::w1()).ucmp_u64(1), Ordering::Equal);
+        }
+
+        #[test]
+        fn signed() {
+            let w300 = BitWidth::new(300).unwrap();
+            let minus_one = ApInt::all_set(w300);
+            assert!(minus_one.eq_i64(-1));
+            assert_eq!(minus_one.scmp_i64(0), Ordering::Less);
+            assert_eq!(minus_one.scmp_i64(-2), Ordering::Greater);
+            assert_eq!(minus_one.scmp_i128(i128::MIN), Ordering::Greater);
+            assert_eq!(
+                ApInt::signed_min_value(w300).scmp_i128(i128::MIN),
+                Ordering::Less
+            );
+            assert_eq!(
+                ApInt::signed_max_value(w300).scmp_i64(i64::MAX),
+                Ordering::Greater
+            );
+            let x = ApInt::from_i64(-42).into_sign_extend(w300).unwrap();
+            assert!(x.eq_i64(-42));
+            assert_eq!(x.scmp_i64(-41), Ordering::Less);
+            assert_eq!(x.scmp_i128(-43), Ordering::Greater);
+            assert!(ApInt::from_i8(-5).eq_i64(-5));
+            assert!(!ApInt::from_i8(-5).eq_u64(u64::MAX - 4));
+            assert!(ApInt::all_set(BitWidth::w1()).eq_i64(-1));
+            let values = [i64::MIN, -300, -129, -42, -1, 0, 1, 42, 127, 300, i64::MAX];
+            for &a in &values {
+                for &b in &values {
+                    let a_ap = ApInt::from_i64(a);
+                    assert_eq!(a_ap.scmp_i64(b), a.cmp(&b));
+                    assert_eq!(a_ap.scmp_i128(i128::from(b)), a.cmp(&b));
+                    assert_eq!(a_ap.ucmp_u64(b as u64), (a as u64).cmp(&(b as u64)));
+                }
+            }
+        }
+    }
+
+    mod cmp_value {
+        use super::*;
+        use crate::BitWidth;
+
+        #[test]
+        fn unsigned() {
+            let short = ApInt::from_u8(42);
+            let long = ApInt::from_u128(42);
+            assert_eq!(short.ucmp_value(&long), Ordering::Equal);
+            assert_eq!(long.ucmp_value(&short), Ordering::Equal);
+            assert!(short.eq_value(&long));
+            assert!(long.eq_value(&short));
+            let wide = ApInt::from([0u64, 0, 0, 42]);
+            assert!(wide.eq_value(&short));
+            assert_eq!(wide.ucmp_value(&long), Ordering::Equal);
+            let big = ApInt::from([0u64, 1, 0, 0]);
+            assert!(!big.eq_value(&short));
+            assert_eq!(big.ucmp_value(&long), Ordering::Greater);
+            assert_eq!(long.ucmp_value(&big), Ordering::Less);
+            assert_eq!(ApInt::from_u8(43).ucmp_value(&long), Ordering::Greater);
+            assert_eq!(ApInt::from_i8(-1).ucmp_value(&long), Ordering::Greater);
+            assert!(!ApInt::from_i8(-1).eq_value(&ApInt::from_i16(-1)));
+            assert_eq!(
+                ApInt::zero(BitWidth::w1()).ucmp_value(&ApInt::zero(BitWidth::w128())),
+                Ordering::Equal
+            );
+        }
+
+        #[test]
+        fn signed() {
+            let short = ApInt::from_i8(-42);
+            let long = ApInt::from_i128(-42);
+            assert_eq!(short.scmp_value(&long), Ordering::Equal);
+            assert_eq!(long.scmp_value(&short), Ordering::Equal);
+            assert_eq!(short.scmp_value(&ApInt::from_i128(-41)), Ordering::Less);
+            assert_eq!(short.scmp_value(&ApInt::from_i128(-43)), Ordering::Greater);
+            assert_eq!(short.scmp_value(&ApInt::from_i128(5)), Ordering::Less);
+            assert_eq!(
+                ApInt::from_i128(i128::MIN).scmp_value(&short),
+                Ordering::Less
+            );
+            assert_eq!(
+                ApInt::from_i128(-1).scmp_value(&ApInt::all_set(BitWidth::w1())),
+                Ordering::Equal
+            );
+            // `short` is negative and `-200` is not representable by it
+            assert_eq!(ApInt::from_i16(-200).scmp_value(&short), Ordering::Less);
+            let values = [i64::MIN, -300, -129, -42, -1, 0, 1, 42, 127, 300, i64::MAX];
+            for &x in &values {
+                for &y in &values {
+                    let x_ap = ApInt::from_i64(x)
+                        .into_sign_extend(BitWidth::new(70).unwrap())
+                        .unwrap();
+                    let y_ap = ApInt::from_i128(y as i128);
+                    assert_eq!(x_ap.scmp_value(&y_ap), x.cmp(&y));
+                    assert_eq!(y_ap.scmp_value(&x_ap), y.cmp(&x));
+                    if (i8::MIN as i64..=i8::MAX as i64).contains(&y) {
+                        let y_small = ApInt::from_i8(y as i8);
+                        assert_eq!(x_ap.scmp_value(&y_small), x.cmp(&y));
+                        assert_eq!(y_small.scmp_value(&x_ap), y.cmp(&x));
+                    }
+                }
+            }
+        }
+    }
 }
@@ -1,8 +1,11 @@
 use crate::{
-    mem::vec::Vec,
+    mem::{
+        format,
+        string::String,
+        vec::Vec,
+    },
     ApInt,
     BitWidth,
-    Digit,
 };
 
 use serde::{
@@ -11,15 +14,6 @@ use serde::{
     Serializer,
 };
 
-impl Serialize for Digit {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.serialize_u64(self.repr())
-    }
-}
-
 impl Serialize for BitWidth {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -36,6 +30,12 @@ impl Serialize for BitWidth {
 }
 
 impl Serialize for ApInt {
+    /// Serializes the bit width together with the value of this `ApInt`.
+    ///
+    /// Human-readable formats get a struct with the `width` and the `value` as
+    /// `0x` prefixed hexadecimal string with exactly `ceil(width / 4)` digits.
+    /// Compact formats get a tuple of the `width` and the 64-bit limbs in
+    /// little-endian order.
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -48,12 +48,14 @@ impl Serialize for ApInt {
         if serializer.is_human_readable() {
             let mut s = serializer.serialize_struct("ApInt", 2)?;
             s.serialize_field("width", &self.len)?;
-            s.serialize_field("digits", self.as_digit_slice())?;
+            s.serialize_field("value", &format!("0x{}", self.to_hex_string()))?;
             s.end()
         } else {
+            // digits are always serialized as 64-bit limbs independent of the
+            // configured `Digit` size
             let mut s = serializer.serialize_tuple(2)?;
             s.serialize_element(&self.len)?;
-            s.serialize_element(&self.as_digit_slice())?;
+            s.serialize_element(&self.to_u64_vec())?;
             s.end()
         }
     }
@@ -137,42 +139,51 @@ impl<'de> Deserialize<'de> for BitWidth {
     }
 }
 
-impl<'de> Deserialize<'de> for Digit {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        struct DigitVisitor;
-
-        impl<'de> Visitor<'de> for DigitVisitor {
-            type Value = Digit;
-
-            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter.write_str("`u64` repr of `Digit`")
-            }
-
-            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
-            where
-                E: de::Error,
-            {
-                Ok(Digit(v))
-            }
-        }
-
-        deserializer.deserialize_u64(DigitVisitor)
+/// Creates an `ApInt` with the given `width` from the given 64-bit `limbs` in
+/// little-endian order.
+///
+/// # Errors
+///
+/// - If the number of `limbs` does not match the `width`.
+/// - If any bit of the most significant limb beyond the `width` is set.
+fn try_from_u64_limbs<E>(width: BitWidth, limbs: Vec<u64>) -> Result<ApInt, E>
+where
+    E: de::Error,
+{
+    if width.required_u64s() != limbs.len() {
+        return Err(de::Error::invalid_length(
+            limbs.len(),
+            &"exactly `ceil(width / 64)` 64-bit limbs",
+        ))
     }
+    let excess_bits = width.to_usize() % 64;
+    let last = limbs[limbs.len() - 1];
+    if excess_bits != 0 && last >> excess_bits != 0 {
+        return Err(de::Error::invalid_value(
+            de::Unexpected::Unsigned(last),
+            &"a most significant limb without bits set beyond the `width`",
+        ))
+    }
+    Ok(ApInt::from_u64_limbs(width, limbs))
 }
 
 impl<'de> Deserialize<'de> for ApInt {
+    /// Deserializes an `ApInt` from the representations of its `Serialize`
+    /// implementation.
+    ///
+    /// Human-readable formats may also provide the `value` as decimal string
+    /// or the legacy `digits` as a sequence of 64-bit limbs in little-endian
+    /// order instead.
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
         enum Field {
             Width,
+            Value,
             Digits,
         }
-        const FIELDS: &[&str] = &["width", "digits"];
+        const FIELDS: &[&str] = &["width", "value", "digits"];
 
         impl<'de> Deserialize<'de> for Field {
             fn deserialize<D>(deserializer: D) -> Result<Field, D::Error>
@@ -185,7 +196,7 @@ impl<'de> Deserialize<'de> for ApInt {
                     type Value = Field;
 
                     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                        formatter.write_str("`width` or `digits`")
+                        formatter.write_str("`width`, `value` or `digits`")
                     }
 
                     fn visit_str<E>(self, value: &str) -> Result<Field, E>
@@ -194,6 +205,7 @@ impl<'de> Deserialize<'de> for ApInt {
                     {
                         match value {
                             "width" => Ok(Field::Width),
+                            "value" => Ok(Field::Value),
                             "digits" => Ok(Field::Digits),
                             _ => Err(de::Error::unknown_field(value, FIELDS)),
                         }
@@ -218,7 +230,8 @@ impl<'de> Deserialize<'de> for ApInt {
                 V: MapAccess<'de>,
             {
                 let mut width: Option<BitWidth> = None;
-                let mut digits: Option<Vec<Digit>> = None;
+                let mut value: Option<String> = None;
+                let mut digits: Option<Vec<u64>> = None;
                 while let Some(key) = map.next_key()? {
                     match key {
                         Field::Width => {
@@ -227,6 +240,12 @@ impl<'de> Deserialize<'de> for ApInt {
                             }
                             width = Some(map.next_value()?);
                         }
+                        Field::Value => {
+                            if value.is_some() {
+                                return Err(de::Error::duplicate_field("value"))
+                            }
+                            value = Some(map.next_value()?);
+                        }
                         Field::Digits => {
                             if digits.is_some() {
                                 return Err(de::Error::duplicate_field("digits"))
@@ -236,27 +255,18 @@ impl<'de> Deserialize<'de> for ApInt {
                     }
                 }
                 let width = width.ok_or_else(|| de::Error::missing_field("width"))?;
-                let digits = digits.ok_or_else(|| de::Error::missing_field("digits"))?;
-
-                if width.required_digits() != digits.len() {
-                    return Err(de::Error::invalid_value(
-                        de::Unexpected::Unsigned(digits.len() as u64),
-                        &"require `width` to be compatible with `digits.len()`",
-                    ))
+                match (value, digits) {
+                    (Some(value), None) => {
+                        ApInt::from_str_prefixed(&value, width).map_err(de::Error::custom)
+                    }
+                    (None, Some(digits)) => try_from_u64_limbs(width, digits),
+                    (None, None) => Err(de::Error::missing_field("value")),
+                    (Some(_), Some(_)) => {
+                        Err(de::Error::custom(
+                            "expected either `value` or `digits` but found both",
+                        ))
+                    }
                 }
-
-                Ok(ApInt::from_iter(digits)
-                    .expect(
-                        "We already asserted that we deserialized the lower-bound of \
-                         `required_digits` so `ApInt::from_iter` is fail free.",
-                    )
-                    .into_truncate(width)
-                    .expect(
-                        "An `into_truncate` call to `width` cannot fail since `digits`
-                             contains exactly `required_digits` digits and \
-                         `ApInt::from_iter always creates an `ApInt` with an upper \
-                         bound bit width.",
-                    ))
             }
         }
 
@@ -273,30 +283,13 @@ impl<'de> Deserialize<'de> for ApInt {
             where
                 V: SeqAccess<'de>,
             {
-                let width: BitWidth = seq.next_element()?.unwrap();
-                let digits: Vec<Digit> = seq
+                let width: BitWidth = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let digits: Vec<u64> = seq
                     .next_element()?
                     .ok_or_else(|| de::Error::invalid_length(1, &self))?;
-
-                if width.required_digits() != digits.len() {
-                    return Err(de::Error::invalid_value(
-                        de::Unexpected::Seq,
-                        &"unexpected number of digits found",
-                    ))
-                }
-
-                Ok(ApInt::from_iter(digits)
-                    .expect(
-                        "We already asserted that we deserialized the lower-bound of \
-                         `required_digits` so `ApInt::from_iter` is fail free.",
-                    )
-                    .into_truncate(width)
-                    .expect(
-                        "An `into_truncate` call to `width` cannot fail since `digits` \
-                         contains exactly `required_digits` digits and \
-                         `ApInt::from_iter always creates an `ApInt` with an upper \
-                         bound bit width.",
-                    ))
+                try_from_u64_limbs(width, digits)
             }
         }
 
@@ -351,6 +344,110 @@ mod tests {
         }
     }
 
+    fn test_values() -> Vec<ApInt> {
+        let mut values = Vec::new();
+        for &width in &[1, 64, 65, 521] {
+            let width = BitWidth::new(width).unwrap();
+            values.push(ApInt::zero(width));
+            values.push(ApInt::one(width));
+            values.push(ApInt::all_set(width));
+            values.push(ApInt::signed_min_value(width));
+            values.push(ApInt::signed_max_value(width));
+            let pattern = ApInt::from_u64(0x0123_4567_89AB_CDEF).into_zero_resize(width);
+            values.push(pattern.into_wrapping_neg());
+        }
+        values
+    }
+
+    mod json {
+        use super::*;
+
+        #[test]
+        fn round_trip() {
+            for value in test_values() {
+                let json = serde_json::to_string(&value).unwrap();
+                assert_eq!(serde_json::from_str::<ApInt>(&json).unwrap(), value);
+            }
+        }
+
+        #[test]
+        fn format() {
+            let value = ApInt::from_u128(0xFF).into_truncate(BitWidth::new(65).unwrap());
+            assert_eq!(
+                serde_json::to_string(&value.unwrap()).unwrap(),
+                r#"{"width":[65],"value":"0x000000000000000ff"}"#
+            );
+        }
+
+        #[test]
+        fn decimal_value() {
+            let value: ApInt =
+                serde_json::from_str(r#"{"value":"1337","width":[521]}"#).unwrap();
+            let w521 = BitWidth::new(521).unwrap();
+            assert_eq!(value, ApInt::from_u64(1337).into_zero_resize(w521));
+        }
+
+        #[test]
+        fn legacy_digits() {
+            let value: ApInt =
+                serde_json::from_str(r#"{"width":[65],"digits":[42,1]}"#).unwrap();
+            let expected = ApInt::from_u128(42 | (1 << 64))
+                .into_truncate(BitWidth::new(65).unwrap())
+                .unwrap();
+            assert_eq!(value, expected);
+        }
+
+        #[test]
+        fn invalid() {
+            for json in &[
+                r#"{"width":[8],"value":"0x100"}"#,
+                r#"{"width":[8],"value":"256"}"#,
+                r#"{"width":[8],"value":"0xzz"}"#,
+                r#"{"width":[0],"value":"0"}"#,
+                r#"{"width":[8]}"#,
+                r#"{"value":"0"}"#,
+                r#"{"width":[8],"value":"0","digits":[0]}"#,
+                r#"{"width":[65],"digits":[0]}"#,
+                r#"{"width":[65],"digits":[0,0,0]}"#,
+                r#"{"width":[65],"digits":[0,2]}"#,
+            ] {
+                assert!(serde_json::from_str::<ApInt>(json).is_err(), "{}", json);
+            }
+        }
+    }
+
+    mod bincode {
+        use super::*;
+
+        #[test]
+        fn round_trip() {
+            for value in test_values() {
+                let bytes = ::bincode::serialize(&value).unwrap();
+                assert_eq!(::bincode::deserialize::<ApInt>(&bytes).unwrap(), value);
+            }
+        }
+
+        #[test]
+        fn invalid() {
+            for &(width, ref limbs) in &[
+                (0_u64, vec![]),
+                (64, vec![]),
+                (64, vec![0, 0]),
+                (65, vec![0]),
+                (65, vec![0, 2]),
+                (1, vec![2]),
+            ] {
+                let bytes = ::bincode::serialize(&(width, limbs)).unwrap();
+                assert!(
+                    ::bincode::deserialize::<ApInt>(&bytes).is_err(),
+                    "{} {:?}",
+                    width,
+                    limbs
+                );
+            }
+        }
+    }
+
     mod human_readable {
         use super::*;
         use serde_test::Configure;
@@ -370,10 +467,8 @@ mod tests {
                 },
                 Token::U64(64),
                 Token::TupleStructEnd,
-                Token::Str("digits"),
-                Token::Seq { len: Some(1) },
-                Token::U64(42),
-                Token::SeqEnd,
+                Token::Str("value"),
+                Token::Str("0x000000000000002a"),
                 Token::StructEnd,
             ];
             assert_tokens(&x.clone().readable(), expected);
@@ -394,11 +489,8 @@ mod tests {
                 },
                 Token::U64(128),
                 Token::TupleStructEnd,
-                Token::Str("digits"),
-                Token::Seq { len: Some(2) },
-                Token::U64(1337),
-                Token::U64(0),
-                Token::SeqEnd,
+                Token::Str("value"),
+                Token::Str("0x00000000000000000000000000000539"),
                 Token::StructEnd,
             ];
             assert_tokens(&x.clone().readable(), expected);
@@ -1,15 +1,31 @@
 use crate::{
     mem::{
+        format,
         string::String,
+        vec,
         vec::Vec,
     },
     ApInt,
+    BitWidth,
     Digit,
+    DigitRepr,
+    DoubleDigit,
     Error,
     Radix,
     Result,
+    Width,
 };
-use core::fmt;
+use core::{
+    convert::TryFrom,
+    fmt,
+    iter,
+    str::{
+        self,
+        FromStr,
+    },
+};
+#[cfg(feature = "std")]
+use std::io;
 
 /// Binary logarithms of the numbers 2..=36 in I3F13 fixed point format and
 /// rounded up. This is used for robustly calculating the maximum number of bits
@@ -31,136 +47,350 @@ const LB_2_36_I3F13: [u16; 35] = [
 
 impl fmt::Binary for ApInt {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if self.is_zero() {
-            return write!(f, "0")
-        }
-        let mut ds = self.as_digit_slice().iter().rev();
-        while let Some(digit) = ds.next() {
-            if digit.is_zero() {
-                continue
-            }
-            write!(f, "{:b}", digit)?;
-            break
-        }
-        for digit in ds {
-            write!(f, "{:064b}", digit)?
-        }
-        Ok(())
+        self.fmt_bitwise_radix(f, 1, "0b", false)
     }
 }
 
 impl fmt::Octal for ApInt {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if self.is_zero() {
-            return write!(f, "0")
-        }
-        unimplemented!()
-        // Ok(())
+        self.fmt_bitwise_radix(f, 3, "0o", false)
     }
 }
 
 impl fmt::LowerHex for ApInt {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if self.is_zero() {
-            return write!(f, "0")
-        }
-        let mut ds = self.as_digit_slice().iter().rev();
-        while let Some(digit) = ds.next() {
-            if digit.is_zero() {
-                continue
-            }
-            write!(f, "{:x}", digit)?;
-            break
-        }
-        for digit in ds {
-            write!(f, "{:016x}", digit)?
-        }
-        Ok(())
+        self.fmt_bitwise_radix(f, 4, "0x", false)
     }
 }
 
 impl fmt::UpperHex for ApInt {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if self.is_zero() {
-            return write!(f, "0")
-        }
-        let mut ds = self.as_digit_slice().iter().rev();
-        while let Some(digit) = ds.next() {
-            if digit.is_zero() {
-                continue
-            }
-            write!(f, "{:X}", digit)?;
-            break
-        }
-        for digit in ds {
-            write!(f, "{:016X}", digit)?
-        }
-        Ok(())
+        self.fmt_bitwise_radix(f, 4, "0x", true)
+    }
+}
+
+impl fmt::Display for ApInt {
+    /// Formats the **unsigned** decimal value of this `ApInt`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_decimal(f, true)
     }
 }
 
+/// # Formatting
+impl ApInt {
+    /// Formats the **unsigned** value of `self` for the power of two radix
+    /// with `bits` bits per radix digit.
+    ///
+    /// The radix digits are streamed directly from the digits of `self` unless
+    /// padding is required by the formatter.
+    fn fmt_bitwise_radix(
+        &self,
+        f: &mut fmt::Formatter,
+        bits: usize,
+        prefix: &str,
+        upper: bool,
+    ) -> fmt::Result {
+        let chars = (0..self.len_bitwise_radix_chars(bits))
+            .rev()
+            .map(|i| self.bitwise_radix_char_at(i, bits))
+            .map(|c| if upper { c.to_ascii_uppercase() } else { c });
+        fmt_integral(f, true, prefix, chars)
+    }
+
+    /// Formats the decimal value of `self` which is interpreted as the
+    /// magnitude of a non-negative value if `is_nonnegative` is `true` or of a
+    /// negative value otherwise.
+    pub(crate) fn fmt_decimal(
+        &self,
+        f: &mut fmt::Formatter,
+        is_nonnegative: bool,
+    ) -> fmt::Result {
+        let chars = self.to_radix_chars(Radix::new(10).unwrap());
+        fmt_integral(f, is_nonnegative, "", chars.into_iter().rev())
+    }
+
+    /// Formats the **signed** decimal value of `self` with a leading minus
+    /// (`-`) for negative values.
+    pub(crate) fn fmt_signed_decimal(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (magnitude, is_negative) = self.signed_magnitude();
+        magnitude.fmt_decimal(f, !is_negative)
+    }
+}
+
+/// Writes the given ASCII `chars` starting with the most significant one into
+/// the formatter while honoring its flags.
+///
+/// The `prefix` is only written for the alternate (`#`) flag. If neither a
+/// `width` nor the `+` flag is given the `chars` are streamed into the
+/// formatter, otherwise they are buffered in order to be padded.
+fn fmt_integral<I>(
+    f: &mut fmt::Formatter,
+    is_nonnegative: bool,
+    prefix: &str,
+    chars: I,
+) -> fmt::Result
+where
+    I: Iterator<Item = u8>,
+{
+    use core::fmt::Write;
+    if f.width().is_some() || f.sign_plus() {
+        let digits = chars.map(char::from).collect::<String>();
+        return f.pad_integral(is_nonnegative, prefix, &digits)
+    }
+    if !is_nonnegative {
+        f.write_char('-')?;
+    }
+    if f.alternate() {
+        f.write_str(prefix)?;
+    }
+    for c in chars {
+        f.write_char(char::from(c))?;
+    }
+    Ok(())
+}
+
 /// # Deserialization
 impl ApInt {
-    /// Parses the given `input` `String` with the given `Radix` and returns an
-    /// `ApInt` with the given `target_width` `BitWidth`.
+    /// Parses the given `input` string with the given `radix` and returns an
+    /// `ApInt` with the given `width`.
     ///
     /// **Note:** The given `input` is parsed as big-endian value. This means,
     /// the most significant bit (MSB) is the leftst bit in the string
     /// representation provided by the user.
     ///
-    /// The string is assumed to contain no whitespace and contain only values
-    /// within a subset of the range of `0`..`9` and `a`..`z` depending on
-    /// the given `radix`.
+    /// The string may start with a single `+` sign and must otherwise contain
+    /// only case-insensitive characters within `0`..`9` and `a`..`z` that are
+    /// valid for the given `radix`. Underscores (`_`) may be used to separate
+    /// digits and are ignored.
     ///
-    /// The string is assumed to have no sign as `ApInt` does not handle
-    /// signdness.
+    /// The string is assumed to have no negative sign as `ApInt` does not
+    /// handle signdness.
     ///
     /// # Errors
     ///
-    /// - If `input` is empty.
-    /// - If `input` is not a valid representation for an `ApInt` for the given
-    ///   `radix`.
-    /// - If `input` has trailing zero characters (`0`), e.g. `"0042"` instead
-    ///   of `"42"`.
+    /// - If `input` is empty or only consists of the `+` sign.
+    /// - If `input` starts or ends with an underscore.
+    /// - If `input` contains a character that is invalid for the given `radix`.
+    ///   The error names the invalid character and its byte offset in `input`.
     /// - If `input` represents an `ApInt` value that does not fit into the
-    ///   given `target_bitwidth`.
+    ///   given `width`.
+    ///
+    /// # Panics
+    ///
+    /// - If `radix` is converted from an `u8` outside of `2..=36`.
     ///
     /// # Examples
     ///
-    /// ```no_run
-    /// # use apint::ApInt;
+    /// ```
+    /// # use apint::{ApInt, BitWidth};
+    /// let w32 = BitWidth::w32();
     /// // ok
-    /// let a = ApInt::from_str_radix(10, "42");
+    /// assert_eq!(ApInt::from_str_radix("42", 10, w32), Ok(ApInt::from_u32(42)));
     /// // ok (dec. = 91)
-    /// let b = ApInt::from_str_radix(2, "1011011");
+    /// assert_eq!(ApInt::from_str_radix("+101_1011", 2, w32), Ok(ApInt::from_u32(91)));
     /// // ok (dec. = 16763904)
-    /// let c = ApInt::from_str_radix(16, "ffcc00");
+    /// assert_eq!(
+    ///     ApInt::from_str_radix("FFcc00", 16, w32),
+    ///     Ok(ApInt::from_u32(0xFFCC00))
+    /// );
     /// // Error: 256 does not fit within 8 bits!
-    /// let c = ApInt::from_str_radix(10, "256");
+    /// assert!(ApInt::from_str_radix("256", 10, BitWidth::w8()).is_err());
     /// // Error: Invalid digit '2' at position 3 for given radix.
-    /// let d = ApInt::from_str_radix(2, "01020");
+    /// assert!(ApInt::from_str_radix("01020", 2, w32).is_err());
     /// // Error: "hello" is not a valid ApInt representation!
-    /// let e = ApInt::from_str_radix(16, "hello");
+    /// assert!(ApInt::from_str_radix("hello", 16, w32).is_err());
     /// ```
-    pub fn from_str_radix<R, S>(radix: R, input: S) -> Result<ApInt>
+    pub fn from_str_radix<R>(input: &str, radix: R, width: BitWidth) -> Result<ApInt>
     where
         R: Into<Radix>,
-        S: AsRef<str>,
     {
         let radix = radix.into();
-        let input = input.as_ref();
+        let result = ApInt::parse_radix(input, radix)?;
+        if result.required_bits(false) > width.to_usize() {
+            return Err(Error::result_overflow(width).with_annotation(format!(
+                "The string representation {:?} with radix {} does not fit into the \
+                 given bit width.",
+                input,
+                radix.to_u8()
+            )))
+        }
+        Ok(result.into_zero_resize(width))
+    }
+
+    /// Parses the given `input` string and returns an `ApInt` with the given
+    /// `width`.
+    ///
+    /// The radix is detected from a case-insensitive prefix of the digits:
+    ///
+    /// - `0x` for hexadecimal
+    /// - `0o` for octal
+    /// - `0b` for binary
+    /// - decimal otherwise
+    ///
+    /// An optional sign (`+` or `-`) may precede the prefix. Negative values
+    /// are represented in two's complement and thus their magnitude must not
+    /// exceed `2^(width-1)`. Apart from that the digits are parsed like in
+    /// [`from_str_radix`](struct.ApInt.html#method.from_str_radix) including
+    /// the use of `_` separators.
+    ///
+    /// # Errors
+    ///
+    /// - If there are no digits after the sign or prefix.
+    /// - If the digits are not valid for the detected radix as described in
+    ///   [`from_str_radix`](struct.ApInt.html#method.from_str_radix). The
+    ///   byte offsets of invalid characters refer to the whole `input`.
+    /// - If the represented value does not fit into the given `width`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use apint::{ApInt, BitWidth};
+    /// let w32 = BitWidth::w32();
+    /// let parse = |input| ApInt::from_str_prefixed(input, w32);
+    /// assert_eq!(parse("0xdead_beef"), Ok(ApInt::from_u32(0xdead_beef)));
+    /// assert_eq!(parse("0B1010_1100"), Ok(ApInt::from_u32(0b1010_1100)));
+    /// assert_eq!(parse("+0o17"), Ok(ApInt::from_u32(0o17)));
+    /// assert_eq!(parse("-42"), Ok(ApInt::from_i32(-42)));
+    /// assert!(parse("0x").is_err());
+    /// ```
+    pub fn from_str_prefixed(input: &str, width: BitWidth) -> Result<ApInt> {
+        let (negative, start) = match input.as_bytes().first() {
+            Some(b'-') => (true, 1),
+            Some(b'+') => (false, 1),
+            _ => (false, 0),
+        };
+        let prefix = input.get(start..start + 2).map(str::to_ascii_lowercase);
+        let (radix, start) = match prefix.as_ref().map(String::as_str) {
+            Some("0x") => (16, start + 2),
+            Some("0o") => (8, start + 2),
+            Some("0b") => (2, start + 2),
+            _ => (10, start),
+        };
+        let radix = Radix::new(radix)?;
+        if radix.to_u8() != 10 && start == input.len() {
+            return Err(Error::invalid_string_repr(input, radix).with_annotation(
+                "Expected digits after the radix prefix (`0x`, `0o` or `0b`).",
+            ))
+        }
+        let magnitude = ApInt::parse_radix_digits(input, start, radix)?;
+        let magnitude_bits = magnitude.required_bits(false);
+        let fits = if negative {
+            magnitude_bits < width.to_usize()
+                || (magnitude_bits == width.to_usize() && magnitude.is_power_of_two())
+        } else {
+            magnitude_bits <= width.to_usize()
+        };
+        if !fits {
+            return Err(Error::result_overflow(width).with_annotation(format!(
+                "The string representation {:?} does not fit into the given bit width.",
+                input
+            )))
+        }
+        let result = magnitude.into_zero_resize(width);
+        Ok(if negative { result.into_wrapping_neg() } else { result })
+    }
+
+    /// Parses the given fixed-width hexadecimal `input` string and returns an
+    /// `ApInt` with the given `width`.
+    ///
+    /// The digits must consist of exactly `ceil(width / 4)` case-insensitive
+    /// hexadecimal characters including leading zeros, optionally preceded by
+    /// a `0x` or `0X` prefix. This is the inverse of
+    /// [`to_hex_string`](struct.ApInt.html#method.to_hex_string), so every
+    /// value has exactly one canonical representation apart from the casing.
+    ///
+    /// # Errors
+    ///
+    /// - If the number of digits is not exactly `ceil(width / 4)`.
+    /// - If `input` contains a character that is not a hexadecimal digit.
+    ///   Unlike [`from_str_radix`](struct.ApInt.html#method.from_str_radix)
+    ///   this includes `_` and signs.
+    /// - If `width` is not a multiple of 4 and the unused high bits of the most
+    ///   significant digit are set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use apint::{ApInt, BitWidth};
+    /// let w16 = BitWidth::w16();
+    /// assert_eq!(ApInt::from_hex_str("00aF", w16), Ok(ApInt::from_u16(0xaf)));
+    /// assert_eq!(ApInt::from_hex_str("0x00af", w16), Ok(ApInt::from_u16(0xaf)));
+    /// assert!(ApInt::from_hex_str("af", w16).is_err());
+    /// // The most significant digit of a 10 bit value must not exceed `3`.
+    /// let w10 = BitWidth::new(10).unwrap();
+    /// assert!(ApInt::from_hex_str("3ff", w10).is_ok());
+    /// assert!(ApInt::from_hex_str("4ff", w10).is_err());
+    /// ```
+    pub fn from_hex_str(input: &str, width: BitWidth) -> Result<ApInt> {
+        let radix = Radix::new(16)?;
+        let start = match input.get(..2) {
+            Some("0x") | Some("0X") => 2,
+            _ => 0,
+        };
+        let digits = &input[start..];
+        let expected_len = ApInt::len_hex_chars(width);
+        if digits.len() != expected_len {
+            return Err(Error::invalid_string_repr(input, radix).with_annotation(format!(
+                "Expected exactly {} hexadecimal digits for a bit width of {} but found \
+                 {} bytes.",
+                expected_len,
+                width.to_usize(),
+                digits.len()
+            )))
+        }
+        let mut v = Vec::with_capacity(expected_len);
+        for (i, c) in digits.char_indices().rev() {
+            match c.to_digit(16) {
+                Some(d) => v.push(d as u8),
+                None => {
+                    return Err(Error::invalid_char_in_string_repr(
+                        input,
+                        radix,
+                        start + i,
+                        c,
+                    ))
+                }
+            }
+        }
+        let result = ApInt::from_bitwise_digits(&v, 4);
+        if result.required_bits(false) > width.to_usize() {
+            return Err(Error::result_overflow(width).with_annotation(format!(
+                "The most significant digit of {:?} has bits set beyond the bit width.",
+                input
+            )))
+        }
+        Ok(result.into_zero_resize(width))
+    }
+
+    /// Parses the given `input` string with the given `radix` and returns an
+    /// `ApInt` with a bit width that is a multiple of `Digit::BITS`.
+    ///
+    /// For more details look into
+    /// [`from_str_radix`](struct.ApInt.html#method.from_str_radix).
+    fn parse_radix(input: &str, radix: Radix) -> Result<ApInt> {
+        let start = if input.starts_with('+') { 1 } else { 0 };
+        ApInt::parse_radix_digits(input, start, radix)
+    }
 
-        if input.is_empty() {
+    /// Parses the digits of the given `input` string starting at the byte
+    /// offset `start` with the given `radix` and returns an `ApInt` with a bit
+    /// width that is a multiple of `Digit::BITS`.
+    ///
+    /// Errors refer to the whole `input` and byte offsets within it.
+    fn parse_radix_digits(input: &str, start: usize, radix: Radix) -> Result<ApInt> {
+        let digits = &input[start..];
+
+        if digits.is_empty() {
             return Err(Error::invalid_string_repr(input, radix)
                 .with_annotation("Cannot parse an empty string into an ApInt."))
         }
-        if input.starts_with('_') {
+        if digits.starts_with('_') {
             return Err(Error::invalid_string_repr(input, radix).with_annotation(
                 "The input string starts with an underscore ('_') instead of a number. \
                  The use of underscores is explicitely for separation of digits.",
             ))
         }
-        if input.ends_with('_') {
+        if digits.ends_with('_') {
             return Err(Error::invalid_string_repr(input, radix).with_annotation(
                 "The input string ends with an underscore ('_') instead of a number. \
                  The use of underscores is explicitely for separation of digits.",
@@ -168,27 +398,27 @@ impl ApInt {
         }
 
         // First normalize all characters to plain digit values.
-        let mut v = Vec::with_capacity(input.len());
-        for (i, b) in input.bytes().enumerate() {
-            let d = match b {
-                b'0'..=b'9' => b - b'0',
-                b'a'..=b'z' => b - b'a' + 10,
-                b'A'..=b'Z' => b - b'A' + 10,
-                b'_' => continue,
+        let mut v = Vec::with_capacity(digits.len());
+        for (i, c) in digits.char_indices() {
+            let d = match c {
+                '0'..='9' => c as u8 - b'0',
+                'a'..='z' => c as u8 - b'a' + 10,
+                'A'..='Z' => c as u8 - b'A' + 10,
+                '_' => continue,
                 _ => ::core::u8::MAX,
             };
             if !radix.is_valid_byte(d) {
                 return Err(Error::invalid_char_in_string_repr(
                     input,
                     radix,
-                    i,
-                    char::from(b),
+                    start + i,
+                    c,
                 ))
             }
             v.push(d);
         }
 
-        let result = match radix.exact_bits_per_digit() {
+        Ok(match radix.exact_bits_per_digit() {
             Some(bits) => {
                 v.reverse();
                 if Digit::BITS % bits == 0 {
@@ -197,9 +427,72 @@ impl ApInt {
                     ApInt::from_inexact_bitwise_digits(&v, bits)
                 }
             }
+            None if radix.to_u8() == 10 => ApInt::from_decimal_digits(&v),
             None => ApInt::from_radix_digits(&v, radix),
-        };
+        })
+    }
 
+    /// Creates a new `ApInt` with the given `width` from the given `bytes` in
+    /// little-endian order, i.e. `bytes[0]` holds the least significant bits.
+    ///
+    /// If `bytes` holds fewer bytes than required for `width` the missing high
+    /// bytes are treated as zero.
+    ///
+    /// # Errors
+    ///
+    /// - If any bit that does not fit into `width` is set. This includes all
+    ///   bits of excess bytes as well as the unused high bits of the most
+    ///   significant byte if `width` is not a multiple of 8.
+    pub fn from_bytes_le(width: BitWidth, bytes: &[u8]) -> Result<ApInt> {
+        ApInt::from_le_byte_iter(width, bytes.iter().cloned())
+    }
+
+    /// Creates a new `ApInt` with the given `width` from the given `bytes` in
+    /// big-endian order, i.e. `bytes[0]` holds the most significant bits.
+    ///
+    /// If `bytes` holds fewer bytes than required for `width` the missing high
+    /// bytes are treated as zero.
+    ///
+    /// # Errors
+    ///
+    /// - If any bit that does not fit into `width` is set. This includes all
+    ///   bits of excess bytes as well as the unused high bits of the most
+    ///   significant byte if `width` is not a multiple of 8.
+    pub fn from_bytes_be(width: BitWidth, bytes: &[u8]) -> Result<ApInt> {
+        ApInt::from_le_byte_iter(width, bytes.iter().rev().cloned())
+    }
+
+    /// Creates a new `ApInt` with the given `width` from the given bytes that
+    /// are yielded starting with the least significant byte.
+    fn from_le_byte_iter<I>(width: BitWidth, bytes: I) -> Result<ApInt>
+    where
+        I: Iterator<Item = u8>,
+    {
+        let width_bits = width.to_usize();
+        let mut result = ApInt::zero(width);
+        let result_digits = result.as_digit_slice_mut();
+        for (i, byte) in bytes.enumerate() {
+            let pos = i * 8;
+            if pos >= width_bits {
+                if byte != 0 {
+                    return Err(Error::result_overflow(width).with_annotation(format!(
+                        "Encountered the non-zero excess byte {:#04x} at index {} when \
+                         constructing an `ApInt` from bytes.",
+                        byte, i
+                    )))
+                }
+                continue
+            }
+            if width_bits - pos < 8 && byte >> (width_bits - pos) != 0 {
+                return Err(Error::result_overflow(width).with_annotation(format!(
+                    "The most significant byte {:#04x} has bits set beyond the bit \
+                     width when constructing an `ApInt` from bytes.",
+                    byte
+                )))
+            }
+            result_digits[pos / Digit::BITS] |=
+                Digit(DigitRepr::from(byte)) << (pos % Digit::BITS);
+        }
         Ok(result)
     }
 
@@ -210,8 +503,6 @@ impl ApInt {
     //
     // TODO: Better document what happens here and why.
     fn from_bitwise_digits(v: &[u8], bits: usize) -> ApInt {
-        use crate::digit::DigitRepr;
-
         debug_assert!(!v.is_empty() && bits <= 8 && Digit::BITS % bits == 0);
         debug_assert!(v.iter().all(|&c| DigitRepr::from(c) < (1 << bits)));
 
@@ -237,8 +528,6 @@ impl ApInt {
     //
     // TODO: Better document what happens here and why.
     fn from_inexact_bitwise_digits(v: &[u8], bits: usize) -> ApInt {
-        use crate::digit::DigitRepr;
-
         debug_assert!(!v.is_empty() && bits <= 8 && Digit::BITS % bits != 0);
         debug_assert!(v.iter().all(|&c| (DigitRepr::from(c)) < (1 << bits)));
 
@@ -272,28 +561,23 @@ impl ApInt {
         ApInt::from_iter(data).unwrap()
     }
 
-    // Read little-endian radix digits.
+    // Read big-endian radix digits.
     //
     // Forked from: https://github.com/rust-num/num/blob/master/bigint/src/biguint.rs#L177
     //
-    // TODO: This does not work, yet. Some parts of the algorithm are
-    //       commented-out since the required functionality does not exist, yet.
+    // The radix digits are processed in chunks of `power` digits where
+    // `base = radix^power` is the greatest power of the radix that fits into a
+    // single `Digit`. Every chunk is accumulated into a `Digit` and then the
+    // intermediate result is multiplied by `base` before adding that chunk.
     fn from_radix_digits(v: &[u8], radix: Radix) -> ApInt {
-        use crate::digit::DigitRepr;
-
         debug_assert!(!v.is_empty() && !radix.is_power_of_two());
         debug_assert!(v.iter().all(|&c| radix.is_valid_byte(c)));
 
         // Estimate how big the result will be, so we can pre-allocate it.
-        // The `unwrap()` here will only panic if `v.len()` is massive.
-        let bits = (LB_2_36_I3F13[(radix.to_u8() - 2) as usize] as usize)
-            .checked_mul(v.len() + 1)
-            .unwrap()
-            >> 13;
-        let big_digits = (bits / Digit::BITS) + 1;
+        let big_digits = (estimated_bits(radix, v.len()) / Digit::BITS) + 1;
         let mut data = Vec::with_capacity(big_digits as usize);
 
-        let (_base, power) = radix.get_radix_base();
+        let (base, power) = radix.get_radix_base();
         let radix = DigitRepr::from(radix.to_u8());
 
         let r = v.len() % power;
@@ -303,35 +587,152 @@ impl ApInt {
         let first = head
             .iter()
             .fold(0, |acc, &d| acc * radix + DigitRepr::from(d));
-        data.push(first);
+        data.push(Digit(first));
 
         debug_assert!(tail.len() % power == 0);
         for chunk in tail.chunks(power) {
-            if data.last() != Some(&0) {
-                data.push(0);
+            if data.last() != Some(&Digit::ZERO) {
+                data.push(Digit::ZERO);
             }
 
-            let carry = 0;
-            for _d in &mut data {
-                // *d = mac_with_carry(0, *d, base, &mut carry); // TODO! This
-                // was commented out.
+            // `data = data * base + n` where the chunk value `n` is the
+            // initial carry
+            let mut carry = Digit(
+                chunk
+                    .iter()
+                    .fold(0, |acc, &d| acc * radix + DigitRepr::from(d)),
+            );
+            for d in &mut data {
+                let (lo, hi) = d.carrying_mul_add(base, carry);
+                *d = lo;
+                carry = hi;
+            }
+            debug_assert!(carry.is_zero());
+        }
 
-                // // fn carry_mul_add(a: Digit, b: Digit, c: Digit, carry:
-                // Digit) -> DigitAndCarry // Returns the result
-                // of `(a + (b * c)) + carry` and its implied carry value.
+        ApInt::from_iter(data).unwrap()
+    }
 
-                // let DigitAndCarry(d, carry) = carry_mul_add(digit::ZERO, *d,
-                // base, carry); // TODO! This was commented out.
+    /// Converts big-endian decimal digits into an `ApInt` with a bit width that
+    /// is a multiple of `Digit::BITS`.
+    ///
+    /// This accumulates 19 decimal digits at a time into a `u64` followed by a
+    /// single scalar multiply and add per chunk which only spans the digits
+    /// that are in use so far.
+    ///
+    /// **Note:** Combining recursively parsed halves as `high * 10^n + low` is
+    /// only faster than this with sub-quadratic multiplication which is not
+    /// implemented, yet.
+    fn from_decimal_digits(v: &[u8]) -> ApInt {
+        debug_assert!(!v.is_empty() && v.iter().all(|&c| c < 10));
+        const CHUNK_LEN: usize = 19;
+        let r = v.len() % CHUNK_LEN;
+        let i = if r == 0 { CHUNK_LEN } else { r };
+        let (head, tail) = v.split_at(i);
+        let mut result = ApInt::zero(decimal_digits_width(v.len()));
+        let digits = result.as_digit_slice_mut();
+        let mut len = 0;
+        for chunk in iter::once(head).chain(tail.chunks(CHUNK_LEN)) {
+            let mul = 10_u64.pow(chunk.len() as u32);
+            let mut carry = chunk
+                .iter()
+                .fold(0_u64, |acc, &d| acc * 10 + u64::from(d));
+            for digit in &mut digits[..len] {
+                let product =
+                    u128::from(digit.repr()) * u128::from(mul) + u128::from(carry);
+                *digit = Digit(product as DigitRepr);
+                carry = (product >> Digit::BITS) as u64;
+            }
+            while carry != 0 {
+                digits[len] = Digit(carry as DigitRepr);
+                carry = carry.checked_shr(Digit::BITS as u32).unwrap_or(0);
+                len += 1;
             }
-            debug_assert!(carry == 0);
+        }
+        result
+    }
+}
 
-            let _n = chunk
-                .iter()
-                .fold(0, |acc, &d| acc * radix + DigitRepr::from(d));
-            // add2(&mut data, &[n]); // TODO: This was commented out.
+/// Returns an upper bound for the number of bits required to represent any
+/// string of `len` digits with the given `radix`.
+///
+/// # Panics
+///
+/// - If `len` is massive.
+fn estimated_bits(radix: Radix, len: usize) -> usize {
+    (LB_2_36_I3F13[(radix.to_u8() - 2) as usize] as usize)
+        .checked_mul(len + 1)
+        .unwrap()
+        >> 13
+}
+
+/// Returns a bit width that is a multiple of `Digit::BITS` and large enough to
+/// represent any string of `len` decimal digits.
+fn decimal_digits_width(len: usize) -> BitWidth {
+    let bits = estimated_bits(Radix::new(10).unwrap(), len);
+    BitWidth::from((bits / Digit::BITS + 1) * Digit::BITS)
+}
+
+/// The number of digits from which on the characters for a radix that is not
+/// a power of two are computed by divide and conquer.
+const RADIX_DIVIDE_AND_CONQUER_THRESHOLD: usize = 32;
+
+/// Returns the powers `radix^(power * 2^k)` together with their number of
+/// radix characters `power * 2^k` where `radix^power` is the greatest power
+/// of the `radix` that fits into a single `Digit`.
+///
+/// Powers are computed as long as they have at most half of the given number
+/// of `bits`.
+fn radix_powers(radix: Radix, bits: usize) -> Vec<(ApInt, usize)> {
+    let (base, power) = radix.get_radix_base();
+    let mut powers = vec![(ApInt::from_iter(iter::once(base)).unwrap(), power)];
+    loop {
+        let (last, len_chars) = powers.last().expect("`powers` is never empty");
+        let last_bits = last.required_bits(false);
+        if 2 * last_bits > bits {
+            break
         }
+        let width = BitWidth::from(2 * last_bits);
+        let last = last.clone().into_zero_resize(width);
+        let square = last.clone().into_wrapping_mul(&last).expect(
+            "Both operands have the same bit width and the product fits into twice \
+             the significant bits of the operands.",
+        );
+        let len_chars = 2 * len_chars;
+        powers.push((square, len_chars));
+    }
+    powers
+}
+
+/// Returns the lower case ASCII character for the given radix digit value.
+fn radix_char(value: u8) -> u8 {
+    debug_assert!(value < 36);
+    match value {
+        0..=9 => b'0' + value,
+        _ => b'a' + value - 10,
+    }
+}
+
+impl FromStr for ApInt {
+    type Err = Error;
 
-        ApInt::from_iter(data.into_iter().map(Digit)).unwrap()
+    /// Parses the given decimal string representation into an `ApInt`.
+    ///
+    /// The resulting bit width is the number of bits required to represent
+    /// the parsed value, which is at least `1`. So `"0"` and `"1"` result in
+    /// an `ApInt` with a bit width of `1` and `"256"` in a bit width of `9`.
+    ///
+    /// Use [`from_str_radix`](struct.ApInt.html#method.from_str_radix) to
+    /// parse with other radices or into a given bit width.
+    ///
+    /// # Errors
+    ///
+    /// - If `input` is not a valid decimal representation as described in
+    ///   [`from_str_radix`](struct.ApInt.html#method.from_str_radix).
+    fn from_str(input: &str) -> Result<ApInt> {
+        let result = ApInt::parse_radix(input, Radix::new(10)?)?;
+        let width = BitWidth::from(result.required_bits(false).max(1));
+        Ok(result.into_zero_resize(width))
     }
 }
 
@@ -339,94 +740,857 @@ impl ApInt {
 ///  Serialization
 /// =======================================================================
 impl ApInt {
-    /// Returns a `String` representation of the binary encoded `ApInt` for the
-    /// given `Radix`.
+    /// Returns a `String` representation of the **unsigned** value of this
+    /// `ApInt` for the given `radix`.
+    ///
+    /// The representation uses lower case letters for digits above `9` and
+    /// has no leading zeros, so the value zero is represented as `"0"`.
+    ///
+    /// # Panics
+    ///
+    /// - If `radix` is converted from an `u8` outside of `2..=36`. Use
+    ///   [`try_to_string_radix`](struct.ApInt.html#method.try_to_string_radix)
+    ///   to handle invalid radices.
     pub fn to_string_radix<R>(&self, radix: R) -> String
     where
         R: Into<Radix>,
     {
-        let _radix = radix.into();
+        let radix = radix.into();
+        let mut chars = match radix.exact_bits_per_digit() {
+            Some(bits) => self.to_bitwise_radix_chars(bits),
+            None => self.to_radix_chars(radix),
+        };
+        chars.reverse();
+        String::from_utf8(chars).expect(
+            "The radix characters are all within `0`..`9` and `a`..`z` and thus valid \
+             UTF-8.",
+        )
+    }
 
-        unimplemented!();
+    /// Returns a `String` representation of the **unsigned** value of this
+    /// `ApInt` for the given `radix`.
+    ///
+    /// For more details look into
+    /// [`to_string_radix`](struct.ApInt.html#method.to_string_radix).
+    ///
+    /// # Errors
+    ///
+    /// - If `radix` is not within `2..=36`.
+    pub fn try_to_string_radix(&self, radix: u8) -> Result<String> {
+        Ok(self.to_string_radix(Radix::new(radix)?))
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Returns a decimal `String` representation of the **signed** value of
+    /// this `ApInt`.
+    ///
+    /// The value is interpreted in two's complement, so negative values are
+    /// represented by a leading minus (`-`) followed by their magnitude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use apint::{ApInt, BitWidth};
+    /// let w8 = BitWidth::w8();
+    /// assert_eq!(ApInt::all_set(w8).to_signed_string(), "-1");
+    /// assert_eq!(ApInt::signed_min_value(w8).to_signed_string(), "-128");
+    /// assert_eq!(ApInt::from_u8(42).to_signed_string(), "42");
+    /// ```
+    pub fn to_signed_string(&self) -> String {
+        let (magnitude, is_negative) = self.signed_magnitude();
+        let digits = magnitude.to_string_radix(10);
+        if is_negative {
+            format!("-{}", digits)
+        } else {
+            digits
+        }
+    }
 
-    use crate::bitwidth::BitWidth;
+    /// Returns the magnitude of the **signed** value of `self` as an
+    /// **unsigned** value of the same bit width and whether it is negative.
+    ///
+    /// **Note:** The negation of `signed_min_value` wraps around to itself
+    /// which is exactly its magnitude according to the **unsigned**
+    /// interpretation, so it needs no special treatment.
+    fn signed_magnitude(&self) -> (ApInt, bool) {
+        let is_negative = self.msb();
+        let mut magnitude = self.clone();
+        if is_negative {
+            magnitude.wrapping_neg();
+        }
+        (magnitude, is_negative)
+    }
 
-    mod constants {
-        use super::*;
-        use std::ops::Mul;
+    /// Returns a lower case hexadecimal `String` representation of the
+    /// **unsigned** value of this `ApInt` with exactly `ceil(width / 4)`
+    /// characters.
+    ///
+    /// Unlike [`to_string_radix`](struct.ApInt.html#method.to_string_radix)
+    /// leading zeros are preserved, so the length of the representation only
+    /// depends on the bit width. There is no `0x` prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use apint::{ApInt, BitWidth};
+    /// assert_eq!(ApInt::from_u16(0xaf).to_hex_string(), "00af");
+    /// let w10 = BitWidth::new(10).unwrap();
+    /// assert_eq!(ApInt::all_set(w10).to_hex_string(), "3ff");
+    /// ```
+    pub fn to_hex_string(&self) -> String {
+        let chars = (0..ApInt::len_hex_chars(self.width()))
+            .rev()
+            .map(|i| self.bitwise_radix_char_at(i, 4))
+            .collect();
+        String::from_utf8(chars).expect(
+            "The hexadecimal characters are all within `0`..`9` and `a`..`f` and thus \
+             valid UTF-8.",
+        )
+    }
 
-        #[test]
-        fn test_lb_2_36_i3f13() {
-            for (i, c) in LB_2_36_I3F13.iter().enumerate() {
-                assert_eq!(
-                    *c,
-                    ((i + 2) as f64).log2().mul((1 << 13) as f64).ceil() as u16
-                );
-            }
-        }
+    /// Returns the number of hexadecimal characters required to represent
+    /// every value of the given `width`.
+    fn len_hex_chars(width: BitWidth) -> usize {
+        width.to_usize().div_ceil(4)
     }
 
-    mod binary {
-        use super::*;
+    /// Returns the characters for a power of two radix with `bits` bits per
+    /// radix digit starting with the least significant character.
+    fn to_bitwise_radix_chars(&self, bits: usize) -> Vec<u8> {
+        (0..self.len_bitwise_radix_chars(bits))
+            .map(|i| self.bitwise_radix_char_at(i, bits))
+            .collect()
+    }
 
-        fn assert_binary(val: ApInt, expected: &str) {
-            assert_eq!(format!("{:b}", val), expected)
-        }
+    /// Returns the number of characters without leading zeros for a power of
+    /// two radix with `bits` bits per radix digit.
+    fn len_bitwise_radix_chars(&self, bits: usize) -> usize {
+        self.required_bits(false).max(1).div_ceil(bits)
+    }
 
-        #[test]
-        fn small() {
-            assert_binary(ApInt::zero(BitWidth::w32()), "0");
-            assert_binary(ApInt::from(0b_1010_0110_0110_1001_u32), "1010011001101001");
-            assert_binary(
-                ApInt::all_set(BitWidth::w32()),
-                "11111111111111111111111111111111", // 32 ones
-            );
-            assert_binary(
-                ApInt::signed_min_value(BitWidth::w32()),
-                "10000000000000000000000000000000", // 31 zeros
-            );
-            assert_binary(
-                ApInt::signed_max_value(BitWidth::w32()),
-                "1111111111111111111111111111111", // 31 ones
-            );
+    /// Returns the character at the given `index` counted from the least
+    /// significant character for a power of two radix with `bits` bits per
+    /// radix digit.
+    fn bitwise_radix_char_at(&self, index: usize, bits: usize) -> u8 {
+        debug_assert!(bits <= 8);
+        let digits = self.as_digit_slice();
+        let pos = index * bits;
+        let (index, offset) = (pos / Digit::BITS, pos % Digit::BITS);
+        let mut value = digits[index].repr() >> offset;
+        if offset + bits > Digit::BITS && index + 1 < digits.len() {
+            value |= digits[index + 1].repr() << (Digit::BITS - offset);
         }
+        radix_char(value as u8 & ((1 << bits) - 1))
+    }
 
-        #[test]
-        fn large() {
-            assert_binary(ApInt::zero(BitWidth::w128()), "0");
-            assert_binary(ApInt::from(0b_1010_0110_0110_1001_u128), "1010011001101001");
-            assert_binary(
-                ApInt::all_set(BitWidth::w128()),
-                "11111111111111111111111111111111\
-                 11111111111111111111111111111111\
-                 11111111111111111111111111111111\
-                 11111111111111111111111111111111",
-            );
-            assert_binary(
-                ApInt::signed_min_value(BitWidth::w128()),
-                "10000000000000000000000000000000\
-                 00000000000000000000000000000000\
-                 00000000000000000000000000000000\
-                 00000000000000000000000000000000",
-            );
-            assert_binary(
-                ApInt::signed_max_value(BitWidth::w128()),
-                "1111111111111111111111111111111\
-                 11111111111111111111111111111111\
-                 11111111111111111111111111111111\
-                 11111111111111111111111111111111",
-            );
+    /// Returns the characters for a radix that is not a power of two starting
+    /// with the least significant character.
+    ///
+    /// Small values are converted by one long division per chunk of
+    /// characters by the greatest power of the radix that fits into a single
+    /// `Digit`. Larger values are recursively split in halves by dividing
+    /// through a precomputed `radix^(power * 2^k)` instead.
+    ///
+    /// Both ways are O(n²) in the number of digits since the divisions use
+    /// schoolbook long division. Splitting only avoids the many passes over
+    /// the whole value of the chunk by chunk conversion.
+    fn to_radix_chars(&self, radix: Radix) -> Vec<u8> {
+        let mut chars = Vec::new();
+        if self.len_digits() < RADIX_DIVIDE_AND_CONQUER_THRESHOLD {
+            self.push_radix_chars_simple(radix, 0, &mut chars);
+        } else {
+            let powers = radix_powers(radix, self.required_bits(false));
+            self.clone()
+                .push_radix_chars_recursive(radix, &powers, 0, &mut chars);
         }
+        if chars.is_empty() {
+            chars.push(b'0');
+        }
+        chars
     }
 
-    mod hex {
-        use super::*;
+    /// Pushes the characters of `self` for a radix that is not a power of two
+    /// starting with the least significant character onto `chars`.
+    ///
+    /// The characters are padded with zeros to at least `pad` characters. The
+    /// value zero pushes no characters apart from padding.
+    fn push_radix_chars_simple(&self, radix: Radix, pad: usize, chars: &mut Vec<u8>) {
+        let (base, power) = radix.get_radix_base();
+        let radix = DigitRepr::from(radix.to_u8());
+        let start = chars.len();
+        let mut value = self.clone();
+        let mut done = value.is_zero();
+        while !done {
+            let mut rem = value.wrapping_udivrem_digit_assign(base).repr();
+            done = value.is_zero();
+            for _ in 0..power {
+                if done && rem == 0 {
+                    break
+                }
+                chars.push(radix_char((rem % radix) as u8));
+                rem /= radix;
+            }
+        }
+        while chars.len() - start < pad {
+            chars.push(b'0');
+        }
+    }
+
+    /// Pushes the characters of `self` for a radix that is not a power of two
+    /// starting with the least significant character onto `chars`.
+    ///
+    /// The value is split into `self / p` and `self % p` by the greatest power
+    /// `p` within `powers` that has at most half the significant bits of
+    /// `self`. Then the remainder is pushed padded to the characters of `p`
+    /// followed by the quotient.
+    fn push_radix_chars_recursive(
+        self,
+        radix: Radix,
+        powers: &[(ApInt, usize)],
+        pad: usize,
+        chars: &mut Vec<u8>,
+    ) {
+        let bits = self.required_bits(false);
+        let split = powers
+            .iter()
+            .rev()
+            .find(|(power, _)| 2 * power.required_bits(false) <= bits);
+        let (power, len_chars) = match split {
+            Some(split) if self.len_digits() >= RADIX_DIVIDE_AND_CONQUER_THRESHOLD => {
+                split
+            }
+            _ => return self.push_radix_chars_simple(radix, pad, chars),
+        };
+        let width = BitWidth::from(bits);
+        let divisor = power.clone().into_zero_resize(width);
+        let (quo, rem) = self
+            .into_zero_resize(width)
+            .into_wrapping_udivrem(&divisor)
+            .expect(
+                "The divisor is a power of the radix and thus non-zero and both \
+                 operands have been resized to the same bit width.",
+            );
+        rem.push_radix_chars_recursive(radix, powers, *len_chars, chars);
+        quo.push_radix_chars_recursive(
+            radix,
+            powers,
+            pad.saturating_sub(*len_chars),
+            chars,
+        );
+    }
+
+    /// Returns an iterator over the base `10^19` chunks of the **unsigned**
+    /// value of this `ApInt`, starting with the least significant chunk.
+    ///
+    /// Every chunk is in the range `0..10^19`, so it always renders to at most
+    /// 19 decimal characters. All chunks except for the most significant one
+    /// need to be zero padded to exactly 19 characters when printed. The
+    /// value zero yields exactly one chunk of `0`.
+    ///
+    /// This is useful for streaming decimal output of very large values since
+    /// every chunk is produced by a single linear pass of division by `10^19`.
+    ///
+    /// **Note:** This allocates a working copy of `self`. Use
+    /// [`into_decimal_chunks`](struct.ApInt.html#method.into_decimal_chunks)
+    /// to reuse the digits of an `ApInt` that is no longer needed.
+    pub fn decimal_chunks(&self) -> impl Iterator<Item = u64> {
+        self.clone().into_decimal_chunks()
+    }
+
+    /// Consumes this `ApInt` and returns an iterator over the base `10^19`
+    /// chunks of its **unsigned** value, starting with the least significant
+    /// chunk.
+    ///
+    /// For more details look into
+    /// [`decimal_chunks`](struct.ApInt.html#method.decimal_chunks).
+    pub fn into_decimal_chunks(self) -> impl Iterator<Item = u64> {
+        const DECIMAL_CHUNK_BASE: u64 = 10_000_000_000_000_000_000;
+        let mut value = self;
+        let mut done = false;
+        iter::from_fn(move || {
+            if done {
+                return None
+            }
+            let chunk = value.div_rem_u64_assign(DECIMAL_CHUNK_BASE).expect(
+                "The decimal chunk base is non-zero so we can expect the division to be \
+                 successful.",
+            );
+            done = value.is_zero();
+            Some(chunk)
+        })
+    }
+
+    /// Returns the byte of this `ApInt` at the given byte index counted from
+    /// the least significant byte.
+    fn byte_at(&self, index: usize) -> u8 {
+        let bytes_per_digit = Digit::BITS / 8;
+        let digit = self.as_digit_slice()[index / bytes_per_digit];
+        (digit >> ((index % bytes_per_digit) * 8)).repr() as u8
+    }
+
+    /// Returns the bytes of this `ApInt` in little-endian order, i.e. the
+    /// least significant byte comes first.
+    ///
+    /// The returned `Vec` has exactly as many bytes as are required to
+    /// represent the bit width of `self`, unused high bits of the most
+    /// significant byte are zero.
+    pub fn to_bytes_le(&self) -> Vec<u8> {
+        (0..len_bytes(self.width())).map(|i| self.byte_at(i)).collect()
+    }
+
+    /// Returns the bytes of this `ApInt` in big-endian order, i.e. the most
+    /// significant byte comes first.
+    ///
+    /// For more details look into
+    /// [`to_bytes_le`](struct.ApInt.html#method.to_bytes_le).
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        (0..len_bytes(self.width()))
+            .rev()
+            .map(|i| self.byte_at(i))
+            .collect()
+    }
+
+    /// Writes the bytes of this `ApInt` in little-endian order into the given
+    /// `buf` without allocating.
+    ///
+    /// For more details look into
+    /// [`to_bytes_le`](struct.ApInt.html#method.to_bytes_le).
+    ///
+    /// # Errors
+    ///
+    /// - If the length of `buf` is not exactly the number of bytes required to
+    ///   represent the bit width of `self`.
+    pub fn write_bytes_le(&self, buf: &mut [u8]) -> Result<()> {
+        self.verify_buffer_length(buf)?;
+        for (i, byte) in buf.iter_mut().enumerate() {
+            *byte = self.byte_at(i);
+        }
+        Ok(())
+    }
+
+    /// Writes the bytes of this `ApInt` in big-endian order into the given
+    /// `buf` without allocating.
+    ///
+    /// For more details look into
+    /// [`to_bytes_be`](struct.ApInt.html#method.to_bytes_be).
+    ///
+    /// # Errors
+    ///
+    /// - If the length of `buf` is not exactly the number of bytes required to
+    ///   represent the bit width of `self`.
+    pub fn write_bytes_be(&self, buf: &mut [u8]) -> Result<()> {
+        self.verify_buffer_length(buf)?;
+        for (i, byte) in buf.iter_mut().rev().enumerate() {
+            *byte = self.byte_at(i);
+        }
+        Ok(())
+    }
+
+    /// Returns an error if `buf` cannot exactly hold the bytes of `self`.
+    fn verify_buffer_length(&self, buf: &[u8]) -> Result<()> {
+        let len_bytes = len_bytes(self.width());
+        if buf.len() != len_bytes {
+            return Err(Error::unmatching_buffer_length(len_bytes, buf.len()))
+        }
+        Ok(())
+    }
+
+    /// Returns the number of bytes of the compact binary encoding of this
+    /// `ApInt`.
+    ///
+    /// For more details look into
+    /// [`encode_into`](struct.ApInt.html#method.encode_into).
+    pub fn encoded_len(&self) -> usize {
+        varint_len(self.width().to_usize() as u64) + len_bytes(self.width())
+    }
+
+    /// Encodes this `ApInt` into the start of the given `buf` and returns the
+    /// number of bytes written.
+    ///
+    /// The encoding is a stable binary layout that consists of
+    ///
+    /// 1. the bit width as unsigned LEB128 variable-length integer, i.e. seven
+    ///    bits per byte starting with the least significant bits where the
+    ///    most significant bit of all but the last byte is set, followed by
+    /// 2. exactly `ceil(width / 8)` bytes of the value in little-endian order
+    ///    where the unused high bits of the most significant byte are zero.
+    ///
+    /// # Errors
+    ///
+    /// - If `buf` is shorter than
+    ///   [`encoded_len`](struct.ApInt.html#method.encoded_len).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use apint::{ApInt, BitWidth};
+    /// let value = ApInt::from_u16(0x1234);
+    /// let mut buf = [0; 8];
+    /// assert_eq!(value.encode_into(&mut buf), Ok(3));
+    /// assert_eq!(buf[..3], [16, 0x34, 0x12]);
+    /// assert_eq!(ApInt::decode_from(&buf), Ok((value, 3)));
+    /// ```
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize> {
+        let len = self.encoded_len();
+        if buf.len() < len {
+            return Err(Error::unmatching_buffer_length(len, buf.len()).with_annotation(
+                "The buffer for encoding an `ApInt` must hold at least `encoded_len` \
+                 bytes.",
+            ))
+        }
+        let mut width = self.width().to_usize() as u64;
+        let mut pos = 0;
+        while width >= 0x80 {
+            buf[pos] = (width as u8) | 0x80;
+            width >>= 7;
+            pos += 1;
+        }
+        buf[pos] = width as u8;
+        self.write_bytes_le(&mut buf[pos + 1..len])?;
+        Ok(len)
+    }
+
+    /// Decodes an `ApInt` from the start of the given `buf` and returns it
+    /// together with the number of bytes read.
+    ///
+    /// Bytes following the encoded `ApInt` are ignored. For more details about
+    /// the encoding look into
+    /// [`encode_into`](struct.ApInt.html#method.encode_into).
+    ///
+    /// # Errors
+    ///
+    /// - If the bit width is not encoded in the shortest possible form, is
+    ///   zero or does not fit into an `usize`.
+    /// - If `buf` is too short for the encoded bit width. This is checked
+    ///   before any memory is allocated, so absurd bit widths are rejected
+    ///   cheaply.
+    /// - If any unused high bit of the most significant value byte is set.
+    pub fn decode_from(buf: &[u8]) -> Result<(ApInt, usize)> {
+        let (width, start) = decode_varint(buf)?;
+        let width = usize::try_from(width)
+            .ok()
+            .and_then(|width| BitWidth::new(width).ok())
+            .ok_or_else(|| {
+                Error::invalid_encoding().with_annotation(format!(
+                    "The encoded bit width of {} is not a valid `BitWidth`.",
+                    width
+                ))
+            })?;
+        let len_bytes = len_bytes(width);
+        let bytes = start
+            .checked_add(len_bytes)
+            .and_then(|end| buf.get(start..end))
+            .ok_or_else(|| {
+                Error::invalid_encoding().with_annotation(format!(
+                    "Expected {} value bytes for the encoded bit width of {} but found \
+                     only {}.",
+                    len_bytes,
+                    width.to_usize(),
+                    buf.len() - start
+                ))
+            })?;
+        Ok((ApInt::from_bytes_le(width, bytes)?, start + len_bytes))
+    }
+}
+
+/// # Streaming
+#[cfg(feature = "std")]
+impl ApInt {
+    /// Writes the bytes of this `ApInt` in little-endian order into `w`
+    /// without an intermediate buffer.
+    ///
+    /// Exactly as many bytes are written as are required to represent the
+    /// bit width of `self`, so this is the same byte layout as for
+    /// [`to_bytes_le`](struct.ApInt.html#method.to_bytes_le).
+    ///
+    /// # Errors
+    ///
+    /// - If writing into `w` fails.
+    pub fn write_digits_le<W>(&self, w: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let mut len_bytes = len_bytes(self.width());
+        for digit in self.as_digit_slice() {
+            let bytes = digit.repr().to_le_bytes();
+            let len = bytes.len().min(len_bytes);
+            w.write_all(&bytes[..len])?;
+            len_bytes -= len;
+        }
+        Ok(())
+    }
+
+    /// Reads an `ApInt` with the given `width` from the bytes in little-endian
+    /// order of `r`.
+    ///
+    /// Exactly as many bytes are read as are required to represent the bit
+    /// width. The digits of the result are allocated once and then filled
+    /// directly from `r`. This is the inverse of
+    /// [`write_digits_le`](struct.ApInt.html#method.write_digits_le).
+    ///
+    /// # Errors
+    ///
+    /// - If reading from `r` fails. Especially if `r` ends before all bytes
+    ///   have been read.
+    /// - If `width` is not a multiple of 8 and any unused high bit of the most
+    ///   significant byte is set.
+    pub fn read_digits_le<R>(width: BitWidth, r: &mut R) -> Result<ApInt>
+    where
+        R: io::Read,
+    {
+        let mut result = ApInt::zero(width);
+        let mut len_bytes = len_bytes(result.width());
+        for digit in result.as_digit_slice_mut() {
+            let mut bytes = [0_u8; core::mem::size_of::<DigitRepr>()];
+            let len = bytes.len().min(len_bytes);
+            r.read_exact(&mut bytes[..len]).map_err(Error::io)?;
+            *digit = Digit(DigitRepr::from_le_bytes(bytes));
+            len_bytes -= len;
+        }
+        if let Some(bits) = width.excess_bits() {
+            let excess = result.most_significant_digit().repr() >> bits;
+            if excess != 0 {
+                return Err(Error::result_overflow(width).with_annotation(
+                    "The most significant byte has bits set beyond the bit width when \
+                     reading an `ApInt`.",
+                ))
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// # Allocation-free formatting
+impl ApInt {
+    /// Writes the representation of the **unsigned** value of this `ApInt`
+    /// for the given `radix` into `out`.
+    ///
+    /// The output is the same as for
+    /// [`to_string_radix`](struct.ApInt.html#method.to_string_radix) but it is
+    /// streamed into `out` chunk by chunk through a small stack buffer.
+    ///
+    /// **Note:** Power of two radices are streamed directly from the digits of
+    /// `self`. All other radices require a scratch `ApInt` holding a copy of
+    /// `self` together with its radix chunks which is allocated internally.
+    /// Use
+    /// [`write_radix_with_scratch`](struct.ApInt.html#method.write_radix_with_scratch)
+    /// in order to reuse a scratch `ApInt` instead.
+    ///
+    /// # Errors
+    ///
+    /// - If writing into `out` fails.
+    ///
+    /// # Panics
+    ///
+    /// - If `radix` is converted from an `u8` outside of `2..=36`.
+    pub fn write_radix<R, W>(&self, radix: R, out: &mut W) -> fmt::Result
+    where
+        R: Into<Radix>,
+        W: fmt::Write,
+    {
+        let radix = radix.into();
+        match radix.exact_bits_per_digit() {
+            Some(bits) => self.write_bitwise_radix(bits, out),
+            None => {
+                let mut scratch = ApInt::zero(BitWidth::w1());
+                self.write_radix_with_scratch(radix, &mut scratch, out)
+            }
+        }
+    }
+
+    /// Writes the lower case hexadecimal representation of the **unsigned**
+    /// value of this `ApInt` into `out` without allocating.
+    ///
+    /// For more details look into
+    /// [`write_radix`](struct.ApInt.html#method.write_radix).
+    pub fn write_hex<W>(&self, out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        self.write_radix(Radix::new(16).unwrap(), out)
+    }
+
+    /// Writes the decimal representation of the **unsigned** value of this
+    /// `ApInt` into `out`.
+    ///
+    /// **Note:** This allocates a scratch `ApInt` internally. Use
+    /// [`write_radix_with_scratch`](struct.ApInt.html#method.write_radix_with_scratch)
+    /// in order to avoid allocations.
+    ///
+    /// For more details look into
+    /// [`write_radix`](struct.ApInt.html#method.write_radix).
+    pub fn write_decimal<W>(&self, out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        self.write_radix(Radix::new(10).unwrap(), out)
+    }
+
+    /// Writes the representation of the **unsigned** value of this `ApInt`
+    /// for the given `radix` into `out` using `scratch` as working memory.
+    ///
+    /// For radices that are not a power of two `scratch` has to hold a copy
+    /// of `self` together with all of its radix chunks, which takes roughly
+    /// twice the bit width of `self`. If `scratch` is smaller it is replaced
+    /// by a big enough `ApInt` which allocates. Reusing the same `scratch`
+    /// for values of similar bit widths thus avoids allocations. The value of
+    /// `scratch` is unspecified afterwards.
+    ///
+    /// The characters for radices that are not a power of two are computed
+    /// in a single pass of repeated divisions through the greatest power of
+    /// the radix that fits into an `u64`. The resulting chunks are stored
+    /// in `scratch` starting with the least significant one and then written
+    /// in reverse order.
+    ///
+    /// For more details look into
+    /// [`write_radix`](struct.ApInt.html#method.write_radix).
+    pub fn write_radix_with_scratch<R, W>(
+        &self,
+        radix: R,
+        scratch: &mut ApInt,
+        out: &mut W,
+    ) -> fmt::Result
+    where
+        R: Into<Radix>,
+        W: fmt::Write,
+    {
+        let radix = radix.into();
+        if let Some(bits) = radix.exact_bits_per_digit() {
+            return self.write_bitwise_radix(bits, out)
+        }
+        if self.is_zero() {
+            return out.write_char('0')
+        }
+        let (base, power) = u64_radix_base(radix);
+        // Every chunk covers at least `floor(log2(base))` bits, so this may
+        // overestimate the number of chunks by one.
+        let base_bits = 63 - base.leading_zeros() as usize;
+        let max_chunks = self.required_bits(false).div_ceil(base_bits);
+        let len_value = self.len_digits();
+        let len_scratch = len_value + max_chunks * Digit::PER_U64;
+        if scratch.len_digits() < len_scratch {
+            *scratch = ApInt::zero(BitWidth::from(len_scratch * Digit::BITS));
+        }
+        let (value, chunks) =
+            scratch.as_digit_slice_mut()[..len_scratch].split_at_mut(len_value);
+        value.copy_from_slice(self.as_digit_slice());
+        let mut len = len_value;
+        let mut len_chunks = 0;
+        while len > 0 {
+            if value[len - 1].is_zero() {
+                len -= 1;
+                continue
+            }
+            let chunk = div_rem_u64_digits(&mut value[..len], base);
+            let start = len_chunks * Digit::PER_U64;
+            for (dst, src) in chunks[start..].iter_mut().zip(Digit::split_u64(chunk)) {
+                *dst = src;
+            }
+            len_chunks += 1;
+        }
+        for (i, digits) in chunks[..(len_chunks * Digit::PER_U64)]
+            .chunks(Digit::PER_U64)
+            .rev()
+            .enumerate()
+        {
+            let chunk = Digit::join_u64(digits);
+            let pad = if i == 0 { 0 } else { power };
+            write_radix_chunk(out, chunk, radix, pad)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the characters for a power of two radix with `bits` bits per
+    /// radix digit into `out` starting with the most significant character.
+    fn write_bitwise_radix<W>(&self, bits: usize, out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        for i in (0..self.len_bitwise_radix_chars(bits)).rev() {
+            out.write_char(char::from(self.bitwise_radix_char_at(i, bits)))?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the number of bytes required to represent the given bit width.
+fn len_bytes(width: BitWidth) -> usize {
+    width.to_usize().div_ceil(8)
+}
+
+/// Returns the number of bytes of the unsigned LEB128 encoding of `value`.
+fn varint_len(value: u64) -> usize {
+    let bits = 64 - value.leading_zeros() as usize;
+    bits.max(1).div_ceil(7)
+}
+
+/// Decodes an unsigned LEB128 encoded `u64` from the start of `buf` and
+/// returns it together with the number of bytes read.
+///
+/// # Errors
+///
+/// - If `buf` ends within the encoding.
+/// - If the encoding is not the shortest possible one.
+/// - If the encoded value does not fit into an `u64`.
+fn decode_varint(buf: &[u8]) -> Result<(u64, usize)> {
+    let mut value = 0_u64;
+    for (i, &byte) in buf.iter().enumerate() {
+        let bits = u64::from(byte & 0x7F);
+        let shift = 7 * i;
+        if shift >= 64 || (bits << shift) >> shift != bits {
+            return Err(Error::invalid_encoding().with_annotation(
+                "The encoded bit width does not fit into 64 bits.",
+            ))
+        }
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            if byte == 0 && i > 0 {
+                return Err(Error::invalid_encoding().with_annotation(
+                    "The bit width is not encoded in the shortest possible form.",
+                ))
+            }
+            return Ok((value, i + 1))
+        }
+    }
+    Err(Error::invalid_encoding()
+        .with_annotation("The input ends within the encoding of the bit width."))
+}
+
+/// Returns the greatest power of the `radix` that fits into an `u64`
+/// together with its exponent.
+fn u64_radix_base(radix: Radix) -> (u64, usize) {
+    let radix = u64::from(radix.to_u8());
+    let (mut base, mut power) = (radix, 1);
+    while let Some(next) = base.checked_mul(radix) {
+        base = next;
+        power += 1;
+    }
+    (base, power)
+}
+
+/// Quotient-assigns the little-endian `digits` by the non-zero `divisor`
+/// inplace and returns the remainder.
+fn div_rem_u64_digits(digits: &mut [Digit], divisor: u64) -> u64 {
+    debug_assert!(divisor != 0);
+    if divisor <= Digit::join_u64(&[Digit::ONES]) {
+        let divisor = Digit(divisor as DigitRepr).dd();
+        let mut rem = Digit::ZERO;
+        for digit in digits.iter_mut().rev() {
+            let (quo, r) = DoubleDigit::from_lo_hi(*digit, rem).wrapping_divrem(divisor);
+            *digit = quo.lo();
+            rem = r.lo();
+        }
+        return Digit::join_u64(&[rem])
+    }
+    // the divisor does not fit into a single `Digit`
+    let divisor = u128::from(divisor);
+    let mut rem = 0;
+    for digit in digits.iter_mut().rev() {
+        let duo = (rem << Digit::BITS) | u128::from(digit.repr());
+        *digit = Digit((duo / divisor) as DigitRepr);
+        rem = duo % divisor;
+    }
+    rem as u64
+}
+
+/// Writes the characters of the given `chunk` for the `radix` into `out`
+/// zero padded to at least `pad` characters.
+fn write_radix_chunk<W>(
+    out: &mut W,
+    mut chunk: u64,
+    radix: Radix,
+    pad: usize,
+) -> fmt::Result
+where
+    W: fmt::Write,
+{
+    let radix = u64::from(radix.to_u8());
+    let mut buf = [0_u8; 64];
+    let mut start = buf.len();
+    while chunk != 0 || buf.len() - start < pad {
+        start -= 1;
+        buf[start] = radix_char((chunk % radix) as u8);
+        chunk /= radix;
+    }
+    out.write_str(str::from_utf8(&buf[start..]).expect(
+        "The radix characters are all within `0`..`9` and `a`..`z` and thus valid UTF-8.",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::apint::test_utils::test_rng;
+
+    mod constants {
+        use super::*;
+        use std::ops::Mul;
+
+        #[test]
+        fn test_lb_2_36_i3f13() {
+            for (i, c) in LB_2_36_I3F13.iter().enumerate() {
+                assert_eq!(
+                    *c,
+                    ((i + 2) as f64).log2().mul((1 << 13) as f64).ceil() as u16
+                );
+            }
+        }
+    }
+
+    mod binary {
+        use super::*;
+
+        fn assert_binary(val: ApInt, expected: &str) {
+            assert_eq!(format!("{:b}", val), expected)
+        }
+
+        #[test]
+        fn small() {
+            assert_binary(ApInt::zero(BitWidth::w32()), "0");
+            assert_binary(ApInt::from(0b_1010_0110_0110_1001_u32), "1010011001101001");
+            assert_binary(
+                ApInt::all_set(BitWidth::w32()),
+                "11111111111111111111111111111111", // 32 ones
+            );
+            assert_binary(
+                ApInt::signed_min_value(BitWidth::w32()),
+                "10000000000000000000000000000000", // 31 zeros
+            );
+            assert_binary(
+                ApInt::signed_max_value(BitWidth::w32()),
+                "1111111111111111111111111111111", // 31 ones
+            );
+        }
+
+        #[test]
+        fn large() {
+            assert_binary(ApInt::zero(BitWidth::w128()), "0");
+            assert_binary(ApInt::from(0b_1010_0110_0110_1001_u128), "1010011001101001");
+            assert_binary(
+                ApInt::all_set(BitWidth::w128()),
+                "11111111111111111111111111111111\
+                 11111111111111111111111111111111\
+                 11111111111111111111111111111111\
+                 11111111111111111111111111111111",
+            );
+            assert_binary(
+                ApInt::signed_min_value(BitWidth::w128()),
+                "10000000000000000000000000000000\
+                 00000000000000000000000000000000\
+                 00000000000000000000000000000000\
+                 00000000000000000000000000000000",
+            );
+            assert_binary(
+                ApInt::signed_max_value(BitWidth::w128()),
+                "1111111111111111111111111111111\
+                 11111111111111111111111111111111\
+                 11111111111111111111111111111111\
+                 11111111111111111111111111111111",
+            );
+        }
+    }
+
+    mod hex {
+        use super::*;
 
         fn assert_hex(val: ApInt, expected: &str) {
             assert_eq!(format!("{:x}", val), expected.to_lowercase());
@@ -434,39 +1598,787 @@ mod tests {
         }
 
         #[test]
-        fn small() {
-            assert_hex(ApInt::zero(BitWidth::w32()), "0");
-            assert_hex(ApInt::from(0xFEDC_BA98_u32), "FEDCBA98");
-            assert_hex(ApInt::all_set(BitWidth::w32()), "FFFFFFFF");
-            assert_hex(ApInt::signed_min_value(BitWidth::w32()), "80000000");
-            assert_hex(ApInt::signed_max_value(BitWidth::w32()), "7FFFFFFF");
+        fn small() {
+            assert_hex(ApInt::zero(BitWidth::w32()), "0");
+            assert_hex(ApInt::from(0xFEDC_BA98_u32), "FEDCBA98");
+            assert_hex(ApInt::all_set(BitWidth::w32()), "FFFFFFFF");
+            assert_hex(ApInt::signed_min_value(BitWidth::w32()), "80000000");
+            assert_hex(ApInt::signed_max_value(BitWidth::w32()), "7FFFFFFF");
+        }
+
+        #[test]
+        fn large() {
+            assert_hex(ApInt::zero(BitWidth::w128()), "0");
+            assert_hex(
+                ApInt::from(0xFEDC_BA98_0A1B_7654_ABCD_0123_u128),
+                "FEDCBA980A1B7654ABCD0123",
+            );
+            assert_hex(
+                ApInt::all_set(BitWidth::w128()),
+                "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+            );
+            assert_hex(
+                ApInt::signed_min_value(BitWidth::w128()),
+                "80000000000000000000000000000000",
+            );
+            assert_hex(
+                ApInt::signed_max_value(BitWidth::w128()),
+                "7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+            );
+        }
+    }
+
+    mod write_radix {
+        use super::*;
+
+        fn write_radix_string(value: &ApInt, radix: u8) -> String {
+            let mut out = String::new();
+            value.write_radix(radix, &mut out).unwrap();
+            out
+        }
+
+        fn test_values() -> Vec<ApInt> {
+            let w1000 = BitWidth::new(1000).unwrap();
+            let mut large = ApInt::zero(w1000);
+            let factor = ApInt::from_u64(0x9E37_79B9_7F4A_7C15).into_zero_resize(w1000);
+            for i in 0..20 {
+                large.wrapping_mul_assign(&factor).unwrap();
+                let addend = ApInt::from_u64(i).into_zero_resize(w1000);
+                large.wrapping_add_assign(&addend).unwrap();
+            }
+            vec![
+                ApInt::zero(BitWidth::w1()),
+                ApInt::zero(BitWidth::w128()),
+                ApInt::one(BitWidth::w8()),
+                ApInt::all_set(BitWidth::w8()),
+                ApInt::from_u64(u64::max_value()),
+                ApInt::from_u128(10_000_000_000_000_000_000),
+                ApInt::from_u128(u128::max_value()),
+                ApInt::signed_min_value(BitWidth::new(100).unwrap()),
+                ApInt::one(w1000),
+                ApInt::all_set(w1000),
+                large,
+            ]
+        }
+
+        #[test]
+        fn all_radices() {
+            for value in test_values() {
+                for radix in 2..=36 {
+                    assert_eq!(
+                        write_radix_string(&value, radix),
+                        value.to_string_radix(radix),
+                        "radix {}",
+                        radix
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn hex_and_decimal() {
+            for value in test_values() {
+                let mut hex = String::new();
+                value.write_hex(&mut hex).unwrap();
+                assert_eq!(hex, value.to_string_radix(16));
+                let mut decimal = String::new();
+                value.write_decimal(&mut decimal).unwrap();
+                assert_eq!(decimal, value.to_string_radix(10));
+            }
+        }
+
+        #[test]
+        fn scratch() {
+            let mut scratch = ApInt::zero(BitWidth::w1());
+            for value in test_values() {
+                for radix in 2..=36 {
+                    let mut out = String::new();
+                    value
+                        .write_radix_with_scratch(radix, &mut scratch, &mut out)
+                        .unwrap();
+                    assert_eq!(out, value.to_string_radix(radix), "radix {}", radix);
+                }
+            }
+        }
+
+        #[test]
+        fn scratch_is_reused() {
+            let mut scratch = ApInt::zero(BitWidth::w1());
+            let large = test_values().pop().unwrap();
+            let mut out = String::new();
+            large.write_radix_with_scratch(10, &mut scratch, &mut out).unwrap();
+            let width = scratch.width();
+            assert!(width > large.width());
+            for value in test_values() {
+                let mut out = String::new();
+                value.write_radix_with_scratch(10, &mut scratch, &mut out).unwrap();
+                assert_eq!(out, value.to_string_radix(10));
+                assert_eq!(scratch.width(), width);
+            }
+        }
+
+        #[test]
+        fn failing_sink() {
+            struct Full;
+            impl fmt::Write for Full {
+                fn write_str(&mut self, _: &str) -> fmt::Result {
+                    Err(fmt::Error)
+                }
+            }
+            let value = ApInt::from_u64(1337);
+            assert_eq!(value.write_decimal(&mut Full), Err(fmt::Error));
+            assert_eq!(value.write_hex(&mut Full), Err(fmt::Error));
+        }
+    }
+
+    mod signed_string {
+        use super::*;
+        use crate::Int;
+
+        #[test]
+        fn primitive_widths() {
+            for &value in &[0, 1, -1, 42, -42, i8::MIN, i8::MAX] {
+                assert_eq!(ApInt::from_i8(value).to_signed_string(), value.to_string());
+            }
+            for &value in &[0, 1, -1, 1337, -1337, i64::MIN, i64::MAX] {
+                assert_eq!(ApInt::from_i64(value).to_signed_string(), value.to_string());
+            }
+        }
+
+        #[test]
+        fn signed_min_value() {
+            let w100 = BitWidth::new(100).unwrap();
+            for &(width, expected) in &[
+                (BitWidth::w8(), "-128"),
+                (BitWidth::w64(), "-9223372036854775808"),
+                (w100, "-633825300114114700748351602688"),
+            ] {
+                let min = ApInt::signed_min_value(width);
+                assert_eq!(min.to_signed_string(), expected);
+                assert_eq!(Int::from(min).to_string(), expected);
+            }
+        }
+
+        #[test]
+        fn non_primitive_width() {
+            let w100 = BitWidth::new(100).unwrap();
+            assert_eq!(ApInt::all_set(w100).to_signed_string(), "-1");
+            assert_eq!(ApInt::zero(w100).to_signed_string(), "0");
+            assert_eq!(
+                ApInt::signed_max_value(w100).to_signed_string(),
+                "633825300114114700748351602687"
+            );
+            assert_eq!(
+                ApInt::from_i128(-1337)
+                    .into_truncate(w100)
+                    .unwrap()
+                    .to_signed_string(),
+                "-1337"
+            );
+        }
+    }
+
+    mod hex_string {
+        use super::*;
+
+        #[test]
+        fn leading_zeros() {
+            assert_eq!(ApInt::from_u8(0).to_hex_string(), "00");
+            assert_eq!(ApInt::from_u32(0xab).to_hex_string(), "000000ab");
+            assert_eq!(
+                ApInt::from_u64(0x0123_4567_89ab_cdef).to_hex_string(),
+                "0123456789abcdef"
+            );
+            let w160 = BitWidth::new(160).unwrap();
+            assert_eq!(ApInt::zero(w160).to_hex_string(), "0".repeat(40));
+            assert_eq!(ApInt::all_set(w160).to_hex_string(), "f".repeat(40));
+        }
+
+        #[test]
+        fn partial_nibble() {
+            let w1 = BitWidth::new(1).unwrap();
+            assert_eq!(ApInt::from_bool(true).to_hex_string(), "1");
+            assert_eq!(ApInt::from_hex_str("1", w1), Ok(ApInt::from_bool(true)));
+            assert!(ApInt::from_hex_str("2", w1).is_err());
+            let w130 = BitWidth::new(130).unwrap();
+            let max = ApInt::all_set(w130);
+            let max_str = format!("3{}", "f".repeat(32));
+            assert_eq!(max.to_hex_string(), max_str);
+            assert_eq!(ApInt::from_hex_str(&max_str, w130), Ok(max));
+            for top in &["4", "8", "f"] {
+                let input = format!("{}{}", top, "0".repeat(32));
+                assert!(ApInt::from_hex_str(&input, w130).is_err());
+            }
+        }
+
+        #[test]
+        fn round_trip() {
+            for width in (1..=260).map(|w| BitWidth::new(w).unwrap()) {
+                for value in &[
+                    ApInt::zero(width),
+                    ApInt::one(width),
+                    ApInt::all_set(width),
+                    ApInt::signed_min_value(width),
+                    ApInt::signed_max_value(width),
+                ] {
+                    let hex = value.to_hex_string();
+                    assert_eq!(hex.len(), width.to_usize().div_ceil(4));
+                    assert_eq!(ApInt::from_hex_str(&hex, width).as_ref(), Ok(value));
+                    let prefixed = format!("0x{}", hex.to_uppercase());
+                    assert_eq!(ApInt::from_hex_str(&prefixed, width).as_ref(), Ok(value));
+                }
+            }
+        }
+
+        #[test]
+        fn mixed_case() {
+            let w32 = BitWidth::w32();
+            let expected = ApInt::from_u32(0xdead_beef);
+            assert_eq!(ApInt::from_hex_str("DeAdBeEf", w32), Ok(expected.clone()));
+            assert_eq!(ApInt::from_hex_str("0xdEADbEEF", w32), Ok(expected));
+        }
+
+        #[test]
+        fn exact_length() {
+            let w32 = BitWidth::w32();
+            for input in &["", "0x", "ff", "0xff", "0000000ff", "0x0000000ff"] {
+                assert!(ApInt::from_hex_str(input, w32).is_err(), "{:?}", input);
+            }
+        }
+
+        #[test]
+        fn invalid_char() {
+            use crate::ErrorKind;
+            let w16 = BitWidth::w16();
+            for &(input, pos, ch) in &[
+                ("00g0", 2, 'g'),
+                ("0x00_0", 4, '_'),
+                ("+000", 0, '+'),
+                ("000-", 3, '-'),
+            ] {
+                match ApInt::from_hex_str(input, w16).unwrap_err().kind() {
+                    ErrorKind::InvalidStringRepr { pos_char, .. } => {
+                        assert_eq!(*pos_char, Some((pos, ch)), "{:?}", input)
+                    }
+                    kind => panic!("unexpected error kind {:?} for {:?}", kind, input),
+                }
+            }
+        }
+    }
+
+    mod fmt_flags {
+        use super::*;
+        use crate::UInt;
+
+        macro_rules! assert_fmt {
+            ( $value:expr, [ $( $fmt:literal ),* ] ) => {
+                $(
+                    let value: u128 = $value;
+                    let expected = format!($fmt, value);
+                    let apint = ApInt::from_u128(value);
+                    assert_eq!(format!($fmt, apint), expected, "{}", $fmt);
+                    let uint = UInt::from_u128(value);
+                    assert_eq!(format!($fmt, uint), expected, "{}", $fmt);
+                )*
+            };
+        }
+
+        #[test]
+        fn differential_u128() {
+            for &value in &[
+                0,
+                1,
+                0xFF,
+                0x0123_4567_89AB_CDEF,
+                u128::from(u64::max_value()) + 1,
+                0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210,
+                u128::max_value(),
+            ] {
+                assert_fmt!(value, ["{}", "{:b}", "{:o}", "{:x}", "{:X}"]);
+                assert_fmt!(value, ["{:#b}", "{:#o}", "{:#x}", "{:#X}"]);
+                assert_fmt!(value, ["{:5}", "{:<10}", "{:^41}", "{:>50}", "{:*^50x}"]);
+                assert_fmt!(value, ["{:#066x}", "{:#034b}", "{:#0130b}", "{:_>8o}"]);
+                assert_fmt!(value, ["{:+}", "{:+x}", "{:040}", "{:#10X}", "{:-<3}"]);
+            }
+        }
+
+        #[test]
+        fn non_primitive_width() {
+            let width = BitWidth::new(200).unwrap();
+            let value = ApInt::all_set(width);
+            assert_eq!(format!("{:x}", value), "ff".repeat(25));
+            assert_eq!(format!("{:#x}", value), format!("0x{}", "ff".repeat(25)));
+            assert_eq!(format!("{:o}", value), format!("3{}", "7".repeat(66)));
+            assert_eq!(format!("{:#b}", value), format!("0b{}", "1".repeat(200)));
+            assert_eq!(
+                format!("{:#060x}", ApInt::one(width)),
+                format!("0x{}1", "0".repeat(57))
+            );
+            assert_eq!(
+                format!("{}", value),
+                "1606938044258990275541962092341162602522202993782792835301375"
+            );
+        }
+    }
+
+    mod decimal_chunks {
+        use super::*;
+
+        const BASE: u64 = 10_000_000_000_000_000_000;
+
+        #[test]
+        fn zero() {
+            assert_eq!(
+                ApInt::zero(BitWidth::w128())
+                    .decimal_chunks()
+                    .collect::<Vec<_>>(),
+                vec![0]
+            );
+        }
+
+        #[test]
+        fn small() {
+            let chunks = |v: u128| ApInt::from(v).decimal_chunks().collect::<Vec<_>>();
+            assert_eq!(chunks(42), vec![42]);
+            assert_eq!(chunks(u128::from(BASE) - 1), vec![BASE - 1]);
+            assert_eq!(chunks(u128::from(BASE)), vec![0, 1]);
+            assert_eq!(
+                chunks(u128::MAX),
+                vec![
+                    (u128::MAX % u128::from(BASE)) as u64,
+                    ((u128::MAX / u128::from(BASE)) % u128::from(BASE)) as u64,
+                    (u128::MAX / u128::from(BASE) / u128::from(BASE)) as u64,
+                ]
+            );
+        }
+
+        #[test]
+        fn recomposition() {
+            let width = BitWidth::new(320).unwrap();
+            let base = ApInt::from(BASE).into_zero_resize(width);
+            for val in &[
+                ApInt::all_set(width),
+                ApInt::signed_min_value(width),
+                ApInt::from([1u64, 2, 3, 4, 5]),
+                ApInt::from(1337u16).into_zero_resize(width),
+            ] {
+                let mut result = ApInt::zero(width);
+                for chunk in val
+                    .clone()
+                    .into_decimal_chunks()
+                    .collect::<Vec<_>>()
+                    .iter()
+                    .rev()
+                {
+                    result.wrapping_mul_assign(&base).unwrap();
+                    result
+                        .wrapping_add_assign(&ApInt::from(*chunk).into_zero_resize(width))
+                        .unwrap();
+                }
+                assert_eq!(&result, val);
+            }
+        }
+    }
+
+    mod bytes {
+        use super::*;
+        use crate::ErrorKind;
+
+        fn test_widths() -> impl Iterator<Item = BitWidth> {
+            [1, 8, 20, 64, 65, 256]
+                .iter()
+                .map(|&w| BitWidth::new(w).unwrap())
+        }
+
+        fn test_values(width: BitWidth) -> Vec<ApInt> {
+            vec![
+                ApInt::zero(width),
+                ApInt::one(width),
+                ApInt::all_set(width),
+                ApInt::signed_min_value(width),
+                ApInt::repeat(&ApInt::from_u8(0b1101_0010), width),
+                ApInt::repeat(&ApInt::from_u64(0x0123_4567_89ab_cdef), width),
+            ]
+        }
+
+        #[test]
+        fn round_trip() {
+            for width in test_widths() {
+                let required_bytes = len_bytes(width);
+                for value in test_values(width) {
+                    let le = value.to_bytes_le();
+                    let be = value.to_bytes_be();
+                    assert_eq!(le.len(), required_bytes);
+                    assert_eq!(
+                        be.iter().rev().collect::<Vec<_>>(),
+                        le.iter().collect::<Vec<_>>()
+                    );
+                    assert_eq!(ApInt::from_bytes_le(width, &le), Ok(value.clone()));
+                    assert_eq!(ApInt::from_bytes_be(width, &be), Ok(value.clone()));
+                }
+            }
+        }
+
+        #[test]
+        fn byte_order() {
+            let value = ApInt::from_u32(0x0102_0304);
+            assert_eq!(value.to_bytes_le(), vec![0x04, 0x03, 0x02, 0x01]);
+            assert_eq!(value.to_bytes_be(), vec![0x01, 0x02, 0x03, 0x04]);
+            let w65 = BitWidth::new(65).unwrap();
+            let value = ApInt::from([1_u64, 0x0123_4567_89ab_cdef])
+                .into_truncate(w65)
+                .unwrap();
+            assert_eq!(
+                value.to_bytes_be(),
+                vec![0x01, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef]
+            );
+            assert_eq!(ApInt::from_bytes_be(w65, &value.to_bytes_be()), Ok(value));
+        }
+
+        #[test]
+        fn write_round_trip() {
+            for width in test_widths() {
+                for value in test_values(width) {
+                    let mut le = vec![0xaa; len_bytes(value.width())];
+                    let mut be = vec![0xaa; len_bytes(value.width())];
+                    value.write_bytes_le(&mut le).unwrap();
+                    value.write_bytes_be(&mut be).unwrap();
+                    assert_eq!(le, value.to_bytes_le());
+                    assert_eq!(be, value.to_bytes_be());
+                    assert_eq!(ApInt::from_bytes_le(width, &le), Ok(value.clone()));
+                    assert_eq!(ApInt::from_bytes_be(width, &be), Ok(value));
+                }
+            }
         }
 
         #[test]
-        fn large() {
-            assert_hex(ApInt::zero(BitWidth::w128()), "0");
-            assert_hex(
-                ApInt::from(0xFEDC_BA98_0A1B_7654_ABCD_0123_u128),
-                "FEDCBA980A1B7654ABCD0123",
+        fn test_vectors() {
+            let w200 = BitWidth::new(200).unwrap();
+            let value = ApInt::from([
+                0xa5_u64,
+                0x0011_2233_4455_6677,
+                0x8899_aabb_ccdd_eeff,
+                0x0123_4567_89ab_cdef,
+            ])
+            .into_truncate(w200)
+            .unwrap();
+            let be = vec![
+                0xa5, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa,
+                0xbb, 0xcc, 0xdd, 0xee, 0xff, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd,
+                0xef,
+            ];
+            let le = be.iter().rev().cloned().collect::<Vec<_>>();
+            assert_eq!(value.to_bytes_be(), be);
+            assert_eq!(value.to_bytes_le(), le);
+            let w12 = BitWidth::new(12).unwrap();
+            let value = ApInt::from_u16(0x0abc).into_truncate(w12).unwrap();
+            assert_eq!(value.to_bytes_le(), vec![0xbc, 0x0a]);
+            assert_eq!(value.to_bytes_be(), vec![0x0a, 0xbc]);
+            let mut buf = [0xff; 2];
+            value.write_bytes_be(&mut buf).unwrap();
+            assert_eq!(buf, [0x0a, 0xbc]);
+        }
+
+        #[test]
+        fn write_unmatching_buffer_length() {
+            let value = ApInt::from_u32(0x0102_0304);
+            for len in &[0, 3, 5] {
+                let mut buf = vec![0; *len];
+                let expected = ErrorKind::UnmatchingBufferLength {
+                    expected: 4,
+                    actual: *len,
+                };
+                assert_eq!(
+                    value.write_bytes_le(&mut buf).unwrap_err().kind(),
+                    &expected
+                );
+                assert_eq!(
+                    value.write_bytes_be(&mut buf).unwrap_err().kind(),
+                    &expected
+                );
+                assert!(buf.iter().all(|&b| b == 0));
+            }
+        }
+
+        #[test]
+        fn short_input() {
+            let w20 = BitWidth::new(20).unwrap();
+            assert_eq!(
+                ApInt::from_bytes_le(w20, &[0x34, 0x12]),
+                Ok(ApInt::from_u32(0x1234).into_truncate(w20).unwrap())
             );
-            assert_hex(
-                ApInt::all_set(BitWidth::w128()),
-                "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+            assert_eq!(
+                ApInt::from_bytes_be(w20, &[0x12, 0x34]),
+                Ok(ApInt::from_u32(0x1234).into_truncate(w20).unwrap())
             );
-            assert_hex(
-                ApInt::signed_min_value(BitWidth::w128()),
-                "80000000000000000000000000000000",
+            assert_eq!(ApInt::from_bytes_le(w20, &[]), Ok(ApInt::zero(w20)));
+            assert_eq!(
+                ApInt::from_bytes_be(BitWidth::w128(), &[0xff; 9]),
+                Ok(ApInt::from([0xff_u64, 0xffff_ffff_ffff_ffff]))
             );
-            assert_hex(
-                ApInt::signed_max_value(BitWidth::w128()),
-                "7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+        }
+
+        #[test]
+        fn excess_bytes() {
+            let w20 = BitWidth::new(20).unwrap();
+            let expected = ApInt::from_u32(0x0f_1234).into_truncate(w20).unwrap();
+            assert_eq!(
+                ApInt::from_bytes_le(w20, &[0x34, 0x12, 0x0f, 0x00, 0x00]),
+                Ok(expected.clone())
+            );
+            assert_eq!(
+                ApInt::from_bytes_be(w20, &[0x00, 0x00, 0x0f, 0x12, 0x34]),
+                Ok(expected)
+            );
+            for bytes in &[
+                &[0x34, 0x12, 0x0f, 0x01][..],
+                &[0x00, 0x00, 0x00, 0x00, 0x80],
+            ] {
+                assert_eq!(
+                    ApInt::from_bytes_le(w20, bytes).unwrap_err().kind(),
+                    &ErrorKind::ResultOverflow(w20)
+                );
+            }
+            assert_eq!(
+                ApInt::from_bytes_be(BitWidth::w8(), &[0x01, 0x00])
+                    .unwrap_err()
+                    .kind(),
+                &ErrorKind::ResultOverflow(BitWidth::w8())
+            );
+        }
+
+        #[test]
+        fn unused_high_bits() {
+            let w20 = BitWidth::new(20).unwrap();
+            assert_eq!(
+                ApInt::from_bytes_le(w20, &[0x00, 0x00, 0x10])
+                    .unwrap_err()
+                    .kind(),
+                &ErrorKind::ResultOverflow(w20)
+            );
+            assert_eq!(
+                ApInt::from_bytes_be(w20, &[0x80, 0x00, 0x00])
+                    .unwrap_err()
+                    .kind(),
+                &ErrorKind::ResultOverflow(w20)
+            );
+            assert_eq!(
+                ApInt::from_bytes_le(BitWidth::w1(), &[0x02])
+                    .unwrap_err()
+                    .kind(),
+                &ErrorKind::ResultOverflow(BitWidth::w1())
+            );
+            assert_eq!(
+                ApInt::from_bytes_le(BitWidth::w1(), &[0x01]),
+                Ok(ApInt::from_bool(true))
+            );
+        }
+    }
+
+    mod codec {
+        use super::*;
+        use crate::ErrorKind;
+
+        fn test_values() -> Vec<ApInt> {
+            let mut values = Vec::new();
+            for &width in &[1, 7, 8, 9, 64, 65, 127, 128, 129, 521, 16_384] {
+                let width = BitWidth::new(width).unwrap();
+                values.push(ApInt::zero(width));
+                values.push(ApInt::one(width));
+                values.push(ApInt::all_set(width));
+                values.push(ApInt::signed_min_value(width));
+                values.push(ApInt::signed_max_value(width));
+            }
+            values
+        }
+
+        fn encode(value: &ApInt) -> Vec<u8> {
+            let mut buf = vec![0; value.encoded_len()];
+            assert_eq!(value.encode_into(&mut buf), Ok(buf.len()));
+            buf
+        }
+
+        #[test]
+        fn round_trip() {
+            for value in test_values() {
+                let mut buf = encode(&value);
+                assert_eq!(ApInt::decode_from(&buf), Ok((value.clone(), buf.len())));
+                // trailing bytes are ignored
+                let len = buf.len();
+                buf.extend_from_slice(&[0xFF; 3]);
+                assert_eq!(ApInt::decode_from(&buf), Ok((value, len)));
+            }
+        }
+
+        #[test]
+        fn layout() {
+            assert_eq!(encode(&ApInt::from_bool(true)), vec![1, 1]);
+            assert_eq!(encode(&ApInt::from_u8(0xAB)), vec![8, 0xAB]);
+            assert_eq!(
+                encode(&ApInt::from_u32(0x1234_5678)),
+                vec![32, 0x78, 0x56, 0x34, 0x12]
+            );
+            let w129 = BitWidth::new(129).unwrap();
+            let mut expected = vec![0x81, 0x01];
+            expected.extend_from_slice(&[0xFF; 16]);
+            expected.push(0x01);
+            assert_eq!(encode(&ApInt::all_set(w129)), expected);
+            let w16384 = BitWidth::new(16_384).unwrap();
+            assert_eq!(encode(&ApInt::zero(w16384))[..3], [0x80, 0x80, 0x01]);
+        }
+
+        #[test]
+        fn short_buffer() {
+            let value = ApInt::from_u64(42);
+            let mut buf = [0; 8];
+            assert_eq!(
+                value.encode_into(&mut buf).unwrap_err().kind(),
+                &ErrorKind::UnmatchingBufferLength {
+                    expected: 9,
+                    actual: 8,
+                }
+            );
+        }
+
+        #[test]
+        fn invalid() {
+            for buf in &[
+                // empty
+                &[][..],
+                // zero bit width
+                &[0x00],
+                // truncated bit width
+                &[0x81],
+                // overlong bit width
+                &[0x88, 0x00, 0xFF],
+                // truncated value
+                &[0x10, 0xFF],
+                // unused high bits set
+                &[0x01, 0x02],
+                &[0x09, 0x00, 0x02],
+                // bit width exceeds 64 bits
+                &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x02],
+                &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x01],
+            ] {
+                assert!(ApInt::decode_from(buf).is_err(), "{:?}", buf);
+            }
+        }
+
+        #[test]
+        fn absurd_width() {
+            // a bit width of `2^63` requires an exabyte of value bytes
+            let buf = [0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x01, 0xFF];
+            assert_eq!(
+                ApInt::decode_from(&buf).unwrap_err().kind(),
+                &ErrorKind::InvalidEncoding
+            );
+        }
+
+        #[test]
+        fn random_bytes() {
+            use rand::RngCore;
+            let mut rng = test_rng();
+            let mut next = || rng.next_u64();
+            for _ in 0..10_000 {
+                let len = (next() % 40) as usize;
+                let mut buf: Vec<u8> = (0..len).map(|_| next() as u8).collect();
+                // favor small bit widths so that some inputs decode successfully
+                if let Some(first) = buf.first_mut() {
+                    if next() % 2 == 0 {
+                        *first &= 0x3F;
+                    }
+                }
+                if let Ok((value, read)) = ApInt::decode_from(&buf) {
+                    assert_eq!(encode(&value), buf[..read]);
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod streaming {
+        use super::*;
+        use crate::ErrorKind;
+        use std::io::{
+            self,
+            Cursor,
+        };
+
+        fn test_values() -> Vec<ApInt> {
+            let mut values = Vec::new();
+            for &width in &[1, 7, 8, 9, 63, 64, 65, 100, 128, 521, 10_000] {
+                let width = BitWidth::new(width).unwrap();
+                let pattern = ApInt::from_u64(0x0123_4567_89AB_CDEF);
+                values.push(ApInt::zero(width));
+                values.push(ApInt::all_set(width));
+                values.push(ApInt::signed_min_value(width));
+                values.push(pattern.into_zero_resize(width).into_wrapping_neg());
+            }
+            values
+        }
+
+        #[test]
+        fn round_trip() {
+            for value in test_values() {
+                let mut cursor = Cursor::new(Vec::new());
+                value.write_digits_le(&mut cursor).unwrap();
+                assert_eq!(cursor.get_ref(), &value.to_bytes_le());
+                cursor.set_position(0);
+                let read = ApInt::read_digits_le(value.width(), &mut cursor).unwrap();
+                assert_eq!(read, value);
+                assert_eq!(cursor.position() as usize, cursor.get_ref().len());
+            }
+        }
+
+        #[test]
+        fn truncated() {
+            for value in test_values() {
+                let bytes = value.to_bytes_le();
+                for len in 0..bytes.len() {
+                    let mut cursor = Cursor::new(&bytes[..len]);
+                    let err = ApInt::read_digits_le(value.width(), &mut cursor);
+                    assert_eq!(
+                        err.unwrap_err().kind(),
+                        &ErrorKind::Io(io::ErrorKind::UnexpectedEof)
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn unused_high_bits() {
+            let w9 = BitWidth::new(9).unwrap();
+            let mut cursor = Cursor::new(vec![0x00, 0x02]);
+            let err = ApInt::read_digits_le(w9, &mut cursor).unwrap_err();
+            assert_eq!(err.kind(), &ErrorKind::ResultOverflow(w9));
+            let mut cursor = Cursor::new(vec![0xFF, 0x01]);
+            assert_eq!(
+                ApInt::read_digits_le(w9, &mut cursor),
+                Ok(ApInt::all_set(w9))
             );
         }
+
+        #[test]
+        fn failing_writer() {
+            struct Full;
+            impl io::Write for Full {
+                fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                    Ok(0)
+                }
+
+                fn flush(&mut self) -> io::Result<()> {
+                    Ok(())
+                }
+            }
+            let err = ApInt::from_u64(42).write_digits_le(&mut Full).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+        }
     }
 
     mod from_str_radix {
 
         use super::*;
+        use crate::ErrorKind;
 
         fn test_radices() -> impl Iterator<Item = Radix> {
             [2, 4, 8, 16, 32, 7, 10, 36]
@@ -474,23 +2386,40 @@ mod tests {
                 .map(|&r| Radix::new(r).unwrap())
         }
 
+        /// Formats `value` with the given `radix` using lower case letters.
+        fn to_radix_string(mut value: u128, radix: u8) -> String {
+            let mut chars = Vec::new();
+            loop {
+                let digit = (value % u128::from(radix)) as u32;
+                chars.push(core::char::from_digit(digit, 36).unwrap());
+                value /= u128::from(radix);
+                if value == 0 {
+                    break
+                }
+            }
+            chars.iter().rev().collect()
+        }
+
         #[test]
         fn empty() {
             for radix in test_radices() {
-                assert_eq!(
-                    ApInt::from_str_radix(radix, ""),
-                    Err(Error::invalid_string_repr("", radix)
-                        .with_annotation("Cannot parse an empty string into an ApInt."))
-                )
+                for &input in &["", "+"] {
+                    assert_eq!(
+                        ApInt::from_str_radix(input, radix, BitWidth::w64()),
+                        Err(Error::invalid_string_repr(input, radix).with_annotation(
+                            "Cannot parse an empty string into an ApInt."
+                        ))
+                    )
+                }
             }
         }
 
         #[test]
         fn starts_with_underscore() {
             for radix in test_radices() {
-                for &input in &["_0", "_123", "__", "_1_0"] {
+                for &input in &["_0", "_123", "__", "_1_0", "+_1"] {
                     assert_eq!(
-                        ApInt::from_str_radix(radix, input),
+                        ApInt::from_str_radix(input, radix, BitWidth::w64()),
                         Err(Error::invalid_string_repr(input, radix).with_annotation(
                             "The input string starts with an underscore ('_') instead \
                              of a number. The use of underscores is explicitely for \
@@ -506,7 +2435,7 @@ mod tests {
             for radix in test_radices() {
                 for &input in &["0_", "123_", "1_0_"] {
                     assert_eq!(
-                        ApInt::from_str_radix(radix, input),
+                        ApInt::from_str_radix(input, radix, BitWidth::w64()),
                         Err(Error::invalid_string_repr(input, radix).with_annotation(
                             "The input string ends with an underscore ('_') instead of \
                              a number. The use of underscores is explicitely for \
@@ -517,13 +2446,59 @@ mod tests {
             }
         }
 
+        #[test]
+        fn invalid_char() {
+            for &(radix, input, pos, ch) in &[
+                (2, "01020", 3, '2'),
+                (10, "12a", 2, 'a'),
+                (16, "+ffg", 3, 'g'),
+                (16, "-1", 0, '-'),
+                (36, "1_z z", 3, ' '),
+                (10, "1\u{e9}2", 1, '\u{e9}'),
+                (10, "+1\u{e9}", 2, '\u{e9}'),
+            ] {
+                let error =
+                    ApInt::from_str_radix(input, radix, BitWidth::w64()).unwrap_err();
+                match error.kind() {
+                    ErrorKind::InvalidStringRepr { pos_char, .. } => {
+                        assert_eq!(*pos_char, Some((pos, ch)))
+                    }
+                    kind => panic!("unexpected error kind: {:?}", kind),
+                }
+            }
+        }
+
+        #[test]
+        fn overflow() {
+            let w8 = BitWidth::w8();
+            assert_eq!(
+                ApInt::from_str_radix("255", 10, w8),
+                Ok(ApInt::from_u8(u8::max_value()))
+            );
+            assert_eq!(
+                ApInt::from_str_radix("256", 10, w8).unwrap_err().kind(),
+                &ErrorKind::ResultOverflow(w8)
+            );
+            assert_eq!(
+                ApInt::from_str_radix("1_0000_0000", 2, w8).unwrap_err().kind(),
+                &ErrorKind::ResultOverflow(w8)
+            );
+            // leading zeros do not count towards the required bits
+            assert_eq!(
+                ApInt::from_str_radix("0000_0000_0000_0001", 16, BitWidth::w1()),
+                Ok(ApInt::from_bool(true))
+            );
+        }
+
         #[test]
         fn zero() {
             for radix in test_radices() {
-                assert_eq!(
-                    ApInt::from_str_radix(radix, "0"),
-                    Ok(ApInt::zero(BitWidth::new(64).unwrap()))
-                )
+                for &input in &["0", "+0", "0_000"] {
+                    assert_eq!(
+                        ApInt::from_str_radix(input, radix, BitWidth::w64()),
+                        Ok(ApInt::zero(BitWidth::w64()))
+                    )
+                }
             }
         }
 
@@ -566,30 +2541,426 @@ mod tests {
                 (8, "777_747_666", 0o777_747_666),
                 (8, "111", 0b001_001_001),
                 (8, "7_7777_7777_7777_7777_7777", u64::max_value() / 2),
-                // ( 8, "17_7777_7777_7777_7777_7777", u64::max_value()), // Does not
-                // work, yet! Should it work?
+                (8, "17_7777_7777_7777_7777_7777", u64::max_value()),
                 (10, "100", 100),
                 (10, "42", 42),
                 (10, "1337", 1337),
                 (10, "5_000_000", 5_000_000),
-                // (10, "18_446_744_073_709_551_615", u64::max_value()), // Does not
-                // work, yet!
+                (10, "18_446_744_073_709_551_615", u64::max_value()),
+                (10, "+18_446_744_073_709_551_615", u64::max_value()),
                 (16, "100", 0x100),
                 (16, "42", 0x42),
                 (16, "1337", 0x1337),
                 (16, "1111", 0x1111),
                 (16, "5555", 0x5555),
                 (16, "FFFF", 0xFFFF),
+                (16, "ffff", 0xFFFF),
                 (16, "0123_4567_89AB_CDEF", 0x0123_4567_89AB_CDEF),
                 (16, "FEDC_BA98_7654_3210", 0xFEDC_BA98_7654_3210),
+                (16, "fedc_ba98_7654_3210", 0xFEDC_BA98_7654_3210),
+                (36, "3w5e11264sgsf", u64::max_value()),
+                (36, "3W5E11264SGSF", u64::max_value()),
+            ];
+            for &(radix, input, expected) in &samples {
+                let result = ApInt::from_str_radix(input, radix, BitWidth::w64());
+                assert_eq!(result, Ok(ApInt::from_u64(expected)))
+            }
+        }
+
+        #[test]
+        fn round_trip_all_radices() {
+            let values = [
+                0,
+                1,
+                35,
+                36,
+                255,
+                256,
+                u128::from(u64::max_value()),
+                u128::from(u64::max_value()) + 1,
+                0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210,
+                u128::max_value() / 3,
+                u128::max_value(),
             ];
-            for sample in &samples {
-                let radix = Radix::new(sample.0).unwrap();
-                let input = sample.1;
-                let result = ApInt::from_str_radix(radix, input).unwrap();
-                let expected = ApInt::from_u64(sample.2);
-                assert_eq!(result, expected)
+            for radix in 2..=36 {
+                for &value in &values {
+                    let input = to_radix_string(value, radix);
+                    assert_eq!(
+                        ApInt::from_str_radix(&input, radix, BitWidth::w128()),
+                        Ok(ApInt::from_u128(value)),
+                        "radix = {}, input = {:?}",
+                        radix,
+                        input
+                    );
+                    assert_eq!(
+                        ApInt::from_str_radix(
+                            &input.to_uppercase(),
+                            radix,
+                            BitWidth::w128()
+                        ),
+                        Ok(ApInt::from_u128(value))
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn large_decimal() {
+            let width = BitWidth::new(700).unwrap();
+            let ten = ApInt::from_u64(10).into_zero_resize(width);
+            let mut expected = ApInt::one(width);
+            for n in 1..200 {
+                expected.wrapping_mul_assign(&ten).unwrap();
+                let input = format!("1{}", "0".repeat(n));
+                assert_eq!(
+                    ApInt::from_str_radix(&input, 10, width),
+                    Ok(expected.clone())
+                );
+                let input = "9".repeat(n);
+                let mut nines = expected.clone();
+                nines.wrapping_dec();
+                assert_eq!(ApInt::from_str_radix(&input, 10, width), Ok(nines));
+            }
+        }
+    }
+
+    mod to_string_radix {
+        use super::*;
+        use crate::ErrorKind;
+
+        #[test]
+        fn u128_boundaries_decimal() {
+            for &value in &[
+                0,
+                1,
+                9,
+                10,
+                u128::from(u64::max_value()),
+                u128::from(u64::max_value()) + 1,
+                10_000_000_000_000_000_000,
+                9_999_999_999_999_999_999,
+                u128::max_value() - 1,
+                u128::max_value(),
+            ] {
+                assert_eq!(
+                    ApInt::from_u128(value).to_string_radix(10),
+                    format!("{}", value)
+                );
+            }
+        }
+
+        #[test]
+        fn power_of_two_radices() {
+            let value = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210_u128;
+            let apint = ApInt::from_u128(value);
+            assert_eq!(apint.to_string_radix(2), format!("{:b}", value));
+            assert_eq!(apint.to_string_radix(8), format!("{:o}", value));
+            assert_eq!(apint.to_string_radix(16), format!("{:x}", value));
+            assert_eq!(apint.to_string_radix(4), {
+                let mut expected = String::new();
+                for i in (0..61).rev() {
+                    expected.push_str(&format!("{}", (value >> (2 * i)) & 0b11));
+                }
+                expected
+            });
+            assert_eq!(
+                ApInt::from_u128(u128::max_value()).to_string_radix(32),
+                format!("7{}", "v".repeat(25))
+            );
+            assert_eq!(ApInt::zero(BitWidth::w1()).to_string_radix(32), "0");
+            assert_eq!(ApInt::from_bool(true).to_string_radix(2), "1");
+        }
+
+        #[test]
+        fn leading_zero_digits() {
+            let width = BitWidth::new(1000).unwrap();
+            for radix in 2..=36 {
+                assert_eq!(ApInt::zero(width).to_string_radix(radix), "0");
+                assert_eq!(ApInt::one(width).to_string_radix(radix), "1");
+            }
+        }
+
+        #[test]
+        fn round_trip_1000_bits() {
+            let width = BitWidth::new(1000).unwrap();
+            // a value with an irregular bit pattern across all digits
+            let mut value = ApInt::zero(width);
+            let factor = ApInt::from_u64(0x9E37_79B9_7F4A_7C15).into_zero_resize(width);
+            for i in 0..20 {
+                value.wrapping_mul_assign(&factor).unwrap();
+                let addend = ApInt::from_u64(i).into_zero_resize(width);
+                value.wrapping_add_assign(&addend).unwrap();
+            }
+            value.set_bit_at(999).unwrap();
+            for &radix in &[2, 7, 10, 16, 36] {
+                let repr = value.to_string_radix(radix);
+                assert!(!repr.starts_with('0'));
+                assert_eq!(ApInt::from_str_radix(&repr, radix, width), Ok(value.clone()));
+            }
+        }
+
+        fn simple_radix_string(value: &ApInt, radix: u8) -> String {
+            let mut chars = Vec::new();
+            value.push_radix_chars_simple(Radix::new(radix).unwrap(), 1, &mut chars);
+            chars.reverse();
+            String::from_utf8(chars).unwrap()
+        }
+
+        #[test]
+        fn divide_and_conquer() {
+            use rand::RngCore;
+            let mut rng = test_rng();
+            let mut next_limb = || rng.next_u64();
+            for &bits in &[
+                (RADIX_DIVIDE_AND_CONQUER_THRESHOLD - 1) * Digit::BITS,
+                RADIX_DIVIDE_AND_CONQUER_THRESHOLD * Digit::BITS,
+                RADIX_DIVIDE_AND_CONQUER_THRESHOLD * Digit::BITS + 1,
+                3000,
+                10_000,
+                25_000,
+            ] {
+                let width = BitWidth::new(bits).unwrap();
+                let limbs = (0..width.required_u64s())
+                    .map(|_| next_limb())
+                    .collect::<Vec<_>>();
+                let random = ApInt::from_le_limbs(&limbs)
+                    .unwrap()
+                    .into_truncate(width)
+                    .unwrap();
+                for &radix in &[3, 7, 10, 36] {
+                    assert_eq!(
+                        random.to_string_radix(radix),
+                        simple_radix_string(&random, radix)
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn divide_and_conquer_zero_padding() {
+            let width = BitWidth::new(8000).unwrap();
+            let ten = ApInt::from_u8(10).into_zero_resize(width);
+            let mut power = ApInt::one(width);
+            for n in 1..2000 {
+                power.wrapping_mul_assign(&ten).unwrap();
+                if n % 97 != 0 {
+                    continue
+                }
+                let expected = format!("1{}", "0".repeat(n));
+                assert_eq!(power.to_string_radix(10), expected);
+                let mut value = power.clone();
+                value.wrapping_inc();
+                assert_eq!(value.to_string_radix(10), format!("1{}1", "0".repeat(n - 1)));
+                let two = ApInt::from_u8(2).into_zero_resize(width);
+                value.wrapping_sub_assign(&two).unwrap();
+                assert_eq!(value.to_string_radix(10), "9".repeat(n));
+            }
+        }
+
+        #[test]
+        fn invalid_radix() {
+            let value = ApInt::from_u8(42);
+            assert_eq!(value.try_to_string_radix(36), Ok(String::from("16")));
+            for &radix in &[0, 1, 37, u8::max_value()] {
+                assert_eq!(
+                    value.try_to_string_radix(radix).unwrap_err().kind(),
+                    &ErrorKind::InvalidRadix(radix)
+                );
+            }
+        }
+    }
+
+    mod from_str_prefixed {
+        use super::*;
+        use crate::ErrorKind;
+
+        fn parse(input: &str) -> Result<ApInt> {
+            ApInt::from_str_prefixed(input, BitWidth::w32())
+        }
+
+        #[test]
+        fn prefixes() {
+            for &(input, expected) in &[
+                ("0xdeadbeef", 0xdead_beef),
+                ("0XDEADBEEF", 0xdead_beef),
+                ("0xDead_Beef", 0xdead_beef),
+                ("0o777", 0o777),
+                ("0O7_7_7", 0o777),
+                ("0b1010_1100", 0b1010_1100),
+                ("0B1", 1),
+                ("1234", 1234),
+                ("0", 0),
+                ("00", 0),
+                ("0_1", 1),
+                ("4_294_967_295", u32::max_value()),
+            ] {
+                assert_eq!(parse(input), Ok(ApInt::from_u32(expected)), "{}", input);
+            }
+        }
+
+        #[test]
+        fn signs() {
+            for &(input, expected) in &[
+                ("+0x10", 16),
+                ("-0x10", -16),
+                ("-0b1", -1),
+                ("+0o10", 8),
+                ("-0", 0),
+                ("-0x0", 0),
+                ("-1_000", -1000),
+                ("-2147483648", i32::min_value()),
+                ("-0x8000_0000", i32::min_value()),
+            ] {
+                assert_eq!(parse(input), Ok(ApInt::from_i32(expected)), "{}", input);
+            }
+        }
+
+        #[test]
+        fn overflow() {
+            let w32 = BitWidth::w32();
+            for &input in &[
+                "0x1_0000_0000",
+                "4294967296",
+                "-2147483649",
+                "-0x8000_0001",
+            ] {
+                assert_eq!(
+                    parse(input).unwrap_err().kind(),
+                    &ErrorKind::ResultOverflow(w32),
+                    "{}",
+                    input
+                );
+            }
+            // the unsigned maximum fits but its negation does not
+            assert!(parse("0xffff_ffff").is_ok());
+            assert!(parse("-0xffff_ffff").is_err());
+        }
+
+        #[test]
+        fn empty_digits() {
+            for &input in &["", "+", "-", "0x", "0o", "-0b", "+0X"] {
+                assert_eq!(
+                    parse(input).unwrap_err().kind(),
+                    &ErrorKind::InvalidStringRepr {
+                        input: input.into(),
+                        radix: match input.trim_start_matches(&['+', '-'][..]) {
+                            "0x" | "0X" => Radix::new(16).unwrap(),
+                            "0o" => Radix::new(8).unwrap(),
+                            "0b" => Radix::new(2).unwrap(),
+                            _ => Radix::new(10).unwrap(),
+                        },
+                        pos_char: None,
+                    },
+                    "{}",
+                    input
+                );
+            }
+        }
+
+        #[test]
+        fn junk() {
+            for &(input, pos, ch) in &[
+                ("0x+5", 2, '+'),
+                ("0x-5", 2, '-'),
+                ("+-5", 1, '-'),
+                ("--5", 1, '-'),
+                ("0xg", 2, 'g'),
+                ("0b102", 4, '2'),
+                ("0o8", 2, '8'),
+                ("12ab", 2, 'a'),
+                ("0d10", 1, 'd'),
+                (" 0x1", 0, ' '),
+                ("0x1 ", 3, ' '),
+            ] {
+                match parse(input).unwrap_err().kind() {
+                    ErrorKind::InvalidStringRepr { pos_char, .. } => {
+                        assert_eq!(*pos_char, Some((pos, ch)), "{}", input)
+                    }
+                    kind => panic!("unexpected error kind: {:?}", kind),
+                }
+            }
+            for &input in &["0x_ff", "0xff_", "-_1", "_0x1"] {
+                assert!(parse(input).is_err(), "{}", input);
+            }
+        }
+    }
+
+    mod from_decimal_digits {
+        use super::*;
+        use rand_xorshift::XorShiftRng;
+
+        /// Returns `len` pseudo random decimal digits.
+        fn random_decimal_digits(len: usize, rng: &mut XorShiftRng) -> Vec<u8> {
+            use rand::RngCore;
+            (0..len).map(|_| (rng.next_u64() % 10) as u8).collect()
+        }
+
+        fn assert_same_value(lhs: ApInt, rhs: ApInt) {
+            let width = lhs.width().to_usize().max(rhs.width().to_usize());
+            let width = BitWidth::from(width);
+            assert_eq!(lhs.into_zero_resize(width), rhs.into_zero_resize(width));
+        }
+
+        #[test]
+        fn differential() {
+            let radix = Radix::new(10).unwrap();
+            let mut rng = test_rng();
+            for len in (1..60).chain(vec![999, 1000, 5000, 20_000]) {
+                let digits = random_decimal_digits(len, &mut rng);
+                assert_same_value(
+                    ApInt::from_decimal_digits(&digits),
+                    ApInt::from_radix_digits(&digits, radix),
+                );
             }
         }
+
+        #[test]
+        fn extreme_digits() {
+            let radix = Radix::new(10).unwrap();
+            for &len in &[19, 20, 38, 3000] {
+                for &digit in &[0, 9] {
+                    let digits = vec![digit; len];
+                    assert_same_value(
+                        ApInt::from_decimal_digits(&digits),
+                        ApInt::from_radix_digits(&digits, radix),
+                    );
+                }
+                let mut digits = vec![0; len];
+                digits[0] = 1;
+                assert_same_value(
+                    ApInt::from_decimal_digits(&digits),
+                    ApInt::from_radix_digits(&digits, radix),
+                );
+            }
+        }
+    }
+
+    mod from_str {
+        use super::*;
+
+        #[test]
+        fn inferred_width() {
+            for &(input, width, value) in &[
+                ("0", 1, 0),
+                ("1", 1, 1),
+                ("+1", 1, 1),
+                ("2", 2, 2),
+                ("255", 8, 255),
+                ("256", 9, 256),
+                ("0_000_256", 9, 256),
+                ("18_446_744_073_709_551_615", 64, u128::from(u64::max_value())),
+                ("18_446_744_073_709_551_616", 65, 1 << 64),
+            ] {
+                let expected = ApInt::from_u128(value).into_truncate(width).unwrap();
+                assert_eq!(input.parse::<ApInt>(), Ok(expected));
+            }
+        }
+
+        #[test]
+        fn decimal_only() {
+            assert!("ff".parse::<ApInt>().is_err());
+            assert!("".parse::<ApInt>().is_err());
+            assert!("-1".parse::<ApInt>().is_err());
+        }
     }
 }
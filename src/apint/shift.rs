@@ -1,9 +1,13 @@
 use crate::{
     apint::utils::DataAccessMut,
     checks,
-    utils::try_forward_bin_mut_impl,
+    utils::{
+        forward_bin_mut_impl,
+        try_forward_bin_mut_impl,
+    },
     ApInt,
     Digit,
+    Error,
     Result,
     Width,
 };
@@ -79,40 +83,7 @@ impl ApInt {
     {
         let shift_amount = shift_amount.into();
         checks::verify_shift_amount(self, shift_amount)?;
-        match self.access_data_mut() {
-            DataAccessMut::Inl(digit) => {
-                *digit.repr_mut() <<= shift_amount.to_usize();
-            }
-            DataAccessMut::Ext(digits) => {
-                let digit_steps = shift_amount.digit_steps();
-                if digit_steps != 0 {
-                    let digits_len = digits.len();
-                    {
-                        use core::ptr;
-                        let src_ptr = digits.as_mut_ptr();
-                        unsafe {
-                            let dst_ptr = src_ptr.add(digit_steps);
-                            ptr::copy(src_ptr, dst_ptr, digits_len - digit_steps)
-                        }
-                    }
-                    digits
-                        .iter_mut()
-                        .take(digit_steps)
-                        .for_each(|d| *d = Digit::ZERO);
-                }
-                let bit_steps = shift_amount.bit_steps();
-                if bit_steps != 0 {
-                    let mut carry = 0;
-                    for elem in digits[digit_steps..].iter_mut() {
-                        let repr = elem.repr();
-                        let new_carry = repr >> (Digit::BITS - bit_steps);
-                        *elem = Digit((repr << bit_steps) | carry);
-                        carry = new_carry;
-                    }
-                }
-            }
-        }
-        self.clear_unused_bits();
+        self.shl_assign_lost(shift_amount);
         Ok(())
     }
 
@@ -146,32 +117,7 @@ impl ApInt {
     {
         let shift_amount = shift_amount.into();
         checks::verify_shift_amount(self, shift_amount)?;
-        match self.access_data_mut() {
-            DataAccessMut::Inl(digit) => {
-                *digit.repr_mut() >>= shift_amount.to_usize();
-            }
-            DataAccessMut::Ext(digits) => {
-                let digit_steps = shift_amount.digit_steps();
-                if digit_steps != 0 {
-                    digits.rotate_left(digit_steps);
-                    digits
-                        .iter_mut()
-                        .rev()
-                        .take(digit_steps)
-                        .for_each(|d| *d = Digit::ZERO);
-                }
-                let bit_steps = shift_amount.bit_steps();
-                if bit_steps > 0 {
-                    let mut borrow = 0;
-                    for elem in digits.iter_mut().rev() {
-                        let repr = elem.repr();
-                        let new_borrow = repr << (Digit::BITS - bit_steps);
-                        *elem = Digit((repr >> bit_steps) | borrow);
-                        borrow = new_borrow;
-                    }
-                }
-            }
-        }
+        self.shr_assign_lost(shift_amount, false);
         Ok(())
     }
 
@@ -209,84 +155,542 @@ impl ApInt {
     where
         S: Into<ShiftAmount>,
     {
-        if !self.msb() {
-            return self.wrapping_lshr_assign(shift_amount)
+        let shift_amount = shift_amount.into();
+        checks::verify_shift_amount(self, shift_amount)?;
+        let sign = self.msb();
+        self.shr_assign_lost(shift_amount, sign);
+        Ok(())
+    }
+
+    /// Arithmetically right-shifts this `ApInt` by the given `shift_amount`
+    /// bits and returns the result.
+    ///
+    /// This operation is inplace and will **not** allocate memory.
+    ///
+    /// # Note
+    ///
+    /// Arithmetic shifting copies the sign bit instead of filling up with
+    /// zeros.
+    ///
+    /// # Errors
+    ///
+    /// - If the given `shift_amount` is invalid for the bit width of this
+    ///   `ApInt`.
+    pub fn into_wrapping_ashr<S>(self, shift_amount: S) -> Result<ApInt>
+    where
+        S: Into<ShiftAmount>,
+    {
+        try_forward_bin_mut_impl(self, shift_amount, ApInt::wrapping_ashr_assign)
+    }
+
+    /// Shift this `ApInt` left by the given `shift_amount` bits.
+    ///
+    /// Unlike
+    /// [`wrapping_shl_assign`](struct.ApInt.html#method.wrapping_shl_assign)
+    /// this accepts any `shift_amount` and results in zero if `shift_amount` is
+    /// not less than the bit width of this `ApInt`.
+    ///
+    /// This operation is inplace and will **not** allocate memory.
+    pub fn unbounded_shl_assign<S>(&mut self, shift_amount: S)
+    where
+        S: Into<ShiftAmount>,
+    {
+        let shift_amount = shift_amount.into();
+        if self.width().is_valid_shift_amount(shift_amount) {
+            self.wrapping_shl_assign(shift_amount).expect(
+                "We have already checked that `shift_amount` is valid for the bit width \
+                 of `self`.",
+            )
+        } else {
+            self.unset_all()
+        }
+    }
+
+    /// Shift this `ApInt` left by the given `shift_amount` bits and returns the
+    /// result.
+    ///
+    /// This results in zero if `shift_amount` is not less than the bit width
+    /// of this `ApInt`.
+    ///
+    /// This operation is inplace and will **not** allocate memory.
+    pub fn into_unbounded_shl<S>(self, shift_amount: S) -> ApInt
+    where
+        S: Into<ShiftAmount>,
+    {
+        forward_bin_mut_impl(self, shift_amount, ApInt::unbounded_shl_assign)
+    }
+
+    /// Logically right-shifts this `ApInt` by the given `shift_amount` bits.
+    ///
+    /// Unlike
+    /// [`wrapping_lshr_assign`](struct.ApInt.html#method.wrapping_lshr_assign)
+    /// this accepts any `shift_amount` and results in zero if `shift_amount` is
+    /// not less than the bit width of this `ApInt`.
+    ///
+    /// This operation is inplace and will **not** allocate memory.
+    pub fn unbounded_lshr_assign<S>(&mut self, shift_amount: S)
+    where
+        S: Into<ShiftAmount>,
+    {
+        let shift_amount = shift_amount.into();
+        if self.width().is_valid_shift_amount(shift_amount) {
+            self.wrapping_lshr_assign(shift_amount).expect(
+                "We have already checked that `shift_amount` is valid for the bit width \
+                 of `self`.",
+            )
+        } else {
+            self.unset_all()
+        }
+    }
+
+    /// Logically right-shifts this `ApInt` by the given `shift_amount` bits
+    /// and returns the result.
+    ///
+    /// This results in zero if `shift_amount` is not less than the bit width
+    /// of this `ApInt`.
+    ///
+    /// This operation is inplace and will **not** allocate memory.
+    pub fn into_unbounded_lshr<S>(self, shift_amount: S) -> ApInt
+    where
+        S: Into<ShiftAmount>,
+    {
+        forward_bin_mut_impl(self, shift_amount, ApInt::unbounded_lshr_assign)
+    }
+
+    /// Arithmetically right-shifts this `ApInt` by the given `shift_amount`
+    /// bits.
+    ///
+    /// Unlike
+    /// [`wrapping_ashr_assign`](struct.ApInt.html#method.wrapping_ashr_assign)
+    /// this accepts any `shift_amount`. If `shift_amount` is not less than the
+    /// bit width of this `ApInt` all bits are filled with the sign bit.
+    ///
+    /// This operation is inplace and will **not** allocate memory.
+    pub fn unbounded_ashr_assign<S>(&mut self, shift_amount: S)
+    where
+        S: Into<ShiftAmount>,
+    {
+        let shift_amount = shift_amount.into();
+        if self.width().is_valid_shift_amount(shift_amount) {
+            self.wrapping_ashr_assign(shift_amount).expect(
+                "We have already checked that `shift_amount` is valid for the bit width \
+                 of `self`.",
+            )
+        } else if self.msb() {
+            self.set_all()
+        } else {
+            self.unset_all()
+        }
+    }
+
+    /// Arithmetically right-shifts this `ApInt` by the given `shift_amount`
+    /// bits and returns the result.
+    ///
+    /// If `shift_amount` is not less than the bit width of this `ApInt` all
+    /// bits are filled with the sign bit.
+    ///
+    /// This operation is inplace and will **not** allocate memory.
+    pub fn into_unbounded_ashr<S>(self, shift_amount: S) -> ApInt
+    where
+        S: Into<ShiftAmount>,
+    {
+        forward_bin_mut_impl(self, shift_amount, ApInt::unbounded_ashr_assign)
+    }
+
+    /// Shift this `ApInt` left by the given `shift_amount` bits and returns
+    /// `true` if any set bit was shifted out past the most significant bit.
+    ///
+    /// This operation is inplace and will **not** allocate memory.
+    ///
+    /// # Note
+    ///
+    /// The returned flag is collected from the shifted out bits by the shift
+    /// itself, `self` is never cloned.
+    ///
+    /// # Errors
+    ///
+    /// - If the given `shift_amount` is invalid for the bit width of this
+    ///   `ApInt`.
+    pub fn overflowing_shl_assign<S>(&mut self, shift_amount: S) -> Result<bool>
+    where
+        S: Into<ShiftAmount>,
+    {
+        let shift_amount = shift_amount.into();
+        checks::verify_shift_amount(self, shift_amount)?;
+        Ok(self.shl_assign_lost(shift_amount))
+    }
+
+    /// Logically right-shifts this `ApInt` by the given `shift_amount` bits
+    /// and returns `true` if any set bit was shifted out past the least
+    /// significant bit.
+    ///
+    /// This operation is inplace and will **not** allocate memory.
+    ///
+    /// # Note
+    ///
+    /// The returned flag is collected from the shifted out bits by the shift
+    /// itself, `self` is never cloned.
+    ///
+    /// # Errors
+    ///
+    /// - If the given `shift_amount` is invalid for the bit width of this
+    ///   `ApInt`.
+    pub fn overflowing_lshr_assign<S>(&mut self, shift_amount: S) -> Result<bool>
+    where
+        S: Into<ShiftAmount>,
+    {
+        let shift_amount = shift_amount.into();
+        checks::verify_shift_amount(self, shift_amount)?;
+        Ok(self.shr_assign_lost(shift_amount, false))
+    }
+
+    /// Logically right-shifts this `ApInt` by the given `shift_amount` bits
+    /// and returns the sticky bit, i.e. `true` if any of the discarded bits
+    /// was set.
+    ///
+    /// Unlike `overflowing_lshr_assign` this accepts any `shift_amount`. If
+    /// `shift_amount` is not less than the bit width of this `ApInt` the
+    /// result is zero and the sticky bit is `true` if `self` was not zero.
+    ///
+    /// The sticky bit is collected from the discarded bits by the shift
+    /// itself. This operation is inplace and will **not** allocate memory.
+    pub fn lshr_sticky<S>(&mut self, shift_amount: S) -> bool
+    where
+        S: Into<ShiftAmount>,
+    {
+        let shift_amount = shift_amount.into();
+        if self.width().is_valid_shift_amount(shift_amount) {
+            self.shr_assign_lost(shift_amount, false)
+        } else {
+            let sticky = !self.is_zero();
+            self.unset_all();
+            sticky
         }
+    }
+
+    /// Arithmetically right-shifts this `ApInt` by the given `shift_amount`
+    /// bits and returns `true` if any bit that differs from the sign bit was
+    /// shifted out past the least significant bit.
+    ///
+    /// This operation is inplace and will **not** allocate memory.
+    ///
+    /// # Note
+    ///
+    /// The returned flag is collected from the shifted out bits by the shift
+    /// itself, `self` is never cloned.
+    ///
+    /// # Errors
+    ///
+    /// - If the given `shift_amount` is invalid for the bit width of this
+    ///   `ApInt`.
+    pub fn overflowing_ashr_assign<S>(&mut self, shift_amount: S) -> Result<bool>
+    where
+        S: Into<ShiftAmount>,
+    {
         let shift_amount = shift_amount.into();
         checks::verify_shift_amount(self, shift_amount)?;
-        let shift_amount = shift_amount.to_usize();
-        // prevents shift overflow below
-        if shift_amount == 0 {
-            return Ok(())
-        }
-        let width = self.width();
-        let width_bits = width.to_usize() % Digit::BITS;
-        let (digits, bits) = (shift_amount / Digit::BITS, shift_amount % Digit::BITS);
-        let uns = Digit::BITS - bits;
+        let sign = self.msb();
+        Ok(self.shr_assign_lost(shift_amount, sign))
+    }
+
+    /// Shifts this `ApInt` left by the given valid `shift_amount` bits and
+    /// returns `true` if any set bit was shifted out past the most significant
+    /// bit.
+    ///
+    /// The shifted out bits are collected by the shift itself.
+    #[inline]
+    fn shl_assign_lost(&mut self, shift_amount: ShiftAmount) -> bool {
+        let mut lost = Digit::ZERO;
         match self.access_data_mut() {
-            DataAccessMut::Inl(x) => {
-                *x = (*x >> bits) | (Digit::ONES << (width.to_usize() - bits));
-            }
-            DataAccessMut::Ext(x) => {
-                if width_bits != 0 {
-                    x[x.len() - 1].sign_extend_from(width_bits).unwrap();
+            DataAccessMut::Inl(digit) => {
+                let shift = shift_amount.to_usize();
+                if shift != 0 {
+                    lost = *digit >> (Digit::BITS - shift);
+                    *digit.repr_mut() <<= shift;
                 }
-                let diff = x.len() - digits;
-                if digits == 0 {
-                    // subdigit shift
-                    for i in 0..(x.len() - 1) {
-                        x[i] = (x[i] >> bits) | (x[i + 1] << uns);
-                    }
-                    x[x.len() - 1] = (x[x.len() - 1] >> bits) | (Digit::ONES << uns);
-                } else if bits == 0 {
-                    // digit shift
-                    for i in digits..x.len() {
-                        x[i - digits] = x[i];
+            }
+            DataAccessMut::Ext(digits) => {
+                let digit_steps = shift_amount.digit_steps();
+                if digit_steps != 0 {
+                    digits.rotate_right(digit_steps);
+                    for digit in digits.iter_mut().take(digit_steps) {
+                        lost |= *digit;
+                        *digit = Digit::ZERO;
                     }
-                    for i in 0..digits {
-                        x[i + diff].set_all();
+                }
+                let bit_steps = shift_amount.bit_steps();
+                if bit_steps != 0 {
+                    let mut carry = 0;
+                    for elem in digits[digit_steps..].iter_mut() {
+                        let repr = elem.repr();
+                        let new_carry = repr >> (Digit::BITS - bit_steps);
+                        *elem = Digit((repr << bit_steps) | carry);
+                        carry = new_carry;
                     }
-                } else {
-                    // digit and subdigit shift
-                    for i in digits..(x.len() - 1) {
-                        x[i - digits] = (x[i] >> bits) | (x[i + 1] << uns);
+                    lost |= Digit(carry);
+                }
+            }
+        }
+        if let Some(bits) = self.width().excess_bits() {
+            lost |= self.most_significant_digit() >> bits;
+        }
+        self.clear_unused_bits();
+        !lost.is_zero()
+    }
+
+    /// Right-shifts this `ApInt` by the given valid `shift_amount` bits while
+    /// shifting in `fill` bits and returns `true` if any bit that differs from
+    /// `fill` was shifted out past the least significant bit.
+    ///
+    /// This is a logical shift for a `fill` of `false` and an arithmetic shift
+    /// for a `fill` equal to the sign bit. The shifted out bits are collected
+    /// by the shift itself.
+    #[inline]
+    fn shr_assign_lost(&mut self, shift_amount: ShiftAmount, fill: bool) -> bool {
+        let fill = if fill { Digit::ONES } else { Digit::ZERO };
+        if let Some(bits) = self.width().excess_bits() {
+            // the excess bits are shifted in from the most significant digit
+            *self.most_significant_digit_mut() |= fill << bits;
+        }
+        let mut lost = Digit::ZERO;
+        match self.access_data_mut() {
+            DataAccessMut::Inl(digit) => {
+                let shift = shift_amount.to_usize();
+                if shift != 0 {
+                    lost = (*digit ^ fill) << (Digit::BITS - shift);
+                    *digit = (*digit >> shift) | (fill << (Digit::BITS - shift));
+                }
+            }
+            DataAccessMut::Ext(digits) => {
+                let digit_steps = shift_amount.digit_steps();
+                if digit_steps != 0 {
+                    digits.rotate_left(digit_steps);
+                    for digit in digits.iter_mut().rev().take(digit_steps) {
+                        lost |= *digit ^ fill;
+                        *digit = fill;
                     }
-                    x[diff - 1] = (x[x.len() - 1] >> bits) | (Digit::ONES << uns);
-                    for i in 0..digits {
-                        x[i + diff].set_all();
+                }
+                let bit_steps = shift_amount.bit_steps();
+                if bit_steps != 0 {
+                    let fill_borrow = fill.repr() << (Digit::BITS - bit_steps);
+                    let mut borrow = fill_borrow;
+                    for elem in digits.iter_mut().rev() {
+                        let repr = elem.repr();
+                        let new_borrow = repr << (Digit::BITS - bit_steps);
+                        *elem = Digit((repr >> bit_steps) | borrow);
+                        borrow = new_borrow;
                     }
+                    lost |= Digit(borrow ^ fill_borrow);
                 }
             }
         }
         self.clear_unused_bits();
+        !lost.is_zero()
+    }
+
+    /// Funnel shifts `hi` and `lo` left by the given `shift_amount` bits.
+    ///
+    /// Conceptually `hi` and `lo` are concatenated to a value of double their
+    /// bit width with `hi` as its most significant half. This value is then
+    /// shifted left and its most significant half is returned. The
+    /// `shift_amount` is reduced modulo the bit width of the operands.
+    ///
+    /// **Note:** This allocates memory for the result but never for the
+    /// concatenation of `hi` and `lo`.
+    ///
+    /// # Errors
+    ///
+    /// - If `hi` and `lo` have unmatching bit widths.
+    pub fn funnel_shl<S>(hi: &ApInt, lo: &ApInt, shift_amount: S) -> Result<ApInt>
+    where
+        S: Into<ShiftAmount>,
+    {
+        let width = hi.width().to_usize();
+        let shift_amount = shift_amount.into().to_usize() % width;
+        ApInt::funnel_extract(hi, lo, width - shift_amount)
+    }
+
+    /// Funnel shifts `hi` and `lo` right by the given `shift_amount` bits.
+    ///
+    /// Conceptually `hi` and `lo` are concatenated to a value of double their
+    /// bit width with `hi` as its most significant half. This value is then
+    /// logically shifted right and its least significant half is returned. The
+    /// `shift_amount` is reduced modulo the bit width of the operands.
+    ///
+    /// **Note:** This allocates memory for the result but never for the
+    /// concatenation of `hi` and `lo`.
+    ///
+    /// # Errors
+    ///
+    /// - If `hi` and `lo` have unmatching bit widths.
+    pub fn funnel_shr<S>(hi: &ApInt, lo: &ApInt, shift_amount: S) -> Result<ApInt>
+    where
+        S: Into<ShiftAmount>,
+    {
+        let width = hi.width().to_usize();
+        let shift_amount = shift_amount.into().to_usize() % width;
+        ApInt::funnel_extract(hi, lo, shift_amount)
+    }
+
+    /// Returns the bits of the concatenation of `hi` and `lo` starting at bit
+    /// position `offset` as new `ApInt` with the bit width of the operands.
+    ///
+    /// # Errors
+    ///
+    /// - If `hi` and `lo` have unmatching bit widths.
+    fn funnel_extract(hi: &ApInt, lo: &ApInt, offset: usize) -> Result<ApInt> {
+        if hi.width() != lo.width() {
+            return Error::unmatching_bitwidths(hi.width(), lo.width()).into()
+        }
+        let width = hi.width();
+        let (hi, lo) = (hi.as_digit_slice(), lo.as_digit_slice());
+        // Reads the `Digit::BITS` bits starting at `pos` of `digits` while
+        // treating out of bounds bits as zero.
+        let read = |digits: &[Digit], pos: usize| -> Digit {
+            let (index, bits) = (pos / Digit::BITS, pos % Digit::BITS);
+            let digit = |i: usize| digits.get(i).cloned().unwrap_or(Digit::ZERO);
+            if bits == 0 {
+                digit(index)
+            } else {
+                (digit(index) >> bits) | (digit(index + 1) << (Digit::BITS - bits))
+            }
+        };
+        let len = width.to_usize();
+        let digits = (0..width.required_digits()).map(|i| {
+            let pos = offset + i * Digit::BITS;
+            if pos >= len {
+                read(hi, pos - len)
+            } else if len - pos < Digit::BITS {
+                // The excess bits of `lo` are zero so there is no need to
+                // mask them before merging in the bits of `hi`.
+                read(lo, pos) | (read(hi, 0) << (len - pos))
+            } else {
+                read(lo, pos)
+            }
+        });
+        ApInt::from_iter(digits)?.into_truncate(width)
+    }
+
+    /// Rotates this `ApInt` left by `count` 64-bit limb positions.
+    ///
+    /// This is equivalent to a left rotation by `count * 64` bits for bit
+    /// widths that are a multiple of 64 but only moves whole limbs around
+    /// instead of shifting individual bits.
+    ///
+    /// The limbs are always 64 bits wide, independent of the internal digit
+    /// representation.
+    ///
+    /// This operation is inplace and will **not** allocate memory.
+    ///
+    /// # Note
+    ///
+    /// For bit widths that are not a multiple of 64 the bits of the limb
+    /// rotated into the most significant position that exceed the bit width
+    /// are truncated.
+    ///
+    /// # Errors
+    ///
+    /// - If `count` is not less than the number of 64-bit limbs of this
+    ///   `ApInt`.
+    pub fn rotate_digit_left(&mut self, count: usize) -> Result<()> {
+        checks::verify_digit_rotation(self, count)?;
+        self.rotate_limbs_left(count);
         Ok(())
     }
 
-    /// Arithmetically right-shifts this `ApInt` by the given `shift_amount`
-    /// bits and returns the result.
+    /// Rotates this `ApInt` right by `count` 64-bit limb positions.
+    ///
+    /// This is equivalent to a right rotation by `count * 64` bits for bit
+    /// widths that are a multiple of 64 but only moves whole limbs around
+    /// instead of shifting individual bits.
+    ///
+    /// The limbs are always 64 bits wide, independent of the internal digit
+    /// representation.
     ///
     /// This operation is inplace and will **not** allocate memory.
     ///
     /// # Note
     ///
-    /// Arithmetic shifting copies the sign bit instead of filling up with
-    /// zeros.
+    /// For bit widths that are not a multiple of 64 the bits of the limb
+    /// rotated into the most significant position that exceed the bit width
+    /// are truncated.
     ///
     /// # Errors
     ///
-    /// - If the given `shift_amount` is invalid for the bit width of this
+    /// - If `count` is not less than the number of 64-bit limbs of this
     ///   `ApInt`.
-    pub fn into_wrapping_ashr<S>(self, shift_amount: S) -> Result<ApInt>
-    where
-        S: Into<ShiftAmount>,
-    {
-        try_forward_bin_mut_impl(self, shift_amount, ApInt::wrapping_ashr_assign)
+    pub fn rotate_digit_right(&mut self, count: usize) -> Result<()> {
+        checks::verify_digit_rotation(self, count)?;
+        if count != 0 {
+            let limbs = self.width().required_u64s();
+            self.rotate_limbs_left(limbs - count);
+        }
+        Ok(())
+    }
+
+    /// Rotates the 64-bit limbs of `self` left by `count` positions.
+    ///
+    /// The digits are treated as if they were zero-padded up to a whole
+    /// number of limbs, so that the rotation does not depend on the
+    /// internal digit size.
+    fn rotate_limbs_left(&mut self, count: usize) {
+        let padded_len = self.width().required_u64s() * Digit::PER_U64;
+        let shift = count * Digit::PER_U64;
+        let digits = self.as_digit_slice_mut();
+        let padding = padded_len - digits.len();
+        digits.rotate_right(shift);
+        if padding != 0 && shift != 0 {
+            // the wrapped around part has to consist of the most significant
+            // `shift - padding` digits followed by the zero-padding
+            digits[..shift].rotate_left(padding);
+            for digit in &mut digits[(shift - padding)..shift] {
+                *digit = Digit::ZERO;
+            }
+        }
+        self.clear_unused_bits();
+    }
+
+    /// Reverses the order of the 64-bit limbs of this `ApInt` so that the
+    /// least significant limb becomes the most significant limb and vice
+    /// versa.
+    ///
+    /// This is useful to convert limbs that were produced in big-endian
+    /// limb order into the little-endian limb order of `ApInt`.
+    ///
+    /// The limbs are always 64 bits wide, independent of the internal digit
+    /// representation.
+    ///
+    /// This operation is inplace and will **not** allocate memory.
+    ///
+    /// # Note
+    ///
+    /// For bit widths that are not a multiple of 64 the bits of the limb
+    /// moved into the most significant position that exceed the bit width
+    /// are truncated.
+    pub fn reverse_digit_order(&mut self) {
+        let limbs = self.width().required_u64s();
+        let digits = self.as_digit_slice_mut();
+        for lo in 0..(limbs / 2) {
+            let hi = limbs - 1 - lo;
+            for offset in 0..Digit::PER_U64 {
+                let lo_pos = lo * Digit::PER_U64 + offset;
+                let hi_pos = hi * Digit::PER_U64 + offset;
+                if hi_pos < digits.len() {
+                    digits.swap(lo_pos, hi_pos);
+                } else {
+                    // the digit is part of the implicit zero-padding
+                    digits[lo_pos] = Digit::ZERO;
+                }
+            }
+        }
+        self.clear_unused_bits();
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::apint::test_utils::test_rng;
 
     fn test_reprs_w64() -> impl Iterator<Item = u64> {
         vec![
@@ -364,8 +768,8 @@ mod tests {
                 assert_eq!(bit_steps, 36);
                 let result = ApInt::from(input).into_wrapping_shl(shamt).unwrap();
                 let expected: [u64; 4] = [
-                    (d1 << bit_steps) | (d2 >> (Digit::BITS - bit_steps)),
-                    (d2 << bit_steps) | (d3 >> (Digit::BITS - bit_steps)),
+                    (d1 << bit_steps) | (d2 >> (64 - bit_steps)),
+                    (d2 << bit_steps) | (d3 >> (64 - bit_steps)),
                     (d3 << bit_steps),
                     0,
                 ];
@@ -380,7 +784,7 @@ mod tests {
                 assert_eq!(bit_steps, 22);
                 let result = ApInt::from(input).into_wrapping_shl(shamt).unwrap();
                 let expected: [u64; 4] = [
-                    (d2 << bit_steps) | (d3 >> (Digit::BITS - bit_steps)),
+                    (d2 << bit_steps) | (d3 >> (64 - bit_steps)),
                     (d3 << bit_steps),
                     0,
                     0,
@@ -587,4 +991,442 @@ mod tests {
             }
         }
     }
+
+    mod unbounded {
+        use super::*;
+
+        #[test]
+        fn in_range_equals_wrapping() {
+            for apint in test_apints_w128() {
+                for shamt in 0..128 {
+                    assert_eq!(
+                        apint.clone().into_unbounded_shl(shamt),
+                        apint.clone().into_wrapping_shl(shamt).unwrap()
+                    );
+                    assert_eq!(
+                        apint.clone().into_unbounded_lshr(shamt),
+                        apint.clone().into_wrapping_lshr(shamt).unwrap()
+                    );
+                    assert_eq!(
+                        apint.clone().into_unbounded_ashr(shamt),
+                        apint.clone().into_wrapping_ashr(shamt).unwrap()
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn out_of_range() {
+            use crate::BitWidth;
+            for apint in test_apints_w64().chain(test_apints_w128()) {
+                let width = apint.width();
+                let len = width.to_usize();
+                for &shamt in &[len, len + 1, 2 * len, 1000, usize::MAX] {
+                    assert_eq!(
+                        apint.clone().into_unbounded_shl(shamt),
+                        ApInt::zero(width)
+                    );
+                    assert_eq!(
+                        apint.clone().into_unbounded_lshr(shamt),
+                        ApInt::zero(width)
+                    );
+                    let expected = if apint.msb() {
+                        ApInt::all_set(width)
+                    } else {
+                        ApInt::zero(width)
+                    };
+                    let mut ashr = apint.clone();
+                    ashr.unbounded_ashr_assign(shamt);
+                    assert_eq!(ashr, expected);
+                }
+            }
+            let width = BitWidth::new(100).unwrap();
+            let negative = ApInt::signed_min_value(width).into_wrapping_inc();
+            assert_eq!(
+                negative.clone().into_unbounded_ashr(100),
+                ApInt::all_set(width)
+            );
+            assert_eq!(
+                negative.clone().into_unbounded_ashr(99),
+                ApInt::all_set(width)
+            );
+            assert_eq!(negative.into_unbounded_lshr(100), ApInt::zero(width));
+        }
+    }
+
+    mod overflowing {
+        use super::*;
+        use crate::BitWidth;
+
+        #[test]
+        fn small() {
+            let mut apint = ApInt::from(0b0110_0000_u8);
+            assert_eq!(apint.overflowing_shl_assign(1), Ok(false));
+            assert_eq!(apint, ApInt::from(0b1100_0000_u8));
+            assert_eq!(apint.overflowing_shl_assign(1), Ok(true));
+            assert_eq!(apint, ApInt::from(0b1000_0000_u8));
+            let mut apint = ApInt::from(0b0000_0110_u8);
+            assert_eq!(apint.overflowing_lshr_assign(1), Ok(false));
+            assert_eq!(apint.overflowing_lshr_assign(2), Ok(true));
+            assert_eq!(apint, ApInt::from(0_u8));
+            let mut apint = ApInt::from(-5_i8);
+            assert_eq!(apint.overflowing_ashr_assign(1), Ok(false));
+            assert_eq!(apint, ApInt::from(-3_i8));
+            assert_eq!(apint.overflowing_ashr_assign(2), Ok(true));
+            assert_eq!(apint, ApInt::from(-1_i8));
+            assert_eq!(apint.overflowing_ashr_assign(7), Ok(false));
+            let mut apint = ApInt::from(8_i8);
+            assert_eq!(apint.overflowing_ashr_assign(3), Ok(false));
+            assert_eq!(apint.overflowing_ashr_assign(1), Ok(true));
+            let mut apint = ApInt::from(1_u8);
+            assert!(apint.overflowing_shl_assign(8).is_err());
+            assert!(apint.overflowing_lshr_assign(8).is_err());
+            assert!(apint.overflowing_ashr_assign(8).is_err());
+        }
+
+        #[test]
+        fn zero_shift() {
+            for apint in test_apints_w64().chain(test_apints_w128()) {
+                let mut x = apint.clone();
+                assert_eq!(x.overflowing_shl_assign(0), Ok(false));
+                assert_eq!(x.overflowing_lshr_assign(0), Ok(false));
+                assert_eq!(x.overflowing_ashr_assign(0), Ok(false));
+                assert_eq!(x, apint);
+            }
+        }
+
+        #[test]
+        fn excess_bits() {
+            let width = BitWidth::new(100).unwrap();
+            let mut apint = ApInt::signed_max_value(width);
+            assert_eq!(apint.overflowing_shl_assign(1), Ok(false));
+            assert_eq!(apint.overflowing_shl_assign(1), Ok(true));
+            let mut apint = ApInt::all_set(width);
+            assert_eq!(apint.overflowing_ashr_assign(99), Ok(false));
+            assert!(apint.is_all_set());
+            let mut apint = ApInt::signed_min_value(width);
+            assert_eq!(apint.overflowing_ashr_assign(99), Ok(true));
+            assert!(apint.is_all_set());
+        }
+
+        #[test]
+        fn random() {
+            let mut rng = test_rng();
+            for &width in &[1, 7, 64, 65, 100, 128, 200] {
+                let width = BitWidth::new(width).unwrap();
+                for _ in 0..8 {
+                    let apint = ApInt::random_with_width_using(width, &mut rng);
+                    for shamt in 0..width.to_usize() {
+                        let mut shl = apint.clone();
+                        let lost = shl.overflowing_shl_assign(shamt).unwrap();
+                        let back = shl.clone().into_wrapping_lshr(shamt).unwrap();
+                        assert_eq!(lost, back != apint);
+
+                        let mut lshr = apint.clone();
+                        let lost = lshr.overflowing_lshr_assign(shamt).unwrap();
+                        let back = lshr.into_wrapping_shl(shamt).unwrap();
+                        assert_eq!(lost, back != apint);
+
+                        let mut ashr = apint.clone();
+                        let lost = ashr.overflowing_ashr_assign(shamt).unwrap();
+                        let expected = (0..shamt)
+                            .any(|pos| apint.get_bit_at(pos).unwrap() != apint.msb());
+                        assert_eq!(lost, expected);
+                    }
+                }
+            }
+        }
+    }
+
+    mod lshr_sticky {
+        use super::*;
+
+        #[test]
+        fn small() {
+            let mut apint = ApInt::from(0b1011_0100_u8);
+            assert!(!apint.lshr_sticky(2));
+            assert_eq!(apint, ApInt::from(0b0010_1101_u8));
+            assert!(apint.lshr_sticky(1));
+            assert_eq!(apint, ApInt::from(0b0001_0110_u8));
+            assert!(!apint.lshr_sticky(0));
+        }
+
+        #[test]
+        fn large() {
+            let mut apint = ApInt::from([1_u64, 0, 1 << 63]);
+            assert!(!apint.lshr_sticky(63));
+            assert_eq!(apint, ApInt::from([0_u64, 2, 1]));
+            assert!(apint.lshr_sticky(65));
+            assert_eq!(apint, ApInt::from([0_u64, 0, 1]));
+        }
+
+        #[test]
+        fn out_of_range() {
+            for apint in test_apints_w64().chain(test_apints_w128()) {
+                let len = apint.width().to_usize();
+                for &shamt in &[len, len + 1, 10 * len, usize::MAX] {
+                    let mut x = apint.clone();
+                    assert_eq!(x.lshr_sticky(shamt), !apint.is_zero());
+                    assert!(x.is_zero());
+                }
+            }
+        }
+    }
+
+    mod funnel {
+        use super::*;
+        use crate::BitWidth;
+
+        /// Returns the bit at `pos` of the concatenation of `hi` and `lo`.
+        fn concat_bit(hi: &ApInt, lo: &ApInt, pos: usize) -> bool {
+            let len = hi.width().to_usize();
+            if pos >= len {
+                hi.get_bit_at(pos - len).unwrap()
+            } else {
+                lo.get_bit_at(pos).unwrap()
+            }
+        }
+
+        /// Returns the bits of the concatenation of `hi` and `lo` starting
+        /// at `offset` by extracting single bits.
+        fn naive_extract(hi: &ApInt, lo: &ApInt, offset: usize) -> ApInt {
+            let mut result = ApInt::zero(hi.width());
+            for pos in 0..hi.width().to_usize() {
+                if concat_bit(hi, lo, pos + offset) {
+                    result.set_bit_at(pos).unwrap();
+                }
+            }
+            result
+        }
+
+        fn naive_fshl(hi: &ApInt, lo: &ApInt, shamt: usize) -> ApInt {
+            let len = hi.width().to_usize();
+            naive_extract(hi, lo, len - shamt % len)
+        }
+
+        fn naive_fshr(hi: &ApInt, lo: &ApInt, shamt: usize) -> ApInt {
+            let len = hi.width().to_usize();
+            naive_extract(hi, lo, shamt % len)
+        }
+
+        #[test]
+        fn small() {
+            let (hi, lo) = (ApInt::from(0xAB_u8), ApInt::from(0xCD_u8));
+            assert_eq!(
+                ApInt::funnel_shl(&hi, &lo, 4).unwrap(),
+                ApInt::from(0xBC_u8)
+            );
+            assert_eq!(
+                ApInt::funnel_shr(&hi, &lo, 4).unwrap(),
+                ApInt::from(0xBC_u8)
+            );
+            assert_eq!(ApInt::funnel_shl(&hi, &lo, 0).unwrap(), hi);
+            assert_eq!(ApInt::funnel_shr(&hi, &lo, 0).unwrap(), lo);
+            assert_eq!(
+                ApInt::funnel_shl(&hi, &lo, 12).unwrap(),
+                ApInt::from(0xBC_u8)
+            );
+            // funnel shifting a value with itself is a rotation
+            assert_eq!(
+                ApInt::funnel_shl(&hi, &hi, 1).unwrap(),
+                ApInt::from(0x57_u8)
+            );
+            assert_eq!(
+                ApInt::funnel_shr(&hi, &hi, 1).unwrap(),
+                ApInt::from(0xD5_u8)
+            );
+        }
+
+        #[test]
+        fn w96() {
+            let width = BitWidth::new(96).unwrap();
+            let hi = ApInt::from([0x0123_4567_u64, 0x89AB_CDEF_FEDC_BA98])
+                .into_truncate(width)
+                .unwrap();
+            let lo = ApInt::from([0x7654_3210_u64, 0x0F1E_2D3C_4B5A_6978])
+                .into_truncate(width)
+                .unwrap();
+            assert_eq!(ApInt::funnel_shl(&hi, &lo, 0).unwrap(), hi);
+            assert_eq!(ApInt::funnel_shr(&hi, &lo, 0).unwrap(), lo);
+            assert_eq!(ApInt::funnel_shl(&hi, &lo, 96).unwrap(), hi);
+            assert_eq!(ApInt::funnel_shr(&hi, &lo, 96).unwrap(), lo);
+            for &shamt in &[1, 31, 32, 33, 63, 64, 65, 95, 96, 97, 191, 1000] {
+                assert_eq!(
+                    ApInt::funnel_shl(&hi, &lo, shamt).unwrap(),
+                    naive_fshl(&hi, &lo, shamt)
+                );
+                assert_eq!(
+                    ApInt::funnel_shr(&hi, &lo, shamt).unwrap(),
+                    naive_fshr(&hi, &lo, shamt)
+                );
+            }
+            // shifting by `width - 1` keeps only the least significant bit of `hi`
+            // for `fshl` and only the most significant bit of `lo` for `fshr`
+            let expected = lo
+                .clone()
+                .into_wrapping_lshr(1)
+                .unwrap()
+                .into_bitor(&hi.clone().into_wrapping_shl(95).unwrap())
+                .unwrap();
+            assert_eq!(ApInt::funnel_shl(&hi, &lo, 95).unwrap(), expected);
+            let expected = hi
+                .clone()
+                .into_wrapping_shl(1)
+                .unwrap()
+                .into_bitor(&lo.clone().into_wrapping_lshr(95).unwrap())
+                .unwrap();
+            assert_eq!(ApInt::funnel_shr(&hi, &lo, 95).unwrap(), expected);
+        }
+
+        #[test]
+        fn random() {
+            let mut rng = test_rng();
+            for &width in &[1, 7, 64, 65, 96, 128, 200] {
+                let width = BitWidth::new(width).unwrap();
+                let hi = ApInt::random_with_width_using(width, &mut rng);
+                let lo = ApInt::random_with_width_using(width, &mut rng);
+                for shamt in 0..(2 * width.to_usize()) {
+                    assert_eq!(
+                        ApInt::funnel_shl(&hi, &lo, shamt).unwrap(),
+                        naive_fshl(&hi, &lo, shamt)
+                    );
+                    assert_eq!(
+                        ApInt::funnel_shr(&hi, &lo, shamt).unwrap(),
+                        naive_fshr(&hi, &lo, shamt)
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn errors() {
+            let (a, b) = (ApInt::from(1_u8), ApInt::from(1_u16));
+            assert!(ApInt::funnel_shl(&a, &b, 1).is_err());
+            assert!(ApInt::funnel_shr(&a, &b, 1).is_err());
+        }
+    }
+
+    mod rotate_digit {
+        use super::*;
+
+        #[test]
+        fn small() {
+            for apint in test_apints_w64() {
+                let mut left = apint.clone();
+                left.rotate_digit_left(0).unwrap();
+                assert_eq!(left, apint);
+                let mut right = apint.clone();
+                right.rotate_digit_right(0).unwrap();
+                assert_eq!(right, apint);
+            }
+        }
+
+        #[test]
+        fn large() {
+            for repr in test_reprs_w128() {
+                let mut left = ApInt::from_u128(repr);
+                left.rotate_digit_left(1).unwrap();
+                assert_eq!(left, ApInt::from_u128(repr.rotate_left(64)));
+                let mut right = ApInt::from_u128(repr);
+                right.rotate_digit_right(1).unwrap();
+                assert_eq!(right, ApInt::from_u128(repr.rotate_right(64)));
+            }
+        }
+
+        #[test]
+        fn xtra_large() {
+            let input = ApInt::from([1_u64, 2, 3, 4, 5]);
+            let mut left = input.clone();
+            left.rotate_digit_left(2).unwrap();
+            assert_eq!(left, ApInt::from([3_u64, 4, 5, 1, 2]));
+            let mut right = input.clone();
+            right.rotate_digit_right(2).unwrap();
+            assert_eq!(right, ApInt::from([4_u64, 5, 1, 2, 3]));
+            for count in 0..5 {
+                let mut apint = input.clone();
+                apint.rotate_digit_left(count).unwrap();
+                apint.rotate_digit_right(count).unwrap();
+                assert_eq!(apint, input);
+            }
+        }
+
+        #[test]
+        fn truncates_excess_bits() {
+            let mut apint = ApInt::from([0_u64, 1 << 63]).into_truncate(96).unwrap();
+            assert!(!apint.is_zero());
+            apint.rotate_digit_left(1).unwrap();
+            assert!(apint.is_zero());
+        }
+
+        #[test]
+        fn partial_limb() {
+            let input = ApInt::from([0xAB_u64, 0x0123_4567_89AB_CDEF, 0x42])
+                .into_truncate(136)
+                .unwrap();
+            let mut left = input.clone();
+            left.rotate_digit_left(1).unwrap();
+            let expected = ApInt::from([0xEF_u64, 0x42, 0xAB])
+                .into_truncate(136)
+                .unwrap();
+            assert_eq!(left, expected);
+            let mut right = input;
+            right.rotate_digit_right(1).unwrap();
+            let expected = ApInt::from([0x42_u64, 0xAB, 0x0123_4567_89AB_CDEF])
+                .into_truncate(136)
+                .unwrap();
+            assert_eq!(right, expected);
+        }
+
+        #[test]
+        fn fail() {
+            for apint in test_apints_w64() {
+                let mut apint = apint.into_truncate(64).unwrap();
+                assert!(apint.rotate_digit_left(1).is_err());
+                assert!(apint.rotate_digit_right(1).is_err());
+            }
+            for apint in test_apints_w128() {
+                let mut apint = apint.into_truncate(128).unwrap();
+                assert!(apint.rotate_digit_left(2).is_err());
+                assert!(apint.rotate_digit_right(2).is_err());
+            }
+        }
+    }
+
+    mod reverse_digit_order {
+        use super::*;
+
+        #[test]
+        fn small() {
+            for apint in test_apints_w64() {
+                let mut reversed = apint.clone();
+                reversed.reverse_digit_order();
+                assert_eq!(reversed, apint);
+            }
+        }
+
+        #[test]
+        fn large() {
+            let mut apint = ApInt::from([1_u64, 2, 3, 4, 5]);
+            apint.reverse_digit_order();
+            assert_eq!(apint, ApInt::from([5_u64, 4, 3, 2, 1]));
+            apint.reverse_digit_order();
+            assert_eq!(apint, ApInt::from([1_u64, 2, 3, 4, 5]));
+        }
+
+        #[test]
+        fn truncates_excess_bits() {
+            let mut apint = ApInt::from([1_u64, u64::MAX]).into_truncate(96).unwrap();
+            apint.reverse_digit_order();
+            let expected = ApInt::from([u64::MAX, 1]).into_truncate(96).unwrap();
+            assert_eq!(apint, expected);
+            let mut apint = ApInt::from([0xAB_u64, 2, 0x0123])
+                .into_truncate(136)
+                .unwrap();
+            apint.reverse_digit_order();
+            let expected = ApInt::from([0x23_u64, 2, 0xAB])
+                .into_truncate(136)
+                .unwrap();
+            assert_eq!(apint, expected);
+        }
+    }
 }
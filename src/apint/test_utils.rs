@@ -0,0 +1,10 @@
+//! Helpers shared by the tests of the `ApInt` modules.
+
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+/// Returns a random number generator with a fixed seed so that randomized
+/// tests are reproducible.
+pub(crate) fn test_rng() -> XorShiftRng {
+    XorShiftRng::from_seed(<XorShiftRng as SeedableRng>::Seed::default())
+}
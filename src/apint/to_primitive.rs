@@ -7,6 +7,11 @@ use crate::{
     Width,
 };
 
+use core::{
+    convert::TryFrom,
+    mem,
+};
+
 /// Represents a primitive data type.
 ///
 /// Used by the `to_primitive` module for an improved
@@ -35,29 +40,13 @@ pub enum PrimitiveTy {
     I128,
     /// Represents Rust's `u128`.
     U128,
+    /// Represents Rust's `isize`.
+    Isize,
+    /// Represents Rust's `usize`.
+    Usize,
 }
 
 impl PrimitiveTy {
-    /// Returns `true` if the given `value` is a valid representation
-    /// for this `PrimitiveTy`.
-    ///
-    /// # Example
-    ///
-    /// If this `PrimitiveTy` was `U8` then `200` is a valid representation
-    /// but `256` is not since it is not representable by `8` bits. Only
-    /// unsigned values are considered in this regard.
-    #[inline]
-    pub(crate) fn is_valid_repr(self, value: u64) -> bool {
-        use self::PrimitiveTy::*;
-        match self {
-            Bool => (value == 0) || (value == 1),
-            I8 | U8 => value < (0x1 << 8),
-            I16 | U16 => value < (0x1 << 16),
-            I32 | U32 => value < (0x1 << 32),
-            I64 | U64 | I128 | U128 => true,
-        }
-    }
-
     /// Returns `true` if the given `value` is a valid double-digit
     /// representation for this `PrimitiveTy`.
     ///
@@ -81,6 +70,7 @@ impl PrimitiveTy {
             I32 | U32 => value < (0x1 << 32),
             I64 | U64 => value < (0x1 << 64),
             I128 | U128 => true,
+            Isize | Usize => value <= usize::MAX as u128,
         }
     }
 
@@ -89,7 +79,7 @@ impl PrimitiveTy {
     pub(crate) fn is_signed(self) -> bool {
         use self::PrimitiveTy::*;
         match self {
-            I8 | I16 | I32 | I64 | I128 => true,
+            I8 | I16 | I32 | I64 | I128 | Isize => true,
             _ => false,
         }
     }
@@ -110,6 +100,12 @@ impl PrimitiveTy {
             I32 | U32 => BitWidth::w32(),
             I64 | U64 => BitWidth::w64(),
             I128 | U128 => BitWidth::w128(),
+            Isize | Usize => {
+                BitWidth::new(mem::size_of::<usize>() * 8).expect(
+                    "The bit width of `usize` is never zero so we can expect \
+                     `BitWidth::new` to be successful.",
+                )
+            }
         }
     }
 }
@@ -124,26 +120,26 @@ impl ApInt {
     ///
     /// This operation cannot fail and is the generic foundation for the
     /// greater part of the `ApInt::resize_to_*` methods.
-    fn resize_to_primitive_ty(&self, prim_ty: PrimitiveTy) -> Digit {
-        debug_assert_ne!(prim_ty, PrimitiveTy::U128);
-        debug_assert_ne!(prim_ty, PrimitiveTy::I128);
-        let mut lsd = self.least_significant_digit();
-        let actual_width = self.width();
-        let target_width = prim_ty.associated_width();
-        if prim_ty.is_signed() && actual_width < target_width {
-            lsd.sign_extend_from(actual_width).expect(
-                "We already asserted that `actual_width` < `target_width` and since \
-                 `target_width` is always less than or equal to `64` bits calling \
-                 `Digit::sign_extend_from` is safe for it.",
-            );
+    fn resize_to_primitive_ty(&self, prim_ty: PrimitiveTy) -> u128 {
+        let mut value = self.least_significant_u128();
+        let actual_width = self.width().to_usize();
+        let target_width = prim_ty.associated_width().to_usize();
+        if prim_ty.is_signed() && actual_width < target_width && self.msb() {
+            value |= u128::MAX << actual_width;
         }
-        if target_width < BitWidth::w64() {
-            lsd.truncate_to(target_width).expect(
-                "Since `target_width` is always less than or equal to `64` bits calling \
-                 `Digit::sign_extend_from` is safe for it.",
-            );
+        if target_width < 128 {
+            value &= !(u128::MAX << target_width);
         }
-        lsd
+        value
+    }
+
+    /// Returns the least significant `128` bits of this `ApInt` as `u128`.
+    fn least_significant_u128(&self) -> u128 {
+        self.as_digit_slice()
+            .iter()
+            .take(128 / Digit::BITS)
+            .rev()
+            .fold(0, |acc, d| (acc << Digit::BITS) | u128::from(d.repr()))
     }
 
     /// Resizes this `ApInt` to a `bool` primitive type.
@@ -169,7 +165,7 @@ impl ApInt {
     /// - All bits but the least significant `8` bits are being ignored by this
     ///   operation to construct the result.
     pub fn resize_to_i8(&self) -> i8 {
-        self.resize_to_primitive_ty(PrimitiveTy::I8).repr() as i8
+        self.resize_to_primitive_ty(PrimitiveTy::I8) as i8
     }
 
     /// Resizes this `ApInt` to a `u8` primitive type.
@@ -179,7 +175,7 @@ impl ApInt {
     /// - All bits but the least significant `8` bits are being ignored by this
     ///   operation to construct the result.
     pub fn resize_to_u8(&self) -> u8 {
-        self.resize_to_primitive_ty(PrimitiveTy::U8).repr() as u8
+        self.resize_to_primitive_ty(PrimitiveTy::U8) as u8
     }
 
     /// Resizes this `ApInt` to a `i16` primitive type.
@@ -192,7 +188,7 @@ impl ApInt {
     /// - All bits but the least significant `16` bits are being ignored by this
     ///   operation to construct the result.
     pub fn resize_to_i16(&self) -> i16 {
-        self.resize_to_primitive_ty(PrimitiveTy::I16).repr() as i16
+        self.resize_to_primitive_ty(PrimitiveTy::I16) as i16
     }
 
     /// Resizes this `ApInt` to a `u16` primitive type.
@@ -202,7 +198,7 @@ impl ApInt {
     /// - All bits but the least significant `16` bits are being ignored by this
     ///   operation to construct the result.
     pub fn resize_to_u16(&self) -> u16 {
-        self.resize_to_primitive_ty(PrimitiveTy::U16).repr() as u16
+        self.resize_to_primitive_ty(PrimitiveTy::U16) as u16
     }
 
     /// Resizes this `ApInt` to a `i32` primitive type.
@@ -215,7 +211,7 @@ impl ApInt {
     /// - All bits but the least significant `32` bits are being ignored by this
     ///   operation to construct the result.
     pub fn resize_to_i32(&self) -> i32 {
-        self.resize_to_primitive_ty(PrimitiveTy::I32).repr() as i32
+        self.resize_to_primitive_ty(PrimitiveTy::I32) as i32
     }
 
     /// Resizes this `ApInt` to a `u32` primitive type.
@@ -225,7 +221,7 @@ impl ApInt {
     /// - All bits but the least significant `32` bits are being ignored by this
     ///   operation to construct the result.
     pub fn resize_to_u32(&self) -> u32 {
-        self.resize_to_primitive_ty(PrimitiveTy::U32).repr() as u32
+        self.resize_to_primitive_ty(PrimitiveTy::U32) as u32
     }
 
     /// Resizes this `ApInt` to a `i64` primitive type.
@@ -238,7 +234,7 @@ impl ApInt {
     /// - All bits but the least significant `64` bits are being ignored by this
     ///   operation to construct the result.
     pub fn resize_to_i64(&self) -> i64 {
-        self.resize_to_primitive_ty(PrimitiveTy::I64).repr() as i64
+        self.resize_to_primitive_ty(PrimitiveTy::I64) as i64
     }
 
     /// Resizes this `ApInt` to a `u64` primitive type.
@@ -248,7 +244,7 @@ impl ApInt {
     /// - All bits but the least significant `64` bits are being ignored by this
     ///   operation to construct the result.
     pub fn resize_to_u64(&self) -> u64 {
-        self.resize_to_primitive_ty(PrimitiveTy::U64).repr() as u64
+        self.resize_to_primitive_ty(PrimitiveTy::U64) as u64
     }
 
     /// Resizes this `ApInt` to a `i128` primitive type.
@@ -261,26 +257,7 @@ impl ApInt {
     /// - All bits but the least significant `128` bits are being ignored by
     ///   this operation to construct the result.
     pub fn resize_to_i128(&self) -> i128 {
-        let (lsd_0, rest) = self.split_least_significant_digit();
-        let (&lsd_1, _) = rest.split_first().unwrap_or((&Digit(0), &[]));
-        let mut result: i128 =
-            (i128::from(lsd_1.repr()) << Digit::BITS) + i128::from(lsd_0.repr());
-        let actual_width = self.width();
-        let target_width = BitWidth::w128();
-
-        if actual_width < target_width {
-            // Sign extend the `i128`. Fill up with `1` up to `128` bits
-            // starting from the sign bit position.
-
-            // Number of bits representing the number in x.
-            let b = actual_width.to_usize();
-            // Mask can be pre-computed if b is fixed.
-            let m: i128 = 1 << (b - 1);
-            // Resulting sign-extended number.
-            result = (result ^ m) - m;
-        }
-
-        result
+        self.resize_to_primitive_ty(PrimitiveTy::I128) as i128
     }
 
     /// Resizes this `ApInt` to a `u128` primitive type.
@@ -290,24 +267,51 @@ impl ApInt {
     /// - All bits but the least significant `128` bits are being ignored by
     ///   this operation to construct the result.
     pub fn resize_to_u128(&self) -> u128 {
-        let (lsd_0, rest) = self.split_least_significant_digit();
-        let (&lsd_1, _) = rest.split_first().unwrap_or((&Digit(0), &[]));
-        let result: u128 =
-            (u128::from(lsd_1.repr()) << Digit::BITS) + u128::from(lsd_0.repr());
-        result
+        self.resize_to_primitive_ty(PrimitiveTy::U128)
     }
 }
 
 /// # Operations to lossless cast to primitive number types.
 impl ApInt {
+    /// Returns the number of bits required to represent the value of this
+    /// `ApInt` which is interpreted as signed if `signed` is `true`.
+    ///
+    /// **Note:** This includes the sign bit for signed interpretations.
+    pub(crate) fn required_bits(&self, signed: bool) -> usize {
+        let width = self.width().to_usize();
+        match (signed, self.msb()) {
+            (false, _) => width - self.leading_zeros(),
+            (true, false) => width - self.leading_zeros() + 1,
+            (true, true) => width - self.clone().into_bitnot().leading_zeros() + 1,
+        }
+    }
+
+    /// Returns an error if the value of this `ApInt` cannot be represented by
+    /// the given primitive type.
+    ///
+    /// The value of this `ApInt` is interpreted as signed if the given
+    /// `PrimitiveTy` is signed and as unsigned otherwise.
+    fn verify_fits_primitive_ty(&self, prim_ty: PrimitiveTy) -> Result<()> {
+        let target_width = prim_ty.associated_width();
+        let fits = if prim_ty.is_signed() {
+            self.sfits_in(target_width)
+        } else {
+            self.ufits_in(target_width)
+        };
+        if !fits {
+            return Error::encountered_unrepresentable_value(self.clone(), prim_ty).into()
+        }
+        Ok(())
+    }
+
     /// Verifies if this `ApInt` can be casted into the given primitive type
-    /// without loss of information and returns the least significant `Digit`
-    /// of this `ApInt` upon success.
+    /// without loss of information and returns the least significant `128`
+    /// bits of the value resized to the primitive type upon success.
     ///
     /// # Note
     ///
     /// If the given `PrimitiveTy` represents a signed integer type the
-    /// returned `Digit` is also sign extended accordingly.
+    /// returned value is also sign extended accordingly.
     /// This sign extension behaves equal to how in Rust a negative signed
     /// `i32` is extended to an `i64` and correctly preserves the negative
     /// value.
@@ -315,31 +319,9 @@ impl ApInt {
     /// # Errors
     ///
     /// If it is not possible to cast this `ApInt` without loss of information.
-    fn try_cast_to_primitive_ty(&self, prim_ty: PrimitiveTy) -> Result<Digit> {
-        debug_assert_ne!(prim_ty, PrimitiveTy::U128);
-        debug_assert_ne!(prim_ty, PrimitiveTy::I128);
-        let (mut lsd, rest) = self.split_least_significant_digit();
-        if !prim_ty.is_valid_repr(lsd.repr()) || rest.iter().any(|d| d.repr() != 0) {
-            return Error::encountered_unrepresentable_value(self.clone(), prim_ty).into()
-        }
-        if prim_ty.is_signed() {
-            let actual_width = self.width();
-            let target_width = prim_ty.associated_width();
-            if actual_width < target_width {
-                lsd.sign_extend_from(actual_width).expect(
-                    "We already asserted that `actual_width` < `target_width` and since \
-                     `target_width` is always less than or equal to `64` bits calling \
-                     `Digit::sign_extend_from` is safe for it.",
-                );
-                if target_width < BitWidth::w64() {
-                    lsd.truncate_to(target_width).expect(
-                        "Since `target_width` is always less than or equal to `64` bits \
-                         calling `Digit::sign_extend_from` is safe for it.",
-                    );
-                }
-            }
-        }
-        Ok(lsd)
+    fn try_cast_to_primitive_ty(&self, prim_ty: PrimitiveTy) -> Result<u128> {
+        self.verify_fits_primitive_ty(prim_ty)?;
+        Ok(self.resize_to_primitive_ty(prim_ty))
     }
 
     /// Tries to represent the value of this `ApInt` as a `bool`.
@@ -347,17 +329,19 @@ impl ApInt {
     /// # Note
     ///
     /// This returns `true` if the value represented by this `ApInt`
-    /// is `1`, returns `false` if the value represented by this
-    /// `ApInt` is `0` and returns an error otherwise.
+    /// is `1` and returns `false` if the value represented by this
+    /// `ApInt` is `0`.
     ///
     /// # Errors
     ///
-    /// - If the value represented by this `ApInt` can not be represented by a
-    ///   `bool`.
+    /// - If the bit width of this `ApInt` is not `1`.
     pub fn try_to_bool(&self) -> Result<bool> {
+        if self.width() != BitWidth::w1() {
+            return Error::unmatching_bitwidths(self.width(), BitWidth::w1()).into()
+        }
         match self.try_cast_to_primitive_ty(PrimitiveTy::Bool)? {
-            Digit(0) => Ok(false),
-            Digit(1) => Ok(true),
+            0 => Ok(false),
+            1 => Ok(true),
             _ => unreachable!(),
         }
     }
@@ -369,8 +353,8 @@ impl ApInt {
     /// - This operation will conserve the signedness of the value. This means
     ///   that for `ApInt` instances with a `BitWidth` less than `8` bits the
     ///   value is sign extended to the target bit width.
-    /// - This conversion is possible as long as the value represented by this
-    ///   `ApInt` does not exceed the maximum value of `u8`.
+    /// - This conversion is possible as long as the **signed** value
+    ///   represented by this `ApInt` is within the range of `i8`.
     ///
     /// # Errors
     ///
@@ -378,7 +362,7 @@ impl ApInt {
     ///   `i8`.
     pub fn try_to_i8(&self) -> Result<i8> {
         self.try_cast_to_primitive_ty(PrimitiveTy::I8)
-            .map(|d| d.repr() as i8)
+            .map(|value| value as i8)
     }
 
     /// Tries to represent the value of this `ApInt` as a `u8`.
@@ -394,7 +378,7 @@ impl ApInt {
     ///   `u8`.
     pub fn try_to_u8(&self) -> Result<u8> {
         self.try_cast_to_primitive_ty(PrimitiveTy::U8)
-            .map(|d| d.repr() as u8)
+            .map(|value| value as u8)
     }
 
     /// Tries to represent the value of this `ApInt` as a `i16`.
@@ -404,8 +388,8 @@ impl ApInt {
     /// - This operation will conserve the signedness of the value. This means
     ///   that for `ApInt` instances with a `BitWidth` less than `16` bits the
     ///   value is sign extended to the target bit width.
-    /// - This conversion is possible as long as the value represented by this
-    ///   `ApInt` does not exceed the maximum value of `u16`.
+    /// - This conversion is possible as long as the **signed** value
+    ///   represented by this `ApInt` is within the range of `i16`.
     ///
     /// # Errors
     ///
@@ -413,7 +397,7 @@ impl ApInt {
     ///   `i16`.
     pub fn try_to_i16(&self) -> Result<i16> {
         self.try_cast_to_primitive_ty(PrimitiveTy::I16)
-            .map(|d| d.repr() as i16)
+            .map(|value| value as i16)
     }
 
     /// Tries to represent the value of this `ApInt` as a `u16`.
@@ -429,7 +413,7 @@ impl ApInt {
     ///   `u16`.
     pub fn try_to_u16(&self) -> Result<u16> {
         self.try_cast_to_primitive_ty(PrimitiveTy::U16)
-            .map(|d| d.repr() as u16)
+            .map(|value| value as u16)
     }
 
     /// Tries to represent the value of this `ApInt` as a `i32`.
@@ -439,8 +423,8 @@ impl ApInt {
     /// - This operation will conserve the signedness of the value. This means
     ///   that for `ApInt` instances with a `BitWidth` less than `32` bits the
     ///   value is sign extended to the target bit width.
-    /// - This conversion is possible as long as the value represented by this
-    ///   `ApInt` does not exceed the maximum value of `u32`.
+    /// - This conversion is possible as long as the **signed** value
+    ///   represented by this `ApInt` is within the range of `i32`.
     ///
     /// # Errors
     ///
@@ -448,7 +432,7 @@ impl ApInt {
     ///   `i32`.
     pub fn try_to_i32(&self) -> Result<i32> {
         self.try_cast_to_primitive_ty(PrimitiveTy::I32)
-            .map(|d| d.repr() as i32)
+            .map(|value| value as i32)
     }
 
     /// Tries to represent the value of this `ApInt` as a `u32`.
@@ -464,7 +448,7 @@ impl ApInt {
     ///   `u32`.
     pub fn try_to_u32(&self) -> Result<u32> {
         self.try_cast_to_primitive_ty(PrimitiveTy::U32)
-            .map(|d| d.repr() as u32)
+            .map(|value| value as u32)
     }
 
     /// Tries to represent the value of this `ApInt` as a `i64`.
@@ -474,8 +458,8 @@ impl ApInt {
     /// - This operation will conserve the signedness of the value. This means
     ///   that for `ApInt` instances with a `BitWidth` less than `64` bits the
     ///   value is sign extended to the target bit width.
-    /// - This conversion is possible as long as the value represented by this
-    ///   `ApInt` does not exceed the maximum value of `u64`.
+    /// - This conversion is possible as long as the **signed** value
+    ///   represented by this `ApInt` is within the range of `i64`.
     ///
     /// # Errors
     ///
@@ -483,7 +467,7 @@ impl ApInt {
     ///   `i64`.
     pub fn try_to_i64(&self) -> Result<i64> {
         self.try_cast_to_primitive_ty(PrimitiveTy::I64)
-            .map(|d| d.repr() as i64)
+            .map(|value| value as i64)
     }
 
     /// Tries to represent the value of this `ApInt` as a `u64`.
@@ -499,7 +483,7 @@ impl ApInt {
     ///   `u64`.
     pub fn try_to_u64(&self) -> Result<u64> {
         self.try_cast_to_primitive_ty(PrimitiveTy::U64)
-            .map(|d| d.repr() as u64)
+            .map(|value| value as u64)
     }
 
     /// Tries to represent the value of this `ApInt` as a `i128`.
@@ -509,41 +493,16 @@ impl ApInt {
     /// - This operation will conserve the signedness of the value. This means
     ///   that for `ApInt` instances with a `BitWidth` less than `128` bits the
     ///   value is sign extended to the target bit width.
-    /// - This conversion is possible as long as the value represented by this
-    ///   `ApInt` does not exceed the maximum value of `u128`.
+    /// - This conversion is possible as long as the **signed** value
+    ///   represented by this `ApInt` is within the range of `i128`.
     ///
     /// # Errors
     ///
     /// - If the value represented by this `ApInt` can not be represented by a
     ///   `i128`.
     pub fn try_to_i128(&self) -> Result<i128> {
-        let (lsd_0, rest) = self.split_least_significant_digit();
-        let (&lsd_1, rest) = rest.split_first().unwrap_or((&Digit(0), &[]));
-        if rest.iter().any(|d| d.repr() != 0) {
-            return Error::encountered_unrepresentable_value(
-                self.clone(),
-                PrimitiveTy::I128,
-            )
-            .into()
-        }
-        let mut result: i128 =
-            (i128::from(lsd_1.repr()) << Digit::BITS) + i128::from(lsd_0.repr());
-
-        let actual_width = self.width();
-        let target_width = BitWidth::w128();
-        if actual_width < target_width {
-            // Sign extend the `i128`. Fill up with `1` up to `128` bits
-            // starting from the sign bit position.
-
-            // Number of bits representing the number in x.
-            let b = actual_width.to_usize();
-            // Mask can be pre-computed if b is fixed.
-            let m: i128 = 1 << (b - 1);
-            // Resulting sign-extended number.
-            result = (result ^ m).wrapping_sub(m);
-        }
-
-        Ok(result)
+        self.try_cast_to_primitive_ty(PrimitiveTy::I128)
+            .map(|value| value as i128)
     }
 
     /// Tries to represent the value of this `ApInt` as a `u128`.
@@ -562,21 +521,70 @@ impl ApInt {
     /// - If the value represented by this `ApInt` can not be represented by a
     ///   `u128`.
     pub fn try_to_u128(&self) -> Result<u128> {
-        let (lsd_0, rest) = self.split_least_significant_digit();
-        let (&lsd_1, rest) = rest.split_first().unwrap_or((&Digit(0), &[]));
-        if rest.iter().any(|d| d.repr() != 0) {
-            return Error::encountered_unrepresentable_value(
-                self.clone(),
-                PrimitiveTy::U128,
-            )
-            .into()
-        }
-        let result: u128 =
-            (u128::from(lsd_1.repr()) << Digit::BITS) + u128::from(lsd_0.repr());
-        Ok(result)
+        self.try_cast_to_primitive_ty(PrimitiveTy::U128)
+    }
+
+    /// Tries to represent the value of this `ApInt` as a `isize`.
+    ///
+    /// # Note
+    ///
+    /// - This operation will conserve the signedness of the value. This means
+    ///   that for `ApInt` instances with a `BitWidth` less than the bit width
+    ///   of `isize` the value is sign extended to the target bit width.
+    /// - This conversion is possible as long as the **signed** value
+    ///   represented by this `ApInt` is within the range of `isize`.
+    ///
+    /// # Errors
+    ///
+    /// - If the value represented by this `ApInt` can not be represented by a
+    ///   `isize`.
+    pub fn try_to_isize(&self) -> Result<isize> {
+        self.try_cast_to_primitive_ty(PrimitiveTy::Isize)
+            .map(|value| value as isize)
+    }
+
+    /// Tries to represent the value of this `ApInt` as a `usize`.
+    ///
+    /// # Note
+    ///
+    /// - This conversion is possible as long as the value represented by this
+    ///   `ApInt` does not exceed the maximum value of `usize`.
+    ///
+    /// # Errors
+    ///
+    /// - If the value represented by this `ApInt` can not be represented by a
+    ///   `usize`.
+    pub fn try_to_usize(&self) -> Result<usize> {
+        self.try_cast_to_primitive_ty(PrimitiveTy::Usize)
+            .map(|value| value as usize)
     }
 }
 
+macro_rules! impl_try_from_apint_for_primitive {
+    ($prim:ty, $method:ident) => {
+        impl TryFrom<&ApInt> for $prim {
+            type Error = Error;
+
+            fn try_from(value: &ApInt) -> Result<$prim> {
+                value.$method()
+            }
+        }
+    };
+}
+
+impl_try_from_apint_for_primitive!(u8, try_to_u8);
+impl_try_from_apint_for_primitive!(u16, try_to_u16);
+impl_try_from_apint_for_primitive!(u32, try_to_u32);
+impl_try_from_apint_for_primitive!(u64, try_to_u64);
+impl_try_from_apint_for_primitive!(u128, try_to_u128);
+impl_try_from_apint_for_primitive!(usize, try_to_usize);
+impl_try_from_apint_for_primitive!(i8, try_to_i8);
+impl_try_from_apint_for_primitive!(i16, try_to_i16);
+impl_try_from_apint_for_primitive!(i32, try_to_i32);
+impl_try_from_apint_for_primitive!(i64, try_to_i64);
+impl_try_from_apint_for_primitive!(i128, try_to_i128);
+impl_try_from_apint_for_primitive!(isize, try_to_isize);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -698,6 +706,7 @@ mod tests {
                         let val = val as u128;
                         (val, ApInt::from_u128(val))
                     }
+                    Isize | Usize => unreachable!(),
                 }
             })
     }
@@ -735,10 +744,9 @@ mod tests {
                 let actual_width = apint.width();
                 let target_width = PrimitiveTy::I8.associated_width();
                 if actual_width < target_width {
-                    let mut digit = Digit(val as u64);
-                    digit.sign_extend_from(actual_width).unwrap();
-                    digit.truncate_to(target_width).unwrap();
-                    assert_eq!(apint.resize_to_i8(), digit.repr() as i8);
+                    let shift = 128 - actual_width.to_usize();
+                    let extended = ((val << shift) as i128) >> shift;
+                    assert_eq!(apint.resize_to_i8(), extended as i8);
                 } else {
                     assert_eq!(apint.resize_to_i8(), val as i8)
                 }
@@ -758,10 +766,9 @@ mod tests {
                 let actual_width = apint.width();
                 let target_width = PrimitiveTy::I16.associated_width();
                 if actual_width < target_width {
-                    let mut digit = Digit(val as u64);
-                    digit.sign_extend_from(actual_width).unwrap();
-                    digit.truncate_to(target_width).unwrap();
-                    assert_eq!(apint.resize_to_i16(), digit.repr() as i16);
+                    let shift = 128 - actual_width.to_usize();
+                    let extended = ((val << shift) as i128) >> shift;
+                    assert_eq!(apint.resize_to_i16(), extended as i16);
                 } else {
                     assert_eq!(apint.resize_to_i16(), val as i16)
                 }
@@ -781,10 +788,9 @@ mod tests {
                 let actual_width = apint.width();
                 let target_width = PrimitiveTy::I32.associated_width();
                 if actual_width < target_width {
-                    let mut digit = Digit(val as u64);
-                    digit.sign_extend_from(actual_width).unwrap();
-                    digit.truncate_to(target_width).unwrap();
-                    assert_eq!(apint.resize_to_i32(), digit.repr() as i32);
+                    let shift = 128 - actual_width.to_usize();
+                    let extended = ((val << shift) as i128) >> shift;
+                    assert_eq!(apint.resize_to_i32(), extended as i32);
                 } else {
                     assert_eq!(apint.resize_to_i32(), val as i32)
                 }
@@ -804,9 +810,9 @@ mod tests {
                 let actual_width = apint.width();
                 let target_width = PrimitiveTy::I64.associated_width();
                 if actual_width < target_width {
-                    let mut digit = Digit(val as u64);
-                    digit.sign_extend_from(actual_width).unwrap();
-                    assert_eq!(apint.resize_to_i64(), digit.repr() as i64);
+                    let shift = 128 - actual_width.to_usize();
+                    let extended = ((val << shift) as i128) >> shift;
+                    assert_eq!(apint.resize_to_i64(), extended as i64);
                 } else {
                     assert_eq!(apint.resize_to_i64(), val as i64)
                 }
@@ -826,9 +832,9 @@ mod tests {
                 let actual_width = apint.width();
                 let target_width = PrimitiveTy::I128.associated_width();
                 if actual_width < target_width {
-                    let mut digit = Digit(val as u64);
-                    digit.sign_extend_from(actual_width).unwrap();
-                    assert_eq!(apint.resize_to_i128(), digit.repr() as i64 as i128);
+                    let shift = 128 - actual_width.to_usize();
+                    let extended = ((val << shift) as i128) >> shift;
+                    assert_eq!(apint.resize_to_i128(), extended);
                 } else {
                     assert_eq!(apint.resize_to_i128(), val as i128)
                 }
@@ -863,53 +869,52 @@ mod tests {
     mod r#try {
         use super::*;
 
+        /// Returns the signed value of the `width` bits wide two's complement
+        /// representation `val`.
+        fn signed_value(val: u128, width: BitWidth) -> i128 {
+            let shift = 128 - width.to_usize();
+            ((val << shift) as i128) >> shift
+        }
+
         #[test]
         fn to_bool_true() {
             assert_eq!(ApInt::from(true).try_to_bool(), Ok(true));
-            assert_eq!(ApInt::from(1_u8).try_to_bool(), Ok(true));
-            assert_eq!(ApInt::from(1_u16).try_to_bool(), Ok(true));
-            assert_eq!(ApInt::from(1_u32).try_to_bool(), Ok(true));
-            assert_eq!(ApInt::from(1_u64).try_to_bool(), Ok(true));
-            assert_eq!(ApInt::from(1_u128).try_to_bool(), Ok(true));
+            assert_eq!(ApInt::all_set(BitWidth::w1()).try_to_bool(), Ok(true));
         }
 
         #[test]
         fn to_bool_false() {
             assert_eq!(ApInt::from(false).try_to_bool(), Ok(false));
-            assert_eq!(ApInt::from(0_u8).try_to_bool(), Ok(false));
-            assert_eq!(ApInt::from(0_u16).try_to_bool(), Ok(false));
-            assert_eq!(ApInt::from(0_u32).try_to_bool(), Ok(false));
-            assert_eq!(ApInt::from(0_u64).try_to_bool(), Ok(false));
-            assert_eq!(ApInt::from(0_u128).try_to_bool(), Ok(false));
+            assert_eq!(ApInt::zero(BitWidth::w1()).try_to_bool(), Ok(false));
         }
 
         #[test]
         fn to_bool_fail() {
+            use crate::errors::ErrorKind;
+            assert_eq!(
+                ApInt::from(1_u8).try_to_bool().unwrap_err().kind(),
+                &ErrorKind::UnmatchingBitwidth(BitWidth::w8(), BitWidth::w1())
+            );
+            assert!(ApInt::from(0_u8).try_to_bool().is_err());
+            assert!(ApInt::from(1_u16).try_to_bool().is_err());
+            assert!(ApInt::from(0_u32).try_to_bool().is_err());
+            assert!(ApInt::from(1_u64).try_to_bool().is_err());
+            assert!(ApInt::from(0_u128).try_to_bool().is_err());
             assert!(ApInt::from(2_u8).try_to_bool().is_err());
             assert!(ApInt::from(-1_i16).try_to_bool().is_err());
             assert!(ApInt::from(42_u32).try_to_bool().is_err());
             assert!(ApInt::from(1337_u64).try_to_bool().is_err());
-            assert!(
-                ApInt::from(0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_u128)
-                    .try_to_bool()
-                    .is_err()
-            );
+            assert!(ApInt::from(0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_u128)
+                .try_to_bool()
+                .is_err());
         }
 
         #[test]
         fn to_i8() {
             for (val, apint) in test_vals_and_apints() {
-                if PrimitiveTy::I8.is_valid_dd(val) {
-                    let actual_width = apint.width();
-                    let target_width = PrimitiveTy::I8.associated_width();
-                    if actual_width < target_width {
-                        let mut digit = Digit(val as u64);
-                        digit.sign_extend_from(actual_width).unwrap();
-                        digit.truncate_to(target_width).unwrap();
-                        assert_eq!(apint.try_to_i8(), Ok(digit.repr() as i8));
-                    } else {
-                        assert_eq!(apint.try_to_i8(), Ok(val as i8))
-                    }
+                let signed = signed_value(val, apint.width());
+                if i128::from(i8::MIN) <= signed && signed <= i128::from(i8::MAX) {
+                    assert_eq!(apint.try_to_i8(), Ok(signed as i8))
                 } else {
                     assert!(apint.try_to_i8().is_err())
                 }
@@ -919,17 +924,9 @@ mod tests {
         #[test]
         fn to_i16() {
             for (val, apint) in test_vals_and_apints() {
-                if PrimitiveTy::I16.is_valid_dd(val) {
-                    let actual_width = apint.width();
-                    let target_width = PrimitiveTy::I16.associated_width();
-                    if actual_width < target_width {
-                        let mut digit = Digit(val as u64);
-                        digit.sign_extend_from(actual_width).unwrap();
-                        digit.truncate_to(target_width).unwrap();
-                        assert_eq!(apint.try_to_i16(), Ok(digit.repr() as i16));
-                    } else {
-                        assert_eq!(apint.try_to_i16(), Ok(val as i16))
-                    }
+                let signed = signed_value(val, apint.width());
+                if i128::from(i16::MIN) <= signed && signed <= i128::from(i16::MAX) {
+                    assert_eq!(apint.try_to_i16(), Ok(signed as i16))
                 } else {
                     assert!(apint.try_to_i16().is_err())
                 }
@@ -939,17 +936,9 @@ mod tests {
         #[test]
         fn to_i32() {
             for (val, apint) in test_vals_and_apints() {
-                if PrimitiveTy::I32.is_valid_dd(val) {
-                    let actual_width = apint.width();
-                    let target_width = PrimitiveTy::I32.associated_width();
-                    if actual_width < target_width {
-                        let mut digit = Digit(val as u64);
-                        digit.sign_extend_from(actual_width).unwrap();
-                        digit.truncate_to(target_width).unwrap();
-                        assert_eq!(apint.try_to_i32(), Ok(digit.repr() as i32));
-                    } else {
-                        assert_eq!(apint.try_to_i32(), Ok(val as i32))
-                    }
+                let signed = signed_value(val, apint.width());
+                if i128::from(i32::MIN) <= signed && signed <= i128::from(i32::MAX) {
+                    assert_eq!(apint.try_to_i32(), Ok(signed as i32))
                 } else {
                     assert!(apint.try_to_i32().is_err())
                 }
@@ -959,16 +948,9 @@ mod tests {
         #[test]
         fn to_i64() {
             for (val, apint) in test_vals_and_apints() {
-                if PrimitiveTy::I64.is_valid_dd(val) {
-                    let actual_width = apint.width();
-                    let target_width = PrimitiveTy::I64.associated_width();
-                    if actual_width < target_width {
-                        let mut digit = Digit(val as u64);
-                        digit.sign_extend_from(actual_width).unwrap();
-                        assert_eq!(apint.try_to_i64(), Ok(digit.repr() as i64));
-                    } else {
-                        assert_eq!(apint.try_to_i64(), Ok(val as i64))
-                    }
+                let signed = signed_value(val, apint.width());
+                if i128::from(i64::MIN) <= signed && signed <= i128::from(i64::MAX) {
+                    assert_eq!(apint.try_to_i64(), Ok(signed as i64))
                 } else {
                     assert!(apint.try_to_i64().is_err())
                 }
@@ -1060,4 +1042,171 @@ mod tests {
             }
         }
     }
+
+    mod boundaries {
+        use super::*;
+        use crate::ErrorKind;
+
+        /// Widths with which every boundary value is tested.
+        fn test_widths() -> impl Iterator<Item = BitWidth> {
+            [129, 130, 200].iter().map(|&w| BitWidth::new(w).unwrap())
+        }
+
+        fn assert_required_bits(result: Result<impl core::fmt::Debug>, expected: usize) {
+            match result.unwrap_err().kind() {
+                ErrorKind::ValueUnrepresentable { required_bits, .. } => {
+                    assert_eq!(*required_bits, expected)
+                }
+                kind => panic!("unexpected error kind: {:?}", kind),
+            }
+        }
+
+        #[test]
+        fn unsigned() {
+            for width in test_widths() {
+                let val = |v: u128| ApInt::from_u128(v).into_zero_resize(width);
+                let two_128 = ApInt::one(width).into_wrapping_shl(128).unwrap();
+                assert_eq!(val(0).try_to_u8(), Ok(0));
+                assert_eq!(val(0xFF).try_to_u8(), Ok(u8::MAX));
+                assert_required_bits(val(0x100).try_to_u8(), 9);
+                assert_eq!(val(0xFFFF).try_to_u16(), Ok(u16::MAX));
+                assert_required_bits(val(0x1_0000).try_to_u16(), 17);
+                assert_eq!(val(0xFFFF_FFFF).try_to_u32(), Ok(u32::MAX));
+                assert_required_bits(val(0x1_0000_0000).try_to_u32(), 33);
+                assert_eq!(val(u128::from(u64::MAX)).try_to_u64(), Ok(u64::MAX));
+                assert_required_bits(val(1 << 64).try_to_u64(), 65);
+                assert_eq!(val(u128::from(u64::MAX)).try_to_usize(), Ok(usize::MAX));
+                assert_required_bits(val(1 << 64).try_to_usize(), 65);
+                assert_eq!(val(u128::MAX).try_to_u128(), Ok(u128::MAX));
+                assert_required_bits(two_128.try_to_u128(), 129);
+                assert_required_bits(
+                    ApInt::all_set(width).try_to_u128(),
+                    width.to_usize(),
+                );
+            }
+        }
+
+        #[test]
+        fn signed() {
+            for width in test_widths() {
+                let val = |v: i128| ApInt::from_i128(v).into_sign_resize(width);
+                let test_cases = vec![
+                    (i128::from(i8::MIN), i128::from(i8::MAX), 8),
+                    (i128::from(i16::MIN), i128::from(i16::MAX), 16),
+                    (i128::from(i32::MIN), i128::from(i32::MAX), 32),
+                    (i128::from(i64::MIN), i128::from(i64::MAX), 64),
+                ];
+                for (min, max, bits) in test_cases {
+                    let try_to = |apint: &ApInt| -> Result<i128> {
+                        match bits {
+                            8 => apint.try_to_i8().map(i128::from),
+                            16 => apint.try_to_i16().map(i128::from),
+                            32 => apint.try_to_i32().map(i128::from),
+                            _ => apint.try_to_i64().map(i128::from),
+                        }
+                    };
+                    assert_eq!(try_to(&val(min)), Ok(min));
+                    assert_eq!(try_to(&val(max)), Ok(max));
+                    assert_eq!(try_to(&val(-1)), Ok(-1));
+                    assert_eq!(try_to(&val(0)), Ok(0));
+                    assert_required_bits(try_to(&val(min - 1)), bits + 1);
+                    assert_required_bits(try_to(&val(max + 1)), bits + 1);
+                }
+                assert_eq!(val(i128::from(i64::MIN)).try_to_isize(), Ok(isize::MIN));
+                assert_eq!(val(i128::from(i64::MAX)).try_to_isize(), Ok(isize::MAX));
+                assert_required_bits(val(i128::from(i64::MIN) - 1).try_to_isize(), 65);
+                assert_eq!(val(i128::MIN).try_to_i128(), Ok(i128::MIN));
+                assert_eq!(val(i128::MAX).try_to_i128(), Ok(i128::MAX));
+                let below_min = val(i128::MIN)
+                    .into_wrapping_sub(&ApInt::one(width))
+                    .unwrap();
+                assert_required_bits(below_min.try_to_i128(), 129);
+                let above_max = val(i128::MAX)
+                    .into_wrapping_add(&ApInt::one(width))
+                    .unwrap();
+                assert_required_bits(above_max.try_to_i128(), 129);
+            }
+        }
+
+        #[test]
+        fn narrow_sources() {
+            let w7 = BitWidth::new(7).unwrap();
+            let all_set = ApInt::all_set(w7);
+            assert_eq!(all_set.try_to_u8(), Ok(0x7F));
+            assert_eq!(all_set.try_to_u64(), Ok(0x7F));
+            assert_eq!(all_set.try_to_u128(), Ok(0x7F));
+            assert_eq!(all_set.try_to_i8(), Ok(-1));
+            assert_eq!(all_set.try_to_i64(), Ok(-1));
+            assert_eq!(all_set.try_to_isize(), Ok(-1));
+            assert_eq!(all_set.try_to_i128(), Ok(-1));
+            let min = ApInt::signed_min_value(w7);
+            assert_eq!(min.try_to_i8(), Ok(-64));
+            assert_eq!(min.try_to_i32(), Ok(-64));
+            assert_eq!(min.try_to_u8(), Ok(64));
+            assert_eq!(ApInt::from_i64(i64::MIN).try_to_i64(), Ok(i64::MIN));
+            assert_required_bits(ApInt::from_i64(i64::MIN).try_to_i32(), 64);
+            assert_required_bits(ApInt::from_u64(0x80).try_to_i8(), 9);
+            assert_eq!(ApInt::from_bool(true).try_to_i8(), Ok(-1));
+        }
+    }
+
+    mod try_from {
+        use super::*;
+
+        /// Asserts that the `TryFrom` implementation for `P` agrees with the
+        /// given `try_to_*` method on the given `ApInt`.
+        fn assert_agree<P>(apint: &ApInt, method: fn(&ApInt) -> Result<P>)
+        where
+            P: for<'a> TryFrom<&'a ApInt, Error = Error> + PartialEq + core::fmt::Debug,
+        {
+            assert_eq!(P::try_from(apint), method(apint));
+        }
+
+        #[test]
+        fn agrees_with_try_to() {
+            let boundaries = vec![
+                u128::from(u64::MAX),
+                1 << 64,
+                i128::MAX as u128,
+                i128::MIN as u128,
+                u128::MAX,
+            ];
+            let apints = test_vals_and_apints().map(|(_, apint)| apint).chain(
+                boundaries.into_iter().flat_map(|v| {
+                    vec![
+                        ApInt::from_u128(v),
+                        ApInt::from_u128(v).into_zero_resize(BitWidth::new(130).unwrap()),
+                        ApInt::from_u128(v).into_sign_resize(BitWidth::new(130).unwrap()),
+                    ]
+                }),
+            );
+            for apint in apints {
+                assert_agree(&apint, ApInt::try_to_u8);
+                assert_agree(&apint, ApInt::try_to_u16);
+                assert_agree(&apint, ApInt::try_to_u32);
+                assert_agree(&apint, ApInt::try_to_u64);
+                assert_agree(&apint, ApInt::try_to_u128);
+                assert_agree(&apint, ApInt::try_to_usize);
+                assert_agree(&apint, ApInt::try_to_i8);
+                assert_agree(&apint, ApInt::try_to_i16);
+                assert_agree(&apint, ApInt::try_to_i32);
+                assert_agree(&apint, ApInt::try_to_i64);
+                assert_agree(&apint, ApInt::try_to_i128);
+                assert_agree(&apint, ApInt::try_to_isize);
+            }
+        }
+
+        #[test]
+        fn composes_with_question_mark() {
+            fn sum(values: &[ApInt]) -> Result<u64> {
+                let mut sum = 0;
+                for value in values {
+                    sum += u64::try_from(value)?;
+                }
+                Ok(sum)
+            }
+            assert_eq!(sum(&[ApInt::from_u8(1), ApInt::from_u64(41)]), Ok(42));
+            assert!(sum(&[ApInt::from_u8(1), ApInt::from_u128(1 << 64)]).is_err());
+        }
+    }
 }
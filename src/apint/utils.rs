@@ -1,8 +1,6 @@
 use crate::{
-    digit_seq::{
-        ContiguousDigitSeq,
-        ContiguousDigitSeqMut,
-    },
+    digit_seq::ContiguousDigitSeqMut,
+    mem::vec::Vec,
     storage::Storage,
     ApInt,
     BitWidth,
@@ -18,6 +16,7 @@ use core::{
         Hash,
         Hasher,
     },
+    ops::Range,
 };
 
 impl fmt::Debug for ApInt {
@@ -39,10 +38,6 @@ impl Hash for ApInt {
 // ============================================================================
 
 impl ApInt {
-    pub(in crate::apint) fn digits(&self) -> ContiguousDigitSeq {
-        ContiguousDigitSeq::from(self.as_digit_slice())
-    }
-
     pub(in crate::apint) fn digits_mut(&mut self) -> ContiguousDigitSeqMut {
         ContiguousDigitSeqMut::from(self.as_digit_slice_mut())
     }
@@ -82,6 +77,48 @@ pub(crate) enum ZipDataAccessMutBoth<'a, 'b> {
 
 // ============================================================================
 
+/// Returns the part of the mask for the bit position `range` of an `ApInt`
+/// that falls into the digit at `digit_pos`.
+#[inline]
+pub(in crate::apint) fn digit_range_mask(
+    digit_pos: usize,
+    range: &Range<usize>,
+) -> Digit {
+    let digit_lo = digit_pos * Digit::BITS;
+    let digit_hi = digit_lo + Digit::BITS;
+    let start = range.start.max(digit_lo).min(digit_hi) - digit_lo;
+    let end = range.end.max(digit_lo).min(digit_hi) - digit_lo;
+    if start >= end {
+        Digit::ZERO
+    } else {
+        (Digit::ONES >> (Digit::BITS - (end - start))) << start
+    }
+}
+
+/// Bitwise-ORs the bits of `part` into `digits` starting at the bit position
+/// `offset`.
+///
+/// Bits that would be placed beyond the end of `digits` are dropped.
+pub(in crate::apint) fn or_digits_at(
+    digits: &mut [Digit],
+    part: &[Digit],
+    offset: usize,
+) {
+    let (start, shift) = (offset / Digit::BITS, offset % Digit::BITS);
+    for (i, digit) in part.iter().enumerate() {
+        if let Some(lo) = digits.get_mut(start + i) {
+            *lo |= *digit << shift;
+        }
+        if shift != 0 {
+            if let Some(hi) = digits.get_mut(start + i + 1) {
+                *hi |= *digit >> (Digit::BITS - shift);
+            }
+        }
+    }
+}
+
+// ============================================================================
+
 impl Width for ApInt {
     /// Returns the `BitWidth` of this `ApInt`.
     #[inline]
@@ -90,6 +127,17 @@ impl Width for ApInt {
     }
 }
 
+/// The sign of an `ApInt` according to the **signed** interpretation.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Sign {
+    /// The value is less than zero.
+    Negative,
+    /// The value is zero.
+    Zero,
+    /// The value is greater than zero.
+    Positive,
+}
+
 /// # Utility & Helper Methods
 impl ApInt {
     /// Returns the number of bits of the bit width of this `ApInt`.
@@ -266,6 +314,30 @@ impl ApInt {
         Ok(())
     }
 
+    /// Computes the given operation on all digits of this `ApInt`
+    /// zipped with the digits of `rhs` zero-extended to the width of `self`.
+    ///
+    /// # Note
+    ///
+    /// If `rhs` is wider than `self` its surplus digits are ignored, however,
+    /// its bits beyond the width of `self` within the most significant digit
+    /// of `self` are not and callers need to take care of them.
+    #[inline]
+    pub(in crate::apint) fn modify_zipped_digits_zext<F>(&mut self, rhs: &ApInt, f: F)
+    where
+        F: Fn(&mut Digit, Digit),
+    {
+        use core::iter;
+        let rhs = rhs
+            .as_digit_slice()
+            .iter()
+            .cloned()
+            .chain(iter::repeat(Digit::ZERO));
+        for (l, r) in self.as_digit_slice_mut().iter_mut().zip(rhs) {
+            f(l, r)
+        }
+    }
+
     /// Returns a slice over the `Digit`s of this `ApInt` in little-endian
     /// order.
     #[inline]
@@ -320,6 +392,78 @@ impl ApInt {
         }
     }
 
+    /// Returns an iterator over the 64-bit limbs of this `ApInt` in
+    /// little-endian order independent of the configured `Digit` size.
+    pub(crate) fn u64_limbs(&self) -> impl Iterator<Item = u64> + '_ {
+        self.as_digit_slice()
+            .chunks(Digit::PER_U64)
+            .map(Digit::join_u64)
+    }
+
+    /// Returns a slice over the 64-bit limbs of this `ApInt` in
+    /// little-endian order, i.e. the least significant limb comes first.
+    ///
+    /// The slice holds exactly as many limbs as are required to represent the
+    /// bit width of `self`, unused high bits of the most significant limb are
+    /// zero.
+    ///
+    /// **Note:** This is only available with the default 64-bit digits, use
+    /// [`to_u64_vec`](struct.ApInt.html#method.to_u64_vec) otherwise.
+    #[cfg(not(any(apint_digit = "u32", apint_digit = "u8")))]
+    pub fn as_u64_slice(&self) -> &[u64] {
+        use core::slice;
+        let digits = self.as_digit_slice();
+        // This is fine since `Digit` is a `repr(transparent)` wrapper around `u64`.
+        unsafe { slice::from_raw_parts(digits.as_ptr() as *const u64, digits.len()) }
+    }
+
+    /// Returns the 64-bit limbs of this `ApInt` as an array in big-endian
+    /// order, i.e. the most significant limb comes first.
+    ///
+    /// This is the same order as used by the `From<[u64; N]>` implementation
+    /// for `ApInt`.
+    ///
+    /// # Errors
+    ///
+    /// - If `N` is not exactly the number of 64-bit limbs required to represent
+    ///   the bit width of `self`.
+    pub fn to_limbs<const N: usize>(&self) -> Result<[u64; N]> {
+        let required_limbs = self.width().required_u64s();
+        if required_limbs != N {
+            return Err(Error::unmatching_buffer_length(required_limbs, N))
+        }
+        let mut result = [0; N];
+        for (dst, src) in result.iter_mut().rev().zip(self.u64_limbs()) {
+            *dst = src;
+        }
+        Ok(result)
+    }
+
+    /// Returns the 64-bit limbs of this `ApInt` as an array in little-endian
+    /// order, i.e. the least significant limb comes first.
+    ///
+    /// This is the same order as used by
+    /// [`from_le_limbs`](struct.ApInt.html#method.from_le_limbs).
+    ///
+    /// # Errors
+    ///
+    /// - If `N` is not exactly the number of 64-bit limbs required to represent
+    ///   the bit width of `self`.
+    pub fn to_le_limbs<const N: usize>(&self) -> Result<[u64; N]> {
+        let mut result = self.to_limbs::<N>()?;
+        result.reverse();
+        Ok(result)
+    }
+
+    /// Returns the 64-bit limbs of this `ApInt` in little-endian order.
+    ///
+    /// The vector holds exactly as many limbs as are required to represent
+    /// the bit width of `self`, unused high bits of the most significant limb
+    /// are zero.
+    pub fn to_u64_vec(&self) -> Vec<u64> {
+        self.u64_limbs().collect()
+    }
+
     /// Returns the most significant bit of this `ApInt`
     #[inline]
     pub fn msb(&self) -> bool {
@@ -393,6 +537,63 @@ impl ApInt {
         }
     }
 
+    /// Returns the sign of this `ApInt` using **signed** interpretation.
+    ///
+    /// **Note:** `ApInt`s with a bit width of 1 can only represent `0` and
+    /// `-1` and thus never have a positive sign.
+    pub fn sign(&self) -> Sign {
+        if self.msb() {
+            Sign::Negative
+        } else if self.is_zero() {
+            Sign::Zero
+        } else {
+            Sign::Positive
+        }
+    }
+
+    /// Returns `-1`, `0` or `1` with the bit width of `self` depending on the
+    /// sign of this `ApInt` using **signed** interpretation.
+    pub fn signum(&self) -> ApInt {
+        match self.sign() {
+            Sign::Negative => ApInt::all_set(self.width()),
+            Sign::Zero => ApInt::zero(self.width()),
+            Sign::Positive => ApInt::one(self.width()),
+        }
+    }
+
+    /// Returns `true` if this `ApInt` is less than zero using **signed**
+    /// interpretation.
+    pub fn is_strictly_negative(&self) -> bool {
+        self.msb()
+    }
+
+    /// Returns `true` if this `ApInt` is greater than zero using **signed**
+    /// interpretation.
+    pub fn is_strictly_positive(&self) -> bool {
+        self.sign() == Sign::Positive
+    }
+
+    /// Returns `true` if any bit of this `ApInt` within the bit position
+    /// `range` differs from `bit`.
+    ///
+    /// Only the digits overlapping with `range` are inspected.
+    pub(in crate::apint) fn any_bit_in_range_differs(
+        &self,
+        range: Range<usize>,
+        bit: bool,
+    ) -> bool {
+        if range.start >= range.end {
+            return false
+        }
+        let digits = self.as_digit_slice();
+        let first = range.start / Digit::BITS;
+        let last = (range.end - 1) / Digit::BITS;
+        (first..=last).any(|i| {
+            let digit = if bit { !digits[i] } else { digits[i] };
+            !(digit & digit_range_mask(i, &range)).is_zero()
+        })
+    }
+
     /// Returns `true` if this `ApInt` represents an even number.
     /// Equivalent to testing if the least significant bit is zero.
     #[inline]
@@ -407,20 +608,26 @@ impl ApInt {
         self.lsb()
     }
 
-    /// Splits the least significant digits from the rest of the digit slice
-    /// and returns it as well as the remaining part of the digit slice.
-    #[inline]
-    pub(in crate::apint) fn split_least_significant_digit(&self) -> (Digit, &[Digit]) {
-        match self.access_data() {
-            DataAccess::Inl(digit) => (digit, &[]),
-            DataAccess::Ext(digits) => {
-                let (lsd, rest) = digits.split_first().expect(
-                    "An `ApInt` always has at least one digit so calling `split_first` \
-                     on a slice of its digits will never return `None`.",
-                );
-                (*lsd, rest)
+    /// Returns `true` if this `ApInt` interpreted as unsigned integer is a
+    /// power of two.
+    ///
+    /// # Note
+    ///
+    /// - Zero (`0`) is not a power of two.
+    /// - This checks that exactly one bit is set in a single pass over the
+    ///   digits.
+    pub fn is_power_of_two(&self) -> bool {
+        let mut found = false;
+        for digit in self.as_digit_slice() {
+            if digit.is_zero() {
+                continue
+            }
+            if found || !digit.repr().is_power_of_two() {
+                return false
             }
+            found = true;
         }
+        found
     }
 
     /// Splits the most significant digits from the rest of the digit slice
@@ -457,4 +664,58 @@ mod tests {
         assert_eq!(false, ApInt::from_u64(0x70FC_A875_4321_1234).msb());
         assert_eq!(true, ApInt::from_u64(0x8765_4321_5555_6666).msb());
     }
+
+    #[test]
+    fn sign() {
+        for &width in &[2, 8, 64, 65, 128, 200] {
+            let width = BitWidth::new(width).unwrap();
+            let zero = ApInt::zero(width);
+            let one = ApInt::one(width);
+            let minus_one = ApInt::all_set(width);
+            assert_eq!(zero.sign(), Sign::Zero);
+            assert_eq!(one.sign(), Sign::Positive);
+            assert_eq!(minus_one.sign(), Sign::Negative);
+            assert_eq!(ApInt::signed_min_value(width).sign(), Sign::Negative);
+            assert_eq!(ApInt::signed_max_value(width).sign(), Sign::Positive);
+            assert_eq!(zero.signum(), zero);
+            assert_eq!(ApInt::signed_max_value(width).signum(), one);
+            assert_eq!(ApInt::signed_min_value(width).signum(), minus_one);
+            assert!(!zero.is_strictly_positive() && !zero.is_strictly_negative());
+            assert!(one.is_strictly_positive() && !one.is_strictly_negative());
+            assert!(
+                !minus_one.is_strictly_positive() && minus_one.is_strictly_negative()
+            );
+        }
+        // only `0` and `-1` exist for a bit width of 1
+        let zero = ApInt::zero(BitWidth::w1());
+        let minus_one = ApInt::all_set(BitWidth::w1());
+        assert_eq!(zero.sign(), Sign::Zero);
+        assert_eq!(minus_one.sign(), Sign::Negative);
+        assert_eq!(zero.signum(), zero);
+        assert_eq!(minus_one.signum(), minus_one);
+        assert!(!minus_one.is_strictly_positive());
+        assert!(minus_one.is_strictly_negative());
+    }
+
+    #[test]
+    fn is_power_of_two() {
+        use crate::BitWidth;
+        for &width in &[1, 8, 64, 65, 128, 200] {
+            let width = BitWidth::new(width).unwrap();
+            assert!(!ApInt::zero(width).is_power_of_two());
+            assert!(ApInt::one(width).is_power_of_two());
+            assert!(ApInt::signed_min_value(width).is_power_of_two());
+            assert_eq!(
+                ApInt::all_set(width).is_power_of_two(),
+                width.to_usize() == 1
+            );
+        }
+        assert!(ApInt::from(64_u8).is_power_of_two());
+        assert!(!ApInt::from(96_u8).is_power_of_two());
+        assert!(ApInt::from([0_u64, 1 << 17, 0]).is_power_of_two());
+        assert!(ApInt::from([1_u64 << 5, 0, 0]).is_power_of_two());
+        assert!(!ApInt::from([0_u64, 1 << 17, 1]).is_power_of_two());
+        assert!(!ApInt::from([1_u64, 1 << 17, 0]).is_power_of_two());
+        assert!(!ApInt::from([0_u64, 3 << 17, 0]).is_power_of_two());
+    }
 }
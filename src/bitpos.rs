@@ -68,11 +68,11 @@ mod tests {
         #[test]
         fn powers_of_two() {
             assert_eq!(
-                BitPos::new(64).unwrap().to_digit_and_bit_pos(),
+                BitPos::new(Digit::BITS).unwrap().to_digit_and_bit_pos(),
                 (1, BitPos::new(0).unwrap())
             );
             assert_eq!(
-                BitPos::new(256).unwrap().to_digit_and_bit_pos(),
+                BitPos::new(4 * Digit::BITS).unwrap().to_digit_and_bit_pos(),
                 (4, BitPos::new(0).unwrap())
             )
         }
@@ -92,12 +92,14 @@ mod tests {
                 (0, BitPos::new(1).unwrap())
             );
             assert_eq!(
-                BitPos::new(63).unwrap().to_digit_and_bit_pos(),
-                (0, BitPos::new(63).unwrap())
+                BitPos::new(Digit::BITS - 1).unwrap().to_digit_and_bit_pos(),
+                (0, BitPos::new(Digit::BITS - 1).unwrap())
             );
             assert_eq!(
-                BitPos::new(255).unwrap().to_digit_and_bit_pos(),
-                (3, BitPos::new(63).unwrap())
+                BitPos::new(4 * Digit::BITS - 1)
+                    .unwrap()
+                    .to_digit_and_bit_pos(),
+                (3, BitPos::new(Digit::BITS - 1).unwrap())
             )
         }
     }
@@ -111,8 +111,8 @@ impl BitWidth {
     /// Returns the number of exceeding bits that is implied for `ApInt`
     /// instances with this `BitWidth`.
     ///
-    /// For example for an `ApInt` with a `BitWidth` of `140` bits requires
-    /// exactly `3` digits (each with its `64` bits). The third however,
+    /// For example with 64-bit digits an `ApInt` with a `BitWidth` of `140`
+    /// bits requires exactly `3` digits. The third however,
     /// only requires `140 - 128 = 12` bits of its `64` bits in total to
     /// represent the `ApInt` instance. So `excess_bits` returns `12` for
     /// a `BitWidth` that is equal to `140`.
@@ -153,12 +153,23 @@ impl BitWidth {
     pub(crate) fn required_digits(self) -> usize {
         ((self.to_usize() - 1) / Digit::BITS) + 1
     }
+
+    /// Returns the number of 64-bit limbs that are required to represent an
+    /// `ApInt` with this `BitWidth`.
+    ///
+    /// This is equal to `required_digits` for the default 64-bit digits.
+    #[inline]
+    pub(crate) fn required_u64s(self) -> usize {
+        ((self.to_usize() - 1) / 64) + 1
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // the literal expectations are written for the default 64-bit digits
+    #[cfg(not(any(apint_digit = "u32", apint_digit = "u8")))]
     mod excess_bits {
         use super::*;
 
@@ -182,4 +193,32 @@ mod tests {
             assert_eq!(BitWidth::new(300).unwrap().excess_bits(), Some(44));
         }
     }
+
+    mod required_digits {
+        use super::*;
+
+        #[test]
+        fn bounds() {
+            for width in 1..300 {
+                let width = BitWidth::new(width).unwrap();
+                let digits = width.required_digits();
+                assert!(digits * Digit::BITS >= width.to_usize());
+                assert!((digits - 1) * Digit::BITS < width.to_usize());
+                assert_eq!(
+                    width.excess_bits(),
+                    Some(width.to_usize() - (digits - 1) * Digit::BITS)
+                        .filter(|&bits| bits != Digit::BITS)
+                );
+            }
+        }
+
+        #[test]
+        fn u64_limbs() {
+            assert_eq!(BitWidth::w1().required_u64s(), 1);
+            assert_eq!(BitWidth::w64().required_u64s(), 1);
+            assert_eq!(BitWidth::new(65).unwrap().required_u64s(), 2);
+            assert_eq!(BitWidth::w128().required_u64s(), 2);
+            assert_eq!(BitWidth::new(200).unwrap().required_u64s(), 4);
+        }
+    }
 }
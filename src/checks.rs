@@ -33,3 +33,15 @@ where
     }
     Ok(())
 }
+
+#[inline]
+pub(crate) fn verify_digit_rotation<W>(a: &W, count: usize) -> Result<()>
+where
+    W: Width,
+{
+    let width = a.width();
+    if count >= width.required_u64s() {
+        return Err(Error::invalid_shift_amount(count * 64, width))
+    }
+    Ok(())
+}
@@ -24,15 +24,36 @@ use core::ops::{
     Sub,
 };
 
+#[cfg(all(apint_digit = "u32", apint_digit = "u8"))]
+compile_error!("`apint_digit` must be set to at most one of `u32` and `u8`.");
+
 /// The type for the internal `Digit` representation.
 ///
 /// Must be exactly half the size of `DoubleDigitRepr`.
+#[cfg(not(any(apint_digit = "u32", apint_digit = "u8")))]
 pub(crate) type DigitRepr = u64;
+#[cfg(all(apint_digit = "u32", not(apint_digit = "u8")))]
+pub(crate) type DigitRepr = u32;
+#[cfg(apint_digit = "u8")]
+pub(crate) type DigitRepr = u8;
 
 /// The type for the internal `DoubleDigit` representation.
 ///
 /// Must be exactly double the size of `DigitRepr`.
+#[cfg(not(any(apint_digit = "u32", apint_digit = "u8")))]
 pub(crate) type DoubleDigitRepr = u128;
+#[cfg(all(apint_digit = "u32", not(apint_digit = "u8")))]
+pub(crate) type DoubleDigitRepr = u64;
+#[cfg(apint_digit = "u8")]
+pub(crate) type DoubleDigitRepr = u16;
+
+/// The signed counterpart of `DigitRepr`.
+#[cfg(not(any(apint_digit = "u32", apint_digit = "u8")))]
+pub(crate) type SignedDigitRepr = i64;
+#[cfg(all(apint_digit = "u32", not(apint_digit = "u8")))]
+pub(crate) type SignedDigitRepr = i32;
+#[cfg(apint_digit = "u8")]
+pub(crate) type SignedDigitRepr = i8;
 
 const REPR_ONE: DigitRepr = 0x1;
 const REPR_ZERO: DigitRepr = 0x0;
@@ -40,11 +61,13 @@ const REPR_ONES: DigitRepr = !REPR_ZERO;
 
 impl Digit {
     /// The amount of bits within a single `Digit`.
-    pub(crate) const BITS: usize = 64;
+    pub(crate) const BITS: usize = core::mem::size_of::<DigitRepr>() * 8;
     /// A `Digit` with a value of 1
     pub(crate) const ONE: Digit = Digit(REPR_ONE);
     /// A `Digit` with all bits set to 1
     pub(crate) const ONES: Digit = Digit(REPR_ONES);
+    /// The amount of `Digit`s within a single `u64`.
+    pub(crate) const PER_U64: usize = 64 / Digit::BITS;
     /// A `Digit` with a value of 0
     pub(crate) const ZERO: Digit = Digit(REPR_ZERO);
 }
@@ -52,7 +75,11 @@ impl Digit {
 /// A (big) digit within an `ApInt` or similar representations.
 ///
 /// It uses the `DoubleDigit` as computation unit.
+///
+/// **Note:** `Digit` is `repr(transparent)` so that slices of `Digit`s can be
+/// exposed as slices of `DigitRepr`.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
 pub(crate) struct Digit(pub DigitRepr);
 
 use core::fmt;
@@ -211,11 +238,11 @@ impl DoubleDigit {
         DoubleDigit(self.repr().wrapping_mul(other.repr()))
     }
 
-    pub(crate) fn wrapping_div(self, other: DoubleDigit) -> Self {
-        self.wrapping_divrem(other).0
-    }
-
-    #[cfg(not(feature = "specialized_div_rem"))]
+    #[cfg(any(
+        not(feature = "specialized_div_rem"),
+        apint_digit = "u32",
+        apint_digit = "u8"
+    ))]
     pub(crate) fn wrapping_divrem(
         self,
         other: DoubleDigit,
@@ -226,7 +253,10 @@ impl DoubleDigit {
         )
     }
 
-    #[cfg(feature = "specialized_div_rem")]
+    #[cfg(all(
+        feature = "specialized_div_rem",
+        not(any(apint_digit = "u32", apint_digit = "u8"))
+    ))]
     pub(crate) fn wrapping_divrem(
         self,
         other: DoubleDigit,
@@ -236,6 +266,26 @@ impl DoubleDigit {
     }
 }
 
+/// # Conversions from and to `u64` limbs.
+///
+/// Public APIs expose 64-bit limbs independent of the configured `DigitRepr`.
+impl Digit {
+    /// Splits the given `u64` limb into `Digit::PER_U64` digits in
+    /// little-endian order.
+    pub(crate) fn split_u64(limb: u64) -> impl Iterator<Item = Digit> {
+        (0..Digit::PER_U64).map(move |i| Digit((limb >> (i * Digit::BITS)) as DigitRepr))
+    }
+
+    /// Joins up to `Digit::PER_U64` digits in little-endian order into a
+    /// `u64` limb.
+    pub(crate) fn join_u64(digits: &[Digit]) -> u64 {
+        debug_assert!(digits.len() <= Digit::PER_U64);
+        digits.iter().enumerate().fold(0, |limb, (i, digit)| {
+            limb | (u64::from(digit.repr()) << (i * Digit::BITS))
+        })
+    }
+}
+
 /// # Utility & helper methods.
 impl Digit {
     /// Returns the `Digit`'s value as internal representation.
@@ -312,6 +362,19 @@ impl Digit {
         (temp.lo(), temp.hi())
     }
 
+    /// Carry-less (GF(2) polynomial) multiplication of `self` and `other`
+    /// returning the low and high digit of the product.
+    pub(crate) fn carryless_mul(self, other: Digit) -> (Digit, Digit) {
+        let lhs = self.dd().repr();
+        let mut rhs = other.repr();
+        let mut acc = 0;
+        while rhs != 0 {
+            acc ^= lhs << rhs.trailing_zeros();
+            rhs &= rhs - 1;
+        }
+        DoubleDigit(acc).lo_hi()
+    }
+
     pub(crate) fn wrapping_divrem(self, other: Digit) -> (Digit, Digit) {
         (self.wrapping_div(other), self.wrapping_rem(other))
     }
@@ -363,7 +426,7 @@ impl Digit {
         Ok(())
     }
 
-    /// Sign extends this `Digit` from a given `BitWidth` to `64` bits.
+    /// Sign extends this `Digit` from a given `BitWidth` to `Digit::BITS` bits.
     ///
     /// # Note
     ///
@@ -384,26 +447,26 @@ impl Digit {
         self.verify_valid_bitwidth(from)?;
 
         let b = from.to_usize(); // number of bits representing the number in x
-        let x = self.repr() as i64; // sign extend this b-bit number to r
-        let m: i64 = 1 << (b - 1); // mask can be pre-computed if b is fixed
+        let x = self.repr() as SignedDigitRepr; // sign extend this b-bit number to r
+        let m: SignedDigitRepr = 1 << (b - 1); // mask can be pre-computed if b is fixed
         // x = x & ((1 << b) - 1);  // (Skip this if bits in x above position b are
         // already zero.) We don't need this step since this condition is an
         // invariant of `Digit`.
-        let r: i64 = (x ^ m).wrapping_sub(m); // resulting sign-extended number
-        self.0 = r as u64;
+        let r: SignedDigitRepr = (x ^ m).wrapping_sub(m); // resulting sign-extended number
+        self.0 = r as DigitRepr;
         Ok(())
     }
 }
 
 impl Width for Digit {
     fn width(&self) -> BitWidth {
-        BitWidth::w64()
+        BitWidth::from(Digit::BITS)
     }
 }
 
 impl Width for DoubleDigit {
     fn width(&self) -> BitWidth {
-        BitWidth::w128()
+        BitWidth::from(Digit::BITS * 2)
     }
 }
 
@@ -599,6 +662,29 @@ impl Not for Digit {
 mod tests {
     use super::*;
 
+    mod u64_limbs {
+        use super::*;
+
+        #[test]
+        fn split_join() {
+            for &limb in &[0, 1, 0xff, 0x0123_4567_89ab_cdef, u64::MAX] {
+                let digits = Digit::split_u64(limb).collect::<Vec<_>>();
+                assert_eq!(digits.len(), Digit::PER_U64);
+                assert_eq!(digits[0].repr(), limb as DigitRepr);
+                assert_eq!(Digit::join_u64(&digits), limb);
+            }
+        }
+
+        #[test]
+        fn join_partial() {
+            let digits = Digit::split_u64(u64::MAX).collect::<Vec<_>>();
+            assert_eq!(Digit::join_u64(&digits[..1]), u64::from(Digit::ONES.repr()));
+            assert_eq!(Digit::join_u64(&[]), 0);
+        }
+    }
+
+    // the following tests are written for the default 64-bit digits
+    #[cfg(not(any(apint_digit = "u32", apint_digit = "u8")))]
     mod double_digit {
         use super::*;
 
@@ -857,6 +943,7 @@ mod tests {
         }
     }
 
+    #[cfg(not(any(apint_digit = "u32", apint_digit = "u8")))]
     mod digit {
         use super::*;
 
@@ -17,12 +17,19 @@ use core::{
     result,
 };
 #[cfg(feature = "std")]
-use std::error;
+use std::{
+    error,
+    io,
+};
 
 /// Represents the kind of an `Error`.
 ///
 /// This also stores the unique information tied to the error report.
+///
+/// Some variants depend on the enabled crate features, e.g. `Io` requires the
+/// `std` feature, so matching on it always requires a wildcard arm.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum ErrorKind {
     /// Returned on trying to create a `Radix` from an invalid `u8`
     /// representation.
@@ -66,6 +73,9 @@ pub enum ErrorKind {
         /// The `PrimitiveTy` that the user wanted for representing the given
         /// `ApInt`.
         destination_ty: PrimitiveTy,
+        /// The number of bits actually required to represent the value with
+        /// the signedness of the `PrimitiveTy`.
+        required_bits: usize,
     },
 
     /// Returned on violation of matching bitwidth constraints of operations.
@@ -103,10 +113,47 @@ pub enum ErrorKind {
 
     /// Returned on constructing an `ApInt` from an empty iterator of `Digit`s.
     ExpectedNonEmptyDigits,
+
+    /// Returned on trying to take the zeroth root of an `ApInt`.
+    InvalidRootDegree(u32),
+
+    /// Returned on a double-width division where the quotient does not fit
+    /// into the bit width of the divisor.
+    QuotientOverflow,
+
+    /// Returned when the exact result of an operation does not fit into the
+    /// requested bit width.
+    ResultOverflow(BitWidth),
+
+    /// Returned on trying to convert a NaN or infinite floating point number
+    /// into an `ApInt`.
+    NonFiniteFloat,
+
+    /// Returned when a buffer does not have the exact length required to hold
+    /// the contents of an `ApInt`.
+    UnmatchingBufferLength {
+        /// The required length of the buffer.
+        expected: usize,
+        /// The actual length of the buffer.
+        actual: usize,
+    },
+
+    /// Returned on decoding an `ApInt` from bytes that are not a valid
+    /// encoding, e.g. because they are truncated.
+    InvalidEncoding,
+
+    /// Returned on trying to sample from an empty range of values.
+    EmptyRange,
+
+    /// Returned when reading an `ApInt` from an I/O source fails, e.g. because
+    /// it ends within the value.
+    #[cfg(feature = "std")]
+    Io(io::ErrorKind),
 }
 
 /// All division operations that may be affected by division-by-zero errors.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum DivOp {
     /// The unsigned quotient and remainder operation
     UnsignedDivRem,
@@ -124,6 +171,28 @@ pub enum DivOp {
     SignedDiv,
     /// The signed remainder operation.
     SignedRem,
+    /// The signed euclidean quotient operation.
+    SignedDivEuclid,
+    /// The signed euclidean remainder operation.
+    SignedRemEuclid,
+    /// The signed floored quotient operation.
+    SignedDivFloor,
+    /// The signed floored remainder operation.
+    SignedRemFloor,
+    /// The unsigned ceiled quotient operation.
+    UnsignedDivCeil,
+    /// The signed ceiled quotient operation.
+    SignedDivCeil,
+    /// The unsigned next multiple operation.
+    UnsignedNextMultipleOf,
+    /// The signed next multiple operation.
+    SignedNextMultipleOf,
+    /// The unsigned double-width quotient and remainder operation.
+    UnsignedDivWide,
+    /// The unsigned modular exponentiation operation.
+    ModPow,
+    /// The unsigned modular inverse operation.
+    ModInverse,
 }
 
 /// Represents an error that may occur upon using the `ApInt` library.
@@ -224,7 +293,7 @@ impl Error {
             kind: ErrorKind::InvalidStringRepr {
                 input,
                 radix,
-                pos_char: None,
+                pos_char: Some((pos, ch)),
             },
             message: format!(
                 "Encountered an invalid character (= '{:?}') at position {:?} within \
@@ -349,19 +418,103 @@ impl Error {
         }
     }
 
+    pub(crate) fn invalid_root_degree(degree: u32) -> Error {
+        Error {
+            kind: ErrorKind::InvalidRootDegree(degree),
+            message: format!("Encountered invalid root degree of {:?}.", degree),
+            annotation: None,
+        }
+    }
+
+    pub(crate) fn quotient_overflow() -> Error {
+        Error {
+            kind: ErrorKind::QuotientOverflow,
+            message: "Encountered a double-width division where the high part of the \
+                      dividend is not less than the divisor, so the quotient does not \
+                      fit into the bit width of the divisor."
+                .to_owned(),
+            annotation: None,
+        }
+    }
+
+    pub(crate) fn result_overflow(width: BitWidth) -> Error {
+        Error {
+            kind: ErrorKind::ResultOverflow(width),
+            message: format!(
+                "Encountered an exact result that does not fit into the bit width of \
+                 {:?}.",
+                width
+            ),
+            annotation: None,
+        }
+    }
+
+    pub(crate) fn non_finite_float(value: f64) -> Error {
+        Error {
+            kind: ErrorKind::NonFiniteFloat,
+            message: format!(
+                "Encountered the non-finite floating point number {} that cannot be \
+                 represented by an `ApInt`.",
+                value
+            ),
+            annotation: None,
+        }
+    }
+
+    pub(crate) fn unmatching_buffer_length(expected: usize, actual: usize) -> Error {
+        Error {
+            kind: ErrorKind::UnmatchingBufferLength { expected, actual },
+            message: format!(
+                "Encountered a buffer with a length of {} where a length of {} was \
+                 required.",
+                actual, expected
+            ),
+            annotation: None,
+        }
+    }
+
+    pub(crate) fn invalid_encoding() -> Error {
+        Error {
+            kind: ErrorKind::InvalidEncoding,
+            message: "Encountered an invalid encoding upon decoding an `ApInt` from \
+                      bytes."
+                .to_owned(),
+            annotation: None,
+        }
+    }
+
+    pub(crate) fn empty_range() -> Error {
+        Error {
+            kind: ErrorKind::EmptyRange,
+            message: "Encountered an empty range of values to sample from.".to_owned(),
+            annotation: None,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn io(err: io::Error) -> Error {
+        Error {
+            kind: ErrorKind::Io(err.kind()),
+            message: format!("Encountered an I/O error: {}", err),
+            annotation: None,
+        }
+    }
+
     pub(crate) fn encountered_unrepresentable_value(
         value: ApInt,
         destination_ty: PrimitiveTy,
     ) -> Error {
+        let required_bits = value.required_bits(destination_ty.is_signed());
         let message = format!(
             "Encountered a value ({:?}) that is unrepresentable by the destination type \
-             {:?}.",
-            value, destination_ty
+             {:?} since it requires {} bits.",
+            value, destination_ty, required_bits
         );
         Error {
             kind: ErrorKind::ValueUnrepresentable {
                 value,
                 destination_ty,
+                required_bits,
             },
             message,
             annotation: None,
@@ -2,6 +2,10 @@
 //! `std_ops.rs`
 
 use crate::{
+    mem::{
+        format,
+        string::String,
+    },
     utils::{
         forward_bin_mut_impl,
         forward_mut_impl,
@@ -10,8 +14,11 @@ use crate::{
     ApInt,
     BitPos,
     BitWidth,
+    Error,
+    Radix,
     Result,
     ShiftAmount,
+    Sign,
     UInt,
     Width,
 };
@@ -19,7 +26,10 @@ use crate::{
 #[cfg(feature = "rand_support")]
 use rand;
 
-use core::cmp::Ordering;
+use core::{
+    cmp::Ordering,
+    convert::TryFrom,
+};
 
 /// Signed machine integer with arbitrary bitwidths and modulo arithmetics.
 ///
@@ -99,6 +109,12 @@ impl Int {
         Int::from(ApInt::from_i128(val))
     }
 
+    /// Creates a new `Int` from a given `isize` value with a bit-width equal
+    /// to the pointer width of the target platform.
+    pub fn from_isize(val: isize) -> Int {
+        Int::from(ApInt::from_isize(val))
+    }
+
     /// Creates a new `Int` with the given bit width that represents zero.
     pub fn zero(width: BitWidth) -> Int {
         Int::from(ApInt::zero(width))
@@ -181,25 +197,62 @@ impl From<i128> for Int {
     }
 }
 
-macro_rules! impl_from_array_for_Int {
-    ($n:expr) => {
-        impl From<[i64; $n]> for Int {
-            fn from(val: [i64; $n]) -> Int {
-                Int::from(ApInt::from(val))
-            }
-        }
-    };
+impl From<isize> for Int {
+    fn from(val: isize) -> Int {
+        Int::from_isize(val)
+    }
 }
 
-impl_from_array_for_Int!(2); // 128 bits
-impl_from_array_for_Int!(3); // 192 bits
-impl_from_array_for_Int!(4); // 256 bits
-impl_from_array_for_Int!(5); // 320 bits
-impl_from_array_for_Int!(6); // 384 bits
-impl_from_array_for_Int!(7); // 448 bits
-impl_from_array_for_Int!(8); // 512 bits
-impl_from_array_for_Int!(16); // 1024 bits
-impl_from_array_for_Int!(32); // 2048 bits
+/// Creates a new `Int` with a bit width of `64 * N` from the given array of
+/// 64-bit limbs in big-endian order, i.e. `val[0]` holds the most significant
+/// bits.
+///
+/// # Panics
+///
+/// - If `N` is zero.
+impl<const N: usize> From<[i64; N]> for Int {
+    fn from(val: [i64; N]) -> Int {
+        Int::from(ApInt::from(val))
+    }
+}
+
+/// Converts the two's complement value of the given `Int` into a `BigInt`.
+#[cfg(feature = "num-bigint")]
+impl From<&Int> for num_bigint::BigInt {
+    fn from(value: &Int) -> num_bigint::BigInt {
+        value.value.to_bigint_signed()
+    }
+}
+
+/// Converts the given `BigInt` into an `Int` with the minimum bit width
+/// required to represent its value in two's complement.
+#[cfg(feature = "num-bigint")]
+impl From<&num_bigint::BigInt> for Int {
+    fn from(value: &num_bigint::BigInt) -> Int {
+        let width = BitWidth::new(ApInt::bigint_signed_bits(value) as usize).expect(
+            "The number of bits required for two's complement is at least `1` so we can \
+             expect `BitWidth::new` to be successful.",
+        );
+        Int::try_from_bigint(value, width).expect(
+            "The bit width is chosen to fit the value of `value` so we can expect the \
+             conversion to be successful.",
+        )
+    }
+}
+
+/// # Conversions with `num-bigint`
+#[cfg(feature = "num-bigint")]
+impl Int {
+    /// Creates a new `Int` with the given `width` that represents the value
+    /// of the given `BigInt` in two's complement.
+    ///
+    /// # Errors
+    ///
+    /// - If the value of `value` does not fit into `width`.
+    pub fn try_from_bigint(value: &num_bigint::BigInt, width: BitWidth) -> Result<Int> {
+        ApInt::try_from_bigint_signed(value, width).map(Int::from)
+    }
+}
 
 /// # Utilities
 impl Int {
@@ -239,29 +292,29 @@ impl Int {
         self.value.is_odd()
     }
 
-    /// Returns `true` if the value of this `Int` is positive.
+    /// Returns `true` if the value of this `Int` is greater than zero.
     pub fn is_positive(&self) -> bool {
-        !self.msb()
+        self.value.is_strictly_positive()
     }
 
-    /// Returns `true` if the value of this `Int` is negative.
+    /// Returns `true` if the value of this `Int` is less than zero.
     pub fn is_negative(&self) -> bool {
-        !self.is_positive()
+        self.value.is_strictly_negative()
+    }
+
+    /// Returns the sign of this `Int`.
+    pub fn sign(&self) -> Sign {
+        self.value.sign()
     }
 
-    /// Returns a number representing sign of this `ApInt`.
+    /// Returns an `Int` with the bit width of `self` representing the sign of
+    /// this `Int`.
     ///
     /// - `0` if the number is zero
     /// - `1` if the number is positive
     /// - `-1` if the number is negative
-    pub fn signum(&self) -> i8 {
-        if self.is_zero() {
-            return 0
-        }
-        if self.is_negative() {
-            return -1
-        }
-        1
+    pub fn signum(&self) -> Int {
+        Int::from(self.value.signum())
     }
 
     /// Returns an absolute value representation of this `Int`.
@@ -278,9 +331,32 @@ impl Int {
     ///
     /// - Does nothing for positive `Int` instances.
     pub fn wrapping_abs(&mut self) {
-        if self.msb() {
-            self.wrapping_neg()
+        self.value.wrapping_abs()
+    }
+
+    /// Returns the absolute value of this `Int` or `None` if `self` is the
+    /// minimum value of its bit width.
+    pub fn checked_abs(self) -> Option<Int> {
+        self.value.into_checked_abs().map(Int::from)
+    }
+
+    /// Returns the magnitude of this `Int` as `UInt` of the same bit width.
+    ///
+    /// **Note:** This always succeeds, even for the minimum value of the bit
+    /// width.
+    pub fn unsigned_abs(self) -> UInt {
+        UInt::from(self.value.into_unsigned_abs())
+    }
+
+    /// Returns the floor of the square root of this `Int` or `None` if it is
+    /// negative.
+    ///
+    /// **Note:** This function allocates memory.
+    pub fn checked_isqrt(self) -> Option<Int> {
+        if self.is_negative() {
+            return None
         }
+        Some(Int::from(self.value.into_isqrt()))
     }
 }
 
@@ -350,37 +426,73 @@ impl Int {
     }
 }
 
-/// If `self` and `rhs` have unmatching bit widths, `None` will be returned for
-/// `partial_cmp` and `false` will be returned for the rest of the `PartialOrd`
-/// methods.
+/// Compares by the **signed** numeric value, also if `self` and `rhs` have
+/// unmatching bit widths.
+///
+/// Two instances of unmatching bit widths are never equal, so if their values
+/// are equal the one with the smaller bit width is ordered first.
+impl Ord for Int {
+    fn cmp(&self, rhs: &Int) -> Ordering {
+        self.value
+            .scmp_value(&rhs.value)
+            .then_with(|| self.width().cmp(&rhs.width()))
+    }
+}
+
 impl PartialOrd for Int {
     fn partial_cmp(&self, rhs: &Int) -> Option<Ordering> {
-        if self.width() != rhs.width() {
-            return None
-        }
-        if self.checked_lt(rhs).unwrap() {
-            return Some(Ordering::Less)
-        }
-        if self.value == rhs.value {
-            return Some(Ordering::Equal)
-        }
-        Some(Ordering::Greater)
+        Some(self.cmp(rhs))
     }
+}
+
+/// Compares the value of `self` with a primitive regardless of the bit width
+/// of `self` and without allocating.
+impl PartialEq<i64> for Int {
+    fn eq(&self, rhs: &i64) -> bool {
+        self.value.eq_i64(*rhs)
+    }
+}
+
+/// Compares the value of `self` with a primitive regardless of the bit width
+/// of `self` and without allocating.
+impl PartialOrd<i64> for Int {
+    fn partial_cmp(&self, rhs: &i64) -> Option<Ordering> {
+        Some(self.value.scmp_i64(*rhs))
+    }
+}
 
-    fn lt(&self, rhs: &Int) -> bool {
-        self.checked_lt(rhs).unwrap_or(false)
+/// Compares the value of `self` with a primitive regardless of the bit width
+/// of `self` and without allocating.
+impl PartialEq<i128> for Int {
+    fn eq(&self, rhs: &i128) -> bool {
+        self.value.scmp_i128(*rhs) == Ordering::Equal
     }
+}
 
-    fn le(&self, rhs: &Int) -> bool {
-        self.checked_le(rhs).unwrap_or(false)
+/// Compares the value of `self` with a primitive regardless of the bit width
+/// of `self` and without allocating.
+impl PartialOrd<i128> for Int {
+    fn partial_cmp(&self, rhs: &i128) -> Option<Ordering> {
+        Some(self.value.scmp_i128(*rhs))
     }
+}
 
-    fn gt(&self, rhs: &Int) -> bool {
-        self.checked_gt(rhs).unwrap_or(false)
+/// Compares the value of `self` with a primitive regardless of the bit width
+/// of `self` and without allocating. Negative values are less than any `u64`.
+impl PartialEq<u64> for Int {
+    fn eq(&self, rhs: &u64) -> bool {
+        !self.is_negative() && self.value.eq_u64(*rhs)
     }
+}
 
-    fn ge(&self, rhs: &Int) -> bool {
-        self.checked_ge(rhs).unwrap_or(false)
+/// Compares the value of `self` with a primitive regardless of the bit width
+/// of `self` and without allocating. Negative values are less than any `u64`.
+impl PartialOrd<u64> for Int {
+    fn partial_cmp(&self, rhs: &u64) -> Option<Ordering> {
+        if self.is_negative() {
+            return Some(Ordering::Less)
+        }
+        Some(self.value.ucmp_u64(*rhs))
     }
 }
 
@@ -456,14 +568,12 @@ impl Int {
     ///
     /// # Note
     ///
-    /// This returns `true` if the value represented by this `Int`
-    /// is `1`, returns `false` if the value represented by this
-    /// `Int` is `0` and returns an error otherwise.
+    /// This returns `true` if the single bit of this `Int` is set and
+    /// returns `false` otherwise.
     ///
     /// # Errors
     ///
-    /// - If the value represented by this `Int` can not be represented by a
-    ///   `bool`.
+    /// - If the bit width of this `Int` is not `1`.
     pub fn try_to_bool(&self) -> Result<bool> {
         self.value.try_to_bool()
     }
@@ -546,8 +656,42 @@ impl Int {
     pub fn try_to_i128(&self) -> Result<i128> {
         self.value.try_to_i128()
     }
+
+    /// Tries to represent the value of this `Int` as a `isize`.
+    ///
+    /// # Note
+    ///
+    /// - This conversion is possible as long as the value represented by this
+    ///   `Int` is within the range of `isize`.
+    ///
+    /// # Errors
+    ///
+    /// - If the value represented by this `Int` can not be represented by a
+    ///   `isize`.
+    pub fn try_to_isize(&self) -> Result<isize> {
+        self.value.try_to_isize()
+    }
+}
+
+macro_rules! impl_try_from_int_for_primitive {
+    ($prim:ty, $method:ident) => {
+        impl TryFrom<&Int> for $prim {
+            type Error = Error;
+
+            fn try_from(value: &Int) -> Result<$prim> {
+                value.$method()
+            }
+        }
+    };
 }
 
+impl_try_from_int_for_primitive!(i8, try_to_i8);
+impl_try_from_int_for_primitive!(i16, try_to_i16);
+impl_try_from_int_for_primitive!(i32, try_to_i32);
+impl_try_from_int_for_primitive!(i64, try_to_i64);
+impl_try_from_int_for_primitive!(i128, try_to_i128);
+impl_try_from_int_for_primitive!(isize, try_to_isize);
+
 /// # Shifts
 impl Int {
     /// Shift this `Int` left by the given `shift_amount` bits.
@@ -988,6 +1132,12 @@ impl Int {
     pub fn trailing_zeros(&self) -> usize {
         self.value.trailing_zeros()
     }
+
+    /// Returns `true` if the number of set bits in the binary representation
+    /// of this `Int` is odd.
+    pub fn parity(&self) -> bool {
+        self.value.parity()
+    }
 }
 
 /// # Arithmetic Operations
@@ -1006,6 +1156,20 @@ impl Int {
         self.value.wrapping_neg()
     }
 
+    /// Negates this `Int` and returns the result as well as a boolean
+    /// indicating if overflow occured, which is only the case for the minimum
+    /// value of the bit width.
+    pub fn overflowing_neg(self) -> (Int, bool) {
+        let (neg, overflow) = self.value.into_overflowing_neg();
+        (Int::from(neg), overflow)
+    }
+
+    /// Negates this `Int` and returns the result or `None` if `self` is the
+    /// minimum value of the bit width.
+    pub fn checked_neg(self) -> Option<Int> {
+        self.value.into_checked_neg().map(Int::from)
+    }
+
     /// Adds `rhs` to `self` and returns the result.
     ///
     /// **Note:** This will **not** allocate memory.
@@ -1058,6 +1222,110 @@ impl Int {
         self.value.wrapping_sub_assign(&rhs.value)
     }
 
+    /// Adds `rhs` to `self` and returns the result, or `None` if the
+    /// addition overflowed.
+    ///
+    /// **Note:** This will **not** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn checked_add(self, rhs: &Int) -> Result<Option<Int>> {
+        Ok(self
+            .value
+            .into_overflow_checked_sadd(&rhs.value)?
+            .map(Int::from))
+    }
+
+    /// Subtracts `rhs` from `self` and returns the result, or `None` if the
+    /// subtraction overflowed.
+    ///
+    /// **Note:** This will **not** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn checked_sub(self, rhs: &Int) -> Result<Option<Int>> {
+        Ok(self
+            .value
+            .into_overflow_checked_ssub(&rhs.value)?
+            .map(Int::from))
+    }
+
+    /// Multiplies `rhs` with `self` and returns the result, or `None` if the
+    /// multiplication overflowed.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn checked_mul(self, rhs: &Int) -> Result<Option<Int>> {
+        Ok(self
+            .value
+            .into_overflow_checked_smul(&rhs.value)?
+            .map(Int::from))
+    }
+
+    /// Adds `rhs` to `self` and returns the result, clamped to
+    /// the minimum or maximum value of the bit width on overflow.
+    ///
+    /// **Note:** This will **not** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn into_saturating_add(self, rhs: &Int) -> Result<Int> {
+        try_forward_bin_mut_impl(self, rhs, Int::saturating_add_assign)
+    }
+
+    /// Add-assigns `rhs` to `self` inplace, clamping the result to
+    /// the minimum or maximum value of the bit width on overflow.
+    ///
+    /// **Note:** This will **not** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn saturating_add_assign(&mut self, rhs: &Int) -> Result<()> {
+        self.value.saturating_sadd_assign(&rhs.value)
+    }
+
+    /// Subtracts `rhs` from `self` and returns the result, clamped to
+    /// the minimum or maximum value of the bit width on overflow.
+    ///
+    /// **Note:** This will **not** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn into_saturating_sub(self, rhs: &Int) -> Result<Int> {
+        try_forward_bin_mut_impl(self, rhs, Int::saturating_sub_assign)
+    }
+
+    /// Subtract-assigns `rhs` from `self` inplace, clamping the result to
+    /// the minimum or maximum value of the bit width on overflow.
+    ///
+    /// **Note:** This will **not** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn saturating_sub_assign(&mut self, rhs: &Int) -> Result<()> {
+        self.value.saturating_ssub_assign(&rhs.value)
+    }
+
+    /// Returns the absolute difference between `self` and `rhs`.
+    ///
+    /// **Note:** The result is a `UInt` since the difference between
+    /// `max_value` and `min_value` is not representable by an `Int` of the
+    /// same bit width.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn abs_diff(&self, rhs: &Int) -> Result<UInt> {
+        Ok(UInt::from(self.value.sabs_diff(&rhs.value)?))
+    }
+
     /// Subtracts `rhs` from `self` and returns the result.
     ///
     /// # Note
@@ -1088,6 +1356,57 @@ impl Int {
         self.value.wrapping_mul_assign(&rhs.value)
     }
 
+    /// Multiplies `rhs` with `self` and returns the result, clamped to the
+    /// minimum or maximum value of the bit width on overflow.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn into_saturating_mul(self, rhs: &Int) -> Result<Int> {
+        try_forward_bin_mut_impl(self, rhs, Int::saturating_mul_assign)
+    }
+
+    /// Multiply-assigns `rhs` to `self` inplace, clamping the result to the
+    /// minimum or maximum value of the bit width on overflow.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn saturating_mul_assign(&mut self, rhs: &Int) -> Result<()> {
+        self.value.saturating_smul_assign(&rhs.value)
+    }
+
+    /// Raises `self` to the power of `exp` and returns the result, wrapping
+    /// around on overflow.
+    ///
+    /// **Note:** Zero to the power of zero yields one.
+    pub fn into_wrapping_pow(self, exp: u32) -> Int {
+        Int::from(self.value.into_wrapping_pow(exp))
+    }
+
+    /// Raises `self` to the power of `exp` inplace, wrapping around on
+    /// overflow.
+    ///
+    /// **Note:** Zero to the power of zero yields one.
+    pub fn wrapping_pow_assign(&mut self, exp: u32) {
+        self.value.wrapping_pow_assign(exp)
+    }
+
+    /// Raises `self` to the power of `exp` and returns the result as well as a
+    /// boolean indicating if overflow occured.
+    pub fn overflowing_pow(self, exp: u32) -> (Int, bool) {
+        let (pow, overflow) = self.value.into_overflowing_spow(exp);
+        (Int::from(pow), overflow)
+    }
+
+    /// Returns `self` to the power of `exp` or `None` if the result is not
+    /// representable by the bit width of `self`.
+    ///
+    /// **Note:** This function allocates memory.
+    pub fn checked_pow(&self, exp: u32) -> Option<Int> {
+        self.value.checked_spow(exp).map(Int::from)
+    }
+
     /// Divides `self` by `rhs` and returns the result.
     ///
     /// # Note
@@ -1100,6 +1419,7 @@ impl Int {
     /// # Errors
     ///
     /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
     pub fn into_wrapping_div(self, rhs: &Int) -> Result<Int> {
         try_forward_bin_mut_impl(self, rhs, Int::wrapping_div_assign)
     }
@@ -1116,6 +1436,7 @@ impl Int {
     /// # Errors
     ///
     /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
     pub fn wrapping_div_assign(&mut self, rhs: &Int) -> Result<()> {
         self.value.wrapping_sdiv_assign(&rhs.value)
     }
@@ -1133,6 +1454,7 @@ impl Int {
     /// # Errors
     ///
     /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
     pub fn into_wrapping_rem(self, rhs: &Int) -> Result<Int> {
         try_forward_bin_mut_impl(self, rhs, Int::wrapping_rem_assign)
     }
@@ -1149,38 +1471,205 @@ impl Int {
     /// # Errors
     ///
     /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
     pub fn wrapping_rem_assign(&mut self, rhs: &Int) -> Result<()> {
         self.value.wrapping_srem_assign(&rhs.value)
     }
+
+    /// Calculates the euclidean quotient of `self` by `rhs` and returns the
+    /// result.
+    ///
+    /// The quotient is rounded such that the remainder is never negative.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn into_div_euclid(self, rhs: &Int) -> Result<Int> {
+        try_forward_bin_mut_impl(self, rhs, Int::div_euclid_assign)
+    }
+
+    /// Assignes `self` to the euclidean quotient of `self` by `rhs`.
+    ///
+    /// The quotient is rounded such that the remainder is never negative.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn div_euclid_assign(&mut self, rhs: &Int) -> Result<()> {
+        self.value.sdiv_euclid_assign(&rhs.value)
+    }
+
+    /// Calculates the euclidean remainder of `self` by `rhs` and returns the
+    /// result.
+    ///
+    /// The remainder is never negative.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn into_rem_euclid(self, rhs: &Int) -> Result<Int> {
+        try_forward_bin_mut_impl(self, rhs, Int::rem_euclid_assign)
+    }
+
+    /// Assignes `self` to the euclidean remainder of `self` by `rhs`.
+    ///
+    /// The remainder is never negative.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn rem_euclid_assign(&mut self, rhs: &Int) -> Result<()> {
+        self.value.srem_euclid_assign(&rhs.value)
+    }
+
+    /// Calculates the quotient of `self` by `rhs` rounded towards negative
+    /// infinity and returns the result.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn into_div_floor(self, rhs: &Int) -> Result<Int> {
+        try_forward_bin_mut_impl(self, rhs, Int::div_floor_assign)
+    }
+
+    /// Assignes `self` to the quotient of `self` by `rhs` rounded towards
+    /// negative infinity.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn div_floor_assign(&mut self, rhs: &Int) -> Result<()> {
+        self.value.sdiv_floor_assign(&rhs.value)
+    }
+
+    /// Calculates the quotient of `self` by `rhs` rounded towards positive
+    /// infinity and returns the result.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn into_div_ceil(self, rhs: &Int) -> Result<Int> {
+        try_forward_bin_mut_impl(self, rhs, Int::div_ceil_assign)
+    }
+
+    /// Assignes `self` to the quotient of `self` by `rhs` rounded towards
+    /// positive infinity.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn div_ceil_assign(&mut self, rhs: &Int) -> Result<()> {
+        self.value.sdiv_ceil_assign(&rhs.value)
+    }
+
+    /// Returns the next multiple of `rhs`, or `None` if it is not representable
+    /// by the bit width.
+    ///
+    /// For positive `rhs` this is the smallest multiple greater than or equal
+    /// to `self`, and for negative `rhs` the largest multiple less than or
+    /// equal to `self`.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn checked_next_multiple_of(&self, rhs: &Int) -> Result<Option<Int>> {
+        Ok(self
+            .value
+            .checked_snext_multiple_of(&rhs.value)?
+            .map(Int::from))
+    }
+}
+
+/// # Serialization
+impl Int {
+    /// Returns a `String` representation of the **signed** value of this `Int`
+    /// for the given `radix`.
+    ///
+    /// Negative values are represented by a leading minus (`-`) followed by
+    /// their magnitude. For more details look into
+    /// [`ApInt::to_string_radix`](struct.ApInt.html#method.to_string_radix).
+    ///
+    /// # Panics
+    ///
+    /// - If `radix` is converted from an `u8` outside of `2..=36`. Use
+    ///   [`try_to_string_radix`](struct.Int.html#method.try_to_string_radix)
+    ///   to handle invalid radices.
+    pub fn to_string_radix<R>(&self, radix: R) -> String
+    where
+        R: Into<Radix>,
+    {
+        let magnitude = self.value.clone().into_unsigned_abs();
+        let digits = magnitude.to_string_radix(radix);
+        if self.is_negative() {
+            format!("-{}", digits)
+        } else {
+            digits
+        }
+    }
+
+    /// Returns a `String` representation of the **signed** value of this `Int`
+    /// for the given `radix`.
+    ///
+    /// For more details look into
+    /// [`to_string_radix`](struct.Int.html#method.to_string_radix).
+    ///
+    /// # Errors
+    ///
+    /// - If `radix` is not within `2..=36`.
+    pub fn try_to_string_radix(&self, radix: u8) -> Result<String> {
+        Ok(self.to_string_radix(Radix::new(radix)?))
+    }
 }
 
 // ============================================================================
-//  Binary, Oct, LowerHex and UpperHex implementations
+//  Binary, Oct, LowerHex, UpperHex and Display implementations
+//
+//  Like for the primitive signed integers the non-decimal representations
+//  format the bits of the two's complement representation.
 // ============================================================================
 
 use core::fmt;
 
 impl fmt::Binary for Int {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.value.fmt(f)
+        fmt::Binary::fmt(&self.value, f)
     }
 }
 
 impl fmt::Octal for Int {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.value.fmt(f)
+        fmt::Octal::fmt(&self.value, f)
     }
 }
 
 impl fmt::LowerHex for Int {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.value.fmt(f)
+        fmt::LowerHex::fmt(&self.value, f)
     }
 }
 
 impl fmt::UpperHex for Int {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.value.fmt(f)
+        fmt::UpperHex::fmt(&self.value, f)
+    }
+}
+
+impl fmt::Display for Int {
+    /// Formats the **signed** decimal value of this `Int`.
+    ///
+    /// Negative values are formatted with a leading minus (`-`) followed by
+    /// their magnitude.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.fmt_signed_decimal(f)
     }
 }
 
@@ -1204,5 +1693,244 @@ mod tests {
                 Some(Int::from([0i64, 0, 1]))
             );
         }
+
+        #[test]
+        fn neg() {
+            let minus_one = Int::all_set(BitWidth::w1());
+            assert_eq!(-minus_one.clone(), minus_one);
+            assert_eq!(minus_one.clone().checked_neg(), None);
+            assert_eq!(minus_one.clone().overflowing_neg(), (minus_one, true));
+            assert_eq!(-Int::from_i8(5), Int::from_i8(-5));
+            assert_eq!(Int::from_i8(-5).checked_neg(), Some(Int::from_i8(5)));
+            assert_eq!(Int::min_value(BitWidth::w8()).checked_neg(), None);
+        }
+
+        #[test]
+        fn checked_isqrt() {
+            assert_eq!(Int::from_i8(-1).checked_isqrt(), None);
+            assert_eq!(Int::min_value(BitWidth::w64()).checked_isqrt(), None);
+            assert_eq!(Int::from_i8(0).checked_isqrt(), Some(Int::from_i8(0)));
+            assert_eq!(Int::from_i8(120).checked_isqrt(), Some(Int::from_i8(10)));
+            assert_eq!(Int::from_i8(127).checked_isqrt(), Some(Int::from_i8(11)));
+        }
+
+        #[test]
+        fn abs_diff() {
+            let (min, max) = (Int::from_i8(i8::MIN), Int::from_i8(i8::MAX));
+            assert_eq!(min.abs_diff(&max).unwrap(), UInt::from_u8(u8::MAX));
+            assert_eq!(max.abs_diff(&min).unwrap(), UInt::from_u8(u8::MAX));
+            assert_eq!(min.abs_diff(&min).unwrap(), UInt::from_u8(0));
+            assert_eq!(
+                Int::from_i8(-3).abs_diff(&Int::from_i8(4)).unwrap(),
+                UInt::from_u8(7)
+            );
+            assert!(min.abs_diff(&Int::from_i16(0)).is_err());
+        }
+
+        #[test]
+        fn ord() {
+            let mut v = vec![
+                Int::from_i64(300),
+                Int::from_i8(-7),
+                Int::from_i128(i128::MIN),
+                Int::from_i8(0),
+                Int::from_i16(-7),
+                Int::from_i8(i8::MAX),
+                Int::from_i64(-300),
+                Int::from_i128(i128::MAX),
+            ];
+            v.sort();
+            assert_eq!(
+                v,
+                vec![
+                    Int::from_i128(i128::MIN),
+                    Int::from_i64(-300),
+                    Int::from_i8(-7),
+                    Int::from_i16(-7),
+                    Int::from_i8(0),
+                    Int::from_i8(i8::MAX),
+                    Int::from_i64(300),
+                    Int::from_i128(i128::MAX),
+                ]
+            );
+            assert!(Int::from_i8(-1) < Int::from_i8(1));
+            assert!(Int::from_i8(-1) < Int::from_i64(0));
+            assert!(Int::from_i64(-1) > Int::from_i8(i8::MIN));
+            let values = [i16::MIN, -300, -1, 0, 1, 300, i16::MAX];
+            for &a in &values {
+                for &b in &values {
+                    assert_eq!(Int::from_i16(a).cmp(&Int::from_i16(b)), a.cmp(&b));
+                }
+            }
+        }
+
+        #[test]
+        fn to_string_radix() {
+            for &value in &[0, 1, -1, 42, -42, i128::MAX, i128::MIN, i128::MIN + 1] {
+                assert_eq!(Int::from_i128(value).to_string_radix(10), value.to_string());
+            }
+            assert_eq!(Int::from_i8(i8::MIN).to_string_radix(16), "-80");
+            assert_eq!(Int::from_i8(-5).to_string_radix(2), "-101");
+            assert_eq!(Int::all_set(BitWidth::w1()).to_string_radix(10), "-1");
+            assert_eq!(Int::from_i16(-1295).try_to_string_radix(36), Ok("-zz".into()));
+            assert!(Int::from_i8(1).try_to_string_radix(1).is_err());
+        }
+
+        #[test]
+        fn fmt_flags() {
+            macro_rules! assert_fmt {
+                ( $value:expr, [ $( $fmt:literal ),* ] ) => {
+                    $(
+                        let value: i128 = $value;
+                        assert_eq!(
+                            format!($fmt, Int::from_i128(value)),
+                            format!($fmt, value),
+                            "{}",
+                            $fmt
+                        );
+                    )*
+                };
+            }
+            for &value in &[0, 1, -1, 42, -42, i128::MAX, i128::MIN, i128::MIN + 1] {
+                assert_fmt!(value, ["{}", "{:+}", "{:5}", "{:<10}", "{:^45}", "{:>50}"]);
+                assert_fmt!(value, ["{:05}", "{:+050}", "{:*^50}", "{:-<3}"]);
+                assert_fmt!(value, ["{:b}", "{:o}", "{:x}", "{:X}", "{:#066x}"]);
+            }
+            assert_eq!(format!("{:+}", Int::from_i8(i8::MIN)), "-128");
+            assert_eq!(format!("{:x}", Int::from_i8(-1)), "ff");
+        }
+
+        #[test]
+        fn cmp_primitive() {
+            let x = Int::from_i8(-5);
+            assert!(x == -5i64);
+            assert!(x == -5i128);
+            assert!(x < 0i64);
+            assert!(x > i64::MIN);
+            assert!(x < i128::MAX);
+            // negative values compare by value against unsigned literals
+            assert!(x != u64::MAX - 4);
+            assert!(x < 0u64);
+            let y = Int::from_i128(i128::MIN);
+            assert!(y == i128::MIN);
+            assert!(y < i64::MIN);
+            let mut z = Int::from(ApInt::from([0u64, 1, 0]));
+            assert!(z > i64::MAX);
+            assert!(z > u64::MAX);
+            assert!(z < i128::MAX);
+            z.unset_bit_at(64).unwrap();
+            assert!(z == 0i64);
+            assert!(z == 0u64);
+        }
+
+        #[test]
+        fn saturating_mul() {
+            let (min, max) = (Int::from_i8(i8::MIN), Int::from_i8(i8::MAX));
+            let minus_two = Int::from_i8(-2);
+            assert_eq!(
+                Int::from_i8(-64).into_saturating_mul(&minus_two).unwrap(),
+                max
+            );
+            assert_eq!(
+                Int::from_i8(64).into_saturating_mul(&minus_two).unwrap(),
+                min
+            );
+            assert_eq!(max.clone().into_saturating_mul(&max).unwrap(), max);
+            assert_eq!(
+                Int::from_i8(-6)
+                    .into_saturating_mul(&Int::from_i8(7))
+                    .unwrap(),
+                Int::from_i8(-42)
+            );
+            assert!(min.into_saturating_mul(&Int::from_i16(3)).is_err());
+        }
+
+        #[test]
+        fn div_ceil() {
+            let (a, b) = (Int::from_i8(-7), Int::from_i8(2));
+            assert_eq!(a.clone().into_div_ceil(&b).unwrap(), Int::from_i8(-3));
+            assert_eq!(a.clone().into_div_floor(&b).unwrap(), Int::from_i8(-4));
+            assert_eq!(
+                a.checked_next_multiple_of(&b).unwrap(),
+                Some(Int::from_i8(-6))
+            );
+            assert_eq!(
+                a.checked_next_multiple_of(&Int::from_i8(-2)).unwrap(),
+                Some(Int::from_i8(-8))
+            );
+            assert_eq!(
+                Int::from_i8(i8::MAX).checked_next_multiple_of(&b).unwrap(),
+                None
+            );
+            assert!(a.into_div_ceil(&Int::from_i8(0)).is_err());
+        }
+
+        #[test]
+        fn sign() {
+            for &x in &[i8::MIN, -5, -1, 0, 1, 5, i8::MAX] {
+                let int = Int::from_i8(x);
+                assert_eq!(int.signum(), Int::from_i8(x.signum()));
+                assert_eq!(int.is_positive(), x.is_positive());
+                assert_eq!(int.is_negative(), x.is_negative());
+            }
+            assert_eq!(Int::from_i8(-5).sign(), Sign::Negative);
+            assert_eq!(Int::from_i128(0).sign(), Sign::Zero);
+            assert_eq!(Int::from_i128(7).sign(), Sign::Positive);
+            let minus_one = Int::all_set(BitWidth::w1());
+            let zero = Int::zero(BitWidth::w1());
+            assert_eq!(minus_one.signum(), minus_one);
+            assert_eq!(zero.signum(), zero);
+            assert!(minus_one.is_negative() && !minus_one.is_positive());
+            assert!(!zero.is_negative() && !zero.is_positive());
+        }
+
+        #[test]
+        fn abs() {
+            let minus_one = Int::all_set(BitWidth::w1());
+            assert_eq!(minus_one.clone().into_abs(), minus_one);
+            assert_eq!(minus_one.clone().checked_abs(), None);
+            assert_eq!(minus_one.unsigned_abs(), UInt::one(BitWidth::w1()));
+            assert_eq!(Int::from_i8(-5).checked_abs(), Some(Int::from_i8(5)));
+            assert_eq!(Int::min_value(BitWidth::w8()).checked_abs(), None);
+            assert_eq!(
+                Int::min_value(BitWidth::w8()).unsigned_abs(),
+                UInt::from_u8(128)
+            );
+        }
+
+        #[test]
+        fn try_to_primitive() {
+            let w65 = BitWidth::new(65).unwrap();
+            let min = Int::from(ApInt::from_i64(i64::MIN).into_sign_extend(w65).unwrap());
+            assert_eq!(min.try_to_i64(), Ok(i64::MIN));
+            assert_eq!(min.try_to_isize(), Ok(i64::MIN as isize));
+            assert!(min.try_to_i32().is_err());
+            let below_min = min.into_wrapping_sub(&Int::from(ApInt::one(w65))).unwrap();
+            assert!(below_min.try_to_i64().is_err());
+            assert_eq!(below_min.try_to_i128(), Ok(i128::from(i64::MIN) - 1));
+            assert_eq!(Int::from_i8(-1).try_to_isize(), Ok(-1));
+            assert_eq!(Int::from(ApInt::from_u8(255)).try_to_isize(), Ok(-1));
+        }
+
+        #[test]
+        fn try_from() {
+            let min =
+                Int::from(ApInt::from_i64(i64::MIN).into_sign_resize(BitWidth::w128()));
+            assert_eq!(i64::try_from(&min), min.try_to_i64());
+            assert_eq!(i64::try_from(&min), Ok(i64::MIN));
+            assert_eq!(isize::try_from(&min), min.try_to_isize());
+            assert_eq!(i32::try_from(&min), min.try_to_i32());
+            assert!(i8::try_from(&min).is_err());
+            assert_eq!(i128::try_from(&min), Ok(i128::from(i64::MIN)));
+        }
+
+        #[test]
+        fn from_isize() {
+            for &val in &[0, 1, isize::MAX] {
+                assert_eq!(Int::from_isize(val), Int::from(ApInt::from_isize(val)));
+                assert_eq!(Int::from(val), Int::from_isize(val));
+                assert_eq!(Int::from_isize(val).try_to_isize(), Ok(val));
+            }
+        }
     }
 }
@@ -31,19 +31,34 @@
 //! # `std::ops` trait implementations
 //!
 //! `ApInt` implements some `std::ops` traits for improved usability.
-//! Only traits for operations that do not depend on the signedness
-//! interpretation of the specific `ApInt` instance are actually implemented.
-//! Operations like `div` and `rem` are not expected to have an
-//! implementation since a favor in unsigned or signed cannot be decided.
+//! Mostly traits for operations that do not depend on the signedness
+//! interpretation of the specific `ApInt` instance are implemented. The
+//! exceptions are `Shr`, `Div` and `Rem` which use the **unsigned**
+//! interpretation, like `ApInt::wrapping_lshr_assign`,
+//! `ApInt::wrapping_udiv_assign` and `ApInt::wrapping_urem_assign`.
 //!
 //! `UInt` and `Int` on the other hand implement `Shr`, `Div`, and `Rem` related
-//! traits.
-//!
-//! These ops all happen inplace and no cloning is happening internally,
-//! but they can allocate memory if their corresponding `into_wrapping_`
-//! or `wrapping_assign` function does.
-//!
-//! No traits have been implemented `for &'b ApInt` or
+//! traits according to their signedness, so `Shr` is an arithmetic right shift
+//! for `Int`.
+//!
+//! `Shl` and `Shr` accept anything that converts into a `ShiftAmount`, e.g.
+//! `usize`, for owned and borrowed values and panic on shift amounts that are
+//! invalid for the bit width.
+//!
+//! The arithmetic operators `Add`, `Sub`, `Mul`, `Div` and `Rem`, the bitwise
+//! operators `BitAnd`, `BitOr` and `BitXor` and their assignment variants are
+//! implemented for all combinations of owned and borrowed operands, as is
+//! `Not`. They panic with a descriptive message on unmatching bit widths or a
+//! division by zero, the `Result` returning methods should be used where this
+//! is not known to be impossible.
+//!
+//! These ops happen inplace on the left hand-side, so only operators with a
+//! borrowed left hand-side clone it. Apart from that they can only allocate
+//! memory if their corresponding `into_wrapping_` or `wrapping_assign`
+//! function does, e.g. `&a + &b` allocates exactly once and `a += &b` does not
+//! allocate at all.
+//!
+//! `Neg` has not been implemented `for &'b ApInt` or
 //! `for &'b mut ApInt`, because doing so involves cloning. This crate strives
 //! for clearly exposing where expensive operations happen, so in this case we
 //! favor the user side to use explicit `.clone()`s.
@@ -95,7 +110,11 @@ mod digit_seq;
 mod errors;
 mod int;
 mod mem;
+#[cfg(feature = "proptest")]
+pub mod proptest;
 mod radix;
+#[cfg(feature = "serde_support")]
+pub mod serde;
 mod std_ops;
 mod storage;
 mod uint;
@@ -104,13 +123,17 @@ mod width;
 
 pub(crate) use digit::{
     Digit,
+    DigitRepr,
     DoubleDigit,
+    SignedDigitRepr,
 };
 
 pub use crate::{
     apint::{
         ApInt,
+        Round,
         ShiftAmount,
+        Sign,
     },
     bitpos::BitPos,
     bitwidth::BitWidth,
@@ -125,6 +148,9 @@ pub use crate::{
     width::Width,
 };
 
+#[cfg(feature = "rand_support")]
+pub use crate::apint::UniformApInt;
+
 /// Re-exports some commonly used items of this crate.
 pub mod prelude {
     #[doc(no_inline)]
@@ -0,0 +1,378 @@
+//! Strategies for generating arbitrary precision integers with
+//! [proptest](https://docs.rs/proptest).
+//!
+//! All strategies shrink the **unsigned** value of the generated integers
+//! towards zero first and then shrink their bit width towards the smallest
+//! bit width the strategy may generate.
+//!
+//! # Example
+//!
+//! ```
+//! use apint::proptest::equal_width_pair;
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn add_commutes((lhs, rhs) in equal_width_pair(200)) {
+//!         let lr = lhs.clone().into_wrapping_add(&rhs).unwrap();
+//!         let rl = rhs.into_wrapping_add(&lhs).unwrap();
+//!         prop_assert_eq!(lr, rl);
+//!     }
+//! }
+//! # add_commutes();
+//! ```
+
+use crate::{
+    mem::vec::Vec,
+    ApInt,
+    BitWidth,
+    Int,
+    UInt,
+    Width,
+};
+
+use ::proptest::{
+    prelude::*,
+    strategy::{
+        NewTree,
+        ValueTree,
+    },
+    test_runner::TestRunner,
+};
+
+/// Returns a strategy that generates `ApInt`s with exactly the given bit width
+/// and uniformly distributed bits.
+///
+/// The generated values shrink towards zero.
+pub fn apint_with_width(width: BitWidth) -> impl Strategy<Value = ApInt> {
+    ApIntStrategy {
+        min_width: width.to_usize(),
+        max_width: width.to_usize(),
+    }
+}
+
+/// Returns a strategy that generates `ApInt`s with a uniformly distributed bit
+/// width of at most `max_width` bits and uniformly distributed bits.
+///
+/// The generated values shrink towards zero and then towards a bit width of 1.
+///
+/// # Panics
+///
+/// If `max_width` is zero.
+pub fn any_apint(max_width: usize) -> impl Strategy<Value = ApInt> {
+    assert!(max_width > 0, "the maximum bit width must not be zero");
+    ApIntStrategy {
+        min_width: 1,
+        max_width,
+    }
+}
+
+/// Returns a strategy that generates `UInt`s with exactly the given bit width.
+///
+/// For more details look into [`apint_with_width`](fn.apint_with_width.html).
+pub fn uint_with_width(width: BitWidth) -> impl Strategy<Value = UInt> {
+    apint_with_width(width).prop_map(UInt::from)
+}
+
+/// Returns a strategy that generates `UInt`s with a bit width of at most
+/// `max_width` bits.
+///
+/// For more details look into [`any_apint`](fn.any_apint.html).
+pub fn any_uint(max_width: usize) -> impl Strategy<Value = UInt> {
+    any_apint(max_width).prop_map(UInt::from)
+}
+
+/// Returns a strategy that generates `Int`s with exactly the given bit width.
+///
+/// For more details look into [`apint_with_width`](fn.apint_with_width.html).
+pub fn int_with_width(width: BitWidth) -> impl Strategy<Value = Int> {
+    apint_with_width(width).prop_map(Int::from)
+}
+
+/// Returns a strategy that generates `Int`s with a bit width of at most
+/// `max_width` bits.
+///
+/// For more details look into [`any_apint`](fn.any_apint.html).
+pub fn any_int(max_width: usize) -> impl Strategy<Value = Int> {
+    any_apint(max_width).prop_map(Int::from)
+}
+
+/// Returns a strategy that generates pairs of `ApInt`s that share the same
+/// uniformly distributed bit width of at most `max_width` bits.
+///
+/// This is useful to generate the operands of binary operations that require
+/// equal bit widths.
+///
+/// # Panics
+///
+/// If `max_width` is zero.
+pub fn equal_width_pair(max_width: usize) -> impl Strategy<Value = (ApInt, ApInt)> {
+    assert!(max_width > 0, "the maximum bit width must not be zero");
+    (1..=max_width).prop_flat_map(|width| {
+        let width = BitWidth::new(width).unwrap();
+        (apint_with_width(width), apint_with_width(width))
+    })
+}
+
+/// Generates `ApInt`s with a bit width within `min_width..=max_width`.
+#[derive(Debug, Clone, Copy)]
+struct ApIntStrategy {
+    min_width: usize,
+    max_width: usize,
+}
+
+impl Strategy for ApIntStrategy {
+    type Tree = ApIntValueTree;
+    type Value = ApInt;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        let width = (self.min_width..=self.max_width)
+            .new_tree(runner)?
+            .current();
+        let width = BitWidth::new(width).unwrap();
+        let limbs = (0..width.required_u64s())
+            .map(|_| any::<u64>().new_tree(runner).map(|limb| limb.current()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let value = ApInt::from_le_limbs(&limbs)
+            .and_then(|value| value.into_truncate(width))
+            .unwrap();
+        Ok(ApIntValueTree::new(value, self.min_width))
+    }
+}
+
+/// The shrinking phase of an `ApIntValueTree`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    /// Shrinks the unsigned value towards zero.
+    Value,
+    /// Shrinks the bit width towards the minimum bit width.
+    Width,
+}
+
+/// Shrinks an `ApInt` by binary searching its unsigned value and then its bit
+/// width.
+///
+/// The binary search works the same as for the primitive integers of
+/// proptest: the current value is always within `lo..=hi` where `hi` is the
+/// smallest known failing value and everything below `lo` is known to pass.
+#[derive(Debug, Clone)]
+pub struct ApIntValueTree {
+    phase: Phase,
+    lo: ApInt,
+    curr: ApInt,
+    hi: ApInt,
+    min_width: usize,
+    lo_width: usize,
+    curr_width: usize,
+    hi_width: usize,
+}
+
+impl ApIntValueTree {
+    /// Creates a new value tree that starts at `value` and does not shrink the
+    /// bit width below `min_width`.
+    fn new(value: ApInt, min_width: usize) -> ApIntValueTree {
+        let width = value.width().to_usize();
+        ApIntValueTree {
+            phase: Phase::Value,
+            lo: ApInt::zero(value.width()),
+            curr: value.clone(),
+            hi: value,
+            min_width,
+            lo_width: min_width,
+            curr_width: width,
+            hi_width: width,
+        }
+    }
+
+    /// Moves `curr` to the middle of `lo..=hi` and returns `true` if it
+    /// changed.
+    fn reposition(&mut self) -> bool {
+        let interval = self.hi.clone().into_wrapping_sub(&self.lo).unwrap();
+        let mid = interval
+            .into_unbounded_lshr(1)
+            .into_wrapping_add(&self.lo)
+            .unwrap();
+        if mid == self.curr {
+            return false
+        }
+        self.curr = mid;
+        true
+    }
+
+    /// Moves `curr_width` to the middle of `lo_width..=hi_width` and returns
+    /// `true` if it changed.
+    fn reposition_width(&mut self) -> bool {
+        let mid = self.lo_width + (self.hi_width - self.lo_width) / 2;
+        if mid == self.curr_width {
+            return false
+        }
+        self.curr_width = mid;
+        true
+    }
+
+    fn simplify_value(&mut self) -> bool {
+        if self.curr == self.lo {
+            return false
+        }
+        self.hi = self.curr.clone();
+        self.reposition()
+    }
+
+    fn complicate_value(&mut self) -> bool {
+        if self.curr == self.hi {
+            return false
+        }
+        self.lo = self.curr.clone().into_wrapping_inc();
+        self.reposition()
+    }
+
+    fn simplify_width(&mut self) -> bool {
+        if self.curr_width == self.lo_width {
+            return false
+        }
+        self.hi_width = self.curr_width;
+        self.reposition_width()
+    }
+
+    fn complicate_width(&mut self) -> bool {
+        if self.curr_width == self.hi_width {
+            return false
+        }
+        self.lo_width = self.curr_width + 1;
+        self.reposition_width()
+    }
+}
+
+impl ValueTree for ApIntValueTree {
+    type Value = ApInt;
+
+    fn current(&self) -> ApInt {
+        if self.curr_width == self.curr.width().to_usize() {
+            return self.curr.clone()
+        }
+        self.curr.clone().into_truncate(self.curr_width).unwrap()
+    }
+
+    fn simplify(&mut self) -> bool {
+        if self.phase == Phase::Value {
+            if self.simplify_value() {
+                return true
+            }
+            self.phase = Phase::Width;
+        }
+        debug_assert!(self.lo_width >= self.min_width);
+        self.simplify_width()
+    }
+
+    fn complicate(&mut self) -> bool {
+        match self.phase {
+            Phase::Value => self.complicate_value(),
+            Phase::Width => self.complicate_width(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::proptest::test_runner::{
+        Config,
+        TestError,
+    };
+
+    /// Runs `test` against `strategy` and returns the minimal failing value.
+    fn minimal_failure<S, F>(strategy: S, test: F) -> ApInt
+    where
+        S: Strategy<Value = ApInt>,
+        F: Fn(&ApInt) -> bool,
+    {
+        let mut runner = TestRunner::new(Config {
+            failure_persistence: None,
+            ..Config::default()
+        });
+        match runner.run(&strategy, |value| {
+            prop_assert!(!test(&value));
+            Ok(())
+        }) {
+            Err(TestError::Fail(_, value)) => value,
+            result => panic!("expected a failure but got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn widths() {
+        let mut runner = TestRunner::default();
+        let w100 = BitWidth::new(100).unwrap();
+        for _ in 0..100 {
+            let value = apint_with_width(w100).new_tree(&mut runner).unwrap();
+            assert_eq!(value.current().width(), w100);
+            let width = any_apint(70).new_tree(&mut runner).unwrap().current().width();
+            assert!((1..=70).contains(&width.to_usize()));
+            let (lhs, rhs) = equal_width_pair(70)
+                .new_tree(&mut runner)
+                .unwrap()
+                .current();
+            assert_eq!(lhs.width(), rhs.width());
+        }
+    }
+
+    #[test]
+    fn shrink_value_then_width() {
+        let at_least_1000 = |value: &ApInt| {
+            value.required_bits(false) > 64 || value.resize_to_u64() >= 1000
+        };
+        let minimal = minimal_failure(any_apint(300), at_least_1000);
+        assert_eq!(minimal, ApInt::from_u64(1000).into_truncate(10).unwrap());
+    }
+
+    #[test]
+    fn shrink_with_fixed_width() {
+        let w100 = BitWidth::new(100).unwrap();
+        let minimal = minimal_failure(apint_with_width(w100), |value| !value.is_zero());
+        assert_eq!(minimal, ApInt::one(w100));
+    }
+
+    #[test]
+    fn simplify_and_complicate() {
+        let mut tree = ApIntValueTree::new(ApInt::from_u8(200), 1);
+        assert!(tree.simplify());
+        assert_eq!(tree.current(), ApInt::from_u8(100));
+        assert!(tree.complicate());
+        assert_eq!(tree.current(), ApInt::from_u8(150));
+        // values below 101 are known to pass so only the bit width shrinks further
+        while tree.simplify() {}
+        assert_eq!(tree.current(), ApInt::from_u8(101).into_truncate(1).unwrap());
+        let mut tree = ApIntValueTree::new(ApInt::from_u8(200), 1);
+        while tree.simplify() {}
+        assert_eq!(tree.current(), ApInt::zero(BitWidth::w1()));
+    }
+
+    proptest! {
+        #[test]
+        fn add_sub_round_trip((lhs, rhs) in equal_width_pair(300)) {
+            let result = lhs
+                .clone()
+                .into_wrapping_add(&rhs)
+                .unwrap()
+                .into_wrapping_sub(&rhs)
+                .unwrap();
+            prop_assert_eq!(result, lhs);
+        }
+
+        #[test]
+        fn neg_is_sub_from_zero(value in any_apint(300)) {
+            let zero = ApInt::zero(value.width());
+            let neg = value.clone().into_wrapping_neg();
+            prop_assert_eq!(neg, zero.into_wrapping_sub(&value).unwrap());
+        }
+
+        #[test]
+        fn signed_unsigned_round_trip(value in any_int(300)) {
+            prop_assert_eq!(value.clone().into_unsigned().into_signed(), value);
+        }
+
+        #[test]
+        fn uint_has_width(value in uint_with_width(BitWidth::new(65).unwrap())) {
+            prop_assert_eq!(value.width(), BitWidth::new(65).unwrap());
+        }
+    }
+}
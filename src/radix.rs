@@ -118,6 +118,17 @@ impl Radix {
     pub(crate) fn get_radix_base(self) -> (Digit, usize) {
         assert!(!self.is_power_of_two());
 
+        if Digit::BITS != 64 {
+            // the table below is only valid for 64-bit digits
+            let radix = DigitRepr::from(self.to_u8());
+            let (mut base, mut power) = (radix, 1);
+            while let Some(b) = base.checked_mul(radix) {
+                base = b;
+                power += 1;
+            }
+            return (Digit(base), power)
+        }
+
         // To generate this table:
         // ```
         // for radix in 2u64..37 {
@@ -130,7 +141,7 @@ impl Radix {
         //     println!("({:20}, {:2}), // {:2}", base, power, radix);
         // }
         // ```
-        const BASES: [(DigitRepr, usize); 37] = [
+        const BASES: [(u64, usize); 37] = [
             (0, 0),                         //  0 (invalid Radix!)
             (0, 0),                         //  1 (invalid Radix!)
             (922_3372_0368_5477_5808, 63),  //  2
@@ -0,0 +1,249 @@
+//! Helpers for deserializing arbitrary precision integers with a bit width
+//! that is fixed by the deserializing type instead of the serialized data.
+
+use crate::{
+    ApInt,
+    BitWidth,
+    ErrorKind,
+};
+
+use core::fmt;
+use ::serde::de::{
+    self,
+    Deserializer,
+    Unexpected,
+    Visitor,
+};
+
+/// Deserializes an `ApInt`, `UInt` or `Int` with a bit width of exactly `W`
+/// from either an integer or a string.
+///
+/// This is meant to be used with the `deserialize_with` attribute of serde:
+///
+/// - Integers are converted from the `u64`, `i64`, `u128` and `i128` values
+///   of the format. Negative integers are represented in two's complement.
+/// - Strings are parsed by
+///   [`ApInt::from_str_prefixed`](../struct.ApInt.html#method.from_str_prefixed),
+///   so they may have a sign and a `0x`, `0o` or `0b` radix prefix.
+///
+/// Values that do not fit into `W` bits are rejected with a deserialization
+/// error that names the bit width `W`.
+///
+/// # Example
+///
+/// ```
+/// # use apint::{ApInt, UInt, BitWidth};
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     #[serde(deserialize_with = "apint::serde::ExactWidth::<256>::deserialize")]
+///     small: UInt,
+///     #[serde(deserialize_with = "apint::serde::ExactWidth::<256>::deserialize")]
+///     large: ApInt,
+/// }
+///
+/// let config: Config = serde_json::from_str(
+///     r#"{ "small": 42, "large": "0x1_0000_0000_0000_0000_0000_0000_0000_0000" }"#,
+/// )
+/// .unwrap();
+/// let w256 = BitWidth::new(256).unwrap();
+/// assert_eq!(config.small, UInt::from(ApInt::from_u8(42).into_zero_resize(w256)));
+/// assert_eq!(config.large, ApInt::one(w256).into_wrapping_shl(128).unwrap());
+/// ```
+pub struct ExactWidth<const W: usize>;
+
+impl<const W: usize> ExactWidth<W> {
+    /// Deserializes a value with a bit width of exactly `W` from either an
+    /// integer or a string.
+    ///
+    /// For more details look into [`ExactWidth`](struct.ExactWidth.html).
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: From<ApInt>,
+    {
+        deserializer
+            .deserialize_any(ExactWidthVisitor::<W>)
+            .map(T::from)
+    }
+}
+
+/// Visits integers and strings and converts them into an `ApInt` with a bit
+/// width of `W`.
+struct ExactWidthVisitor<const W: usize>;
+
+impl<const W: usize> ExactWidthVisitor<W> {
+    /// Returns the bit width `W` or an error if it is zero.
+    fn width<E>(&self) -> Result<BitWidth, E>
+    where
+        E: de::Error,
+    {
+        BitWidth::new(W).map_err(|_| de::Error::custom("invalid bit width of 0"))
+    }
+
+    /// Returns the error for a `value` that does not fit into `W` bits.
+    fn overflow<E, V>(&self, value: V) -> E
+    where
+        E: de::Error,
+        V: fmt::Display,
+    {
+        de::Error::custom(format_args!(
+            "{} does not fit into the {} bits of an `ExactWidth::<{}>` field",
+            value, W, W
+        ))
+    }
+
+    /// Converts the given **unsigned** `value` or returns an error if it does
+    /// not fit into `W` bits.
+    fn visit_unsigned<E>(&self, value: u128, unexp: Unexpected) -> Result<ApInt, E>
+    where
+        E: de::Error,
+    {
+        let value = ApInt::from_u128(value);
+        if value.required_bits(false) > W {
+            return Err(self.overflow(unexp))
+        }
+        Ok(value.into_zero_resize(self.width()?))
+    }
+
+    /// Converts the given **signed** `value` or returns an error if it does
+    /// not fit into `W` bits.
+    fn visit_signed<E>(&self, value: i128, unexp: Unexpected) -> Result<ApInt, E>
+    where
+        E: de::Error,
+    {
+        let value = ApInt::from_i128(value);
+        if value.required_bits(true) > W {
+            return Err(self.overflow(unexp))
+        }
+        Ok(value.into_sign_resize(self.width()?))
+    }
+}
+
+impl<'de, const W: usize> Visitor<'de> for ExactWidthVisitor<W> {
+    type Value = ApInt;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "an integer or a string representation of an integer that fits into {} \
+             bits",
+            W
+        )
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<ApInt, E>
+    where
+        E: de::Error,
+    {
+        self.visit_unsigned(u128::from(value), Unexpected::Unsigned(value))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<ApInt, E>
+    where
+        E: de::Error,
+    {
+        self.visit_signed(i128::from(value), Unexpected::Signed(value))
+    }
+
+    fn visit_u128<E>(self, value: u128) -> Result<ApInt, E>
+    where
+        E: de::Error,
+    {
+        self.visit_unsigned(value, Unexpected::Other("128-bit unsigned integer"))
+    }
+
+    fn visit_i128<E>(self, value: i128) -> Result<ApInt, E>
+    where
+        E: de::Error,
+    {
+        self.visit_signed(value, Unexpected::Other("128-bit signed integer"))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<ApInt, E>
+    where
+        E: de::Error,
+    {
+        ApInt::from_str_prefixed(value, self.width()?).map_err(|err| {
+            match err.kind() {
+                ErrorKind::ResultOverflow(_) => self.overflow(Unexpected::Str(value)),
+                _ => de::Error::invalid_value(Unexpected::Str(value), &self),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Int,
+        UInt,
+    };
+
+    #[derive(::serde::Deserialize, Debug)]
+    struct Config {
+        #[serde(deserialize_with = "ExactWidth::<8>::deserialize")]
+        small: UInt,
+        #[serde(deserialize_with = "ExactWidth::<256>::deserialize")]
+        large: ApInt,
+        #[serde(deserialize_with = "ExactWidth::<100>::deserialize")]
+        signed: Int,
+    }
+
+    fn parse(json: &str) -> serde_json::Result<Config> {
+        serde_json::from_str(json)
+    }
+
+    #[test]
+    fn numbers() {
+        let config = parse(r#"{ "small": 255, "large": 42, "signed": -1 }"#).unwrap();
+        assert_eq!(config.small, UInt::from_u8(255));
+        let w256 = BitWidth::new(256).unwrap();
+        assert_eq!(config.large, ApInt::from_u8(42).into_zero_resize(w256));
+        let w100 = BitWidth::new(100).unwrap();
+        assert_eq!(config.signed, Int::from(ApInt::all_set(w100)));
+    }
+
+    #[test]
+    fn strings() {
+        let config = parse(&format!(
+            r#"{{ "small": "0xff", "large": "0x8{}", "signed": "{}" }}"#,
+            "0".repeat(63),
+            "-633825300114114700748351602688"
+        ))
+        .unwrap();
+        assert_eq!(config.small, UInt::from_u8(0xff));
+        let w256 = BitWidth::new(256).unwrap();
+        assert_eq!(config.large, ApInt::signed_min_value(w256));
+        let w100 = BitWidth::new(100).unwrap();
+        assert_eq!(config.signed, Int::from(ApInt::signed_min_value(w100)));
+    }
+
+    #[test]
+    fn overflow() {
+        for json in &[
+            r#"{ "small": 256, "large": 0, "signed": 0 }"#,
+            r#"{ "small": -129, "large": 0, "signed": 0 }"#,
+            r#"{ "small": "0x100", "large": 0, "signed": 0 }"#,
+            r#"{ "small": 0, "large": 0, "signed": "-633825300114114700748351602689" }"#,
+        ] {
+            let err = parse(json).unwrap_err().to_string();
+            assert!(err.contains("does not fit into the"), "{}: {}", json, err);
+            let width = if json.contains(r#""signed": "-"#) { 100 } else { 8 };
+            let context = format!("{} bits of an `ExactWidth::<{}>` field", width, width);
+            assert!(err.contains(&context), "{}: {}", json, err);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        for json in &[
+            r#"{ "small": 1.5, "large": 0, "signed": 0 }"#,
+            r#"{ "small": "0xzz", "large": 0, "signed": 0 }"#,
+            r#"{ "small": "", "large": 0, "signed": 0 }"#,
+            r#"{ "small": [1], "large": 0, "signed": 0 }"#,
+        ] {
+            assert!(parse(json).is_err(), "{}", json);
+        }
+    }
+}
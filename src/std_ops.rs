@@ -3,10 +3,16 @@
 use crate::{
     ApInt,
     Int,
+    Result,
     ShiftAmount,
     UInt,
 };
 
+use core::iter::{
+    Product,
+    Sum,
+};
+
 use core::ops::{
     Add,
     AddAssign,
@@ -47,6 +53,14 @@ macro_rules! common_std_ops {
             }
         }
 
+        impl<'a> Not for &'a $ty {
+            type Output = $ty;
+
+            fn not(self) -> $ty {
+                self.clone().into_bitnot()
+            }
+        }
+
         // `std` implements this even for unsigned primitives, and this crate does so
         // too.
         impl Neg for $ty {
@@ -57,175 +71,328 @@ macro_rules! common_std_ops {
             }
         }
 
-        impl<S> Shl<S> for $ty
-        where
-            S: Into<ShiftAmount>,
-        {
-            type Output = Self;
-
-            fn shl(self, shift_amount: S) -> Self::Output {
-                self.into_wrapping_shl(shift_amount).unwrap()
+        // iterator folds
+        //
+        // The bit width is taken from the first element, which is also reused as
+        // the accumulator. `ApInt::try_sum` and `ApInt::try_product` handle empty
+        // iterators and unmatching bit widths without panicking.
+
+        impl Sum<$ty> for $ty {
+            /// Wrapping sum of all elements.
+            ///
+            /// # Panics
+            ///
+            /// - If the iterator is empty.
+            /// - If the elements have unmatching bit widths.
+            fn sum<I: Iterator<Item = $ty>>(mut iter: I) -> Self {
+                let mut acc = iter.next().expect(EMPTY_SUM);
+                for x in iter {
+                    acc.wrapping_add_assign(&x).expect(UNMATCHING_SUM);
+                }
+                acc
             }
         }
 
-        // binary ops
-
-        impl<'a> BitAnd<&'a $ty> for $ty {
-            type Output = Self;
-
-            fn bitand(self, rhs: &'a Self) -> Self::Output {
-                self.into_bitand(rhs).unwrap()
+        impl<'a> Sum<&'a $ty> for $ty {
+            /// Wrapping sum of all elements.
+            ///
+            /// # Panics
+            ///
+            /// - If the iterator is empty.
+            /// - If the elements have unmatching bit widths.
+            fn sum<I: Iterator<Item = &'a $ty>>(mut iter: I) -> Self {
+                let mut acc = iter.next().expect(EMPTY_SUM).clone();
+                for x in iter {
+                    acc.wrapping_add_assign(x).expect(UNMATCHING_SUM);
+                }
+                acc
             }
         }
 
-        impl<'a> BitOr<&'a $ty> for $ty {
-            type Output = Self;
-
-            fn bitor(self, rhs: &'a Self) -> Self::Output {
-                self.into_bitor(rhs).unwrap()
+        impl Product<$ty> for $ty {
+            /// Wrapping product of all elements.
+            ///
+            /// # Panics
+            ///
+            /// - If the iterator is empty.
+            /// - If the elements have unmatching bit widths.
+            fn product<I: Iterator<Item = $ty>>(mut iter: I) -> Self {
+                let mut acc = iter.next().expect(EMPTY_PRODUCT);
+                for x in iter {
+                    acc.wrapping_mul_assign(&x).expect(UNMATCHING_PRODUCT);
+                }
+                acc
             }
         }
 
-        impl<'a> BitXor<&'a $ty> for $ty {
-            type Output = Self;
-
-            fn bitxor(self, rhs: &'a Self) -> Self::Output {
-                self.into_bitxor(rhs).unwrap()
+        impl<'a> Product<&'a $ty> for $ty {
+            /// Wrapping product of all elements.
+            ///
+            /// # Panics
+            ///
+            /// - If the iterator is empty.
+            /// - If the elements have unmatching bit widths.
+            fn product<I: Iterator<Item = &'a $ty>>(mut iter: I) -> Self {
+                let mut acc = iter.next().expect(EMPTY_PRODUCT).clone();
+                for x in iter {
+                    acc.wrapping_mul_assign(x).expect(UNMATCHING_PRODUCT);
+                }
+                acc
             }
         }
+    };
+}
 
-        impl<'a> Add<&'a $ty> for $ty {
-            type Output = Self;
+const EMPTY_SUM: &str = "cannot infer the bit width of the sum of an empty iterator, \
+                         use `ApInt::try_sum` instead";
+const UNMATCHING_SUM: &str = "encountered unmatching bit widths in a sum";
+const EMPTY_PRODUCT: &str = "cannot infer the bit width of the product of an empty \
+                             iterator, use `ApInt::try_product` instead";
+const UNMATCHING_PRODUCT: &str = "encountered unmatching bit widths in a product";
 
-            fn add(self, rhs: &'a Self) -> Self::Output {
-                self.into_wrapping_add(rhs).unwrap()
-            }
-        }
-
-        impl<'a> Sub<&'a $ty> for $ty {
-            type Output = Self;
+common_std_ops!(ApInt);
+common_std_ops!(UInt);
+common_std_ops!(Int);
 
-            fn sub(self, rhs: &'a Self) -> Self::Output {
-                self.into_wrapping_sub(rhs).unwrap()
-            }
-        }
 
-        impl<'a> Mul<&'a $ty> for $ty {
-            type Output = Self;
+/// Unwraps the `result` of an operator or panics with a message that names the
+/// failed `operation`.
+fn expect_op<T>(result: Result<T>, operation: &str) -> T {
+    result.unwrap_or_else(|err| panic!("{} failed: {}", operation, err.message()))
+}
 
-            fn mul(self, rhs: &'a Self) -> Self::Output {
-                self.into_wrapping_mul(rhs).unwrap()
+// Implements a binary operator for all combinations of owned and borrowed
+// operands as well as its assignment variant for owned and borrowed right
+// hand-sides, all in terms of the given `$inplace` method.
+//
+// Only the operators with a borrowed left hand-side allocate, since they need
+// to clone it to have space for the result.
+macro_rules! binary_std_ops {
+    (
+        $ty:ty,
+        $op:ident::$fn:ident,
+        $op_assign:ident::$fn_assign:ident,
+        $inplace:ident,
+        $operation:expr $(,)?
+    ) => {
+        impl<'a> $op<&'a $ty> for $ty {
+            type Output = $ty;
+
+            fn $fn(mut self, rhs: &'a $ty) -> $ty {
+                expect_op(self.$inplace(rhs), $operation);
+                self
             }
         }
 
-        // assignment ops
+        impl $op<$ty> for $ty {
+            type Output = $ty;
 
-        impl<S> ShlAssign<S> for $ty
-        where
-            S: Into<ShiftAmount>,
-        {
-            fn shl_assign(&mut self, shift_amount: S) {
-                self.wrapping_shl_assign(shift_amount).unwrap();
+            fn $fn(mut self, rhs: $ty) -> $ty {
+                expect_op(self.$inplace(&rhs), $operation);
+                self
             }
         }
 
-        impl<'a> BitAndAssign<&'a $ty> for $ty {
-            fn bitand_assign(&mut self, rhs: &'a Self) {
-                self.bitand_assign(rhs).unwrap();
-            }
-        }
+        impl<'a, 'b> $op<&'b $ty> for &'a $ty {
+            type Output = $ty;
 
-        impl<'a> BitOrAssign<&'a $ty> for $ty {
-            fn bitor_assign(&mut self, rhs: &'a Self) {
-                self.bitor_assign(rhs).unwrap();
+            fn $fn(self, rhs: &'b $ty) -> $ty {
+                let mut result = self.clone();
+                expect_op(result.$inplace(rhs), $operation);
+                result
             }
         }
 
-        impl<'a> BitXorAssign<&'a $ty> for $ty {
-            fn bitxor_assign(&mut self, rhs: &'a Self) {
-                self.bitxor_assign(rhs).unwrap();
-            }
-        }
+        impl<'a> $op<$ty> for &'a $ty {
+            type Output = $ty;
 
-        impl<'a> AddAssign<&'a $ty> for $ty {
-            fn add_assign(&mut self, rhs: &'a Self) {
-                self.wrapping_add_assign(rhs).unwrap()
+            fn $fn(self, rhs: $ty) -> $ty {
+                let mut result = self.clone();
+                expect_op(result.$inplace(&rhs), $operation);
+                result
             }
         }
 
-        impl<'a> SubAssign<&'a $ty> for $ty {
-            fn sub_assign(&mut self, rhs: &'a Self) {
-                self.wrapping_sub_assign(rhs).unwrap()
+        impl<'a> $op_assign<&'a $ty> for $ty {
+            fn $fn_assign(&mut self, rhs: &'a $ty) {
+                expect_op(self.$inplace(rhs), $operation);
             }
         }
 
-        impl<'a> MulAssign<&'a $ty> for $ty {
-            fn mul_assign(&mut self, rhs: &'a Self) {
-                self.wrapping_mul_assign(rhs).unwrap();
+        impl $op_assign<$ty> for $ty {
+            fn $fn_assign(&mut self, rhs: $ty) {
+                expect_op(self.$inplace(&rhs), $operation);
             }
         }
     };
 }
 
-common_std_ops!(ApInt);
-common_std_ops!(UInt);
-common_std_ops!(Int);
-
-// For signedness dependent cases
-macro_rules! signed_std_ops {
+macro_rules! common_arithmetic_std_ops {
     ($ty:ty) => {
-        impl<S> Shr<S> for $ty
-        where
-            S: Into<ShiftAmount>,
-        {
-            type Output = Self;
+        binary_std_ops!(
+            $ty,
+            Add::add,
+            AddAssign::add_assign,
+            wrapping_add_assign,
+            "addition",
+        );
+        binary_std_ops!(
+            $ty,
+            Sub::sub,
+            SubAssign::sub_assign,
+            wrapping_sub_assign,
+            "subtraction",
+        );
+        binary_std_ops!(
+            $ty,
+            Mul::mul,
+            MulAssign::mul_assign,
+            wrapping_mul_assign,
+            "multiplication",
+        );
+    };
+}
 
-            fn shr(self, shift_amount: S) -> Self::Output {
-                self.into_wrapping_shr(shift_amount).unwrap()
-            }
-        }
+common_arithmetic_std_ops!(ApInt);
+common_arithmetic_std_ops!(UInt);
+common_arithmetic_std_ops!(Int);
 
-        impl<'a> Div<&'a $ty> for $ty {
-            type Output = Self;
+macro_rules! bitwise_std_ops {
+    ($ty:ty) => {
+        binary_std_ops!(
+            $ty,
+            BitAnd::bitand,
+            BitAndAssign::bitand_assign,
+            bitand_assign,
+            "bitwise and",
+        );
+        binary_std_ops!(
+            $ty,
+            BitOr::bitor,
+            BitOrAssign::bitor_assign,
+            bitor_assign,
+            "bitwise or",
+        );
+        binary_std_ops!(
+            $ty,
+            BitXor::bitxor,
+            BitXorAssign::bitxor_assign,
+            bitxor_assign,
+            "bitwise xor",
+        );
+    };
+}
 
-            fn div(self, rhs: &'a Self) -> Self::Output {
-                self.into_wrapping_div(rhs).unwrap()
-            }
-        }
+bitwise_std_ops!(ApInt);
+bitwise_std_ops!(UInt);
+bitwise_std_ops!(Int);
 
-        impl<'a> Rem<&'a $ty> for $ty {
-            type Output = Self;
+// `ApInt` uses the unsigned interpretation for division since it is the more
+// common one for bit vectors, `UInt` and `Int` use their own interpretation.
+binary_std_ops!(
+    ApInt,
+    Div::div,
+    DivAssign::div_assign,
+    wrapping_udiv_assign,
+    "division",
+);
+binary_std_ops!(
+    ApInt,
+    Rem::rem,
+    RemAssign::rem_assign,
+    wrapping_urem_assign,
+    "remainder",
+);
+binary_std_ops!(
+    UInt,
+    Div::div,
+    DivAssign::div_assign,
+    wrapping_div_assign,
+    "division",
+);
+binary_std_ops!(
+    UInt,
+    Rem::rem,
+    RemAssign::rem_assign,
+    wrapping_rem_assign,
+    "remainder",
+);
+binary_std_ops!(
+    Int,
+    Div::div,
+    DivAssign::div_assign,
+    wrapping_div_assign,
+    "division",
+);
+binary_std_ops!(
+    Int,
+    Rem::rem,
+    RemAssign::rem_assign,
+    wrapping_rem_assign,
+    "remainder",
+);
+
+// Implements a shift operator by anything convertible into a `ShiftAmount` for
+// owned and borrowed values as well as its assignment variant, all in terms of
+// the given inplace `wrapping_` method.
+macro_rules! shift_std_ops {
+    (
+        $ty:ty,
+        $op:ident::$fn:ident,
+        $op_assign:ident::$fn_assign:ident,
+        $wrapping_assign:ident,
+        $operation:expr $(,)?
+    ) => {
+        impl<S> $op<S> for $ty
+        where
+            S: Into<ShiftAmount>,
+        {
+            type Output = $ty;
 
-            fn rem(self, rhs: &'a Self) -> Self::Output {
-                self.into_wrapping_rem(rhs).unwrap()
+            fn $fn(mut self, shift_amount: S) -> $ty {
+                expect_op(self.$wrapping_assign(shift_amount), $operation);
+                self
             }
         }
 
-        impl<S> ShrAssign<S> for $ty
+        impl<'a, S> $op<S> for &'a $ty
         where
             S: Into<ShiftAmount>,
         {
-            fn shr_assign(&mut self, shift_amount: S) {
-                self.wrapping_shr_assign(shift_amount).unwrap();
-            }
-        }
+            type Output = $ty;
 
-        impl<'a> DivAssign<&'a $ty> for $ty {
-            fn div_assign(&mut self, rhs: &'a Self) {
-                self.wrapping_div_assign(rhs).unwrap();
+            fn $fn(self, shift_amount: S) -> $ty {
+                let mut result = self.clone();
+                expect_op(result.$wrapping_assign(shift_amount), $operation);
+                result
             }
         }
 
-        impl<'a> RemAssign<&'a $ty> for $ty {
-            fn rem_assign(&mut self, rhs: &'a Self) {
-                self.wrapping_rem_assign(rhs).unwrap();
+        impl<S> $op_assign<S> for $ty
+        where
+            S: Into<ShiftAmount>,
+        {
+            fn $fn_assign(&mut self, shift_amount: S) {
+                expect_op(self.$wrapping_assign(shift_amount), $operation);
             }
         }
     };
 }
 
-signed_std_ops!(UInt);
-signed_std_ops!(Int);
+shift_std_ops!(ApInt, Shl::shl, ShlAssign::shl_assign, wrapping_shl_assign, "left shift");
+shift_std_ops!(UInt, Shl::shl, ShlAssign::shl_assign, wrapping_shl_assign, "left shift");
+shift_std_ops!(Int, Shl::shl, ShlAssign::shl_assign, wrapping_shl_assign, "left shift");
+
+// `ApInt` shifts logically to the right like `UInt`, `Int` shifts arithmetically.
+shift_std_ops!(
+    ApInt,
+    Shr::shr,
+    ShrAssign::shr_assign,
+    wrapping_lshr_assign,
+    "right shift",
+);
+shift_std_ops!(UInt, Shr::shr, ShrAssign::shr_assign, wrapping_shr_assign, "right shift");
+shift_std_ops!(Int, Shr::shr, ShrAssign::shr_assign, wrapping_shr_assign, "right shift");
 
 // check that all the operations exist and have the right methods
 #[cfg(test)]
@@ -291,6 +458,40 @@ mod tests {
             assert_eq!(x, ApInt::from(21u8));
         }
 
+        #[test]
+        fn iter() {
+            let values: Vec<ApInt> = (1..=10u8).map(ApInt::from).collect();
+            assert_eq!(values.iter().sum::<ApInt>(), ApInt::from(55u8));
+            assert_eq!(values.clone().into_iter().sum::<ApInt>(), ApInt::from(55u8));
+            // `10!` wraps around
+            assert_eq!(
+                values.iter().product::<ApInt>(),
+                ApInt::from(3_628_800u32 as u8)
+            );
+            assert_eq!(
+                values.into_iter().product::<ApInt>(),
+                ApInt::from(3_628_800u32 as u8)
+            );
+            let uints = [UInt::from(200u8), UInt::from(100u8)];
+            assert_eq!(uints.iter().sum::<UInt>(), UInt::from(44u8));
+            let ints = [Int::from(-3i8), Int::from(5i8)];
+            assert_eq!(ints.iter().product::<Int>(), Int::from(-15i8));
+        }
+
+        #[test]
+        #[should_panic(expected = "empty iterator")]
+        fn empty_sum() {
+            let _ = Vec::<ApInt>::new().into_iter().sum::<ApInt>();
+        }
+
+        #[test]
+        #[should_panic(expected = "unmatching bit widths")]
+        fn unmatching_product() {
+            let _ = [ApInt::from(1u8), ApInt::from(1u16)]
+                .iter()
+                .product::<ApInt>();
+        }
+
         #[test]
         fn signed() {
             // Set the sign bit to see if the wrong kind of divisions are being called.
@@ -326,5 +527,226 @@ mod tests {
             x %= &y;
             assert_eq!(x, Int::from(-1i8));
         }
+
+        #[test]
+        fn shifts() {
+            // `Shr` is a logical right shift for `ApInt` and `UInt` and an
+            // arithmetic right shift for `Int`, -63i8 has the same bit pattern as
+            // 0b1100_0001u8
+            assert_eq!(ApInt::from(0b1100_0001u8) >> 4, ApInt::from(0b0000_1100u8));
+            assert_eq!(UInt::from(0b1100_0001u8) >> 4, UInt::from(0b0000_1100u8));
+            assert_eq!(Int::from(-63i8) >> 4, Int::from(-4i8));
+            let x = ApInt::from(0b1100_0001u8);
+            assert_eq!(&x << 1, ApInt::from(0b1000_0010u8));
+            assert_eq!(&x >> 1_usize, ApInt::from(0b0110_0000u8));
+            assert_eq!(&x << ShiftAmount::from(7), ApInt::from(0b1000_0000u8));
+            assert_eq!(x.clone() >> ShiftAmount::from(7), ApInt::from(1u8));
+            let mut y = x.clone();
+            y >>= 6;
+            assert_eq!(y, ApInt::from(0b11u8));
+            y <<= ShiftAmount::from(6);
+            assert_eq!(y, ApInt::from(0b1100_0000u8));
+            let i = Int::from(i64::MIN);
+            assert_eq!(&i >> 63, Int::from(-1i64));
+            let mut i = i;
+            i >>= ShiftAmount::from(62);
+            assert_eq!(i, Int::from(-2i64));
+        }
+
+        #[test]
+        #[should_panic(expected = "left shift failed: Encountered invalid shift amount")]
+        fn invalid_shl() {
+            let _ = ApInt::from(1u8) << 8;
+        }
+
+        #[test]
+        #[should_panic(expected = "right shift failed: Encountered invalid shift amount")]
+        fn invalid_shr() {
+            let mut x = Int::from(1i8);
+            x >>= 8;
+        }
+
+        #[test]
+        fn ownership() {
+            let a = ApInt::from(200u8);
+            let b = ApInt::from(100u8);
+            let sum = ApInt::from(44u8);
+            assert_eq!(a.clone() + b.clone(), sum);
+            assert_eq!(a.clone() + &b, sum);
+            assert_eq!(&a + b.clone(), sum);
+            assert_eq!(&a + &b, sum);
+            let product = ApInt::from(32u8);
+            assert_eq!(a.clone() * b.clone(), product);
+            assert_eq!(a.clone() * &b, product);
+            assert_eq!(&a * b.clone(), product);
+            assert_eq!(&a * &b, product);
+            let difference = ApInt::from(100u8);
+            assert_eq!(&a - &b, difference);
+            assert_eq!(&a - b.clone(), difference);
+
+            let mut x = a.clone();
+            x += b.clone();
+            assert_eq!(x, sum);
+            let mut x = a.clone();
+            x *= b.clone();
+            assert_eq!(x, product);
+            let mut x = a;
+            x -= b;
+            assert_eq!(x, difference);
+        }
+
+        #[test]
+        fn unsigned_division() {
+            // -57i8 has the same bit pattern as 199u8 and `ApInt` divides unsigned
+            let a = ApInt::from(-57i8);
+            let b = ApInt::from(7u8);
+            assert_eq!(&a / &b, ApInt::from(28u8));
+            assert_eq!(&a % &b, ApInt::from(3u8));
+            assert_eq!(a.clone() / b.clone(), ApInt::from(28u8));
+            assert_eq!(a.clone() % b.clone(), ApInt::from(3u8));
+            let mut x = a.clone();
+            x /= &b;
+            assert_eq!(x, ApInt::from(28u8));
+            let mut x = a;
+            x %= b;
+            assert_eq!(x, ApInt::from(3u8));
+        }
+
+        #[test]
+        #[should_panic(expected = "addition failed: Encountered invalid operation on \
+                                   entities with non-matching bit-widths")]
+        fn unmatching_add() {
+            let _ = ApInt::from(1u8) + ApInt::from(1u16);
+        }
+
+        #[test]
+        #[should_panic(expected = "division failed: Encountered a division-by-zero")]
+        fn division_by_zero() {
+            let _ = ApInt::from(1u8) / ApInt::from(0u8);
+        }
+
+        #[test]
+        #[should_panic(expected = "remainder failed: Encountered a division-by-zero")]
+        fn remainder_by_zero() {
+            let mut x = UInt::from(1u8);
+            x %= UInt::from(0u8);
+        }
+
+        #[test]
+        fn bitwise_ownership() {
+            let a = ApInt::from(0b0110u8);
+            let b = ApInt::from(0b1010u8);
+            for (result, expected) in &[
+                (a.clone() & b.clone(), 0b0010u8),
+                (a.clone() & &b, 0b0010),
+                (&a & b.clone(), 0b0010),
+                (&a & &b, 0b0010),
+                (a.clone() | b.clone(), 0b1110),
+                (a.clone() | &b, 0b1110),
+                (&a | b.clone(), 0b1110),
+                (&a | &b, 0b1110),
+                (a.clone() ^ b.clone(), 0b1100),
+                (a.clone() ^ &b, 0b1100),
+                (&a ^ b.clone(), 0b1100),
+                (&a ^ &b, 0b1100),
+                (!&a, 0b1111_1001),
+                (!a.clone(), 0b1111_1001),
+            ] {
+                assert_eq!(*result, ApInt::from(*expected));
+            }
+            let mut x = a.clone();
+            x &= b.clone();
+            assert_eq!(x, ApInt::from(0b0010u8));
+            x |= b.clone();
+            assert_eq!(x, ApInt::from(0b1010u8));
+            x ^= a;
+            assert_eq!(x, ApInt::from(0b1100u8));
+        }
+
+        #[test]
+        fn bitwise_chained() {
+            let a = UInt::from(0b1111_0110u8);
+            let mask = UInt::from(0b0000_1111u8);
+            let b = UInt::from(0b1000_0000u8);
+            assert_eq!(!(a & &mask) | &b, UInt::from(0b1111_1001u8));
+            let a = Int::from(-1i16);
+            let mask = Int::from(0x00FFi16);
+            assert_eq!(!&mask ^ (&a & &mask), a);
+        }
+
+        #[test]
+        #[should_panic(expected = "bitwise and failed: Encountered invalid operation on \
+                                   entities with non-matching bit-widths of BitWidth(8) \
+                                   and BitWidth(16)")]
+        fn unmatching_bitand() {
+            let _ = ApInt::from(1u8) & ApInt::from(1u16);
+        }
+
+        #[test]
+        #[should_panic(expected = "bitwise xor failed")]
+        fn unmatching_bitxor_assign() {
+            let mut x = Int::from(1i8);
+            x ^= &Int::from(1i16);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod allocations {
+        use super::*;
+        use crate::BitWidth;
+        use std::{
+            alloc::{
+                GlobalAlloc,
+                Layout,
+                System,
+            },
+            cell::Cell,
+        };
+
+        /// Counts the allocations of the current thread.
+        struct CountingAllocator;
+
+        thread_local! {
+            static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+        }
+
+        unsafe impl GlobalAlloc for CountingAllocator {
+            unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+                let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+                System.alloc(layout)
+            }
+
+            unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+                System.dealloc(ptr, layout)
+            }
+        }
+
+        #[global_allocator]
+        static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+        /// Returns the number of allocations of the current thread during `f`.
+        fn count_allocations<F, T>(f: F) -> usize
+        where
+            F: FnOnce() -> T,
+        {
+            let before = ALLOCATIONS.with(Cell::get);
+            let result = f();
+            let after = ALLOCATIONS.with(Cell::get);
+            drop(result);
+            after - before
+        }
+
+        #[test]
+        fn add() {
+            let width = BitWidth::new(300).unwrap();
+            let a = ApInt::all_set(width);
+            let b = ApInt::one(width);
+            assert_eq!(count_allocations(|| &a + &b), 1);
+            let mut c = a.clone();
+            assert_eq!(count_allocations(|| c += &b), 0);
+            assert_eq!(c, ApInt::zero(width));
+            let c = a.clone();
+            assert_eq!(count_allocations(move || c + &b), 0);
+        }
     }
 }
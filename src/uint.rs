@@ -10,6 +10,7 @@ use crate::{
     ApInt,
     BitPos,
     BitWidth,
+    Error,
     Int,
     Result,
     ShiftAmount,
@@ -19,7 +20,10 @@ use crate::{
 #[cfg(feature = "rand_support")]
 use rand;
 
-use core::cmp::Ordering;
+use core::{
+    cmp::Ordering,
+    convert::TryFrom,
+};
 
 /// Unsigned machine integer with arbitrary bitwidths and modulo arithmetics.
 ///
@@ -97,6 +101,12 @@ impl UInt {
         UInt::from(ApInt::from_u128(val))
     }
 
+    /// Creates a new `UInt` from a given `usize` value with a bit-width equal
+    /// to the pointer width of the target platform.
+    pub fn from_usize(val: usize) -> UInt {
+        UInt::from(ApInt::from_usize(val))
+    }
+
     /// Creates a new `UInt` with the given bit width that represents zero.
     pub fn zero(width: BitWidth) -> UInt {
         UInt::from(ApInt::zero(width))
@@ -173,25 +183,24 @@ impl From<u128> for UInt {
     }
 }
 
-macro_rules! impl_from_array_for_uint {
-    ($n:expr) => {
-        impl From<[u64; $n]> for UInt {
-            fn from(val: [u64; $n]) -> UInt {
-                UInt::from(ApInt::from(val))
-            }
-        }
-    };
+impl From<usize> for UInt {
+    fn from(val: usize) -> UInt {
+        UInt::from_usize(val)
+    }
 }
 
-impl_from_array_for_uint!(2); // 128 bits
-impl_from_array_for_uint!(3); // 192 bits
-impl_from_array_for_uint!(4); // 256 bits
-impl_from_array_for_uint!(5); // 320 bits
-impl_from_array_for_uint!(6); // 384 bits
-impl_from_array_for_uint!(7); // 448 bits
-impl_from_array_for_uint!(8); // 512 bits
-impl_from_array_for_uint!(16); // 1024 bits
-impl_from_array_for_uint!(32); // 2048 bits
+/// Creates a new `UInt` with a bit width of `64 * N` from the given array of
+/// 64-bit limbs in big-endian order, i.e. `val[0]` holds the most significant
+/// bits.
+///
+/// # Panics
+///
+/// - If `N` is zero.
+impl<const N: usize> From<[u64; N]> for UInt {
+    fn from(val: [u64; N]) -> UInt {
+        UInt::from(ApInt::from(val))
+    }
+}
 
 /// # Utilities
 impl UInt {
@@ -225,6 +234,13 @@ impl UInt {
     pub fn is_odd(&self) -> bool {
         self.value.is_odd()
     }
+
+    /// Returns `true` if this `UInt` is a power of two.
+    ///
+    /// **Note:** Zero (`0`) is not a power of two.
+    pub fn is_power_of_two(&self) -> bool {
+        self.value.is_power_of_two()
+    }
 }
 
 impl UInt {
@@ -292,37 +308,54 @@ impl UInt {
     }
 }
 
-/// If `self` and `rhs` have unmatching bit widths, `None` will be returned for
-/// `partial_cmp` and `false` will be returned for the rest of the `PartialOrd`
-/// methods.
+/// Compares by the **unsigned** numeric value, also if `self` and `rhs` have
+/// unmatching bit widths.
+///
+/// Two instances of unmatching bit widths are never equal, so if their values
+/// are equal the one with the smaller bit width is ordered first.
+impl Ord for UInt {
+    fn cmp(&self, rhs: &UInt) -> Ordering {
+        self.value
+            .ucmp_value(&rhs.value)
+            .then_with(|| self.width().cmp(&rhs.width()))
+    }
+}
+
 impl PartialOrd for UInt {
     fn partial_cmp(&self, rhs: &UInt) -> Option<Ordering> {
-        if self.width() != rhs.width() {
-            return None
-        }
-        if self.checked_lt(rhs).unwrap() {
-            return Some(Ordering::Less)
-        }
-        if self.value == rhs.value {
-            return Some(Ordering::Equal)
-        }
-        Some(Ordering::Greater)
+        Some(self.cmp(rhs))
     }
+}
 
-    fn lt(&self, rhs: &UInt) -> bool {
-        self.checked_lt(rhs).unwrap_or(false)
+/// Compares the value of `self` with a primitive regardless of the bit width
+/// of `self` and without allocating.
+impl PartialEq<u64> for UInt {
+    fn eq(&self, rhs: &u64) -> bool {
+        self.value.eq_u64(*rhs)
     }
+}
 
-    fn le(&self, rhs: &UInt) -> bool {
-        self.checked_le(rhs).unwrap_or(false)
+/// Compares the value of `self` with a primitive regardless of the bit width
+/// of `self` and without allocating.
+impl PartialOrd<u64> for UInt {
+    fn partial_cmp(&self, rhs: &u64) -> Option<Ordering> {
+        Some(self.value.ucmp_u64(*rhs))
     }
+}
 
-    fn gt(&self, rhs: &UInt) -> bool {
-        self.checked_gt(rhs).unwrap_or(false)
+/// Compares the value of `self` with a primitive regardless of the bit width
+/// of `self` and without allocating.
+impl PartialEq<u128> for UInt {
+    fn eq(&self, rhs: &u128) -> bool {
+        self.value.ucmp_u128(*rhs) == Ordering::Equal
     }
+}
 
-    fn ge(&self, rhs: &UInt) -> bool {
-        self.checked_ge(rhs).unwrap_or(false)
+/// Compares the value of `self` with a primitive regardless of the bit width
+/// of `self` and without allocating.
+impl PartialOrd<u128> for UInt {
+    fn partial_cmp(&self, rhs: &u128) -> Option<Ordering> {
+        Some(self.value.ucmp_u128(*rhs))
     }
 }
 
@@ -399,13 +432,12 @@ impl UInt {
     /// # Note
     ///
     /// This returns `true` if the value represented by this `UInt`
-    /// is `1`, returns `false` if the value represented by this
-    /// `UInt` is `0` and returns an error otherwise.
+    /// is `1` and returns `false` if the value represented by this
+    /// `UInt` is `0`.
     ///
     /// # Errors
     ///
-    /// - If the value represented by this `UInt` can not be represented by a
-    ///   `bool`.
+    /// - If the bit width of this `UInt` is not `1`.
     pub fn try_to_bool(&self) -> Result<bool> {
         self.value.try_to_bool()
     }
@@ -488,8 +520,42 @@ impl UInt {
     pub fn try_to_u128(&self) -> Result<u128> {
         self.value.try_to_u128()
     }
+
+    /// Tries to represent the value of this `UInt` as a `usize`.
+    ///
+    /// # Note
+    ///
+    /// - This conversion is possible as long as the value represented by this
+    ///   `UInt` does not exceed the maximum value of `usize`.
+    ///
+    /// # Errors
+    ///
+    /// - If the value represented by this `UInt` can not be represented by a
+    ///   `usize`.
+    pub fn try_to_usize(&self) -> Result<usize> {
+        self.value.try_to_usize()
+    }
+}
+
+macro_rules! impl_try_from_uint_for_primitive {
+    ($prim:ty, $method:ident) => {
+        impl TryFrom<&UInt> for $prim {
+            type Error = Error;
+
+            fn try_from(value: &UInt) -> Result<$prim> {
+                value.$method()
+            }
+        }
+    };
 }
 
+impl_try_from_uint_for_primitive!(u8, try_to_u8);
+impl_try_from_uint_for_primitive!(u16, try_to_u16);
+impl_try_from_uint_for_primitive!(u32, try_to_u32);
+impl_try_from_uint_for_primitive!(u64, try_to_u64);
+impl_try_from_uint_for_primitive!(u128, try_to_u128);
+impl_try_from_uint_for_primitive!(usize, try_to_usize);
+
 /// # Shifts
 impl UInt {
     /// Shift this `UInt` left by the given `shift_amount` bits.
@@ -910,6 +976,12 @@ impl UInt {
     pub fn trailing_zeros(&self) -> usize {
         self.value.trailing_zeros()
     }
+
+    /// Returns `true` if the number of set bits in the binary representation
+    /// of this `UInt` is odd.
+    pub fn parity(&self) -> bool {
+        self.value.parity()
+    }
 }
 
 /// # Arithmetic Operations
@@ -930,6 +1002,24 @@ impl UInt {
         forward_mut_impl(self, UInt::wrapping_neg)
     }
 
+    /// Negates this `UInt` and returns the result as well as a boolean
+    /// indicating if overflow occured, which is the case for every nonzero
+    /// `UInt`.
+    pub fn overflowing_neg(self) -> (UInt, bool) {
+        let overflow = !self.is_zero();
+        (self.into_wrapping_neg(), overflow)
+    }
+
+    /// Negates this `UInt` and returns the result or `None` if `self` is not
+    /// zero, since no other `UInt` has a representable negation.
+    pub fn checked_neg(self) -> Option<UInt> {
+        if self.is_zero() {
+            Some(self)
+        } else {
+            None
+        }
+    }
+
     /// Adds `rhs` to `self` and returns the result.
     ///
     /// **Note:** This will **not** allocate memory.
@@ -982,6 +1072,106 @@ impl UInt {
         self.value.wrapping_sub_assign(&rhs.value)
     }
 
+    /// Adds `rhs` to `self` and returns the result, or `None` if the
+    /// addition overflowed.
+    ///
+    /// **Note:** This will **not** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn checked_add(self, rhs: &UInt) -> Result<Option<UInt>> {
+        Ok(self
+            .value
+            .into_overflow_checked_uadd(&rhs.value)?
+            .map(UInt::from))
+    }
+
+    /// Subtracts `rhs` from `self` and returns the result, or `None` if the
+    /// subtraction overflowed.
+    ///
+    /// **Note:** This will **not** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn checked_sub(self, rhs: &UInt) -> Result<Option<UInt>> {
+        Ok(self
+            .value
+            .into_overflow_checked_usub(&rhs.value)?
+            .map(UInt::from))
+    }
+
+    /// Multiplies `rhs` with `self` and returns the result, or `None` if the
+    /// multiplication overflowed.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn checked_mul(self, rhs: &UInt) -> Result<Option<UInt>> {
+        Ok(self
+            .value
+            .into_overflow_checked_umul(&rhs.value)?
+            .map(UInt::from))
+    }
+
+    /// Adds `rhs` to `self` and returns the result, clamped to
+    /// the maximum value of the bit width on overflow.
+    ///
+    /// **Note:** This will **not** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn into_saturating_add(self, rhs: &UInt) -> Result<UInt> {
+        try_forward_bin_mut_impl(self, rhs, UInt::saturating_add_assign)
+    }
+
+    /// Add-assigns `rhs` to `self` inplace, clamping the result to
+    /// the maximum value of the bit width on overflow.
+    ///
+    /// **Note:** This will **not** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn saturating_add_assign(&mut self, rhs: &UInt) -> Result<()> {
+        self.value.saturating_uadd_assign(&rhs.value)
+    }
+
+    /// Subtracts `rhs` from `self` and returns the result, clamped to
+    /// zero on overflow.
+    ///
+    /// **Note:** This will **not** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn into_saturating_sub(self, rhs: &UInt) -> Result<UInt> {
+        try_forward_bin_mut_impl(self, rhs, UInt::saturating_sub_assign)
+    }
+
+    /// Subtract-assigns `rhs` from `self` inplace, clamping the result to
+    /// zero on overflow.
+    ///
+    /// **Note:** This will **not** allocate memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn saturating_sub_assign(&mut self, rhs: &UInt) -> Result<()> {
+        self.value.saturating_usub_assign(&rhs.value)
+    }
+
+    /// Returns the absolute difference between `self` and `rhs`.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn abs_diff(&self, rhs: &UInt) -> Result<UInt> {
+        Ok(UInt::from(self.value.abs_diff(&rhs.value)?))
+    }
+
     /// Subtracts `rhs` from `self` and returns the result.
     ///
     /// # Note
@@ -1012,6 +1202,57 @@ impl UInt {
         self.value.wrapping_mul_assign(&rhs.value)
     }
 
+    /// Multiplies `rhs` with `self` and returns the result, clamped to the
+    /// maximum value of the bit width on overflow.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn into_saturating_mul(self, rhs: &UInt) -> Result<UInt> {
+        try_forward_bin_mut_impl(self, rhs, UInt::saturating_mul_assign)
+    }
+
+    /// Multiply-assigns `rhs` to `self` inplace, clamping the result to the
+    /// maximum value of the bit width on overflow.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn saturating_mul_assign(&mut self, rhs: &UInt) -> Result<()> {
+        self.value.saturating_umul_assign(&rhs.value)
+    }
+
+    /// Raises `self` to the power of `exp` and returns the result, wrapping
+    /// around on overflow.
+    ///
+    /// **Note:** Zero to the power of zero yields one.
+    pub fn into_wrapping_pow(self, exp: u32) -> UInt {
+        UInt::from(self.value.into_wrapping_pow(exp))
+    }
+
+    /// Raises `self` to the power of `exp` inplace, wrapping around on
+    /// overflow.
+    ///
+    /// **Note:** Zero to the power of zero yields one.
+    pub fn wrapping_pow_assign(&mut self, exp: u32) {
+        self.value.wrapping_pow_assign(exp)
+    }
+
+    /// Raises `self` to the power of `exp` and returns the result as well as a
+    /// boolean indicating if overflow occured.
+    pub fn overflowing_pow(self, exp: u32) -> (UInt, bool) {
+        let (pow, overflow) = self.value.into_overflowing_upow(exp);
+        (UInt::from(pow), overflow)
+    }
+
+    /// Returns `self` to the power of `exp` or `None` if the result is not
+    /// representable by the bit width of `self`.
+    ///
+    /// **Note:** This function allocates memory.
+    pub fn checked_pow(&self, exp: u32) -> Option<UInt> {
+        self.value.checked_upow(exp).map(UInt::from)
+    }
+
     /// Divides `self` by `rhs` and returns the result.
     ///
     /// # Note
@@ -1024,6 +1265,7 @@ impl UInt {
     /// # Errors
     ///
     /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
     pub fn into_wrapping_div(self, rhs: &UInt) -> Result<UInt> {
         try_forward_bin_mut_impl(self, rhs, UInt::wrapping_div_assign)
     }
@@ -1040,6 +1282,7 @@ impl UInt {
     /// # Errors
     ///
     /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
     pub fn wrapping_div_assign(&mut self, rhs: &UInt) -> Result<()> {
         self.value.wrapping_udiv_assign(&rhs.value)
     }
@@ -1057,6 +1300,7 @@ impl UInt {
     /// # Errors
     ///
     /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
     pub fn into_wrapping_rem(self, rhs: &UInt) -> Result<UInt> {
         try_forward_bin_mut_impl(self, rhs, UInt::wrapping_rem_assign)
     }
@@ -1073,38 +1317,179 @@ impl UInt {
     /// # Errors
     ///
     /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
     pub fn wrapping_rem_assign(&mut self, rhs: &UInt) -> Result<()> {
         self.value.wrapping_urem_assign(&rhs.value)
     }
+
+    /// Calculates the quotient of `self` by `rhs` rounded towards negative
+    /// infinity and returns the result.
+    ///
+    /// This is the same as `into_wrapping_div`.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn into_div_floor(self, rhs: &UInt) -> Result<UInt> {
+        try_forward_bin_mut_impl(self, rhs, UInt::div_floor_assign)
+    }
+
+    /// Assignes `self` to the quotient of `self` by `rhs` rounded towards
+    /// negative infinity.
+    ///
+    /// This is the same as `wrapping_div_assign`.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn div_floor_assign(&mut self, rhs: &UInt) -> Result<()> {
+        self.value.div_floor_assign(&rhs.value)
+    }
+
+    /// Calculates the quotient of `self` by `rhs` rounded towards positive
+    /// infinity and returns the result.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn into_div_ceil(self, rhs: &UInt) -> Result<UInt> {
+        try_forward_bin_mut_impl(self, rhs, UInt::div_ceil_assign)
+    }
+
+    /// Assignes `self` to the quotient of `self` by `rhs` rounded towards
+    /// positive infinity.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn div_ceil_assign(&mut self, rhs: &UInt) -> Result<()> {
+        self.value.div_ceil_assign(&rhs.value)
+    }
+
+    /// Returns the smallest multiple of `rhs` that is greater than or equal to
+    /// `self`, or `None` if it is not representable by the bit width.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    /// - If division by zero is attempted
+    pub fn checked_next_multiple_of(&self, rhs: &UInt) -> Result<Option<UInt>> {
+        Ok(self
+            .value
+            .checked_next_multiple_of(&rhs.value)?
+            .map(UInt::from))
+    }
+
+    /// Returns the smallest power of two that is greater than or equal to
+    /// `self`.
+    ///
+    /// # Note
+    ///
+    /// - Zero (`0`) results in one (`1`).
+    /// - If the next power of two is not representable by the bit width of
+    ///   `self` the result wraps around to zero (`0`).
+    /// - This operation will **not** allocate memory and computes inplace of
+    ///   `self`.
+    pub fn into_next_power_of_two(self) -> UInt {
+        UInt::from(self.value.into_next_power_of_two())
+    }
+
+    /// Returns the smallest power of two that is greater than or equal to
+    /// `self` or `None` if it is not representable by the bit width of `self`.
+    ///
+    /// **Note:** This function **may** allocate memory.
+    pub fn checked_next_power_of_two(&self) -> Option<UInt> {
+        self.value.checked_next_power_of_two().map(UInt::from)
+    }
+
+    /// Returns the least common multiple of `self` and `rhs` with a bit width
+    /// of twice the bit width of `self` and `rhs`, which can always represent
+    /// it.
+    ///
+    /// **Note:** The lcm of zero and any `x` is zero. This function allocates
+    /// memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn lcm(&self, rhs: &UInt) -> Result<UInt> {
+        self.value.widening_lcm(&rhs.value).map(UInt::from)
+    }
+
+    /// Returns the least common multiple of `self` and `rhs` or `None` if it
+    /// is not representable by the bit width of `self` and `rhs`.
+    ///
+    /// **Note:** The lcm of zero and any `x` is zero. This function allocates
+    /// memory.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` and `rhs` have unmatching bit widths.
+    pub fn checked_lcm(&self, rhs: &UInt) -> Result<Option<UInt>> {
+        Ok(self.value.checked_lcm(&rhs.value)?.map(UInt::from))
+    }
+
+    /// Returns the base 2 logarithm of this `UInt` rounded down, or `None` if
+    /// it is zero.
+    pub fn checked_ilog2(&self) -> Option<usize> {
+        self.value.checked_ilog2()
+    }
+
+    /// Returns the base 10 logarithm of this `UInt` rounded down, or `None` if
+    /// it is zero.
+    ///
+    /// **Note:** This function allocates memory.
+    pub fn checked_ilog10(&self) -> Option<usize> {
+        self.value.checked_ilog10()
+    }
+
+    /// Returns the logarithm of this `UInt` with respect to `base` rounded
+    /// down, or `None` if it is zero or `base` is less than 2.
+    ///
+    /// **Note:** This function allocates memory.
+    pub fn checked_ilog(&self, base: u64) -> Option<usize> {
+        self.value.checked_ilog(base)
+    }
 }
 
 // ============================================================================
-//  Binary, Oct, LowerHex and UpperHex implementations
+//  Binary, Oct, LowerHex, UpperHex and Display implementations
 // ============================================================================
 
 use core::fmt;
 
 impl fmt::Binary for UInt {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.value.fmt(f)
+        fmt::Binary::fmt(&self.value, f)
     }
 }
 
 impl fmt::Octal for UInt {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.value.fmt(f)
+        fmt::Octal::fmt(&self.value, f)
     }
 }
 
 impl fmt::LowerHex for UInt {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.value.fmt(f)
+        fmt::LowerHex::fmt(&self.value, f)
     }
 }
 
 impl fmt::UpperHex for UInt {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.value.fmt(f)
+        fmt::UpperHex::fmt(&self.value, f)
+    }
+}
+
+impl fmt::Display for UInt {
+    /// Formats the **unsigned** decimal value of this `UInt`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
     }
 }
 
@@ -1129,6 +1514,119 @@ mod tests {
             );
         }
 
+        #[test]
+        fn neg() {
+            let zero = UInt::zero(BitWidth::w8());
+            assert_eq!(zero.clone().checked_neg(), Some(zero.clone()));
+            assert_eq!(zero.clone().overflowing_neg(), (zero, false));
+            assert_eq!(UInt::from_u8(1).checked_neg(), None);
+            assert_eq!(
+                UInt::from_u8(1).overflowing_neg(),
+                (UInt::from_u8(u8::MAX), true)
+            );
+        }
+
+        #[test]
+        fn ilog() {
+            assert_eq!(UInt::zero(BitWidth::w64()).checked_ilog2(), None);
+            assert_eq!(UInt::from_u64(1000).checked_ilog2(), Some(9));
+            assert_eq!(UInt::from_u64(1000).checked_ilog10(), Some(3));
+            assert_eq!(UInt::from_u64(999).checked_ilog10(), Some(2));
+            assert_eq!(UInt::from_u64(1000).checked_ilog(31), Some(2));
+            assert_eq!(UInt::from_u64(1000).checked_ilog(1), None);
+        }
+
+        #[test]
+        fn abs_diff() {
+            let (a, b) = (UInt::from_u8(3), UInt::from_u8(250));
+            assert_eq!(a.abs_diff(&b).unwrap(), UInt::from_u8(247));
+            assert_eq!(b.abs_diff(&a).unwrap(), UInt::from_u8(247));
+            assert_eq!(a.abs_diff(&a).unwrap(), UInt::from_u8(0));
+            assert!(a.abs_diff(&UInt::from_u16(3)).is_err());
+        }
+
+        #[test]
+        fn ord() {
+            use crate::mem::collections::BTreeMap;
+            let mut v = vec![
+                UInt::from_u64(300),
+                UInt::from_u8(7),
+                UInt::from([1u64, 0]),
+                UInt::from_u16(7),
+                UInt::from_u8(0),
+                UInt::from_u8(u8::MAX),
+            ];
+            v.sort();
+            assert_eq!(
+                v,
+                vec![
+                    UInt::from_u8(0),
+                    UInt::from_u8(7),
+                    UInt::from_u16(7),
+                    UInt::from_u8(u8::MAX),
+                    UInt::from_u64(300),
+                    UInt::from([1u64, 0]),
+                ]
+            );
+            assert!(UInt::from_u8(7) < UInt::from_u64(8));
+            assert!(UInt::from_u64(7) > UInt::from_u8(6));
+            let mut map = BTreeMap::new();
+            map.insert(UInt::from_u32(5), "five");
+            map.insert(UInt::from_u32(1), "one");
+            map.insert(UInt::from_u8(5), "narrow five");
+            assert_eq!(map.get(&UInt::from_u32(5)), Some(&"five"));
+            assert_eq!(
+                map.values().cloned().collect::<Vec<_>>(),
+                vec!["one", "narrow five", "five"]
+            );
+        }
+
+        #[test]
+        fn cmp_primitive() {
+            let x = UInt::from_u8(200);
+            assert!(x == 200u64);
+            assert!(x != 201u64);
+            assert!(x > 7u64);
+            assert!(x < 1u128 << 100);
+            let mut y = UInt::from([1u64, 0, 0, 5]);
+            assert!(y > u64::MAX);
+            assert!(y > u128::MAX);
+            y.unset_bit_at(192).unwrap();
+            assert!(y == 5u64);
+            assert!(y == 5u128);
+            assert!(y <= 5u64);
+        }
+
+        #[test]
+        fn saturating_mul() {
+            let (a, b) = (UInt::from_u8(15), UInt::from_u8(17));
+            assert_eq!(
+                a.clone().into_saturating_mul(&b).unwrap(),
+                UInt::from_u8(255)
+            );
+            assert_eq!(
+                b.into_saturating_mul(&UInt::from_u8(16)).unwrap(),
+                UInt::from_u8(u8::MAX)
+            );
+            assert!(a.into_saturating_mul(&UInt::from_u16(3)).is_err());
+        }
+
+        #[test]
+        fn div_ceil() {
+            let (a, b) = (UInt::from_u8(250), UInt::from_u8(7));
+            assert_eq!(a.clone().into_div_ceil(&b).unwrap(), UInt::from_u8(36));
+            assert_eq!(a.clone().into_div_floor(&b).unwrap(), UInt::from_u8(35));
+            assert_eq!(
+                a.checked_next_multiple_of(&b).unwrap(),
+                Some(UInt::from_u8(252))
+            );
+            assert_eq!(
+                UInt::from_u8(253).checked_next_multiple_of(&b).unwrap(),
+                None
+            );
+            assert!(a.into_div_ceil(&UInt::from_u8(0)).is_err());
+        }
+
         #[test]
         fn count() {
             assert_eq!(UInt::one(BitWidth::w1()).count_ones(), 1);
@@ -1159,5 +1657,41 @@ mod tests {
             assert_eq!(UInt::one(BitWidth::w64()).trailing_zeros(), 0);
             assert_eq!(UInt::one(BitWidth::w128()).trailing_zeros(), 0);
         }
+
+        #[test]
+        fn try_to_primitive() {
+            let w65 = BitWidth::new(65).unwrap();
+            let max =
+                UInt::from(ApInt::from_u64(u64::MAX).into_zero_extend(w65).unwrap());
+            assert_eq!(max.try_to_u64(), Ok(u64::MAX));
+            assert_eq!(max.try_to_usize(), Ok(u64::MAX as usize));
+            assert!(max.try_to_u32().is_err());
+            let above_max = max.into_wrapping_add(&UInt::one(w65)).unwrap();
+            assert!(above_max.try_to_u64().is_err());
+            assert!(above_max.try_to_usize().is_err());
+            assert_eq!(above_max.try_to_u128(), Ok(1 << 64));
+            assert_eq!(UInt::from_u8(255).try_to_usize(), Ok(255));
+        }
+
+        #[test]
+        fn try_from() {
+            let max =
+                UInt::from(ApInt::from_u64(u64::MAX).into_zero_resize(BitWidth::w128()));
+            assert_eq!(u64::try_from(&max), max.try_to_u64());
+            assert_eq!(u64::try_from(&max), Ok(u64::MAX));
+            assert_eq!(usize::try_from(&max), max.try_to_usize());
+            assert_eq!(u32::try_from(&max), max.try_to_u32());
+            assert!(u8::try_from(&max).is_err());
+            assert_eq!(u128::try_from(&max), Ok(u128::from(u64::MAX)));
+        }
+
+        #[test]
+        fn from_usize() {
+            for &val in &[0, 1, usize::MAX] {
+                assert_eq!(UInt::from_usize(val), UInt::from(ApInt::from_usize(val)));
+                assert_eq!(UInt::from(val), UInt::from_usize(val));
+                assert_eq!(UInt::from_usize(val).try_to_usize(), Ok(val));
+            }
+        }
     }
 }